@@ -0,0 +1,42 @@
+use chrono::{Datelike, NaiveDate, NaiveTime, TimeZone, Utc, Weekday};
+use chrono_tz::America::New_York;
+
+use calendar_mod_io::calendar as io;
+
+/// Every weekday is a trading day with the regular session.
+pub struct ServiceFake;
+
+impl io::Api for ServiceFake {
+    fn get_holiday(&self, _date: &NaiveDate) -> Option<io::Holiday> {
+        None
+    }
+
+    fn is_trading_day(&self, date: &NaiveDate) -> bool {
+        !matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
+    }
+
+    fn trading_days(&self, from: &NaiveDate, to: &NaiveDate) -> Vec<NaiveDate> {
+        from.iter_days()
+            .take_while(|date| date <= to)
+            .filter(|date| self.is_trading_day(date))
+            .collect()
+    }
+
+    fn get_session(&self, date: &NaiveDate) -> Option<io::Session> {
+        if !self.is_trading_day(date) {
+            return None;
+        }
+        let new_york = |time: NaiveTime| {
+            New_York
+                .from_local_datetime(&date.and_time(time))
+                .unwrap()
+                .with_timezone(&Utc)
+        };
+        Some(io::Session {
+            open: new_york(NaiveTime::from_hms_opt(9, 30, 0).unwrap()),
+            close: new_york(NaiveTime::from_hms_opt(16, 0, 0).unwrap()),
+        })
+    }
+
+    fn update_holidays(&self, _holidays: &[io::Holiday]) {}
+}
@@ -0,0 +1,2 @@
+#[path = "calendar-fixture.rs"]
+pub mod calendar_fixture;
@@ -0,0 +1,74 @@
+use std::{collections::HashMap, sync::RwLock};
+
+use chrono::{DateTime, Datelike, NaiveDate, NaiveTime, TimeZone, Utc, Weekday};
+use chrono_tz::America::New_York;
+
+use crate::calendar_rules;
+use calendar_mod_io::calendar as io;
+
+/// The service to implement the API of Calendar.
+#[derive(Debug, Default)]
+pub struct Service {
+    updated: RwLock<HashMap<NaiveDate, io::Holiday>>,
+}
+
+impl Service {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl io::Api for Service {
+    fn get_holiday(&self, date: &NaiveDate) -> Option<io::Holiday> {
+        let updated = self.updated.read().unwrap().get(date).cloned();
+        updated.or_else(|| calendar_rules::get_holiday(date))
+    }
+
+    fn is_trading_day(&self, date: &NaiveDate) -> bool {
+        match date.weekday() {
+            Weekday::Sat | Weekday::Sun => false,
+            _ => !matches!(
+                self.get_holiday(date),
+                Some(io::Holiday {
+                    status: io::Status::Closed,
+                    ..
+                })
+            ),
+        }
+    }
+
+    fn trading_days(&self, from: &NaiveDate, to: &NaiveDate) -> Vec<NaiveDate> {
+        from.iter_days()
+            .take_while(|date| date <= to)
+            .filter(|date| self.is_trading_day(date))
+            .collect()
+    }
+
+    fn get_session(&self, date: &NaiveDate) -> Option<io::Session> {
+        if !self.is_trading_day(date) {
+            return None;
+        }
+        let close = match self.get_holiday(date) {
+            Some(_) => NaiveTime::from_hms_opt(13, 0, 0).unwrap(),
+            None => NaiveTime::from_hms_opt(16, 0, 0).unwrap(),
+        };
+        Some(io::Session {
+            open: new_york(date, NaiveTime::from_hms_opt(9, 30, 0).unwrap()),
+            close: new_york(date, close),
+        })
+    }
+
+    fn update_holidays(&self, holidays: &[io::Holiday]) {
+        let mut updated = self.updated.write().unwrap();
+        for holiday in holidays {
+            updated.insert(holiday.date, holiday.clone());
+        }
+    }
+}
+
+fn new_york(date: &NaiveDate, time: NaiveTime) -> DateTime<Utc> {
+    New_York
+        .from_local_datetime(&date.and_time(time))
+        .unwrap()
+        .with_timezone(&Utc)
+}
@@ -0,0 +1,141 @@
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+
+use calendar_mod_io::calendar::{Holiday, Status};
+
+/// The closures that no rule can compute, like the national days of mourning.
+pub const UNSCHEDULED: [(i32, u32, u32, &str); 11] = [
+    (1994, 4, 27, "National Day of Mourning for Richard Nixon"),
+    (2001, 9, 11, "September 11 Attacks"),
+    (2001, 9, 12, "September 11 Attacks"),
+    (2001, 9, 13, "September 11 Attacks"),
+    (2001, 9, 14, "September 11 Attacks"),
+    (2004, 6, 11, "National Day of Mourning for Ronald Reagan"),
+    (2007, 1, 2, "National Day of Mourning for Gerald Ford"),
+    (2012, 10, 29, "Hurricane Sandy"),
+    (2012, 10, 30, "Hurricane Sandy"),
+    (
+        2018,
+        12,
+        5,
+        "National Day of Mourning for George H. W. Bush",
+    ),
+    (2025, 1, 9, "National Day of Mourning for Jimmy Carter"),
+];
+
+/// Returns the holiday or the early close on the date by the rules of the NYSE, or the
+/// unscheduled closures.
+pub fn get_holiday(date: &NaiveDate) -> Option<Holiday> {
+    let (name, status) = unscheduled(date)
+        .map(|name| (name, Status::Closed))
+        .or_else(|| closed(date).map(|name| (name, Status::Closed)))
+        .or_else(|| early_close(date).map(|name| (name, Status::EarlyClose)))?;
+    Some(Holiday {
+        date: *date,
+        name: name.into(),
+        status,
+    })
+}
+
+fn unscheduled(date: &NaiveDate) -> Option<&'static str> {
+    UNSCHEDULED
+        .iter()
+        .find(|(y, m, d, _)| NaiveDate::from_ymd_opt(*y, *m, *d).as_ref() == Some(date))
+        .map(|(_, _, _, name)| *name)
+}
+
+fn closed(date: &NaiveDate) -> Option<&'static str> {
+    let year = date.year();
+    // The NYSE doesn't close on Friday, December 31, for a New Year's Day on Saturday.
+    if *date == ymd(year, 1, 1) && date.weekday() != Weekday::Sat
+        || *date == ymd(year, 1, 2) && date.weekday() == Weekday::Mon
+    {
+        return Some("New Year's Day");
+    }
+    if year >= 1998 && *date == nth_weekday(year, 1, Weekday::Mon, 3) {
+        return Some("Martin Luther King, Jr. Day");
+    }
+    if *date == nth_weekday(year, 2, Weekday::Mon, 3) {
+        return Some("Washington's Birthday");
+    }
+    if *date == easter(year) - Duration::try_days(2).unwrap() {
+        return Some("Good Friday");
+    }
+    if *date == last_weekday(year, 5, Weekday::Mon) {
+        return Some("Memorial Day");
+    }
+    if year >= 2022 && *date == observed(ymd(year, 6, 19)) {
+        return Some("Juneteenth National Independence Day");
+    }
+    if *date == observed(ymd(year, 7, 4)) {
+        return Some("Independence Day");
+    }
+    if *date == nth_weekday(year, 9, Weekday::Mon, 1) {
+        return Some("Labor Day");
+    }
+    if *date == nth_weekday(year, 11, Weekday::Thu, 4) {
+        return Some("Thanksgiving Day");
+    }
+    if *date == observed(ymd(year, 12, 25)) {
+        return Some("Christmas Day");
+    }
+    None
+}
+
+fn early_close(date: &NaiveDate) -> Option<&'static str> {
+    let year = date.year();
+    let before_weekend_or_holiday = matches!(
+        date.weekday(),
+        Weekday::Mon | Weekday::Tue | Weekday::Wed | Weekday::Thu
+    );
+    if *date == ymd(year, 7, 3) && before_weekend_or_holiday {
+        return Some("Independence Day");
+    }
+    if *date == nth_weekday(year, 11, Weekday::Thu, 4) + Duration::try_days(1).unwrap() {
+        return Some("Thanksgiving Day");
+    }
+    if *date == ymd(year, 12, 24) && before_weekend_or_holiday {
+        return Some("Christmas Day");
+    }
+    None
+}
+
+/// A holiday on Saturday is observed on Friday, and on Sunday is observed on Monday.
+fn observed(date: NaiveDate) -> NaiveDate {
+    match date.weekday() {
+        Weekday::Sat => date.pred_opt().unwrap(),
+        Weekday::Sun => date.succ_opt().unwrap(),
+        _ => date,
+    }
+}
+
+fn nth_weekday(year: i32, month: u32, weekday: Weekday, n: u8) -> NaiveDate {
+    NaiveDate::from_weekday_of_month_opt(year, month, weekday, n).unwrap()
+}
+
+fn last_weekday(year: i32, month: u32, weekday: Weekday) -> NaiveDate {
+    NaiveDate::from_weekday_of_month_opt(year, month, weekday, 5)
+        .unwrap_or_else(|| nth_weekday(year, month, weekday, 4))
+}
+
+fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+/// The Easter Sunday of the Gregorian calendar, by the anonymous algorithm.
+pub fn easter(year: i32) -> NaiveDate {
+    let a = year % 19;
+    let b = year / 100;
+    let c = year % 100;
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+    ymd(year, month as u32, day as u32)
+}
@@ -0,0 +1,7 @@
+#[path = "calendar-impl.rs"]
+pub mod calendar_impl;
+#[path = "calendar-rules.rs"]
+pub mod calendar_rules;
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,154 @@
+use chrono::{NaiveDate, TimeZone, Utc};
+use rstest::rstest;
+
+use crate::calendar_impl::Service;
+use crate::calendar_rules::easter;
+use calendar_mod_io::calendar as io;
+use io::Api;
+
+#[rstest]
+#[case(2024, 3, 31)]
+#[case(2025, 4, 20)]
+#[case(2026, 4, 5)]
+#[case(2038, 4, 25)]
+fn easter_sunday(#[case] year: i32, #[case] month: u32, #[case] day: u32) {
+    assert_eq!(
+        NaiveDate::from_ymd_opt(year, month, day).unwrap(),
+        easter(year)
+    );
+}
+
+#[rstest]
+#[case(2012, 10, 29, "Hurricane Sandy")]
+#[case(2012, 10, 30, "Hurricane Sandy")]
+#[case(2024, 1, 1, "New Year's Day")]
+#[case(2024, 1, 15, "Martin Luther King, Jr. Day")]
+#[case(2024, 2, 19, "Washington's Birthday")]
+#[case(2024, 3, 29, "Good Friday")]
+#[case(2024, 5, 27, "Memorial Day")]
+#[case(2024, 6, 19, "Juneteenth National Independence Day")]
+#[case(2024, 7, 4, "Independence Day")]
+#[case(2024, 9, 2, "Labor Day")]
+#[case(2024, 11, 28, "Thanksgiving Day")]
+#[case(2024, 12, 25, "Christmas Day")]
+#[case(2025, 1, 9, "National Day of Mourning for Jimmy Carter")]
+#[case(2026, 7, 3, "Independence Day")]
+#[case(2027, 6, 18, "Juneteenth National Independence Day")]
+#[case(2027, 12, 24, "Christmas Day")]
+fn closed_on_holiday(#[case] year: i32, #[case] month: u32, #[case] day: u32, #[case] name: &str) {
+    let service = Service::new();
+    let date = NaiveDate::from_ymd_opt(year, month, day).unwrap();
+
+    assert!(!service.is_trading_day(&date));
+    assert_eq!(
+        Some(io::Holiday {
+            date,
+            name: name.into(),
+            status: io::Status::Closed,
+        }),
+        service.get_holiday(&date)
+    );
+}
+
+#[rstest]
+#[case(2024, 7, 3)]
+#[case(2024, 11, 29)]
+#[case(2024, 12, 24)]
+#[case(2025, 7, 3)]
+fn early_close_session(#[case] year: i32, #[case] month: u32, #[case] day: u32) {
+    let service = Service::new();
+    let date = NaiveDate::from_ymd_opt(year, month, day).unwrap();
+
+    let session = service.get_session(&date).unwrap();
+
+    assert_eq!(
+        Some(io::Status::EarlyClose),
+        service.get_holiday(&date).map(|h| h.status)
+    );
+    assert_eq!(210, (session.close - session.open).num_minutes());
+}
+
+#[rstest]
+// New Year's Day on Saturday isn't observed on the Friday before.
+#[case(2021, 12, 31)]
+// The day before an observed Independence Day on Friday closes as usual.
+#[case(2026, 7, 2)]
+#[case(2024, 4, 17)]
+fn regular_session(#[case] year: i32, #[case] month: u32, #[case] day: u32) {
+    let service = Service::new();
+    let date = NaiveDate::from_ymd_opt(year, month, day).unwrap();
+
+    let session = service.get_session(&date).unwrap();
+
+    assert_eq!(None, service.get_holiday(&date));
+    assert_eq!(390, (session.close - session.open).num_minutes());
+}
+
+#[test]
+fn session_in_daylight_saving_time() {
+    let service = Service::new();
+
+    let summer = service
+        .get_session(&NaiveDate::from_ymd_opt(2024, 4, 17).unwrap())
+        .unwrap();
+    let winter = service
+        .get_session(&NaiveDate::from_ymd_opt(2024, 1, 17).unwrap())
+        .unwrap();
+
+    assert_eq!(
+        Utc.with_ymd_and_hms(2024, 4, 17, 13, 30, 0).unwrap(),
+        summer.open
+    );
+    assert_eq!(
+        Utc.with_ymd_and_hms(2024, 4, 17, 20, 0, 0).unwrap(),
+        summer.close
+    );
+    assert_eq!(
+        Utc.with_ymd_and_hms(2024, 1, 17, 14, 30, 0).unwrap(),
+        winter.open
+    );
+}
+
+#[rstest]
+#[case(2012, 250)]
+#[case(2023, 250)]
+#[case(2024, 252)]
+#[case(2025, 250)]
+fn trading_days_of_year(#[case] year: i32, #[case] count: usize) {
+    let service = Service::new();
+
+    let days = service.trading_days(
+        &NaiveDate::from_ymd_opt(year, 1, 1).unwrap(),
+        &NaiveDate::from_ymd_opt(year, 12, 31).unwrap(),
+    );
+
+    assert_eq!(count, days.len());
+    assert_eq!(
+        None,
+        service.get_session(&NaiveDate::from_ymd_opt(year, 1, 1).unwrap())
+    );
+}
+
+#[test]
+fn updated_holidays_override_the_rules() {
+    let service = Service::new();
+    let closure = NaiveDate::from_ymd_opt(2024, 4, 17).unwrap();
+    let christmas_eve = NaiveDate::from_ymd_opt(2024, 12, 24).unwrap();
+
+    service.update_holidays(&[
+        io::Holiday {
+            date: closure,
+            name: "Unscheduled Closure".into(),
+            status: io::Status::Closed,
+        },
+        io::Holiday {
+            date: christmas_eve,
+            name: "Christmas Eve".into(),
+            status: io::Status::Closed,
+        },
+    ]);
+
+    assert!(!service.is_trading_day(&closure));
+    assert!(!service.is_trading_day(&christmas_eve));
+    assert_eq!(None, service.get_session(&closure));
+}
@@ -0,0 +1,41 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, NaiveDate, Utc};
+
+/// How the market deviates from the regular session on a holiday.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Status {
+    /// The market is closed the whole day.
+    Closed,
+    /// The market closes at 13:00 in New York.
+    EarlyClose,
+}
+
+/// A day that the NYSE is closed or closes early, other than the weekends.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Holiday {
+    pub date: NaiveDate,
+    pub name: Arc<str>,
+    pub status: Status,
+}
+
+/// The regular trading session of a trading day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Session {
+    pub open: DateTime<Utc>,
+    pub close: DateTime<Utc>,
+}
+
+/// The API of Calendar, which defines all the functionality of Calendar modules.
+pub trait Api {
+    /// Returns the holiday or the early close on the date, if any.
+    fn get_holiday(&self, date: &NaiveDate) -> Option<Holiday>;
+    fn is_trading_day(&self, date: &NaiveDate) -> bool;
+    /// Returns the trading days from `from` to `to`, both inclusive, in ascending order.
+    fn trading_days(&self, from: &NaiveDate, to: &NaiveDate) -> Vec<NaiveDate>;
+    /// Returns the regular trading session of the date, or `None` if the market is closed.
+    fn get_session(&self, date: &NaiveDate) -> Option<Session>;
+    /// Overrides the computed holidays with the announced ones, e.g. an unscheduled closure
+    /// from the upcoming market status of Polygon.io.
+    fn update_holidays(&self, holidays: &[Holiday]);
+}
@@ -0,0 +1,272 @@
+use std::{collections::HashSet, sync::Arc};
+
+use async_trait::async_trait;
+use calendar_mod_io::calendar;
+use chrono::{DateTime, Duration, NaiveDate, NaiveTime, TimeZone, Utc};
+use chrono_tz::America::New_York;
+use snp_mod_io::snp;
+
+use crawler_mod_aggregates_io::crawler_aggregates as io;
+use database_mod_aggregates_io::aggregates as database;
+use database_mod_ticker_events_io::ticker_events as ticker_events_database;
+use network_mod_aggregates_io::aggregates as network;
+use policy_mod_io::policy;
+
+#[derive(Debug)]
+pub struct Service;
+
+/// The service to implement the API of Aggregates.
+impl Service {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Default for Service {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl io::Api for Service {
+    async fn crawl_aggregates(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        data: &io::Data,
+        policy_data: &mut policy::Data,
+    ) -> io::Result<()> {
+        if !deps.provide_calendar().is_trading_day(&data.date) {
+            return Ok(());
+        }
+
+        let policy = deps.provide_policy().clone();
+
+        let snp = deps.provide_snp().clone();
+        let network_service = deps.provide_network_aggregates().clone();
+        let database_service = deps.provide_database_aggregates().clone();
+        let database_depends = Arc::new(DatabaseDepends { snp: snp.clone() });
+        // The bars of a renamed ticker are found by its symbol on the date.
+        let network_depends = Arc::new(NetworkDepends {
+            snp: deps
+                .provide_database_ticker_events()
+                .historical_snp(database_depends.clone(), &data.ticker, &data.date)
+                .await?,
+        });
+
+        let day_fetch = to_fetch(data, database::Timespan::Day);
+        let (day_start, day_end) = day(&data.date);
+        let day_bars = database_service
+            .find_bars(
+                database_depends.clone(),
+                &data.ticker,
+                database::Timespan::Day,
+                &day_start,
+                &day_end,
+            )
+            .await?;
+
+        let traded = if !day_bars.is_empty() {
+            true
+        } else if database_service
+            .is_fetched(database_depends.clone(), &day_fetch)
+            .await?
+        {
+            false
+        } else {
+            let request = to_request(data, network::Timespan::Day);
+            let response = network_service
+                .get_aggregates(network_depends.clone(), &request)
+                .await;
+            policy.handle_request_rate(policy_data).await?;
+            let network_bars = response?;
+            database_service
+                .save_bars(
+                    database_depends.clone(),
+                    &to_bars(&network_bars, &data.ticker, database::Timespan::Day),
+                )
+                .await?;
+            database_service
+                .save_fetch(database_depends.clone(), &day_fetch)
+                .await?;
+            !network_bars.is_empty()
+        };
+        // No daily bar means the ticker wasn't traded, so there's no minute bar either.
+        if !traded {
+            return Ok(());
+        }
+
+        let minute_fetch = to_fetch(data, database::Timespan::Minute);
+        let missing = self.find_missing_bars(deps.clone(), data).await?;
+        // A minute without a trade has no bar, so a fetched day is requested again only for a
+        // gap that's too long to be quiet minutes, like the rest of a session that was
+        // requested before its close.
+        if missing.is_empty()
+            || (longest_gap(&missing) < GAP_MINUTES
+                && database_service
+                    .is_fetched(database_depends.clone(), &minute_fetch)
+                    .await?)
+        {
+            return Ok(());
+        }
+
+        let request = to_request(data, network::Timespan::Minute);
+        let response = network_service
+            .get_aggregates(network_depends.clone(), &request)
+            .await;
+        policy.handle_request_rate(policy_data).await?;
+        let network_bars = response?;
+        database_service
+            .save_bars(
+                database_depends.clone(),
+                &to_bars(&network_bars, &data.ticker, database::Timespan::Minute),
+            )
+            .await?;
+        database_service
+            .save_fetch(database_depends.clone(), &minute_fetch)
+            .await?;
+        Ok(())
+    }
+
+    async fn find_missing_bars(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        data: &io::Data,
+    ) -> io::Result<Vec<DateTime<Utc>>> {
+        let snp = deps.provide_snp().clone();
+        let database_service = deps.provide_database_aggregates().clone();
+        let database_depends = Arc::new(DatabaseDepends { snp: snp.clone() });
+
+        let Some(calendar::Session { open, close }) =
+            deps.provide_calendar().get_session(&data.date)
+        else {
+            return Ok(vec![]);
+        };
+        let stored: HashSet<DateTime<Utc>> = database_service
+            .find_bars(
+                database_depends,
+                &data.ticker,
+                database::Timespan::Minute,
+                &open,
+                &close,
+            )
+            .await?
+            .into_iter()
+            .map(|b| b.start)
+            .collect();
+
+        let minute = Duration::try_minutes(1).unwrap();
+        let mut missing = vec![];
+        let mut start = open;
+        while start < close {
+            if !stored.contains(&start) {
+                missing.push(start);
+            }
+            start += minute;
+        }
+        Ok(missing)
+    }
+}
+
+/// The minutes in a row that a constituent goes without a trade at most in the regular session,
+/// so a longer gap in the stored minute bars is bars that are missing.
+const GAP_MINUTES: usize = 15;
+
+/// Returns the number of the minutes of the longest run of consecutive missing minutes.
+fn longest_gap(missing: &[DateTime<Utc>]) -> usize {
+    let minute = Duration::try_minutes(1).unwrap();
+    let mut longest = 0;
+    let mut run = 0;
+    for (i, start) in missing.iter().enumerate() {
+        run = match i {
+            0 => 1,
+            _ if *start - missing[i - 1] == minute => run + 1,
+            _ => 1,
+        };
+        longest = longest.max(run);
+    }
+    longest
+}
+
+/// The start and the end of the date in New York, where Polygon.io starts the daily bars.
+fn day(date: &NaiveDate) -> (DateTime<Utc>, DateTime<Utc>) {
+    (
+        new_york(date, NaiveTime::MIN),
+        new_york(&date.succ_opt().unwrap(), NaiveTime::MIN),
+    )
+}
+
+fn new_york(date: &NaiveDate, time: NaiveTime) -> DateTime<Utc> {
+    New_York
+        .from_local_datetime(&date.and_time(time))
+        .unwrap()
+        .with_timezone(&Utc)
+}
+
+fn to_request(data: &io::Data, timespan: network::Timespan) -> network::Request {
+    network::Request {
+        ticker: snp::Instrument::Stock(data.ticker.clone()),
+        multiplier: 1,
+        timespan,
+        from: data.date,
+        to: data.date,
+        adjusted: false,
+    }
+}
+
+fn to_fetch(data: &io::Data, timespan: database::Timespan) -> database::Fetch {
+    database::Fetch {
+        snp: data.ticker.clone(),
+        timespan,
+        date: data.date,
+    }
+}
+
+fn to_bars(
+    bars: &[network::Data],
+    snp: &snp::SnP,
+    timespan: database::Timespan,
+) -> Vec<database::Bar> {
+    bars.iter()
+        .filter_map(|b| {
+            Some(database::Bar {
+                snp: snp.clone(),
+                timespan,
+                start: DateTime::from_timestamp_millis(b.timestamp)?,
+                open: b.open,
+                high: b.high,
+                low: b.low,
+                close: b.close,
+                volume: b.volume,
+                vwap: b.vwap,
+                transactions: b.transactions,
+            })
+        })
+        .collect()
+}
+
+struct NetworkDepends {
+    snp: Arc<dyn snp::Api + Send + Sync>,
+}
+
+impl network::Depends for NetworkDepends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        self.snp.clone()
+    }
+}
+
+struct DatabaseDepends {
+    snp: Arc<dyn snp::Api + Send + Sync>,
+}
+
+impl database::Depends for DatabaseDepends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        self.snp.clone()
+    }
+}
+
+impl ticker_events_database::Depends for DatabaseDepends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        self.snp.clone()
+    }
+}
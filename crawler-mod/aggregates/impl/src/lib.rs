@@ -0,0 +1,5 @@
+#[path = "crawler-aggregates-impl.rs"]
+pub mod crawler_aggregates_impl;
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,396 @@
+use std::sync::{Arc, Mutex};
+
+use crate::crawler_aggregates_impl::Service;
+use async_trait::async_trait;
+use calendar_mod_fixture::calendar_fixture::ServiceFake as CalendarServiceFake;
+use calendar_mod_io::calendar;
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+use crawler_mod_aggregates_io::crawler_aggregates as io;
+use database_mod_aggregates_fixture::aggregates_fixture::ServiceFake as DBServiceFake;
+use database_mod_aggregates_io::aggregates as database;
+use database_mod_ticker_events_fixture::ticker_events_fixture::ServiceFake as TickerEventsServiceFake;
+use database_mod_ticker_events_io::ticker_events as ticker_events_database;
+use io::Api;
+use network_mod_aggregates_fixture::aggregates_fixture::ServiceFake as NetServiceFake;
+use network_mod_aggregates_io::aggregates as network;
+use policy_mod_fixture::policy_fixture::ServiceFake as PolicyServiceFake;
+use policy_mod_io::policy;
+use rstest::rstest;
+use snp_mod_fixture::snp_fixture::ServiceFake as SnPServiceFake;
+use snp_mod_io::snp;
+
+#[tokio::test]
+async fn call_crawl_aggregates() -> io::Result<()> {
+    let format = "%B %e, %Y";
+    let service = Service::new();
+    let data = io::Data {
+        ticker: snp::SnP::AAPL,
+        date: NaiveDate::parse_from_str("April 17, 2024", format).unwrap(),
+    };
+    let deps = given_deps();
+    let mut policy_data = policy::Data::default();
+
+    service
+        .crawl_aggregates(deps, &data, &mut policy_data)
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn find_missing_bars_of_whole_session() -> io::Result<()> {
+    let service = Service::new();
+    let deps = given_deps();
+
+    // Daylight saving time, so the session starts at 13:30 UTC.
+    let summer = io::Data {
+        ticker: snp::SnP::AAPL,
+        date: NaiveDate::from_ymd_opt(2024, 4, 17).unwrap(),
+    };
+    let missing = service.find_missing_bars(deps.clone(), &summer).await?;
+    assert_eq!(390, missing.len());
+    assert_eq!(
+        Utc.with_ymd_and_hms(2024, 4, 17, 13, 30, 0).unwrap(),
+        missing[0]
+    );
+    assert_eq!(
+        Utc.with_ymd_and_hms(2024, 4, 17, 19, 59, 0).unwrap(),
+        missing[389]
+    );
+
+    let winter = io::Data {
+        ticker: snp::SnP::AAPL,
+        date: NaiveDate::from_ymd_opt(2024, 1, 17).unwrap(),
+    };
+    let missing = service.find_missing_bars(deps, &winter).await?;
+    assert_eq!(
+        Utc.with_ymd_and_hms(2024, 1, 17, 14, 30, 0).unwrap(),
+        missing[0]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn find_no_missing_bars_on_weekend() -> io::Result<()> {
+    let service = Service::new();
+    let data = io::Data {
+        ticker: snp::SnP::AAPL,
+        date: NaiveDate::from_ymd_opt(2024, 4, 20).unwrap(),
+    };
+
+    let missing = service.find_missing_bars(given_deps(), &data).await?;
+
+    assert!(missing.is_empty());
+    Ok(())
+}
+
+#[rstest]
+// Quiet minutes, one in every ten, had no trade.
+#[case((0..390).filter(|m| m % 10 != 5).collect(), 2)]
+// The response ends in the middle of the session, like a request before its close.
+#[case((0..200).collect(), 3)]
+#[tokio::test]
+async fn request_again_a_day_with_a_gap_only(
+    #[case] minutes: Vec<i64>,
+    #[case] requests: usize,
+) -> io::Result<()> {
+    let service = Service::new();
+    let data = io::Data {
+        ticker: snp::SnP::AAPL,
+        date: NaiveDate::from_ymd_opt(2024, 4, 17).unwrap(),
+    };
+    let network = Arc::new(NetworkBars::new(minutes));
+    let database = Arc::new(DatabaseRecorder::default());
+    let deps = Arc::new(RecordingDepends {
+        network: network.clone(),
+        database: database.clone(),
+        database_ticker_events: Arc::new(TickerEventsServiceFake {}),
+    });
+    let mut policy_data = policy::Data::default();
+
+    service
+        .crawl_aggregates(deps.clone(), &data, &mut policy_data)
+        .await?;
+    service
+        .crawl_aggregates(deps, &data, &mut policy_data)
+        .await?;
+
+    let timespans = network.requests.lock().unwrap();
+    assert_eq!(requests, timespans.len());
+    assert_eq!(network::Timespan::Day, timespans[0]);
+    assert!(timespans[1..]
+        .iter()
+        .all(|t| *t == network::Timespan::Minute));
+    Ok(())
+}
+
+#[tokio::test]
+async fn request_the_symbol_of_the_date() -> io::Result<()> {
+    let service = Service::new();
+    let data = io::Data {
+        ticker: snp::SnP::AAPL,
+        date: NaiveDate::from_ymd_opt(2024, 4, 17).unwrap(),
+    };
+    let network = Arc::new(NetworkBars::new((0..390).collect()));
+    let deps = Arc::new(RecordingDepends {
+        network: network.clone(),
+        database: Arc::new(DatabaseRecorder::default()),
+        database_ticker_events: Arc::new(TickerEventsFB {}),
+    });
+    let mut policy_data = policy::Data::default();
+
+    service
+        .crawl_aggregates(deps, &data, &mut policy_data)
+        .await?;
+
+    assert_eq!(
+        vec![Arc::from("FB"), Arc::from("FB")],
+        *network.symbols.lock().unwrap()
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn return_the_error_of_the_request() {
+    let service = Service::new();
+    let data = io::Data {
+        ticker: snp::SnP::AAPL,
+        date: NaiveDate::from_ymd_opt(2024, 4, 17).unwrap(),
+    };
+    let database = Arc::new(DatabaseRecorder::default());
+    let deps = Arc::new(RecordingDepends {
+        network: Arc::new(NetworkError {}),
+        database: database.clone(),
+        database_ticker_events: Arc::new(TickerEventsServiceFake {}),
+    });
+    let mut policy_data = policy::Data::default();
+
+    let result = service
+        .crawl_aggregates(deps, &data, &mut policy_data)
+        .await;
+
+    assert!(
+        matches!(result, Err(io::Error::Net(_))),
+        "Unexpected {:?}",
+        result
+    );
+    assert!(database.fetches.lock().unwrap().is_empty());
+}
+
+fn given_deps() -> Arc<dyn io::Depends + Send + Sync> {
+    Arc::new(DependsFake {})
+}
+
+struct DependsFake {}
+
+impl DependsFake {}
+
+impl io::Depends for DependsFake {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        Arc::new(SnPServiceFake {})
+    }
+
+    fn provide_policy(&self) -> Arc<dyn policy::Api + Send + Sync> {
+        Arc::new(PolicyServiceFake {})
+    }
+
+    fn provide_calendar(&self) -> Arc<dyn calendar::Api + Send + Sync> {
+        Arc::new(CalendarServiceFake {})
+    }
+
+    fn provide_network_aggregates(&self) -> Arc<dyn network::Api + Send + Sync> {
+        Arc::new(NetServiceFake {})
+    }
+
+    fn provide_database_aggregates(&self) -> Arc<dyn database::Api + Send + Sync> {
+        Arc::new(DBServiceFake {})
+    }
+
+    fn provide_database_ticker_events(&self) -> Arc<dyn ticker_events_database::Api + Send + Sync> {
+        Arc::new(TickerEventsServiceFake {})
+    }
+}
+
+/// Returns a daily bar, and the minute bars of the minutes since the open of the session,
+/// recording the timespans and the symbols of the requests.
+struct NetworkBars {
+    minutes: Vec<i64>,
+    requests: Mutex<Vec<network::Timespan>>,
+    symbols: Mutex<Vec<Arc<str>>>,
+}
+
+impl NetworkBars {
+    fn new(minutes: Vec<i64>) -> Self {
+        Self {
+            minutes,
+            requests: Mutex::new(vec![]),
+            symbols: Mutex::new(vec![]),
+        }
+    }
+}
+
+#[async_trait]
+impl network::Api for NetworkBars {
+    async fn get_aggregates(
+        &self,
+        deps: Arc<dyn network::Depends + Send + Sync>,
+        request: &network::Request,
+    ) -> network::Result<Vec<network::Data>> {
+        self.requests.lock().unwrap().push(request.timespan);
+        self.symbols
+            .lock()
+            .unwrap()
+            .push(request.ticker.ticker(deps.provide_snp().as_ref()));
+        let minutes = match request.timespan {
+            network::Timespan::Minute => self.minutes.clone(),
+            _ => vec![0],
+        };
+        // The open of the session of April 17, 2024.
+        let open = 1713360600000;
+        Ok(minutes
+            .into_iter()
+            .map(|minute| network::Data {
+                open: 168.8,
+                high: 169.1,
+                low: 168.7,
+                close: 169.0,
+                volume: 100.0,
+                vwap: None,
+                timestamp: open + minute * 60000,
+                transactions: Some(1),
+            })
+            .collect())
+    }
+}
+
+/// Fails like a request over the rate limit.
+struct NetworkError;
+
+#[async_trait]
+impl network::Api for NetworkError {
+    async fn get_aggregates(
+        &self,
+        _deps: Arc<dyn network::Depends + Send + Sync>,
+        _request: &network::Request,
+    ) -> network::Result<Vec<network::Data>> {
+        Err(network::Error::Server(
+            "/v2/aggs/ticker/AAPL/range/1/day/2024-04-17/2024-04-17".into(),
+            "You've exceeded the maximum requests".into(),
+        ))
+    }
+}
+
+#[derive(Default)]
+struct DatabaseRecorder {
+    bars: Mutex<Vec<database::Bar>>,
+    fetches: Mutex<Vec<database::Fetch>>,
+}
+
+#[async_trait]
+impl database::Api for DatabaseRecorder {
+    async fn save_bars(
+        &self,
+        _deps: Arc<dyn database::Depends + Send + Sync>,
+        bars: &[database::Bar],
+    ) -> database::Result<()> {
+        self.bars.lock().unwrap().extend_from_slice(bars);
+        Ok(())
+    }
+
+    async fn find_bars(
+        &self,
+        _deps: Arc<dyn database::Depends + Send + Sync>,
+        _snp: &snp::SnP,
+        timespan: database::Timespan,
+        _from: &DateTime<Utc>,
+        _to: &DateTime<Utc>,
+    ) -> database::Result<Vec<database::Bar>> {
+        let bars = self.bars.lock().unwrap();
+        Ok(bars
+            .iter()
+            .filter(|b| b.timespan == timespan)
+            .cloned()
+            .collect())
+    }
+
+    async fn save_fetch(
+        &self,
+        _deps: Arc<dyn database::Depends + Send + Sync>,
+        fetch: &database::Fetch,
+    ) -> database::Result<()> {
+        self.fetches.lock().unwrap().push(fetch.clone());
+        Ok(())
+    }
+
+    async fn is_fetched(
+        &self,
+        _deps: Arc<dyn database::Depends + Send + Sync>,
+        fetch: &database::Fetch,
+    ) -> database::Result<bool> {
+        Ok(self.fetches.lock().unwrap().contains(fetch))
+    }
+}
+
+/// Returns `FB` as the historical symbol of any ticker.
+struct TickerEventsFB;
+
+#[async_trait]
+impl ticker_events_database::Api for TickerEventsFB {
+    async fn save_ticker_changes(
+        &self,
+        _deps: Arc<dyn ticker_events_database::Depends + Send + Sync>,
+        _changes: &[ticker_events_database::TickerChange],
+    ) -> ticker_events_database::Result<()> {
+        Ok(())
+    }
+
+    async fn find_ticker_changes(
+        &self,
+        _deps: Arc<dyn ticker_events_database::Depends + Send + Sync>,
+        _composite_figi: &str,
+    ) -> ticker_events_database::Result<Vec<ticker_events_database::TickerChange>> {
+        Ok(vec![])
+    }
+
+    async fn find_historical_symbol(
+        &self,
+        _deps: Arc<dyn ticker_events_database::Depends + Send + Sync>,
+        _symbol: &str,
+        _date: &NaiveDate,
+    ) -> ticker_events_database::Result<Arc<str>> {
+        Ok(Arc::from("FB"))
+    }
+}
+
+struct RecordingDepends {
+    network: Arc<dyn network::Api + Send + Sync>,
+    database: Arc<dyn database::Api + Send + Sync>,
+    database_ticker_events: Arc<dyn ticker_events_database::Api + Send + Sync>,
+}
+
+impl io::Depends for RecordingDepends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        Arc::new(SnPServiceFake {})
+    }
+
+    fn provide_policy(&self) -> Arc<dyn policy::Api + Send + Sync> {
+        Arc::new(PolicyServiceFake {})
+    }
+
+    fn provide_calendar(&self) -> Arc<dyn calendar::Api + Send + Sync> {
+        Arc::new(CalendarServiceFake {})
+    }
+
+    fn provide_network_aggregates(&self) -> Arc<dyn network::Api + Send + Sync> {
+        self.network.clone()
+    }
+
+    fn provide_database_aggregates(&self) -> Arc<dyn database::Api + Send + Sync> {
+        self.database.clone()
+    }
+
+    fn provide_database_ticker_events(&self) -> Arc<dyn ticker_events_database::Api + Send + Sync> {
+        self.database_ticker_events.clone()
+    }
+}
@@ -0,0 +1,67 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use calendar_mod_io::calendar;
+use chrono::{DateTime, NaiveDate, Utc};
+use database_mod_aggregates_io::aggregates as database;
+use database_mod_ticker_events_io::ticker_events as ticker_events_database;
+use network_mod_aggregates_io::aggregates as network;
+use policy_mod_io::policy;
+use snp_mod_io::snp;
+
+/// The data that is needed to interact with the Aggregates modules.
+#[derive(Debug)]
+pub struct Data {
+    pub ticker: snp::SnP,
+    pub date: NaiveDate,
+}
+
+/// All possible errors of Aggregates modules.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("{0}")]
+    Db(#[from] database::Error),
+    #[error("{0}")]
+    Net(#[from] network::Error),
+    #[error("{0}")]
+    Policy(#[from] policy::Error),
+    #[error("{0}")]
+    SnP(#[from] snp::Error),
+    #[error("{0}")]
+    TickerEvents(#[from] ticker_events_database::Error),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The API of Aggregates, which defines all the functionality of Aggregates modules.
+#[async_trait]
+pub trait Api {
+    /// Stores the daily bar and the minute bars of the ticker on the date, requesting only
+    /// what's missing from the database. The fetched minute bars are requested again only for a
+    /// gap in the session that's too long to be minutes without a trade. The error of a
+    /// request is returned.
+    async fn crawl_aggregates(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        data: &Data,
+        policy_data: &mut policy::Data,
+    ) -> Result<()>;
+
+    /// Returns the starts of the minute bars in the regular trading session, which are not
+    /// stored yet, or nothing if the market is closed.
+    async fn find_missing_bars(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        data: &Data,
+    ) -> Result<Vec<DateTime<Utc>>>;
+}
+
+/// All the dependencies of the Aggregates modules to other modules.
+pub trait Depends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync>;
+    fn provide_policy(&self) -> Arc<dyn policy::Api + Send + Sync>;
+    fn provide_calendar(&self) -> Arc<dyn calendar::Api + Send + Sync>;
+    fn provide_network_aggregates(&self) -> Arc<dyn network::Api + Send + Sync>;
+    fn provide_database_aggregates(&self) -> Arc<dyn database::Api + Send + Sync>;
+    fn provide_database_ticker_events(&self) -> Arc<dyn ticker_events_database::Api + Send + Sync>;
+}
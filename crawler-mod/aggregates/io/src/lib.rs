@@ -0,0 +1,2 @@
+#[path = "crawler-aggregates.rs"]
+pub mod crawler_aggregates;
@@ -0,0 +1,128 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use snp_mod_io::snp;
+
+use crawler_mod_corporate_actions_io::crawler_corporate_actions as io;
+use database_mod_dividends_io::dividends as dividends_database;
+use database_mod_splits_io::splits as splits_database;
+use network_mod_dividends_io::dividends as dividends_network;
+use network_mod_splits_io::splits as splits_network;
+use policy_mod_io::policy;
+
+#[derive(Debug)]
+pub struct Service;
+
+/// The service to implement the API of Corporate_actions.
+impl Service {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Default for Service {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl io::Api for Service {
+    async fn crawl_corporate_actions(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        data: &io::Data,
+        policy_data: &mut policy::Data,
+    ) -> io::Result<()> {
+        let policy = deps.provide_policy().clone();
+
+        let snp = deps.provide_snp().clone();
+        let network_depends = Arc::new(NetworkDepends { snp: snp.clone() });
+        let database_depends = Arc::new(DatabaseDepends { snp: snp.clone() });
+
+        let response = deps
+            .provide_network_dividends()
+            .get_dividends(network_depends.clone(), &data.ticker)
+            .await;
+        policy.handle_request_rate(policy_data).await?;
+        let dividends: Vec<dividends_database::Dividend> = response?
+            .into_iter()
+            .map(|d| to_dividend(d, &data.ticker))
+            .collect();
+        deps.provide_database_dividends()
+            .save_dividends(database_depends.clone(), &dividends)
+            .await?;
+
+        let response = deps
+            .provide_network_splits()
+            .get_splits(network_depends.clone(), &data.ticker)
+            .await;
+        policy.handle_request_rate(policy_data).await?;
+        let splits: Vec<splits_database::Split> = response?
+            .into_iter()
+            .map(|s| to_split(s, &data.ticker))
+            .collect();
+        deps.provide_database_splits()
+            .save_splits(database_depends.clone(), &splits)
+            .await?;
+
+        Ok(())
+    }
+}
+
+fn to_dividend(data: dividends_network::Data, snp: &snp::SnP) -> dividends_database::Dividend {
+    dividends_database::Dividend {
+        polygon_id: data.id,
+        snp: snp.clone(),
+        cash_amount: data.cash_amount,
+        currency: data.currency,
+        declaration_date: data.declaration_date,
+        dividend_type: data.dividend_type,
+        ex_dividend_date: data.ex_dividend_date,
+        frequency: data.frequency,
+        pay_date: data.pay_date,
+        record_date: data.record_date,
+    }
+}
+
+fn to_split(data: splits_network::Data, snp: &snp::SnP) -> splits_database::Split {
+    splits_database::Split {
+        polygon_id: data.id,
+        snp: snp.clone(),
+        execution_date: data.execution_date,
+        split_from: data.split_from,
+        split_to: data.split_to,
+    }
+}
+
+struct NetworkDepends {
+    snp: Arc<dyn snp::Api + Send + Sync>,
+}
+
+impl dividends_network::Depends for NetworkDepends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        self.snp.clone()
+    }
+}
+
+impl splits_network::Depends for NetworkDepends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        self.snp.clone()
+    }
+}
+
+struct DatabaseDepends {
+    snp: Arc<dyn snp::Api + Send + Sync>,
+}
+
+impl dividends_database::Depends for DatabaseDepends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        self.snp.clone()
+    }
+}
+
+impl splits_database::Depends for DatabaseDepends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        self.snp.clone()
+    }
+}
@@ -0,0 +1,5 @@
+#[path = "crawler-corporate-actions-impl.rs"]
+pub mod crawler_corporate_actions_impl;
+
+#[cfg(test)]
+mod test;
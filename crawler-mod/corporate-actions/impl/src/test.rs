@@ -0,0 +1,109 @@
+use std::sync::Arc;
+
+use crate::crawler_corporate_actions_impl::Service;
+use async_trait::async_trait;
+use crawler_mod_corporate_actions_io::crawler_corporate_actions as io;
+use database_mod_dividends_fixture::dividends_fixture::ServiceFake as DividendsDBServiceFake;
+use database_mod_dividends_io::dividends as dividends_database;
+use database_mod_splits_fixture::splits_fixture::ServiceFake as SplitsDBServiceFake;
+use database_mod_splits_io::splits as splits_database;
+use io::Api;
+use network_mod_dividends_fixture::dividends_fixture::ServiceFake as DividendsNetServiceFake;
+use network_mod_dividends_io::dividends as dividends_network;
+use network_mod_splits_fixture::splits_fixture::ServiceFake as SplitsNetServiceFake;
+use network_mod_splits_io::splits as splits_network;
+use policy_mod_fixture::policy_fixture::ServiceFake as PolicyServiceFake;
+use policy_mod_io::policy;
+use snp_mod_fixture::snp_fixture::ServiceFake as SnPServiceFake;
+use snp_mod_io::snp;
+
+#[tokio::test]
+async fn call_crawl_corporate_actions() -> io::Result<()> {
+    let service = Service::new();
+    let data = io::Data {
+        ticker: snp::SnP::AAPL,
+    };
+    let deps = given_deps();
+    let mut policy_data = policy::Data::default();
+
+    service
+        .crawl_corporate_actions(deps, &data, &mut policy_data)
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn return_the_error_of_the_request() {
+    let service = Service::new();
+    let data = io::Data {
+        ticker: snp::SnP::AAPL,
+    };
+    let deps = Arc::new(DependsFake {
+        network_dividends: Arc::new(NetworkError {}),
+    });
+    let mut policy_data = policy::Data::default();
+
+    let result = service
+        .crawl_corporate_actions(deps, &data, &mut policy_data)
+        .await;
+
+    assert!(
+        matches!(result, Err(io::Error::DividendsNet(_))),
+        "Unexpected {:?}",
+        result
+    );
+}
+
+/// Fails like a request over the rate limit.
+struct NetworkError;
+
+#[async_trait]
+impl dividends_network::Api for NetworkError {
+    async fn get_dividends(
+        &self,
+        _deps: Arc<dyn dividends_network::Depends + Send + Sync>,
+        _ticker: &snp::SnP,
+    ) -> dividends_network::Result<Vec<dividends_network::Data>> {
+        Err(dividends_network::Error::Server(
+            "/v3/reference/dividends".into(),
+            "You've exceeded the maximum requests".into(),
+        ))
+    }
+}
+
+fn given_deps() -> Arc<dyn io::Depends + Send + Sync> {
+    Arc::new(DependsFake {
+        network_dividends: Arc::new(DividendsNetServiceFake {}),
+    })
+}
+
+struct DependsFake {
+    network_dividends: Arc<dyn dividends_network::Api + Send + Sync>,
+}
+
+impl io::Depends for DependsFake {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        Arc::new(SnPServiceFake {})
+    }
+
+    fn provide_policy(&self) -> Arc<dyn policy::Api + Send + Sync> {
+        Arc::new(PolicyServiceFake {})
+    }
+
+    fn provide_network_dividends(&self) -> Arc<dyn dividends_network::Api + Send + Sync> {
+        self.network_dividends.clone()
+    }
+
+    fn provide_network_splits(&self) -> Arc<dyn splits_network::Api + Send + Sync> {
+        Arc::new(SplitsNetServiceFake {})
+    }
+
+    fn provide_database_dividends(&self) -> Arc<dyn dividends_database::Api + Send + Sync> {
+        Arc::new(DividendsDBServiceFake {})
+    }
+
+    fn provide_database_splits(&self) -> Arc<dyn splits_database::Api + Send + Sync> {
+        Arc::new(SplitsDBServiceFake {})
+    }
+}
@@ -0,0 +1,59 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use database_mod_dividends_io::dividends as dividends_database;
+use database_mod_splits_io::splits as splits_database;
+use network_mod_dividends_io::dividends as dividends_network;
+use network_mod_splits_io::splits as splits_network;
+use policy_mod_io::policy;
+use snp_mod_io::snp;
+
+/// The data that is needed to interact with the Corporate-actions modules.
+#[derive(Debug)]
+pub struct Data {
+    pub ticker: snp::SnP,
+}
+
+/// All possible errors of Corporate-actions modules.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("{0}")]
+    DividendsDb(#[from] dividends_database::Error),
+    #[error("{0}")]
+    SplitsDb(#[from] splits_database::Error),
+    #[error("{0}")]
+    DividendsNet(#[from] dividends_network::Error),
+    #[error("{0}")]
+    SplitsNet(#[from] splits_network::Error),
+    #[error("{0}")]
+    Policy(#[from] policy::Error),
+    #[error("{0}")]
+    SnP(#[from] snp::Error),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The API of Corporate-actions, which defines all the functionality of Corporate-actions
+/// modules.
+#[async_trait]
+pub trait Api {
+    /// Requests the whole dividend and split history of the ticker, and stores it, so the
+    /// announcements and corrections since the last crawl are kept too. The error of a request
+    /// is returned, so a failure never looks like a ticker without corporate actions.
+    async fn crawl_corporate_actions(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        data: &Data,
+        policy_data: &mut policy::Data,
+    ) -> Result<()>;
+}
+
+/// All the dependencies of the Corporate-actions modules to other modules.
+pub trait Depends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync>;
+    fn provide_policy(&self) -> Arc<dyn policy::Api + Send + Sync>;
+    fn provide_network_dividends(&self) -> Arc<dyn dividends_network::Api + Send + Sync>;
+    fn provide_network_splits(&self) -> Arc<dyn splits_network::Api + Send + Sync>;
+    fn provide_database_dividends(&self) -> Arc<dyn dividends_database::Api + Send + Sync>;
+    fn provide_database_splits(&self) -> Arc<dyn splits_database::Api + Send + Sync>;
+}
@@ -0,0 +1,2 @@
+#[path = "crawler-corporate-actions.rs"]
+pub mod crawler_corporate_actions;
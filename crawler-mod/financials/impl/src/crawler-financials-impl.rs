@@ -0,0 +1,119 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use snp_mod_io::snp;
+
+use crawler_mod_financials_io::crawler_financials as io;
+use database_mod_financials_io::financials as database;
+use network_mod_financials_io::financials as network;
+use policy_mod_io::policy;
+
+#[derive(Debug)]
+pub struct Service;
+
+/// The service to implement the API of Financials.
+impl Service {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Default for Service {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl io::Api for Service {
+    async fn crawl_financials(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        data: &io::Data,
+        policy_data: &mut policy::Data,
+    ) -> io::Result<usize> {
+        let policy = deps.provide_policy().clone();
+
+        let snp = deps.provide_snp().clone();
+        let network_depends = Arc::new(NetworkDepends { snp: snp.clone() });
+        let database_depends = Arc::new(DatabaseDepends { snp: snp.clone() });
+
+        let response = deps
+            .provide_network_financials()
+            .get_financials(network_depends, &data.cik)
+            .await;
+        policy.handle_request_rate(policy_data).await?;
+        let filings = response?;
+
+        let mut skipped = 0;
+        let mut line_items = vec![];
+        for filing in filings {
+            let (Some(start_date), Some(end_date)) = (filing.start_date, filing.end_date) else {
+                skipped += 1;
+                continue;
+            };
+            line_items.extend(to_line_items(filing, start_date, end_date));
+        }
+
+        deps.provide_database_financials()
+            .save_line_items(database_depends, &line_items)
+            .await?;
+
+        Ok(skipped)
+    }
+}
+
+fn to_line_items(
+    filing: network::Data,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+) -> Vec<database::LineItem> {
+    filing
+        .line_items
+        .into_iter()
+        .map(|l| database::LineItem {
+            cik: filing.cik.clone(),
+            timeframe: filing.timeframe.clone(),
+            start_date,
+            end_date,
+            fiscal_year: filing.fiscal_year.clone(),
+            fiscal_period: filing.fiscal_period.clone(),
+            filing_date: filing.filing_date,
+            statement: to_statement(l.statement),
+            concept: l.concept,
+            label: l.label,
+            unit: l.unit,
+            value: l.value,
+        })
+        .collect()
+}
+
+fn to_statement(statement: network::Statement) -> database::Statement {
+    match statement {
+        network::Statement::IncomeStatement => database::Statement::IncomeStatement,
+        network::Statement::BalanceSheet => database::Statement::BalanceSheet,
+        network::Statement::CashFlowStatement => database::Statement::CashFlowStatement,
+        network::Statement::ComprehensiveIncome => database::Statement::ComprehensiveIncome,
+    }
+}
+
+struct NetworkDepends {
+    snp: Arc<dyn snp::Api + Send + Sync>,
+}
+
+impl network::Depends for NetworkDepends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        self.snp.clone()
+    }
+}
+
+struct DatabaseDepends {
+    snp: Arc<dyn snp::Api + Send + Sync>,
+}
+
+impl database::Depends for DatabaseDepends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        self.snp.clone()
+    }
+}
@@ -0,0 +1,5 @@
+#[path = "crawler-financials-impl.rs"]
+pub mod crawler_financials_impl;
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,153 @@
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+
+use crate::crawler_financials_impl::Service;
+use crawler_mod_financials_io::crawler_financials as io;
+use database_mod_financials_fixture::financials_fixture::ServiceFake as DBServiceFake;
+use database_mod_financials_io::financials as database;
+use io::Api;
+use network_mod_financials_fixture::financials_fixture::ServiceFake as NetServiceFake;
+use network_mod_financials_io::financials as network;
+use policy_mod_fixture::policy_fixture::ServiceFake as PolicyServiceFake;
+use policy_mod_io::policy;
+use snp_mod_fixture::snp_fixture::ServiceFake as SnPServiceFake;
+use snp_mod_io::snp;
+
+#[tokio::test]
+async fn call_crawl_financials() -> io::Result<()> {
+    let service = Service::new();
+    let data = io::Data {
+        cik: "0000320193".into(),
+    };
+    let deps = given_deps(Arc::new(NetServiceFake {}), Arc::new(DBServiceFake {}));
+    let mut policy_data = policy::Data::default();
+
+    let skipped = service
+        .crawl_financials(deps, &data, &mut policy_data)
+        .await?;
+
+    assert_eq!(0, skipped);
+    Ok(())
+}
+
+#[tokio::test]
+async fn skip_a_filing_without_a_period() -> io::Result<()> {
+    let service = Service::new();
+    let data = io::Data {
+        cik: "0000320193".into(),
+    };
+    let database = Arc::new(DatabaseRecorder::default());
+    let deps = given_deps(Arc::new(NetworkFilings {}), database.clone());
+    let mut policy_data = policy::Data::default();
+
+    let skipped = service
+        .crawl_financials(deps, &data, &mut policy_data)
+        .await?;
+
+    assert_eq!(1, skipped);
+    let saved = database.saved.lock().unwrap();
+    assert_eq!(1, saved.len());
+    assert_eq!("0000320193", &*saved[0].cik);
+    assert_eq!(
+        NaiveDate::from_ymd_opt(2023, 10, 1).unwrap(),
+        saved[0].start_date
+    );
+    assert_eq!(
+        NaiveDate::from_ymd_opt(2023, 12, 30).unwrap(),
+        saved[0].end_date
+    );
+    assert_eq!(database::Statement::BalanceSheet, saved[0].statement);
+    assert_eq!("assets", &*saved[0].concept);
+    Ok(())
+}
+
+/// Returns a filing with a period, and one without an end date.
+struct NetworkFilings {}
+
+#[async_trait]
+impl network::Api for NetworkFilings {
+    async fn get_financials(
+        &self,
+        _deps: Arc<dyn network::Depends + Send + Sync>,
+        cik: &str,
+    ) -> network::Result<Vec<network::Data>> {
+        let filing = |end_date| network::Data {
+            cik: cik.into(),
+            timeframe: "quarterly".into(),
+            fiscal_year: Some("2024".into()),
+            fiscal_period: "Q1".into(),
+            start_date: NaiveDate::from_ymd_opt(2023, 10, 1),
+            end_date,
+            filing_date: NaiveDate::from_ymd_opt(2024, 2, 2),
+            line_items: vec![network::LineItem {
+                statement: network::Statement::BalanceSheet,
+                concept: "assets".into(),
+                label: "Assets".into(),
+                unit: "USD".into(),
+                value: 353514000000.0,
+            }],
+        };
+        Ok(vec![
+            filing(NaiveDate::from_ymd_opt(2023, 12, 30)),
+            filing(None),
+        ])
+    }
+}
+
+#[derive(Default)]
+struct DatabaseRecorder {
+    saved: Mutex<Vec<database::LineItem>>,
+}
+
+#[async_trait]
+impl database::Api for DatabaseRecorder {
+    async fn save_line_items(
+        &self,
+        _deps: Arc<dyn database::Depends + Send + Sync>,
+        line_items: &[database::LineItem],
+    ) -> database::Result<()> {
+        self.saved.lock().unwrap().extend_from_slice(line_items);
+        Ok(())
+    }
+
+    async fn find_line_items(
+        &self,
+        _deps: Arc<dyn database::Depends + Send + Sync>,
+        _cik: &str,
+        _concept: &str,
+    ) -> database::Result<Vec<database::LineItem>> {
+        Ok(vec![])
+    }
+}
+
+fn given_deps(
+    network: Arc<dyn network::Api + Send + Sync>,
+    database: Arc<dyn database::Api + Send + Sync>,
+) -> Arc<dyn io::Depends + Send + Sync> {
+    Arc::new(DependsFake { network, database })
+}
+
+struct DependsFake {
+    network: Arc<dyn network::Api + Send + Sync>,
+    database: Arc<dyn database::Api + Send + Sync>,
+}
+
+impl io::Depends for DependsFake {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        Arc::new(SnPServiceFake {})
+    }
+
+    fn provide_policy(&self) -> Arc<dyn policy::Api + Send + Sync> {
+        Arc::new(PolicyServiceFake {})
+    }
+
+    fn provide_network_financials(&self) -> Arc<dyn network::Api + Send + Sync> {
+        self.network.clone()
+    }
+
+    fn provide_database_financials(&self) -> Arc<dyn database::Api + Send + Sync> {
+        self.database.clone()
+    }
+}
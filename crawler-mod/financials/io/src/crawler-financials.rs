@@ -0,0 +1,51 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use database_mod_financials_io::financials as database;
+use network_mod_financials_io::financials as network;
+use policy_mod_io::policy;
+use snp_mod_io::snp;
+
+/// The data that is needed to interact with the Financials modules.
+#[derive(Debug)]
+pub struct Data {
+    /// The CIK of the company in the SEC, like `0000320193` for Apple.
+    pub cik: Arc<str>,
+}
+
+/// All possible errors of Financials modules.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("{0}")]
+    Db(#[from] database::Error),
+    #[error("{0}")]
+    Net(#[from] network::Error),
+    #[error("{0}")]
+    Policy(#[from] policy::Error),
+    #[error("{0}")]
+    SnP(#[from] snp::Error),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The API of Financials, which defines all the functionality of Financials modules.
+#[async_trait]
+pub trait Api {
+    /// Requests every filing period of the company, and stores its line items. A filing
+    /// without a start or an end date can't be keyed by its period, so it's skipped.
+    /// Returns the number of the skipped filings.
+    async fn crawl_financials(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        data: &Data,
+        policy_data: &mut policy::Data,
+    ) -> Result<usize>;
+}
+
+/// All the dependencies of the Financials modules to other modules.
+pub trait Depends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync>;
+    fn provide_policy(&self) -> Arc<dyn policy::Api + Send + Sync>;
+    fn provide_network_financials(&self) -> Arc<dyn network::Api + Send + Sync>;
+    fn provide_database_financials(&self) -> Arc<dyn database::Api + Send + Sync>;
+}
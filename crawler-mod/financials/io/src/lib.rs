@@ -0,0 +1,2 @@
+#[path = "crawler-financials.rs"]
+pub mod crawler_financials;
@@ -0,0 +1,253 @@
+use std::{collections::HashMap, sync::Arc};
+
+use async_trait::async_trait;
+use chrono::DateTime;
+use snp_mod_io::snp;
+
+use crawler_mod_flat_files_io::crawler_flat_files as io;
+use database_mod_aggregates_io::aggregates as database_aggregates;
+use database_mod_quotes_io::quotes as database_quotes;
+use database_mod_ticker_events_io::ticker_events as ticker_events_database;
+use database_mod_trades_io::trades as database_trades;
+use network_mod_aggregates_io::aggregates as network_aggregates;
+use network_mod_flat_files_io::flat_files as network;
+use network_mod_quotes_io::quotes as network_quotes;
+use network_mod_trades_io::trades as network_trades;
+
+/// The number of rows of each insert, to keep the memory bounded on the big files.
+const BATCH_SIZE: usize = 50000;
+
+#[derive(Debug)]
+pub struct Service;
+
+/// The service to implement the API of Flat Files.
+impl Service {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Default for Service {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl io::Api for Service {
+    async fn ingest_flat_file(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        data: &io::Data,
+    ) -> io::Result<usize> {
+        if !deps.provide_calendar().is_trading_day(&data.date) {
+            return Ok(0);
+        }
+
+        let snp = deps.provide_snp().clone();
+        let network_service = deps.provide_network_flat_files().clone();
+        let network_depends = Arc::new(NetworkDepends {
+            names: deps.provide_names(),
+        });
+        let mut rows = match network_service
+            .get_rows(network_depends, data.dataset, &data.date)
+            .await?
+        {
+            Some(rows) => rows,
+            None => return Ok(0),
+        };
+
+        // The files name the tickers by their symbols on the date, so the constituents are keyed
+        // by their historical symbols, e.g. `FB` for `META` before June 9, 2022.
+        let ticker_events_service = deps.provide_database_ticker_events();
+        let ticker_events_depends = Arc::new(DatabaseDepends { snp: snp.clone() });
+        let mut members: HashMap<Arc<str>, snp::SnP> = HashMap::new();
+        for member in snp.get_snp_by_date(&data.date) {
+            let symbol = ticker_events_service
+                .find_historical_symbol(
+                    ticker_events_depends.clone(),
+                    &snp.get_symbol(&member),
+                    &data.date,
+                )
+                .await?;
+            members.insert(symbol, member);
+        }
+        let timespan = match data.dataset {
+            network::Dataset::DayAggregates => database_aggregates::Timespan::Day,
+            _ => database_aggregates::Timespan::Minute,
+        };
+        let mut batch = Batch::new(deps.clone(), snp.clone());
+        // The files have all the US stocks, but only the constituents of the date are kept.
+        let member = |ticker: &str| members.get(ticker).cloned();
+        while let Some(row) = rows.recv().await {
+            match row? {
+                network::Row::Trade { ticker, data } => {
+                    if let Some(snp) = member(&ticker) {
+                        batch.trades.push(to_trade(data, snp));
+                    }
+                }
+                network::Row::Quote { ticker, data } => {
+                    if let Some(snp) = member(&ticker) {
+                        batch.quotes.push(to_quote(data, snp));
+                    }
+                }
+                network::Row::Bar { ticker, data } => {
+                    if let Some(snp) = member(&ticker) {
+                        batch.bars.extend(to_bar(data, snp, timespan));
+                    }
+                }
+            }
+            if batch.len() >= BATCH_SIZE {
+                batch.save().await?;
+            }
+        }
+        batch.save().await?;
+
+        Ok(batch.saved)
+    }
+}
+
+/// The rows that are waiting to be inserted into the database.
+struct Batch {
+    deps: Arc<dyn io::Depends + Send + Sync>,
+    database_depends: Arc<DatabaseDepends>,
+    trades: Vec<database_trades::Trade>,
+    quotes: Vec<database_quotes::Quote>,
+    bars: Vec<database_aggregates::Bar>,
+    saved: usize,
+}
+
+impl Batch {
+    fn new(deps: Arc<dyn io::Depends + Send + Sync>, snp: Arc<dyn snp::Api + Send + Sync>) -> Self {
+        Self {
+            deps,
+            database_depends: Arc::new(DatabaseDepends { snp }),
+            trades: vec![],
+            quotes: vec![],
+            bars: vec![],
+            saved: 0,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.trades.len() + self.quotes.len() + self.bars.len()
+    }
+
+    async fn save(&mut self) -> io::Result<()> {
+        if !self.trades.is_empty() {
+            self.deps
+                .provide_database_trades()
+                .save_trades(self.database_depends.clone(), &self.trades)
+                .await?;
+        }
+        if !self.quotes.is_empty() {
+            self.deps
+                .provide_database_quotes()
+                .save_quotes(self.database_depends.clone(), &self.quotes)
+                .await?;
+        }
+        if !self.bars.is_empty() {
+            self.deps
+                .provide_database_aggregates()
+                .save_bars(self.database_depends.clone(), &self.bars)
+                .await?;
+        }
+        self.saved += self.len();
+        self.trades.clear();
+        self.quotes.clear();
+        self.bars.clear();
+        Ok(())
+    }
+}
+
+fn to_trade(trade: network_trades::Data, snp: snp::SnP) -> database_trades::Trade {
+    database_trades::Trade {
+        snp,
+        sip_timestamp: trade.sip_timestamp,
+        sequence_number: trade.sequence_number,
+        polygon_id: trade.id,
+        price: trade.price,
+        size: trade.size,
+        exchange: trade.exchange,
+        conditions: trade.conditions,
+        correction: trade.correction,
+        participant_timestamp: trade.participant_timestamp,
+        tape: trade.tape,
+        trf_id: trade.trf_id,
+        trf_timestamp: trade.trf_timestamp,
+    }
+}
+
+fn to_quote(quote: network_quotes::Data, snp: snp::SnP) -> database_quotes::Quote {
+    database_quotes::Quote {
+        snp,
+        sip_timestamp: quote.sip_timestamp,
+        sequence_number: quote.sequence_number,
+        participant_timestamp: quote.participant_timestamp,
+        ask_exchange: quote.ask_exchange,
+        ask_price: quote.ask_price,
+        ask_size: quote.ask_size,
+        bid_exchange: quote.bid_exchange,
+        bid_price: quote.bid_price,
+        bid_size: quote.bid_size,
+        conditions: quote.conditions,
+        tape: quote.tape,
+    }
+}
+
+fn to_bar(
+    bar: network_aggregates::Data,
+    snp: snp::SnP,
+    timespan: database_aggregates::Timespan,
+) -> Option<database_aggregates::Bar> {
+    Some(database_aggregates::Bar {
+        snp,
+        timespan,
+        start: DateTime::from_timestamp_millis(bar.timestamp)?,
+        open: bar.open,
+        high: bar.high,
+        low: bar.low,
+        close: bar.close,
+        volume: bar.volume,
+        vwap: bar.vwap,
+        transactions: bar.transactions,
+    })
+}
+
+struct NetworkDepends {
+    names: Arc<network_trades::Names>,
+}
+
+impl network::Depends for NetworkDepends {
+    fn provide_names(&self) -> Arc<network_trades::Names> {
+        self.names.clone()
+    }
+}
+
+struct DatabaseDepends {
+    snp: Arc<dyn snp::Api + Send + Sync>,
+}
+
+impl database_trades::Depends for DatabaseDepends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        self.snp.clone()
+    }
+}
+
+impl database_quotes::Depends for DatabaseDepends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        self.snp.clone()
+    }
+}
+
+impl database_aggregates::Depends for DatabaseDepends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        self.snp.clone()
+    }
+}
+
+impl ticker_events_database::Depends for DatabaseDepends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        self.snp.clone()
+    }
+}
@@ -0,0 +1,5 @@
+#[path = "crawler-flat-files-impl.rs"]
+pub mod crawler_flat_files_impl;
+
+#[cfg(test)]
+mod test;
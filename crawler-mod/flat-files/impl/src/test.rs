@@ -0,0 +1,405 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use crate::crawler_flat_files_impl::Service;
+use async_trait::async_trait;
+use calendar_mod_fixture::calendar_fixture::ServiceFake as CalendarServiceFake;
+use calendar_mod_io::calendar;
+use chrono::{DateTime, NaiveDate, Utc};
+use crawler_mod_flat_files_io::crawler_flat_files as io;
+use database_mod_aggregates_fixture::aggregates_fixture::ServiceFake as AggregatesDBServiceFake;
+use database_mod_aggregates_io::aggregates as database_aggregates;
+use database_mod_quotes_fixture::quotes_fixture::ServiceFake as QuotesDBServiceFake;
+use database_mod_quotes_io::quotes as database_quotes;
+use database_mod_ticker_events_fixture::ticker_events_fixture::ServiceFake as TickerEventsDBServiceFake;
+use database_mod_ticker_events_io::ticker_events as ticker_events_database;
+use database_mod_trades_fixture::trades_fixture::ServiceFake as TradesDBServiceFake;
+use database_mod_trades_io::trades as database_trades;
+use io::Api;
+use network_mod_aggregates_io::aggregates as network_aggregates;
+use network_mod_flat_files_fixture::flat_files_fixture::ServiceFake as NetServiceFake;
+use network_mod_flat_files_io::flat_files as network;
+use network_mod_trades_io::trades as network_trades;
+use snp_mod_fixture::snp_fixture::ServiceFake as SnPServiceFake;
+use snp_mod_io::snp;
+
+#[tokio::test]
+async fn call_ingest_flat_file() -> io::Result<()> {
+    let service = Service::new();
+    let data = io::Data {
+        dataset: network::Dataset::Trades,
+        date: NaiveDate::from_ymd_opt(2024, 3, 14).unwrap(),
+    };
+    let deps = Arc::new(DependsFake {
+        snp: Arc::new(SnPServiceFake {}),
+        network: Arc::new(NetServiceFake {}),
+        database_trades: Arc::new(TradesDBServiceFake {}),
+        database_aggregates: Arc::new(AggregatesDBServiceFake {}),
+        database_ticker_events: Arc::new(TickerEventsDBServiceFake {}),
+    });
+
+    let saved = service.ingest_flat_file(deps, &data).await?;
+
+    assert_eq!(0, saved);
+    Ok(())
+}
+
+#[tokio::test]
+async fn keep_the_constituents_of_the_date() -> io::Result<()> {
+    let service = Service::new();
+    let data = io::Data {
+        dataset: network::Dataset::Trades,
+        date: NaiveDate::from_ymd_opt(2024, 3, 14).unwrap(),
+    };
+    let database = Arc::new(TradesRecorder::default());
+    let deps = Arc::new(DependsFake {
+        snp: Arc::new(SnPFake {}),
+        network: Arc::new(NetworkRows {}),
+        database_trades: database.clone(),
+        database_aggregates: Arc::new(AggregatesDBServiceFake {}),
+        database_ticker_events: Arc::new(TickerEventsDBServiceFake {}),
+    });
+
+    let saved = service.ingest_flat_file(deps, &data).await?;
+
+    // MSFT isn't a constituent on the date, and FB and ZVZZT aren't the symbols of the date.
+    assert_eq!(1, saved);
+    let trades = database.trades.lock().unwrap();
+    assert_eq!(1, trades.len());
+    assert_eq!(snp::SnP::AAPL, trades[0].snp);
+    assert_eq!(vec![12, 37], trades[0].conditions);
+    Ok(())
+}
+
+#[tokio::test]
+async fn keep_the_constituents_of_an_old_symbol() -> io::Result<()> {
+    let service = Service::new();
+    let data = io::Data {
+        dataset: network::Dataset::Trades,
+        date: NaiveDate::from_ymd_opt(2022, 6, 8).unwrap(),
+    };
+    let database = Arc::new(TradesRecorder::default());
+    let deps = Arc::new(DependsFake {
+        snp: Arc::new(SnPFake {}),
+        network: Arc::new(NetworkRows {}),
+        database_trades: database.clone(),
+        database_aggregates: Arc::new(AggregatesDBServiceFake {}),
+        database_ticker_events: Arc::new(TickerEventsFB {}),
+    });
+
+    let saved = service.ingest_flat_file(deps, &data).await?;
+
+    // The constituent traded as FB on the date, so the rows of AAPL aren't its rows.
+    assert_eq!(1, saved);
+    let trades = database.trades.lock().unwrap();
+    assert_eq!(1, trades.len());
+    assert_eq!(snp::SnP::AAPL, trades[0].snp);
+    Ok(())
+}
+
+#[tokio::test]
+async fn store_day_bars() -> io::Result<()> {
+    let service = Service::new();
+    let data = io::Data {
+        dataset: network::Dataset::DayAggregates,
+        date: NaiveDate::from_ymd_opt(2024, 3, 14).unwrap(),
+    };
+    let database = Arc::new(BarsRecorder::default());
+    let deps = Arc::new(DependsFake {
+        snp: Arc::new(SnPFake {}),
+        network: Arc::new(NetworkRows {}),
+        database_trades: Arc::new(TradesDBServiceFake {}),
+        database_aggregates: database.clone(),
+        database_ticker_events: Arc::new(TickerEventsDBServiceFake {}),
+    });
+
+    service.ingest_flat_file(deps, &data).await?;
+
+    let bars = database.bars.lock().unwrap();
+    assert_eq!(1, bars.len());
+    assert_eq!(database_aggregates::Timespan::Day, bars[0].timespan);
+    assert_eq!(
+        DateTime::from_timestamp_millis(1710388800000).unwrap(),
+        bars[0].start
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn skip_the_holidays() -> io::Result<()> {
+    let service = Service::new();
+    let data = io::Data {
+        dataset: network::Dataset::Trades,
+        date: NaiveDate::from_ymd_opt(2024, 3, 16).unwrap(),
+    };
+    let database = Arc::new(TradesRecorder::default());
+    let deps = Arc::new(DependsFake {
+        snp: Arc::new(SnPFake {}),
+        network: Arc::new(NetworkRows {}),
+        database_trades: database.clone(),
+        database_aggregates: Arc::new(AggregatesDBServiceFake {}),
+        database_ticker_events: Arc::new(TickerEventsDBServiceFake {}),
+    });
+
+    let saved = service.ingest_flat_file(deps, &data).await?;
+
+    assert_eq!(0, saved);
+    assert!(database.trades.lock().unwrap().is_empty());
+    Ok(())
+}
+
+/// Knows AAPL and MSFT, but only AAPL is a constituent.
+struct SnPFake;
+
+impl snp::Api for SnPFake {
+    fn get_snp(&self, ticker: &str) -> snp::Result<snp::SnP> {
+        match ticker {
+            "AAPL" => Ok(snp::SnP::AAPL),
+            "MSFT" => Ok(snp::SnP::MSFT),
+            _ => Err(snp::Error::NotFound(ticker.into())),
+        }
+    }
+
+    fn get_symbol(&self, _snp: &snp::SnP) -> Arc<str> {
+        Arc::from("AAPL")
+    }
+
+    fn get_snp_map(&self) -> HashMap<snp::SnP, snp::Data> {
+        HashMap::new()
+    }
+
+    fn get_snp_by_date(&self, _date: &NaiveDate) -> Vec<snp::SnP> {
+        vec![snp::SnP::AAPL]
+    }
+
+    fn get_all_snp(&self) -> Vec<snp::SnP> {
+        vec![snp::SnP::AAPL, snp::SnP::MSFT]
+    }
+}
+
+/// Returns the rows of AAPL, MSFT, FB and ZVZZT of the dataset.
+struct NetworkRows;
+
+#[async_trait]
+impl network::Api for NetworkRows {
+    async fn get_rows(
+        &self,
+        _deps: Arc<dyn network::Depends + Send + Sync>,
+        dataset: network::Dataset,
+        _date: &NaiveDate,
+    ) -> network::Result<Option<network::Rows>> {
+        let (sender, rows) = tokio::sync::mpsc::channel(4);
+        for row in ["AAPL", "MSFT", "FB", "ZVZZT"]
+            .into_iter()
+            .map(|ticker| match dataset {
+                network::Dataset::Trades => Ok(network::Row::Trade {
+                    ticker: ticker.into(),
+                    data: given_trade(),
+                }),
+                _ => Ok(network::Row::Bar {
+                    ticker: ticker.into(),
+                    data: given_bar(),
+                }),
+            })
+        {
+            let _ = sender.try_send(row);
+        }
+        Ok(Some(rows))
+    }
+}
+
+/// Returns `FB` as the historical symbol of any ticker.
+struct TickerEventsFB;
+
+#[async_trait]
+impl ticker_events_database::Api for TickerEventsFB {
+    async fn save_ticker_changes(
+        &self,
+        _deps: Arc<dyn ticker_events_database::Depends + Send + Sync>,
+        _changes: &[ticker_events_database::TickerChange],
+    ) -> ticker_events_database::Result<()> {
+        Ok(())
+    }
+
+    async fn find_ticker_changes(
+        &self,
+        _deps: Arc<dyn ticker_events_database::Depends + Send + Sync>,
+        _composite_figi: &str,
+    ) -> ticker_events_database::Result<Vec<ticker_events_database::TickerChange>> {
+        Ok(vec![])
+    }
+
+    async fn find_historical_symbol(
+        &self,
+        _deps: Arc<dyn ticker_events_database::Depends + Send + Sync>,
+        _symbol: &str,
+        _date: &NaiveDate,
+    ) -> ticker_events_database::Result<Arc<str>> {
+        Ok(Arc::from("FB"))
+    }
+}
+
+fn given_trade() -> network_trades::Data {
+    network_trades::Data {
+        id: "52983525029461".into(),
+        price: 172.5,
+        size: 100,
+        participant_timestamp: None,
+        sip_timestamp: 1710423000010698000,
+        sequence_number: 1063,
+        exchange: 11,
+        exchange_name: None,
+        conditions: vec![12, 37],
+        condition_names: vec![],
+        correction: Some(0),
+        tape: Some(3),
+        trf_id: None,
+        trf_name: None,
+        trf_timestamp: None,
+    }
+}
+
+fn given_bar() -> network_aggregates::Data {
+    network_aggregates::Data {
+        open: 172.91,
+        high: 174.3078,
+        low: 172.05,
+        close: 173.0,
+        volume: 72913507.0,
+        vwap: None,
+        timestamp: 1710388800000,
+        transactions: Some(849018),
+    }
+}
+
+#[derive(Default)]
+struct TradesRecorder {
+    trades: Mutex<Vec<database_trades::Trade>>,
+}
+
+#[async_trait]
+impl database_trades::Api for TradesRecorder {
+    async fn save_trades(
+        &self,
+        _deps: Arc<dyn database_trades::Depends + Send + Sync>,
+        trades: &[database_trades::Trade],
+    ) -> database_trades::Result<()> {
+        self.trades.lock().unwrap().extend_from_slice(trades);
+        Ok(())
+    }
+
+    async fn find_trades(
+        &self,
+        _deps: Arc<dyn database_trades::Depends + Send + Sync>,
+        _snp: &snp::SnP,
+        _from: i64,
+        _to: i64,
+    ) -> database_trades::Result<Vec<database_trades::Trade>> {
+        Ok(self.trades.lock().unwrap().clone())
+    }
+
+    async fn save_cursor(
+        &self,
+        _deps: Arc<dyn database_trades::Depends + Send + Sync>,
+        _cursor: &database_trades::Cursor,
+    ) -> database_trades::Result<()> {
+        Ok(())
+    }
+
+    async fn find_cursor(
+        &self,
+        _deps: Arc<dyn database_trades::Depends + Send + Sync>,
+        _snp: &snp::SnP,
+        _date: &NaiveDate,
+    ) -> database_trades::Result<Option<database_trades::Cursor>> {
+        Ok(None)
+    }
+}
+
+#[derive(Default)]
+struct BarsRecorder {
+    bars: Mutex<Vec<database_aggregates::Bar>>,
+}
+
+#[async_trait]
+impl database_aggregates::Api for BarsRecorder {
+    async fn save_bars(
+        &self,
+        _deps: Arc<dyn database_aggregates::Depends + Send + Sync>,
+        bars: &[database_aggregates::Bar],
+    ) -> database_aggregates::Result<()> {
+        self.bars.lock().unwrap().extend_from_slice(bars);
+        Ok(())
+    }
+
+    async fn find_bars(
+        &self,
+        _deps: Arc<dyn database_aggregates::Depends + Send + Sync>,
+        _snp: &snp::SnP,
+        _timespan: database_aggregates::Timespan,
+        _from: &DateTime<Utc>,
+        _to: &DateTime<Utc>,
+    ) -> database_aggregates::Result<Vec<database_aggregates::Bar>> {
+        Ok(self.bars.lock().unwrap().clone())
+    }
+
+    async fn save_fetch(
+        &self,
+        _deps: Arc<dyn database_aggregates::Depends + Send + Sync>,
+        _fetch: &database_aggregates::Fetch,
+    ) -> database_aggregates::Result<()> {
+        Ok(())
+    }
+
+    async fn is_fetched(
+        &self,
+        _deps: Arc<dyn database_aggregates::Depends + Send + Sync>,
+        _fetch: &database_aggregates::Fetch,
+    ) -> database_aggregates::Result<bool> {
+        Ok(false)
+    }
+}
+
+struct DependsFake {
+    snp: Arc<dyn snp::Api + Send + Sync>,
+    network: Arc<dyn network::Api + Send + Sync>,
+    database_trades: Arc<dyn database_trades::Api + Send + Sync>,
+    database_aggregates: Arc<dyn database_aggregates::Api + Send + Sync>,
+    database_ticker_events: Arc<dyn ticker_events_database::Api + Send + Sync>,
+}
+
+impl io::Depends for DependsFake {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        self.snp.clone()
+    }
+
+    fn provide_calendar(&self) -> Arc<dyn calendar::Api + Send + Sync> {
+        Arc::new(CalendarServiceFake {})
+    }
+
+    fn provide_network_flat_files(&self) -> Arc<dyn network::Api + Send + Sync> {
+        self.network.clone()
+    }
+
+    fn provide_names(&self) -> Arc<network_trades::Names> {
+        Arc::new(network_trades::Names::default())
+    }
+
+    fn provide_database_trades(&self) -> Arc<dyn database_trades::Api + Send + Sync> {
+        self.database_trades.clone()
+    }
+
+    fn provide_database_quotes(&self) -> Arc<dyn database_quotes::Api + Send + Sync> {
+        Arc::new(QuotesDBServiceFake {})
+    }
+
+    fn provide_database_aggregates(&self) -> Arc<dyn database_aggregates::Api + Send + Sync> {
+        self.database_aggregates.clone()
+    }
+
+    fn provide_database_ticker_events(&self) -> Arc<dyn ticker_events_database::Api + Send + Sync> {
+        self.database_ticker_events.clone()
+    }
+}
@@ -0,0 +1,64 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use calendar_mod_io::calendar;
+use chrono::NaiveDate;
+use database_mod_aggregates_io::aggregates as database_aggregates;
+use database_mod_quotes_io::quotes as database_quotes;
+use database_mod_ticker_events_io::ticker_events as ticker_events_database;
+use database_mod_trades_io::trades as database_trades;
+use network_mod_flat_files_io::flat_files as network;
+use network_mod_trades_io::trades as network_trades;
+use snp_mod_io::snp;
+
+/// The data that is needed to interact with the Flat Files modules.
+#[derive(Debug)]
+pub struct Data {
+    pub dataset: network::Dataset,
+    pub date: NaiveDate,
+}
+
+/// All possible errors of Flat Files modules.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("{0}")]
+    TradesDb(#[from] database_trades::Error),
+    #[error("{0}")]
+    QuotesDb(#[from] database_quotes::Error),
+    #[error("{0}")]
+    AggregatesDb(#[from] database_aggregates::Error),
+    #[error("{0}")]
+    TickerEventsDb(#[from] ticker_events_database::Error),
+    #[error("{0}")]
+    Net(#[from] network::Error),
+    #[error("{0}")]
+    SnP(#[from] snp::Error),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The API of Flat Files, which defines all the functionality of Flat Files modules.
+#[async_trait]
+pub trait Api {
+    /// Stores the rows of the file of the dataset on the date that belong to the S&P 500
+    /// constituents of that date, and returns the number of the stored rows. The rows are
+    /// matched by the symbols of the constituents on that date, e.g. `FB` for `META` before
+    /// June 9, 2022.
+    async fn ingest_flat_file(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        data: &Data,
+    ) -> Result<usize>;
+}
+
+/// All the dependencies of the Flat Files modules to other modules.
+pub trait Depends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync>;
+    fn provide_calendar(&self) -> Arc<dyn calendar::Api + Send + Sync>;
+    fn provide_network_flat_files(&self) -> Arc<dyn network::Api + Send + Sync>;
+    fn provide_names(&self) -> Arc<network_trades::Names>;
+    fn provide_database_trades(&self) -> Arc<dyn database_trades::Api + Send + Sync>;
+    fn provide_database_quotes(&self) -> Arc<dyn database_quotes::Api + Send + Sync>;
+    fn provide_database_aggregates(&self) -> Arc<dyn database_aggregates::Api + Send + Sync>;
+    fn provide_database_ticker_events(&self) -> Arc<dyn ticker_events_database::Api + Send + Sync>;
+}
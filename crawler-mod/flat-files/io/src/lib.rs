@@ -0,0 +1,2 @@
+#[path = "crawler-flat-files.rs"]
+pub mod crawler_flat_files;
@@ -0,0 +1,191 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use calendar_mod_io::calendar;
+use chrono::{DateTime, NaiveDate, NaiveTime, TimeZone, Utc};
+use chrono_tz::America::New_York;
+use database_mod_aggregates_io::aggregates::Timespan;
+use network_mod_aggregates_io::aggregates as network_aggregates;
+use snp_mod_io::snp;
+
+use crawler_mod_indices_io::crawler_indices as io;
+use database_mod_indices_io::indices as database;
+use network_mod_indices_io::indices as network;
+use policy_mod_io::policy;
+
+#[derive(Debug)]
+pub struct Service;
+
+/// The service to implement the API of Indices.
+impl Service {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Default for Service {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl io::Api for Service {
+    async fn crawl_index_aggregates(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        data: &io::Data,
+        policy_data: &mut policy::Data,
+    ) -> io::Result<()> {
+        let Some(calendar::Session { open, close }) =
+            deps.provide_calendar().get_session(&data.date)
+        else {
+            return Ok(());
+        };
+
+        let policy = deps.provide_policy().clone();
+
+        let snp = deps.provide_snp().clone();
+        let network_service = deps.provide_network_indices().clone();
+        let database_service = deps.provide_database_indices().clone();
+        let network_depends = Arc::new(NetworkDepends { snp: snp.clone() });
+        let database_depends = Arc::new(DatabaseDepends { snp: snp.clone() });
+
+        let (day_start, day_end) = day(&data.date);
+        let ranges = [
+            (Timespan::Day, day_start, day_end),
+            (Timespan::Minute, open, close),
+        ];
+        for (timespan, from, to) in ranges {
+            let stored = database_service
+                .find_bars(database_depends.clone(), data.index, timespan, &from, &to)
+                .await?;
+            if !stored.is_empty() {
+                continue;
+            }
+
+            let request = network::Request {
+                index: data.index,
+                multiplier: 1,
+                timespan: match timespan {
+                    Timespan::Minute => network_aggregates::Timespan::Minute,
+                    Timespan::Hour => network_aggregates::Timespan::Hour,
+                    Timespan::Day => network_aggregates::Timespan::Day,
+                },
+                from: data.date,
+                to: data.date,
+            };
+            let response = network_service
+                .get_aggregates(network_depends.clone(), &request)
+                .await;
+            policy.handle_request_rate(policy_data).await?;
+            let network_bars = match response {
+                Ok(bars) => bars,
+                Err(_) => return Ok(()),
+            };
+            database_service
+                .save_bars(
+                    database_depends.clone(),
+                    &to_bars(&network_bars, data.index, timespan),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn capture_snapshots(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        policy_data: &mut policy::Data,
+    ) -> io::Result<usize> {
+        let policy = deps.provide_policy().clone();
+
+        let snp = deps.provide_snp().clone();
+        let network_depends = Arc::new(NetworkDepends { snp: snp.clone() });
+        let database_depends = Arc::new(DatabaseDepends { snp: snp.clone() });
+
+        let response = deps
+            .provide_network_indices()
+            .get_snapshots(network_depends, &snp::Index::ALL)
+            .await;
+        policy.handle_request_rate(policy_data).await?;
+        let snapshots: Vec<database::Snapshot> = match response {
+            Ok(snapshots) => snapshots.into_iter().map(to_snapshot).collect(),
+            Err(_) => return Ok(0),
+        };
+
+        deps.provide_database_indices()
+            .save_snapshots(database_depends, &snapshots)
+            .await?;
+        Ok(snapshots.len())
+    }
+}
+
+/// The start and the end of the date in New York, where Polygon.io starts the daily bars.
+fn day(date: &NaiveDate) -> (DateTime<Utc>, DateTime<Utc>) {
+    (
+        new_york(date, NaiveTime::MIN),
+        new_york(&date.succ_opt().unwrap(), NaiveTime::MIN),
+    )
+}
+
+fn new_york(date: &NaiveDate, time: NaiveTime) -> DateTime<Utc> {
+    New_York
+        .from_local_datetime(&date.and_time(time))
+        .unwrap()
+        .with_timezone(&Utc)
+}
+
+fn to_bars(bars: &[network::Bar], index: snp::Index, timespan: Timespan) -> Vec<database::Bar> {
+    bars.iter()
+        .filter_map(|b| {
+            Some(database::Bar {
+                index,
+                timespan,
+                start: DateTime::from_timestamp_millis(b.timestamp)?,
+                open: b.open,
+                high: b.high,
+                low: b.low,
+                close: b.close,
+            })
+        })
+        .collect()
+}
+
+fn to_snapshot(snapshot: network::Snapshot) -> database::Snapshot {
+    database::Snapshot {
+        index: snapshot.index,
+        updated: DateTime::from_timestamp_nanos(snapshot.last_updated),
+        value: snapshot.value,
+        market_status: snapshot.market_status,
+        session: snapshot.session.map(|s| database::Session {
+            open: s.open,
+            high: s.high,
+            low: s.low,
+            close: s.close,
+            previous_close: s.previous_close,
+            change: s.change,
+            change_percent: s.change_percent,
+        }),
+    }
+}
+
+struct NetworkDepends {
+    snp: Arc<dyn snp::Api + Send + Sync>,
+}
+
+impl network::Depends for NetworkDepends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        self.snp.clone()
+    }
+}
+
+struct DatabaseDepends {
+    snp: Arc<dyn snp::Api + Send + Sync>,
+}
+
+impl database::Depends for DatabaseDepends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        self.snp.clone()
+    }
+}
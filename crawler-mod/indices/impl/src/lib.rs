@@ -0,0 +1,5 @@
+#[path = "crawler-indices-impl.rs"]
+pub mod crawler_indices_impl;
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,253 @@
+use std::sync::{Arc, Mutex};
+
+use crate::crawler_indices_impl::Service;
+use async_trait::async_trait;
+use calendar_mod_fixture::calendar_fixture::ServiceFake as CalendarServiceFake;
+use calendar_mod_io::calendar;
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+use crawler_mod_indices_io::crawler_indices as io;
+use database_mod_aggregates_io::aggregates::Timespan;
+use database_mod_indices_fixture::indices_fixture::ServiceFake as DBServiceFake;
+use database_mod_indices_io::indices as database;
+use io::Api;
+use network_mod_aggregates_io::aggregates as network_aggregates;
+use network_mod_indices_fixture::indices_fixture::ServiceFake as NetServiceFake;
+use network_mod_indices_io::indices as network;
+use policy_mod_fixture::policy_fixture::ServiceFake as PolicyServiceFake;
+use policy_mod_io::policy;
+use snp_mod_fixture::snp_fixture::ServiceFake as SnPServiceFake;
+use snp_mod_io::snp;
+
+#[tokio::test]
+async fn call_crawl_index_aggregates() -> io::Result<()> {
+    let service = Service::new();
+    let data = io::Data {
+        index: snp::Index::SPX,
+        date: NaiveDate::from_ymd_opt(2024, 3, 14).unwrap(),
+    };
+    let deps = Arc::new(DependsFake {
+        network: Arc::new(NetServiceFake {}),
+        database: Arc::new(DBServiceFake {}),
+    });
+    let mut policy_data = policy::Data::default();
+
+    service
+        .crawl_index_aggregates(deps, &data, &mut policy_data)
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn store_day_and_minute_bars_once() -> io::Result<()> {
+    let service = Service::new();
+    let data = io::Data {
+        index: snp::Index::SPX,
+        date: NaiveDate::from_ymd_opt(2024, 3, 14).unwrap(),
+    };
+    let network = Arc::new(NetworkIndices::default());
+    let database = Arc::new(DatabaseRecorder::default());
+    let deps = Arc::new(DependsFake {
+        network: network.clone(),
+        database: database.clone(),
+    });
+    let mut policy_data = policy::Data::default();
+
+    service
+        .crawl_index_aggregates(deps.clone(), &data, &mut policy_data)
+        .await?;
+    service
+        .crawl_index_aggregates(deps, &data, &mut policy_data)
+        .await?;
+
+    assert_eq!(
+        vec![
+            network_aggregates::Timespan::Day,
+            network_aggregates::Timespan::Minute
+        ],
+        *network.requests.lock().unwrap()
+    );
+    let bars = database.bars.lock().unwrap();
+    assert_eq!(2, bars.len());
+    assert_eq!(
+        database::Bar {
+            index: snp::Index::SPX,
+            timespan: Timespan::Day,
+            start: Utc.with_ymd_and_hms(2024, 3, 14, 4, 0, 0).unwrap(),
+            open: 5175.14,
+            high: 5176.85,
+            low: 5123.3,
+            close: 5150.48,
+        },
+        bars[0]
+    );
+    assert_eq!(Timespan::Minute, bars[1].timespan);
+    Ok(())
+}
+
+#[tokio::test]
+async fn skip_the_weekend() -> io::Result<()> {
+    let service = Service::new();
+    let data = io::Data {
+        index: snp::Index::NDX,
+        date: NaiveDate::from_ymd_opt(2024, 3, 16).unwrap(),
+    };
+    let network = Arc::new(NetworkIndices::default());
+    let deps = Arc::new(DependsFake {
+        network: network.clone(),
+        database: Arc::new(DatabaseRecorder::default()),
+    });
+    let mut policy_data = policy::Data::default();
+
+    service
+        .crawl_index_aggregates(deps, &data, &mut policy_data)
+        .await?;
+
+    assert!(network.requests.lock().unwrap().is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn capture_entitled_snapshots() -> io::Result<()> {
+    let service = Service::new();
+    let database = Arc::new(DatabaseRecorder::default());
+    let deps = Arc::new(DependsFake {
+        network: Arc::new(NetworkIndices::default()),
+        database: database.clone(),
+    });
+    let mut policy_data = policy::Data::default();
+
+    let saved = service.capture_snapshots(deps, &mut policy_data).await?;
+
+    assert_eq!(1, saved);
+    assert_eq!(
+        vec![database::Snapshot {
+            index: snp::Index::SPX,
+            updated: Utc.with_ymd_and_hms(2024, 3, 15, 0, 0, 0).unwrap(),
+            value: 5150.48,
+            market_status: Some("closed".into()),
+            session: None,
+        }],
+        *database.snapshots.lock().unwrap()
+    );
+    Ok(())
+}
+
+/// Returns a bar of each request, and the snapshot of SPX only, recording the timespans of the
+/// requests.
+#[derive(Default)]
+struct NetworkIndices {
+    requests: Mutex<Vec<network_aggregates::Timespan>>,
+}
+
+#[async_trait]
+impl network::Api for NetworkIndices {
+    async fn get_aggregates(
+        &self,
+        _deps: Arc<dyn network::Depends + Send + Sync>,
+        request: &network::Request,
+    ) -> network::Result<Vec<network::Bar>> {
+        self.requests.lock().unwrap().push(request.timespan);
+        Ok(vec![network::Bar {
+            open: 5175.14,
+            high: 5176.85,
+            low: 5123.3,
+            close: 5150.48,
+            timestamp: 1710388800000,
+        }])
+    }
+
+    async fn get_snapshots(
+        &self,
+        _deps: Arc<dyn network::Depends + Send + Sync>,
+        _indices: &[snp::Index],
+    ) -> network::Result<Vec<network::Snapshot>> {
+        Ok(vec![network::Snapshot {
+            index: snp::Index::SPX,
+            value: 5150.48,
+            market_status: Some("closed".into()),
+            last_updated: 1710460800000000000,
+            session: None,
+        }])
+    }
+}
+
+#[derive(Default)]
+struct DatabaseRecorder {
+    bars: Mutex<Vec<database::Bar>>,
+    snapshots: Mutex<Vec<database::Snapshot>>,
+}
+
+#[async_trait]
+impl database::Api for DatabaseRecorder {
+    async fn save_bars(
+        &self,
+        _deps: Arc<dyn database::Depends + Send + Sync>,
+        bars: &[database::Bar],
+    ) -> database::Result<()> {
+        self.bars.lock().unwrap().extend_from_slice(bars);
+        Ok(())
+    }
+
+    async fn find_bars(
+        &self,
+        _deps: Arc<dyn database::Depends + Send + Sync>,
+        index: snp::Index,
+        timespan: Timespan,
+        _from: &DateTime<Utc>,
+        _to: &DateTime<Utc>,
+    ) -> database::Result<Vec<database::Bar>> {
+        let bars = self.bars.lock().unwrap();
+        Ok(bars
+            .iter()
+            .filter(|b| b.index == index && b.timespan == timespan)
+            .cloned()
+            .collect())
+    }
+
+    async fn save_snapshots(
+        &self,
+        _deps: Arc<dyn database::Depends + Send + Sync>,
+        snapshots: &[database::Snapshot],
+    ) -> database::Result<()> {
+        self.snapshots.lock().unwrap().extend_from_slice(snapshots);
+        Ok(())
+    }
+
+    async fn find_snapshots(
+        &self,
+        _deps: Arc<dyn database::Depends + Send + Sync>,
+        _index: snp::Index,
+        _from: &DateTime<Utc>,
+        _to: &DateTime<Utc>,
+    ) -> database::Result<Vec<database::Snapshot>> {
+        Ok(self.snapshots.lock().unwrap().clone())
+    }
+}
+
+struct DependsFake {
+    network: Arc<dyn network::Api + Send + Sync>,
+    database: Arc<dyn database::Api + Send + Sync>,
+}
+
+impl io::Depends for DependsFake {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        Arc::new(SnPServiceFake {})
+    }
+
+    fn provide_policy(&self) -> Arc<dyn policy::Api + Send + Sync> {
+        Arc::new(PolicyServiceFake {})
+    }
+
+    fn provide_calendar(&self) -> Arc<dyn calendar::Api + Send + Sync> {
+        Arc::new(CalendarServiceFake {})
+    }
+
+    fn provide_network_indices(&self) -> Arc<dyn network::Api + Send + Sync> {
+        self.network.clone()
+    }
+
+    fn provide_database_indices(&self) -> Arc<dyn database::Api + Send + Sync> {
+        self.database.clone()
+    }
+}
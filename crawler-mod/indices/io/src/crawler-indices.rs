@@ -0,0 +1,60 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use calendar_mod_io::calendar;
+use chrono::NaiveDate;
+use database_mod_indices_io::indices as database;
+use network_mod_indices_io::indices as network;
+use policy_mod_io::policy;
+use snp_mod_io::snp;
+
+/// The data that is needed to interact with the Indices modules.
+#[derive(Debug)]
+pub struct Data {
+    pub index: snp::Index,
+    pub date: NaiveDate,
+}
+
+/// All possible errors of Indices modules.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("{0}")]
+    Db(#[from] database::Error),
+    #[error("{0}")]
+    Net(#[from] network::Error),
+    #[error("{0}")]
+    Policy(#[from] policy::Error),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The API of Indices, which defines all the functionality of Indices modules.
+#[async_trait]
+pub trait Api {
+    /// Stores the daily bar and the minute bars of the index on the date, unless they're
+    /// stored already.
+    async fn crawl_index_aggregates(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        data: &Data,
+        policy_data: &mut policy::Data,
+    ) -> Result<()>;
+
+    /// Requests the snapshots of all the indices by one request, and stores them. Returns the
+    /// number of the stored snapshots, where the indices that the plan isn't entitled to are
+    /// missing.
+    async fn capture_snapshots(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        policy_data: &mut policy::Data,
+    ) -> Result<usize>;
+}
+
+/// All the dependencies of the Indices modules to other modules.
+pub trait Depends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync>;
+    fn provide_policy(&self) -> Arc<dyn policy::Api + Send + Sync>;
+    fn provide_calendar(&self) -> Arc<dyn calendar::Api + Send + Sync>;
+    fn provide_network_indices(&self) -> Arc<dyn network::Api + Send + Sync>;
+    fn provide_database_indices(&self) -> Arc<dyn database::Api + Send + Sync>;
+}
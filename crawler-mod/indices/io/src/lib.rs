@@ -0,0 +1,2 @@
+#[path = "crawler-indices.rs"]
+pub mod crawler_indices;
@@ -0,0 +1,122 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use snp_mod_io::snp;
+
+use crawler_mod_news_io::crawler_news as io;
+use database_mod_news_io::news as news_database;
+use network_mod_news_io::news as news_network;
+use policy_mod_io::policy;
+
+#[derive(Debug)]
+pub struct Service;
+
+/// The service to implement the API of News.
+impl Service {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Default for Service {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl io::Api for Service {
+    async fn crawl_news(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        data: &io::Data,
+        policy_data: &mut policy::Data,
+    ) -> io::Result<()> {
+        let policy = deps.provide_policy().clone();
+
+        let snp = deps.provide_snp().clone();
+        let network_depends = Arc::new(NetworkDepends { snp: snp.clone() });
+        let database_depends = Arc::new(DatabaseDepends { snp: snp.clone() });
+
+        let last_published = deps
+            .provide_database_news()
+            .find_last_published(database_depends.clone(), &data.ticker)
+            .await?;
+
+        let response = deps
+            .provide_network_news()
+            .get_news(network_depends.clone(), &data.ticker, last_published)
+            .await;
+        policy.handle_request_rate(policy_data).await?;
+        let articles: Vec<news_database::Article> = response?
+            .into_iter()
+            .map(|a| to_article(a, snp.as_ref()))
+            .collect();
+        deps.provide_database_news()
+            .save_articles(database_depends.clone(), &articles)
+            .await?;
+        // Only the articles of the ticker's own crawl move its watermark, not the ones that
+        // the crawls of other tickers link to it.
+        if let Some(published_utc) = articles.iter().map(|a| a.published_utc).max() {
+            deps.provide_database_news()
+                .save_last_published(database_depends.clone(), &data.ticker, &published_utc)
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Links the article to the tickers that are in the S&P 500, and drops the others.
+fn to_article(
+    data: news_network::Data,
+    snp: &(dyn snp::Api + Send + Sync),
+) -> news_database::Article {
+    let mut tickers: Vec<news_database::TickerLink> = vec![];
+    for ticker in &data.tickers {
+        let Ok(ticker_snp) = snp.get_snp(ticker) else {
+            continue;
+        };
+        if tickers.iter().any(|t| t.snp == ticker_snp) {
+            continue;
+        }
+        let insight = data.insights.iter().find(|i| &i.ticker == ticker);
+        tickers.push(news_database::TickerLink {
+            snp: ticker_snp,
+            sentiment: insight.and_then(|i| i.sentiment.clone()),
+            sentiment_reasoning: insight.and_then(|i| i.sentiment_reasoning.clone()),
+        });
+    }
+
+    news_database::Article {
+        polygon_id: data.id,
+        publisher: data.publisher,
+        title: data.title,
+        author: data.author,
+        published_utc: data.published_utc,
+        article_url: data.article_url,
+        description: data.description,
+        keywords: data.keywords,
+        tickers,
+    }
+}
+
+struct NetworkDepends {
+    snp: Arc<dyn snp::Api + Send + Sync>,
+}
+
+impl news_network::Depends for NetworkDepends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        self.snp.clone()
+    }
+}
+
+struct DatabaseDepends {
+    snp: Arc<dyn snp::Api + Send + Sync>,
+}
+
+impl news_database::Depends for DatabaseDepends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        self.snp.clone()
+    }
+}
@@ -0,0 +1,5 @@
+#[path = "crawler-news-impl.rs"]
+pub mod crawler_news_impl;
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,171 @@
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use chrono::{DateTime, TimeZone, Utc};
+
+use crate::crawler_news_impl::Service;
+use crawler_mod_news_io::crawler_news as io;
+use database_mod_news_fixture::news_fixture::ServiceFake as NewsDBServiceFake;
+use database_mod_news_io::news as news_database;
+use io::Api;
+use network_mod_news_fixture::news_fixture::ServiceFake as NewsNetServiceFake;
+use network_mod_news_io::news as news_network;
+use policy_mod_fixture::policy_fixture::ServiceFake as PolicyServiceFake;
+use policy_mod_io::policy;
+use snp_mod_fixture::snp_fixture::ServiceFake as SnPServiceFake;
+use snp_mod_io::snp;
+
+#[tokio::test]
+async fn call_crawl_news() -> io::Result<()> {
+    let service = Service::new();
+    let data = io::Data {
+        ticker: snp::SnP::AAPL,
+    };
+    let deps = given_deps(
+        Arc::new(NewsNetServiceFake {}),
+        Arc::new(NewsDBServiceFake {}),
+    );
+    let mut policy_data = policy::Data::default();
+
+    service.crawl_news(deps, &data, &mut policy_data).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn keep_the_watermark_of_the_crawled_ticker_only() -> io::Result<()> {
+    let service = Service::new();
+    let data = io::Data {
+        ticker: snp::SnP::AAPL,
+    };
+    let database = Arc::new(DatabaseRecorder::default());
+    let deps = given_deps(Arc::new(NewsNetServiceFake {}), database.clone());
+    let mut policy_data = policy::Data::default();
+
+    // The article of the fixture mentions MSFT too.
+    service.crawl_news(deps, &data, &mut policy_data).await?;
+
+    assert_eq!(
+        vec![(
+            snp::SnP::AAPL,
+            Utc.with_ymd_and_hms(2024, 6, 10, 20, 15, 0).unwrap()
+        )],
+        *database.watermarks.lock().unwrap()
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn return_the_error_of_the_request() {
+    let service = Service::new();
+    let data = io::Data {
+        ticker: snp::SnP::AAPL,
+    };
+    let database = Arc::new(DatabaseRecorder::default());
+    let deps = given_deps(Arc::new(NetworkError {}), database.clone());
+    let mut policy_data = policy::Data::default();
+
+    let result = service.crawl_news(deps, &data, &mut policy_data).await;
+
+    assert!(
+        matches!(result, Err(io::Error::Net(_))),
+        "Unexpected {:?}",
+        result
+    );
+    assert!(database.watermarks.lock().unwrap().is_empty());
+}
+
+/// Fails like a request over the rate limit.
+struct NetworkError;
+
+#[async_trait]
+impl news_network::Api for NetworkError {
+    async fn get_news(
+        &self,
+        _deps: Arc<dyn news_network::Depends + Send + Sync>,
+        _ticker: &snp::SnP,
+        _published_after: Option<DateTime<Utc>>,
+    ) -> news_network::Result<Vec<news_network::Data>> {
+        Err(news_network::Error::Server(
+            "/v2/reference/news".into(),
+            "You've exceeded the maximum requests".into(),
+        ))
+    }
+}
+
+#[derive(Default)]
+struct DatabaseRecorder {
+    watermarks: Mutex<Vec<(snp::SnP, DateTime<Utc>)>>,
+}
+
+#[async_trait]
+impl news_database::Api for DatabaseRecorder {
+    async fn save_articles(
+        &self,
+        _deps: Arc<dyn news_database::Depends + Send + Sync>,
+        _articles: &[news_database::Article],
+    ) -> news_database::Result<()> {
+        Ok(())
+    }
+
+    async fn find_articles(
+        &self,
+        _deps: Arc<dyn news_database::Depends + Send + Sync>,
+        _snp: &snp::SnP,
+        _from: &DateTime<Utc>,
+        _to: &DateTime<Utc>,
+    ) -> news_database::Result<Vec<news_database::Article>> {
+        Ok(vec![])
+    }
+
+    async fn save_last_published(
+        &self,
+        _deps: Arc<dyn news_database::Depends + Send + Sync>,
+        snp: &snp::SnP,
+        published_utc: &DateTime<Utc>,
+    ) -> news_database::Result<()> {
+        self.watermarks
+            .lock()
+            .unwrap()
+            .push((snp.clone(), *published_utc));
+        Ok(())
+    }
+
+    async fn find_last_published(
+        &self,
+        _deps: Arc<dyn news_database::Depends + Send + Sync>,
+        _snp: &snp::SnP,
+    ) -> news_database::Result<Option<DateTime<Utc>>> {
+        Ok(None)
+    }
+}
+
+fn given_deps(
+    network: Arc<dyn news_network::Api + Send + Sync>,
+    database: Arc<dyn news_database::Api + Send + Sync>,
+) -> Arc<dyn io::Depends + Send + Sync> {
+    Arc::new(DependsFake { network, database })
+}
+
+struct DependsFake {
+    network: Arc<dyn news_network::Api + Send + Sync>,
+    database: Arc<dyn news_database::Api + Send + Sync>,
+}
+
+impl io::Depends for DependsFake {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        Arc::new(SnPServiceFake {})
+    }
+
+    fn provide_policy(&self) -> Arc<dyn policy::Api + Send + Sync> {
+        Arc::new(PolicyServiceFake {})
+    }
+
+    fn provide_network_news(&self) -> Arc<dyn news_network::Api + Send + Sync> {
+        self.network.clone()
+    }
+
+    fn provide_database_news(&self) -> Arc<dyn news_database::Api + Send + Sync> {
+        self.database.clone()
+    }
+}
@@ -0,0 +1,50 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use database_mod_news_io::news as news_database;
+use network_mod_news_io::news as news_network;
+use policy_mod_io::policy;
+use snp_mod_io::snp;
+
+/// The data that is needed to interact with the News modules.
+#[derive(Debug)]
+pub struct Data {
+    pub ticker: snp::SnP,
+}
+
+/// All possible errors of News modules.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("{0}")]
+    Db(#[from] news_database::Error),
+    #[error("{0}")]
+    Net(#[from] news_network::Error),
+    #[error("{0}")]
+    Policy(#[from] policy::Error),
+    #[error("{0}")]
+    SnP(#[from] snp::Error),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The API of News, which defines all the functionality of News modules.
+#[async_trait]
+pub trait Api {
+    /// Requests the articles of the ticker that are published after the latest one of its
+    /// previous crawl, and stores them, linked to all the S&P 500 tickers they mention. The
+    /// error of the request is returned, and the watermark is kept.
+    async fn crawl_news(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        data: &Data,
+        policy_data: &mut policy::Data,
+    ) -> Result<()>;
+}
+
+/// All the dependencies of the News modules to other modules.
+pub trait Depends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync>;
+    fn provide_policy(&self) -> Arc<dyn policy::Api + Send + Sync>;
+    fn provide_network_news(&self) -> Arc<dyn news_network::Api + Send + Sync>;
+    fn provide_database_news(&self) -> Arc<dyn news_database::Api + Send + Sync>;
+}
@@ -0,0 +1,2 @@
+#[path = "crawler-news.rs"]
+pub mod crawler_news;
@@ -0,0 +1,116 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use snp_mod_io::snp;
+
+use crawler_mod_options_contracts_io::crawler_options_contracts as io;
+use database_mod_options_contracts_io::options_contracts as options_contracts_database;
+use network_mod_options_contracts_io::options_contracts as options_contracts_network;
+use policy_mod_io::policy;
+
+#[derive(Debug)]
+pub struct Service;
+
+/// The service to implement the API of Options-contracts.
+impl Service {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Default for Service {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl io::Api for Service {
+    async fn crawl_options_contracts(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        data: &io::Data,
+        policy_data: &mut policy::Data,
+    ) -> io::Result<()> {
+        if !deps.provide_calendar().is_trading_day(&data.date) {
+            return Ok(());
+        }
+
+        let policy = deps.provide_policy().clone();
+
+        let snp = deps.provide_snp().clone();
+        let network_depends = Arc::new(NetworkDepends { snp: snp.clone() });
+        let database_depends = Arc::new(DatabaseDepends { snp: snp.clone() });
+        let database_service = deps.provide_database_options_contracts().clone();
+
+        let stored = database_service
+            .find_chain(database_depends.clone(), &data.ticker, &data.date)
+            .await?;
+        if !stored.is_empty() {
+            return Ok(());
+        }
+
+        let request = options_contracts_network::Request {
+            underlying: data.ticker.clone(),
+            contract_type: None,
+            expiration_date: None,
+            strike_price: None,
+            as_of: Some(data.date),
+        };
+        let response = deps
+            .provide_network_options_contracts()
+            .get_options_contracts(network_depends.clone(), &request)
+            .await;
+        policy.handle_request_rate(policy_data).await?;
+        if let Ok(contracts) = response {
+            let contracts: Vec<options_contracts_database::Contract> = contracts
+                .into_iter()
+                .map(|c| to_contract(c, &data.ticker, &data.date))
+                .collect();
+            database_service
+                .save_contracts(database_depends.clone(), &contracts)
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+fn to_contract(
+    data: options_contracts_network::Data,
+    snp: &snp::SnP,
+    as_of: &NaiveDate,
+) -> options_contracts_database::Contract {
+    options_contracts_database::Contract {
+        snp: snp.clone(),
+        as_of: *as_of,
+        ticker: data.ticker,
+        contract_type: data.contract_type,
+        exercise_style: data.exercise_style,
+        expiration_date: data.expiration_date,
+        strike_price: data.strike_price,
+        shares_per_contract: data.shares_per_contract,
+        primary_exchange: data.primary_exchange,
+    }
+}
+
+struct NetworkDepends {
+    snp: Arc<dyn snp::Api + Send + Sync>,
+}
+
+impl options_contracts_network::Depends for NetworkDepends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        self.snp.clone()
+    }
+}
+
+struct DatabaseDepends {
+    snp: Arc<dyn snp::Api + Send + Sync>,
+}
+
+impl options_contracts_database::Depends for DatabaseDepends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        self.snp.clone()
+    }
+}
@@ -0,0 +1,5 @@
+#[path = "crawler-options-contracts-impl.rs"]
+pub mod crawler_options_contracts_impl;
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,67 @@
+use std::sync::Arc;
+
+use crate::crawler_options_contracts_impl::Service;
+use calendar_mod_fixture::calendar_fixture::ServiceFake as CalendarServiceFake;
+use calendar_mod_io::calendar;
+use chrono::NaiveDate;
+use crawler_mod_options_contracts_io::crawler_options_contracts as io;
+use database_mod_options_contracts_fixture::options_contracts_fixture::ServiceFake as OptionsContractsDBServiceFake;
+use database_mod_options_contracts_io::options_contracts as options_contracts_database;
+use io::Api;
+use network_mod_options_contracts_fixture::options_contracts_fixture::ServiceFake as OptionsContractsNetServiceFake;
+use network_mod_options_contracts_io::options_contracts as options_contracts_network;
+use policy_mod_fixture::policy_fixture::ServiceFake as PolicyServiceFake;
+use policy_mod_io::policy;
+use snp_mod_fixture::snp_fixture::ServiceFake as SnPServiceFake;
+use snp_mod_io::snp;
+
+#[tokio::test]
+async fn call_crawl_options_contracts() -> io::Result<()> {
+    let service = Service::new();
+    let data = io::Data {
+        ticker: snp::SnP::AAPL,
+        date: NaiveDate::from_ymd_opt(2024, 6, 11).unwrap(),
+    };
+    let deps = given_deps();
+    let mut policy_data = policy::Data::default();
+
+    service
+        .crawl_options_contracts(deps, &data, &mut policy_data)
+        .await?;
+
+    Ok(())
+}
+
+fn given_deps() -> Arc<dyn io::Depends + Send + Sync> {
+    Arc::new(DependsFake {})
+}
+
+struct DependsFake {}
+
+impl DependsFake {}
+
+impl io::Depends for DependsFake {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        Arc::new(SnPServiceFake {})
+    }
+
+    fn provide_policy(&self) -> Arc<dyn policy::Api + Send + Sync> {
+        Arc::new(PolicyServiceFake {})
+    }
+
+    fn provide_calendar(&self) -> Arc<dyn calendar::Api + Send + Sync> {
+        Arc::new(CalendarServiceFake {})
+    }
+
+    fn provide_network_options_contracts(
+        &self,
+    ) -> Arc<dyn options_contracts_network::Api + Send + Sync> {
+        Arc::new(OptionsContractsNetServiceFake {})
+    }
+
+    fn provide_database_options_contracts(
+        &self,
+    ) -> Arc<dyn options_contracts_database::Api + Send + Sync> {
+        Arc::new(OptionsContractsDBServiceFake {})
+    }
+}
@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use calendar_mod_io::calendar;
+use chrono::NaiveDate;
+use database_mod_options_contracts_io::options_contracts as options_contracts_database;
+use network_mod_options_contracts_io::options_contracts as options_contracts_network;
+use policy_mod_io::policy;
+use snp_mod_io::snp;
+
+/// The data that is needed to interact with the Options-contracts modules.
+#[derive(Debug)]
+pub struct Data {
+    pub ticker: snp::SnP,
+    pub date: NaiveDate,
+}
+
+/// All possible errors of Options-contracts modules.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("{0}")]
+    Db(#[from] options_contracts_database::Error),
+    #[error("{0}")]
+    Net(#[from] options_contracts_network::Error),
+    #[error("{0}")]
+    Policy(#[from] policy::Error),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The API of Options-contracts, which defines all the functionality of Options-contracts
+/// modules.
+#[async_trait]
+pub trait Api {
+    /// Stores the options chain of the ticker as of the date, unless it's stored already or
+    /// the market is closed.
+    async fn crawl_options_contracts(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        data: &Data,
+        policy_data: &mut policy::Data,
+    ) -> Result<()>;
+}
+
+/// All the dependencies of the Options-contracts modules to other modules.
+pub trait Depends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync>;
+    fn provide_policy(&self) -> Arc<dyn policy::Api + Send + Sync>;
+    fn provide_calendar(&self) -> Arc<dyn calendar::Api + Send + Sync>;
+    fn provide_network_options_contracts(
+        &self,
+    ) -> Arc<dyn options_contracts_network::Api + Send + Sync>;
+    fn provide_database_options_contracts(
+        &self,
+    ) -> Arc<dyn options_contracts_database::Api + Send + Sync>;
+}
@@ -0,0 +1,2 @@
+#[path = "crawler-options-contracts.rs"]
+pub mod crawler_options_contracts;
@@ -0,0 +1,133 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use snp_mod_io::snp;
+
+use crawler_mod_references_io::crawler_references as io;
+use database_mod_conditions_io::conditions as conditions_database;
+use database_mod_exchanges_io::exchanges as exchanges_database;
+use network_mod_conditions_io::conditions as conditions_network;
+use network_mod_exchanges_io::exchanges as exchanges_network;
+use policy_mod_io::policy;
+
+#[derive(Debug)]
+pub struct Service;
+
+/// The service to implement the API of References.
+impl Service {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Default for Service {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl io::Api for Service {
+    async fn crawl_references(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        data: &io::Data,
+        policy_data: &mut policy::Data,
+    ) -> io::Result<()> {
+        let policy = deps.provide_policy().clone();
+
+        let snp = deps.provide_snp().clone();
+        let network_depends = Arc::new(NetworkDepends { snp: snp.clone() });
+        let database_depends = Arc::new(DatabaseDepends { snp: snp.clone() });
+
+        let response = deps
+            .provide_network_exchanges()
+            .get_exchanges(network_depends.clone(), &data.asset_class)
+            .await;
+        policy.handle_request_rate(policy_data).await?;
+        if let Ok(exchanges) = response {
+            let exchanges: Vec<exchanges_database::Exchange> =
+                exchanges.into_iter().map(to_exchange).collect();
+            deps.provide_database_exchanges()
+                .save_exchanges(database_depends.clone(), &exchanges)
+                .await?;
+        }
+
+        let response = deps
+            .provide_network_conditions()
+            .get_conditions(network_depends.clone(), &data.asset_class)
+            .await;
+        policy.handle_request_rate(policy_data).await?;
+        if let Ok(conditions) = response {
+            let conditions: Vec<conditions_database::Condition> =
+                conditions.into_iter().map(to_condition).collect();
+            deps.provide_database_conditions()
+                .save_conditions(database_depends.clone(), &conditions)
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+fn to_exchange(data: exchanges_network::Data) -> exchanges_database::Exchange {
+    exchanges_database::Exchange {
+        id: data.id,
+        exchange_type: data.exchange_type,
+        asset_class: data.asset_class,
+        locale: data.locale,
+        name: data.name,
+        acronym: data.acronym,
+        mic: data.mic,
+        operating_mic: data.operating_mic,
+        participant_id: data.participant_id,
+        url: data.url,
+    }
+}
+
+fn to_condition(data: conditions_network::Data) -> conditions_database::Condition {
+    conditions_database::Condition {
+        id: data.id,
+        condition_type: data.condition_type,
+        name: data.name,
+        abbreviation: data.abbreviation,
+        asset_class: data.asset_class,
+        data_types: data.data_types,
+        legacy: data.legacy,
+        updates_high_low: data.updates_high_low,
+        updates_open_close: data.updates_open_close,
+        updates_volume: data.updates_volume,
+    }
+}
+
+struct NetworkDepends {
+    snp: Arc<dyn snp::Api + Send + Sync>,
+}
+
+impl exchanges_network::Depends for NetworkDepends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        self.snp.clone()
+    }
+}
+
+impl conditions_network::Depends for NetworkDepends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        self.snp.clone()
+    }
+}
+
+struct DatabaseDepends {
+    snp: Arc<dyn snp::Api + Send + Sync>,
+}
+
+impl exchanges_database::Depends for DatabaseDepends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        self.snp.clone()
+    }
+}
+
+impl conditions_database::Depends for DatabaseDepends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        self.snp.clone()
+    }
+}
@@ -0,0 +1,5 @@
+#[path = "crawler-references-impl.rs"]
+pub mod crawler_references_impl;
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,67 @@
+use std::sync::Arc;
+
+use crate::crawler_references_impl::Service;
+use crawler_mod_references_io::crawler_references as io;
+use database_mod_conditions_fixture::conditions_fixture::ServiceFake as ConditionsDBServiceFake;
+use database_mod_conditions_io::conditions as conditions_database;
+use database_mod_exchanges_fixture::exchanges_fixture::ServiceFake as ExchangesDBServiceFake;
+use database_mod_exchanges_io::exchanges as exchanges_database;
+use io::Api;
+use network_mod_conditions_fixture::conditions_fixture::ServiceFake as ConditionsNetServiceFake;
+use network_mod_conditions_io::conditions as conditions_network;
+use network_mod_exchanges_fixture::exchanges_fixture::ServiceFake as ExchangesNetServiceFake;
+use network_mod_exchanges_io::exchanges as exchanges_network;
+use policy_mod_fixture::policy_fixture::ServiceFake as PolicyServiceFake;
+use policy_mod_io::policy;
+use snp_mod_fixture::snp_fixture::ServiceFake as SnPServiceFake;
+use snp_mod_io::snp;
+
+#[tokio::test]
+async fn call_crawl_references() -> io::Result<()> {
+    let service = Service::new();
+    let data = io::Data {
+        asset_class: "stocks".into(),
+    };
+    let deps = given_deps();
+    let mut policy_data = policy::Data::default();
+
+    service
+        .crawl_references(deps, &data, &mut policy_data)
+        .await?;
+
+    Ok(())
+}
+
+fn given_deps() -> Arc<dyn io::Depends + Send + Sync> {
+    Arc::new(DependsFake {})
+}
+
+struct DependsFake {}
+
+impl DependsFake {}
+
+impl io::Depends for DependsFake {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        Arc::new(SnPServiceFake {})
+    }
+
+    fn provide_policy(&self) -> Arc<dyn policy::Api + Send + Sync> {
+        Arc::new(PolicyServiceFake {})
+    }
+
+    fn provide_network_exchanges(&self) -> Arc<dyn exchanges_network::Api + Send + Sync> {
+        Arc::new(ExchangesNetServiceFake {})
+    }
+
+    fn provide_network_conditions(&self) -> Arc<dyn conditions_network::Api + Send + Sync> {
+        Arc::new(ConditionsNetServiceFake {})
+    }
+
+    fn provide_database_exchanges(&self) -> Arc<dyn exchanges_database::Api + Send + Sync> {
+        Arc::new(ExchangesDBServiceFake {})
+    }
+
+    fn provide_database_conditions(&self) -> Arc<dyn conditions_database::Api + Send + Sync> {
+        Arc::new(ConditionsDBServiceFake {})
+    }
+}
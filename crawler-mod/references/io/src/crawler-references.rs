@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use database_mod_conditions_io::conditions as conditions_database;
+use database_mod_exchanges_io::exchanges as exchanges_database;
+use network_mod_conditions_io::conditions as conditions_network;
+use network_mod_exchanges_io::exchanges as exchanges_network;
+use policy_mod_io::policy;
+use snp_mod_io::snp;
+
+/// The data that is needed to interact with the References modules.
+#[derive(Debug)]
+pub struct Data {
+    /// Like `stocks`.
+    pub asset_class: Arc<str>,
+}
+
+/// All possible errors of References modules.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("{0}")]
+    ExchangesDb(#[from] exchanges_database::Error),
+    #[error("{0}")]
+    ConditionsDb(#[from] conditions_database::Error),
+    #[error("{0}")]
+    ExchangesNet(#[from] exchanges_network::Error),
+    #[error("{0}")]
+    ConditionsNet(#[from] conditions_network::Error),
+    #[error("{0}")]
+    Policy(#[from] policy::Error),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The API of References, which defines all the functionality of References modules.
+#[async_trait]
+pub trait Api {
+    /// Requests the exchanges and the trade conditions of the asset class, and stores them, so
+    /// the trades can be decoded locally.
+    async fn crawl_references(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        data: &Data,
+        policy_data: &mut policy::Data,
+    ) -> Result<()>;
+}
+
+/// All the dependencies of the References modules to other modules.
+pub trait Depends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync>;
+    fn provide_policy(&self) -> Arc<dyn policy::Api + Send + Sync>;
+    fn provide_network_exchanges(&self) -> Arc<dyn exchanges_network::Api + Send + Sync>;
+    fn provide_network_conditions(&self) -> Arc<dyn conditions_network::Api + Send + Sync>;
+    fn provide_database_exchanges(&self) -> Arc<dyn exchanges_database::Api + Send + Sync>;
+    fn provide_database_conditions(&self) -> Arc<dyn conditions_database::Api + Send + Sync>;
+}
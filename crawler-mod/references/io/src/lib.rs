@@ -0,0 +1,2 @@
+#[path = "crawler-references.rs"]
+pub mod crawler_references;
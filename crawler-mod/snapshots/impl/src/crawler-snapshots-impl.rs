@@ -0,0 +1,146 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, TimeZone, Utc};
+use snp_mod_io::snp;
+
+use calendar_mod_io::calendar;
+use crawler_mod_snapshots_io::crawler_snapshots as io;
+use database_mod_snapshots_io::snapshots as snapshots_database;
+use network_mod_snapshots_io::snapshots as snapshots_network;
+use policy_mod_io::policy;
+
+#[derive(Debug)]
+pub struct Service;
+
+/// The service to implement the API of Snapshots.
+impl Service {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Default for Service {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl io::Api for Service {
+    async fn crawl_snapshots(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        data: &io::Data,
+        policy_data: &mut policy::Data,
+    ) -> io::Result<()> {
+        let Some(calendar::Session { open, close }) =
+            deps.provide_calendar().get_session(&data.date)
+        else {
+            return Ok(());
+        };
+        if data.captured_at < open || data.captured_at >= close {
+            return Ok(());
+        }
+
+        let policy = deps.provide_policy().clone();
+
+        let snp = deps.provide_snp().clone();
+        let network_depends = Arc::new(NetworkDepends { snp: snp.clone() });
+        let database_depends = Arc::new(DatabaseDepends { snp: snp.clone() });
+
+        let tickers = snp.get_snp_by_date(&data.date);
+        let response = deps
+            .provide_network_snapshots()
+            .get_snapshots(network_depends.clone(), &tickers)
+            .await;
+        policy.handle_request_rate(policy_data).await?;
+        if let Ok(snapshots) = response {
+            let snapshots: Vec<snapshots_database::Snapshot> = snapshots
+                .into_iter()
+                .filter_map(|s| to_snapshot(s, snp.as_ref(), &data.captured_at))
+                .collect();
+            deps.provide_database_snapshots()
+                .save_snapshots(database_depends.clone(), &snapshots)
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Converts the snapshot of an S&P 500 ticker, where the zero timestamps and the empty bars of
+/// Polygon.io mean nothing has happened yet.
+fn to_snapshot(
+    data: snapshots_network::Data,
+    snp: &(dyn snp::Api + Send + Sync),
+    captured_at: &DateTime<Utc>,
+) -> Option<snapshots_database::Snapshot> {
+    Some(snapshots_database::Snapshot {
+        snp: snp.get_snp(&data.ticker).ok()?,
+        captured_at: *captured_at,
+        updated: to_time(data.updated),
+        todays_change: data.todays_change,
+        todays_change_perc: data.todays_change_perc,
+        day: data.day.and_then(to_bar),
+        prev_day: data.prev_day.and_then(to_bar),
+        last_trade: data.last_trade.and_then(|t| {
+            Some(snapshots_database::LastTrade {
+                price: t.price,
+                size: t.size,
+                exchange: t.exchange,
+                timestamp: to_time(t.timestamp)?,
+            })
+        }),
+        last_quote: data.last_quote.and_then(|q| {
+            Some(snapshots_database::LastQuote {
+                bid_price: q.bid_price,
+                bid_size: q.bid_size,
+                ask_price: q.ask_price,
+                ask_size: q.ask_size,
+                timestamp: to_time(q.timestamp)?,
+            })
+        }),
+    })
+}
+
+fn to_bar(bar: snapshots_network::Bar) -> Option<snapshots_database::Bar> {
+    if bar.volume == 0.0 {
+        return None;
+    }
+    Some(snapshots_database::Bar {
+        open: bar.open,
+        high: bar.high,
+        low: bar.low,
+        close: bar.close,
+        volume: bar.volume,
+        vwap: bar.vwap,
+    })
+}
+
+fn to_time(nanos: i64) -> Option<DateTime<Utc>> {
+    if nanos == 0 {
+        return None;
+    }
+    Some(Utc.timestamp_nanos(nanos))
+}
+
+struct NetworkDepends {
+    snp: Arc<dyn snp::Api + Send + Sync>,
+}
+
+impl snapshots_network::Depends for NetworkDepends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        self.snp.clone()
+    }
+}
+
+struct DatabaseDepends {
+    snp: Arc<dyn snp::Api + Send + Sync>,
+}
+
+impl snapshots_database::Depends for DatabaseDepends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        self.snp.clone()
+    }
+}
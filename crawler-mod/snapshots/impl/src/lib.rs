@@ -0,0 +1,5 @@
+#[path = "crawler-snapshots-impl.rs"]
+pub mod crawler_snapshots_impl;
+
+#[cfg(test)]
+mod test;
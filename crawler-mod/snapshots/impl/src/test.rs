@@ -0,0 +1,63 @@
+use std::sync::Arc;
+
+use crate::crawler_snapshots_impl::Service;
+use calendar_mod_fixture::calendar_fixture::ServiceFake as CalendarServiceFake;
+use calendar_mod_io::calendar;
+use chrono::{NaiveDate, TimeZone, Utc};
+use crawler_mod_snapshots_io::crawler_snapshots as io;
+use database_mod_snapshots_fixture::snapshots_fixture::ServiceFake as SnapshotsDBServiceFake;
+use database_mod_snapshots_io::snapshots as snapshots_database;
+use io::Api;
+use network_mod_snapshots_fixture::snapshots_fixture::ServiceFake as SnapshotsNetServiceFake;
+use network_mod_snapshots_io::snapshots as snapshots_network;
+use policy_mod_fixture::policy_fixture::ServiceFake as PolicyServiceFake;
+use policy_mod_io::policy;
+use snp_mod_fixture::snp_fixture::ServiceFake as SnPServiceFake;
+use snp_mod_io::snp;
+
+#[tokio::test]
+async fn call_crawl_snapshots() -> io::Result<()> {
+    let service = Service::new();
+    let data = io::Data {
+        date: NaiveDate::from_ymd_opt(2024, 6, 11).unwrap(),
+        captured_at: Utc.with_ymd_and_hms(2024, 6, 11, 15, 0, 0).unwrap(),
+    };
+    let deps = given_deps();
+    let mut policy_data = policy::Data::default();
+
+    service
+        .crawl_snapshots(deps, &data, &mut policy_data)
+        .await?;
+
+    Ok(())
+}
+
+fn given_deps() -> Arc<dyn io::Depends + Send + Sync> {
+    Arc::new(DependsFake {})
+}
+
+struct DependsFake {}
+
+impl DependsFake {}
+
+impl io::Depends for DependsFake {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        Arc::new(SnPServiceFake {})
+    }
+
+    fn provide_policy(&self) -> Arc<dyn policy::Api + Send + Sync> {
+        Arc::new(PolicyServiceFake {})
+    }
+
+    fn provide_calendar(&self) -> Arc<dyn calendar::Api + Send + Sync> {
+        Arc::new(CalendarServiceFake {})
+    }
+
+    fn provide_network_snapshots(&self) -> Arc<dyn snapshots_network::Api + Send + Sync> {
+        Arc::new(SnapshotsNetServiceFake {})
+    }
+
+    fn provide_database_snapshots(&self) -> Arc<dyn snapshots_database::Api + Send + Sync> {
+        Arc::new(SnapshotsDBServiceFake {})
+    }
+}
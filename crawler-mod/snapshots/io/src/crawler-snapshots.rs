@@ -0,0 +1,52 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use calendar_mod_io::calendar;
+use chrono::{DateTime, NaiveDate, Utc};
+use database_mod_snapshots_io::snapshots as snapshots_database;
+use network_mod_snapshots_io::snapshots as snapshots_network;
+use policy_mod_io::policy;
+use snp_mod_io::snp;
+
+/// The data that is needed to interact with the Snapshots modules, which is a capture of the
+/// constituents of the date.
+#[derive(Debug)]
+pub struct Data {
+    pub date: NaiveDate,
+    pub captured_at: DateTime<Utc>,
+}
+
+/// All possible errors of Snapshots modules.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("{0}")]
+    Db(#[from] snapshots_database::Error),
+    #[error("{0}")]
+    Net(#[from] snapshots_network::Error),
+    #[error("{0}")]
+    Policy(#[from] policy::Error),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The API of Snapshots, which defines all the functionality of Snapshots modules.
+#[async_trait]
+pub trait Api {
+    /// Requests the snapshots of all the S&P 500 constituents by one request, and stores them,
+    /// unless the capture is out of the trading session of the date.
+    async fn crawl_snapshots(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        data: &Data,
+        policy_data: &mut policy::Data,
+    ) -> Result<()>;
+}
+
+/// All the dependencies of the Snapshots modules to other modules.
+pub trait Depends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync>;
+    fn provide_policy(&self) -> Arc<dyn policy::Api + Send + Sync>;
+    fn provide_calendar(&self) -> Arc<dyn calendar::Api + Send + Sync>;
+    fn provide_network_snapshots(&self) -> Arc<dyn snapshots_network::Api + Send + Sync>;
+    fn provide_database_snapshots(&self) -> Arc<dyn snapshots_database::Api + Send + Sync>;
+}
@@ -0,0 +1,2 @@
+#[path = "crawler-snapshots.rs"]
+pub mod crawler_snapshots;
@@ -0,0 +1,158 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::DateTime;
+use snp_mod_io::snp;
+
+use crawler_mod_stream_io::crawler_stream as io;
+use database_mod_aggregates_io::aggregates as aggregates_database;
+use database_mod_quotes_io::quotes as quotes_database;
+use database_mod_trades_io::trades as trades_database;
+use network_mod_aggregates_io::aggregates as aggregates_network;
+use network_mod_quotes_io::quotes as quotes_network;
+use network_mod_stream_io::stream as network;
+use network_mod_trades_io::trades as trades_network;
+
+#[derive(Debug)]
+pub struct Service;
+
+/// The service to implement the API of Stream.
+impl Service {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Default for Service {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl io::Api for Service {
+    async fn save_events(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        events: &[network::Event],
+    ) -> io::Result<usize> {
+        let snp = deps.provide_snp().clone();
+        let database_depends = Arc::new(DatabaseDepends { snp: snp.clone() });
+
+        let mut trades = vec![];
+        let mut quotes = vec![];
+        let mut bars = vec![];
+        for event in events {
+            match event {
+                network::Event::Trade { ticker, data } => {
+                    if let Ok(ticker) = snp.get_snp(ticker) {
+                        trades.push(to_trade(data, &ticker));
+                    }
+                }
+                network::Event::Quote { ticker, data } => {
+                    if let Ok(ticker) = snp.get_snp(ticker) {
+                        quotes.push(to_quote(data, &ticker));
+                    }
+                }
+                network::Event::MinuteBar { ticker, data } => {
+                    let Ok(ticker) = snp.get_snp(ticker) else {
+                        continue;
+                    };
+                    if let Some(bar) = to_bar(data, &ticker) {
+                        bars.push(bar);
+                    }
+                }
+            }
+        }
+
+        if !trades.is_empty() {
+            deps.provide_database_trades()
+                .save_trades(database_depends.clone(), &trades)
+                .await?;
+        }
+        if !quotes.is_empty() {
+            deps.provide_database_quotes()
+                .save_quotes(database_depends.clone(), &quotes)
+                .await?;
+        }
+        if !bars.is_empty() {
+            deps.provide_database_aggregates()
+                .save_bars(database_depends.clone(), &bars)
+                .await?;
+        }
+
+        Ok(trades.len() + quotes.len() + bars.len())
+    }
+}
+
+fn to_trade(trade: &trades_network::Data, snp: &snp::SnP) -> trades_database::Trade {
+    trades_database::Trade {
+        snp: snp.clone(),
+        sip_timestamp: trade.sip_timestamp,
+        sequence_number: trade.sequence_number,
+        polygon_id: trade.id.clone(),
+        price: trade.price,
+        size: trade.size,
+        exchange: trade.exchange,
+        conditions: trade.conditions.clone(),
+        correction: trade.correction,
+        participant_timestamp: trade.participant_timestamp,
+        tape: trade.tape,
+        trf_id: trade.trf_id,
+        trf_timestamp: trade.trf_timestamp,
+    }
+}
+
+fn to_quote(quote: &quotes_network::Data, snp: &snp::SnP) -> quotes_database::Quote {
+    quotes_database::Quote {
+        snp: snp.clone(),
+        sip_timestamp: quote.sip_timestamp,
+        sequence_number: quote.sequence_number,
+        participant_timestamp: quote.participant_timestamp,
+        ask_exchange: quote.ask_exchange,
+        ask_price: quote.ask_price,
+        ask_size: quote.ask_size,
+        bid_exchange: quote.bid_exchange,
+        bid_price: quote.bid_price,
+        bid_size: quote.bid_size,
+        conditions: quote.conditions.clone(),
+        tape: quote.tape,
+    }
+}
+
+fn to_bar(bar: &aggregates_network::Data, snp: &snp::SnP) -> Option<aggregates_database::Bar> {
+    Some(aggregates_database::Bar {
+        snp: snp.clone(),
+        timespan: aggregates_database::Timespan::Minute,
+        start: DateTime::from_timestamp_millis(bar.timestamp)?,
+        open: bar.open,
+        high: bar.high,
+        low: bar.low,
+        close: bar.close,
+        volume: bar.volume,
+        vwap: bar.vwap,
+        transactions: bar.transactions,
+    })
+}
+
+struct DatabaseDepends {
+    snp: Arc<dyn snp::Api + Send + Sync>,
+}
+
+impl trades_database::Depends for DatabaseDepends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        self.snp.clone()
+    }
+}
+
+impl quotes_database::Depends for DatabaseDepends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        self.snp.clone()
+    }
+}
+
+impl aggregates_database::Depends for DatabaseDepends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        self.snp.clone()
+    }
+}
@@ -0,0 +1,5 @@
+#[path = "crawler-stream-impl.rs"]
+pub mod crawler_stream_impl;
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,252 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+
+use crate::crawler_stream_impl::Service;
+use crawler_mod_stream_io::crawler_stream as io;
+use database_mod_aggregates_io::aggregates as aggregates_database;
+use database_mod_quotes_io::quotes as quotes_database;
+use database_mod_trades_io::trades as trades_database;
+use io::Api;
+use network_mod_mock_server::{mock_stream::MockStream, responses};
+use network_mod_stream_impl::stream_impl::Service as StreamService;
+use network_mod_stream_io::stream as network;
+use network_mod_trades_io::trades as trades_network;
+use snp_mod_fixture::snp_fixture::ServiceFake as SnPServiceFake;
+use snp_mod_io::snp;
+
+use network::Api as _;
+
+#[tokio::test]
+async fn save_the_events_of_the_mock_stream() -> io::Result<()> {
+    let events = given_streamed_events(4).await;
+    let database = Arc::new(DatabaseRecorder::default());
+    let deps = Arc::new(DependsFake {
+        database: database.clone(),
+    });
+
+    let saved = Service::new().save_events(deps, &events).await?;
+
+    assert_eq!(4, saved);
+    let trades = database.trades.lock().unwrap();
+    assert_eq!(
+        vec![(1710426600010000000, 1063), (1710426600011000000, 1064)],
+        trades
+            .iter()
+            .map(|t| (t.sip_timestamp, t.sequence_number))
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(Some(201), trades[1].trf_id);
+    let quotes = database.quotes.lock().unwrap();
+    assert_eq!(1, quotes.len());
+    assert_eq!(1065, quotes[0].sequence_number);
+    assert_eq!(Some(172.52), quotes[0].ask_price);
+    let bars = database.bars.lock().unwrap();
+    assert_eq!(1, bars.len());
+    assert_eq!(aggregates_database::Timespan::Minute, bars[0].timespan);
+    assert_eq!(
+        Utc.with_ymd_and_hms(2024, 3, 14, 14, 30, 0).unwrap(),
+        bars[0].start
+    );
+    assert_eq!(172.71, bars[0].close);
+    Ok(())
+}
+
+/// Streams from the mock stream until the count of events is received.
+async fn given_streamed_events(count: usize) -> Vec<network::Event> {
+    let server = MockStream::start(
+        "key",
+        vec![
+            responses::STREAM_TRADES_AAPL,
+            responses::STREAM_QUOTES_AAPL,
+            responses::STREAM_MINUTE_BARS_AAPL,
+        ],
+    )
+    .await
+    .unwrap();
+    let stream = Arc::new(
+        StreamService::with_host("key", &server.host(), network::Options::default()).unwrap(),
+    );
+    let subscriptions = vec![
+        network::Subscription {
+            channel: network::Channel::Trades,
+            ticker: Some(snp::SnP::AAPL),
+        },
+        network::Subscription {
+            channel: network::Channel::Quotes,
+            ticker: Some(snp::SnP::AAPL),
+        },
+        network::Subscription {
+            channel: network::Channel::MinuteBars,
+            ticker: None,
+        },
+    ];
+    let handle = tokio::spawn({
+        let stream = stream.clone();
+        async move {
+            stream
+                .stream(Arc::new(StreamDependsFake {}), &subscriptions)
+                .await
+        }
+    });
+
+    let mut events = vec![];
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while events.len() < count {
+            match stream.channel.try_recv() {
+                Ok(event) => events.push(event),
+                Err(_) => tokio::time::sleep(Duration::from_millis(10)).await,
+            }
+        }
+    })
+    .await
+    .expect("Not all the events are streamed");
+    stream.close().await.unwrap();
+    handle.await.unwrap().unwrap();
+    events
+}
+
+#[derive(Default)]
+struct DatabaseRecorder {
+    trades: Mutex<Vec<trades_database::Trade>>,
+    quotes: Mutex<Vec<quotes_database::Quote>>,
+    bars: Mutex<Vec<aggregates_database::Bar>>,
+}
+
+#[async_trait]
+impl trades_database::Api for DatabaseRecorder {
+    async fn save_trades(
+        &self,
+        _deps: Arc<dyn trades_database::Depends + Send + Sync>,
+        trades: &[trades_database::Trade],
+    ) -> trades_database::Result<()> {
+        self.trades.lock().unwrap().extend_from_slice(trades);
+        Ok(())
+    }
+
+    async fn find_trades(
+        &self,
+        _deps: Arc<dyn trades_database::Depends + Send + Sync>,
+        _snp: &snp::SnP,
+        _from: i64,
+        _to: i64,
+    ) -> trades_database::Result<Vec<trades_database::Trade>> {
+        Ok(vec![])
+    }
+
+    async fn save_cursor(
+        &self,
+        _deps: Arc<dyn trades_database::Depends + Send + Sync>,
+        _cursor: &trades_database::Cursor,
+    ) -> trades_database::Result<()> {
+        Ok(())
+    }
+
+    async fn find_cursor(
+        &self,
+        _deps: Arc<dyn trades_database::Depends + Send + Sync>,
+        _snp: &snp::SnP,
+        _date: &NaiveDate,
+    ) -> trades_database::Result<Option<trades_database::Cursor>> {
+        Ok(None)
+    }
+}
+
+#[async_trait]
+impl quotes_database::Api for DatabaseRecorder {
+    async fn save_quotes(
+        &self,
+        _deps: Arc<dyn quotes_database::Depends + Send + Sync>,
+        quotes: &[quotes_database::Quote],
+    ) -> quotes_database::Result<()> {
+        self.quotes.lock().unwrap().extend_from_slice(quotes);
+        Ok(())
+    }
+
+    async fn find_quotes(
+        &self,
+        _deps: Arc<dyn quotes_database::Depends + Send + Sync>,
+        _snp: &snp::SnP,
+        _from: i64,
+        _to: i64,
+    ) -> quotes_database::Result<Vec<quotes_database::Quote>> {
+        Ok(vec![])
+    }
+}
+
+#[async_trait]
+impl aggregates_database::Api for DatabaseRecorder {
+    async fn save_bars(
+        &self,
+        _deps: Arc<dyn aggregates_database::Depends + Send + Sync>,
+        bars: &[aggregates_database::Bar],
+    ) -> aggregates_database::Result<()> {
+        self.bars.lock().unwrap().extend_from_slice(bars);
+        Ok(())
+    }
+
+    async fn find_bars(
+        &self,
+        _deps: Arc<dyn aggregates_database::Depends + Send + Sync>,
+        _snp: &snp::SnP,
+        _timespan: aggregates_database::Timespan,
+        _from: &DateTime<Utc>,
+        _to: &DateTime<Utc>,
+    ) -> aggregates_database::Result<Vec<aggregates_database::Bar>> {
+        Ok(vec![])
+    }
+
+    async fn save_fetch(
+        &self,
+        _deps: Arc<dyn aggregates_database::Depends + Send + Sync>,
+        _fetch: &aggregates_database::Fetch,
+    ) -> aggregates_database::Result<()> {
+        Ok(())
+    }
+
+    async fn is_fetched(
+        &self,
+        _deps: Arc<dyn aggregates_database::Depends + Send + Sync>,
+        _fetch: &aggregates_database::Fetch,
+    ) -> aggregates_database::Result<bool> {
+        Ok(false)
+    }
+}
+
+struct DependsFake {
+    database: Arc<DatabaseRecorder>,
+}
+
+impl io::Depends for DependsFake {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        Arc::new(SnPServiceFake {})
+    }
+
+    fn provide_database_trades(&self) -> Arc<dyn trades_database::Api + Send + Sync> {
+        self.database.clone()
+    }
+
+    fn provide_database_quotes(&self) -> Arc<dyn quotes_database::Api + Send + Sync> {
+        self.database.clone()
+    }
+
+    fn provide_database_aggregates(&self) -> Arc<dyn aggregates_database::Api + Send + Sync> {
+        self.database.clone()
+    }
+}
+
+struct StreamDependsFake {}
+
+impl network::Depends for StreamDependsFake {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        Arc::new(SnPServiceFake {})
+    }
+
+    fn provide_names(&self) -> Arc<trades_network::Names> {
+        Arc::new(trades_network::Names::default())
+    }
+}
@@ -0,0 +1,42 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use database_mod_aggregates_io::aggregates as aggregates_database;
+use database_mod_quotes_io::quotes as quotes_database;
+use database_mod_trades_io::trades as trades_database;
+use network_mod_stream_io::stream as network;
+use snp_mod_io::snp;
+
+/// All possible errors of Stream modules.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("{0}")]
+    TradesDb(#[from] trades_database::Error),
+    #[error("{0}")]
+    QuotesDb(#[from] quotes_database::Error),
+    #[error("{0}")]
+    AggregatesDb(#[from] aggregates_database::Error),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The API of Stream, which defines all the functionality of Stream modules.
+#[async_trait]
+pub trait Api {
+    /// Stores the trades, the quotes and the minute bars of the events, by the same database
+    /// modules as the REST ones, and drops the events of the tickers out of the S&P 500.
+    /// Returns the number of the stored events.
+    async fn save_events(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        events: &[network::Event],
+    ) -> Result<usize>;
+}
+
+/// All the dependencies of the Stream modules to other modules.
+pub trait Depends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync>;
+    fn provide_database_trades(&self) -> Arc<dyn trades_database::Api + Send + Sync>;
+    fn provide_database_quotes(&self) -> Arc<dyn quotes_database::Api + Send + Sync>;
+    fn provide_database_aggregates(&self) -> Arc<dyn aggregates_database::Api + Send + Sync>;
+}
@@ -0,0 +1,2 @@
+#[path = "crawler-stream.rs"]
+pub mod crawler_stream;
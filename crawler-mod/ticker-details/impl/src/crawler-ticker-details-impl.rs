@@ -1,10 +1,14 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use chrono::{NaiveDate, Utc};
 use snp_mod_io::snp;
 
 use crawler_mod_ticker_details_io::crawler_ticker_details as io;
+use database_mod_raw_responses_io::raw_responses as raw;
 use database_mod_ticker_details_io::ticker_details as database;
+use database_mod_ticker_events_io::ticker_events as ticker_events_database;
+use network_mod_cache_io::cache;
 use network_mod_ticker_details_io::ticker_details as network;
 use policy_mod_io::policy;
 
@@ -18,6 +22,12 @@ impl Service {
     }
 }
 
+impl Default for Service {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[async_trait]
 impl io::Api for Service {
     async fn crawl_ticker_details(
@@ -26,12 +36,16 @@ impl io::Api for Service {
         data: &io::Data,
         policy_data: &mut policy::Data,
     ) -> io::Result<()> {
+        // Polygon.io answers with the details of the last trading day, so there's nothing new.
+        if !deps.provide_calendar().is_trading_day(&data.date) {
+            return Ok(());
+        }
+
         let policy = deps.provide_policy().clone();
 
         let snp = deps.provide_snp().clone();
         let network_service = deps.provide_network_ticker_details().clone();
         let database_service = deps.provide_database_ticker_details().clone();
-        let network_depends = Arc::new(NetworkDepends { snp: snp.clone() });
         let database_depends = Arc::new(DatabaseDepends { snp: snp.clone() });
 
         let _ = match database_service
@@ -45,6 +59,14 @@ impl io::Api for Service {
             },
         };
 
+        let network_depends = Arc::new(NetworkDepends {
+            snp: deps
+                .provide_database_ticker_events()
+                .historical_snp(database_depends.clone(), &data.ticker, &data.date)
+                .await?,
+            cache: deps.provide_network_cache(),
+        });
+
         let network_data_response = network_service
             .get_ticker_details(network_depends.clone(), &data.ticker, &data.date)
             .await;
@@ -56,14 +78,18 @@ impl io::Api for Service {
                 return Ok(());
             }
         };
-        let database_data = database::Details {
-            active: network_data.active,
-            date: data.date,
+        let raw_response = raw::RawResponse {
+            endpoint: network::ENDPOINT.into(),
             snp: data.ticker.clone(),
-            share_class_shares_outstanding: network_data.share_class_shares_outstanding,
-            total_employees: network_data.total_employees,
-            weighted_shares_outstanding: network_data.weighted_shares_outstanding,
+            date: data.date,
+            request_id: network_data.request_id.clone(),
+            fetched_at: Utc::now(),
+            body: network_data.body.clone(),
         };
+        deps.provide_database_raw_responses()
+            .save_raw_response(database_depends.clone(), &raw_response)
+            .await?;
+        let database_data = to_details(&network_data, &data.ticker, &data.date);
         let _ = database_service
             .save_ticker_details(database_depends.clone(), &database_data)
             .await?;
@@ -71,15 +97,55 @@ impl io::Api for Service {
         policy.handle_request_rate(policy_data).await?;
         Ok(())
     }
+
+    async fn reprocess_ticker_details(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        date: &NaiveDate,
+    ) -> io::Result<()> {
+        let snp = deps.provide_snp().clone();
+        let network_service = deps.provide_network_ticker_details().clone();
+        let database_service = deps.provide_database_ticker_details().clone();
+        let raw_service = deps.provide_database_raw_responses().clone();
+        let database_depends = Arc::new(DatabaseDepends { snp: snp.clone() });
+
+        let raw_responses = raw_service
+            .find_raw_responses(database_depends.clone(), network::ENDPOINT, date)
+            .await?;
+        for raw_response in raw_responses {
+            let network_data = network_service.parse_ticker_details(&raw_response.body)?;
+            let database_data = to_details(&network_data, &raw_response.snp, &raw_response.date);
+            database_service
+                .save_ticker_details(database_depends.clone(), &database_data)
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+fn to_details(data: &network::Data, snp: &snp::SnP, date: &NaiveDate) -> database::Details {
+    database::Details {
+        active: data.active,
+        date: *date,
+        snp: snp.clone(),
+        share_class_shares_outstanding: data.share_class_shares_outstanding,
+        total_employees: data.total_employees,
+        weighted_shares_outstanding: data.weighted_shares_outstanding,
+    }
 }
 
 struct NetworkDepends {
     snp: Arc<dyn snp::Api + Send + Sync>,
+    cache: Option<Arc<dyn cache::Api + Send + Sync>>,
 }
 
 impl network::Depends for NetworkDepends {
     fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
-        return self.snp.clone();
+        self.snp.clone()
+    }
+
+    fn provide_cache(&self) -> Option<Arc<dyn cache::Api + Send + Sync>> {
+        self.cache.clone()
     }
 }
 
@@ -89,6 +155,18 @@ struct DatabaseDepends {
 
 impl database::Depends for DatabaseDepends {
     fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
-        return self.snp.clone();
+        self.snp.clone()
+    }
+}
+
+impl raw::Depends for DatabaseDepends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        self.snp.clone()
+    }
+}
+
+impl ticker_events_database::Depends for DatabaseDepends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        self.snp.clone()
     }
 }
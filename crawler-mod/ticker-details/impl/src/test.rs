@@ -1,11 +1,19 @@
 use std::sync::Arc;
 
 use crate::crawler_ticker_details_impl::Service;
+use calendar_mod_fixture::calendar_fixture::ServiceFake as CalendarServiceFake;
+use calendar_mod_io::calendar;
 use chrono::NaiveDate;
 use crawler_mod_ticker_details_io::crawler_ticker_details as io;
+use database_mod_raw_responses_fixture::raw_responses_fixture::ServiceFake as RawServiceFake;
+use database_mod_raw_responses_io::raw_responses as raw;
 use database_mod_ticker_details_fixture::ticker_details_fixture::ServiceFake as DBServiceFake;
 use database_mod_ticker_details_io::ticker_details as database;
+use database_mod_ticker_events_fixture::ticker_events_fixture::ServiceFake as TickerEventsServiceFake;
+use database_mod_ticker_events_io::ticker_events as ticker_events_database;
 use io::Api;
+use network_mod_cache_fixture::cache_fixture::ServiceFake as CacheServiceFake;
+use network_mod_cache_io::cache;
 use network_mod_ticker_details_fixture::ticker_details_fixture::ServiceFake as NetServiceFake;
 use network_mod_ticker_details_io::ticker_details as network;
 use policy_mod_fixture::policy_fixture::ServiceFake as PolicyServiceFake;
@@ -31,6 +39,18 @@ async fn call_crawl_ticker_details() -> io::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn call_reprocess_ticker_details() -> io::Result<()> {
+    let format = "%B %e, %Y";
+    let service = Service::new();
+    let date = NaiveDate::parse_from_str("April 17, 2024", format).unwrap();
+    let deps = given_deps();
+
+    service.reprocess_ticker_details(deps, &date).await?;
+
+    Ok(())
+}
+
 fn given_deps() -> Arc<dyn io::Depends + Send + Sync> {
     Arc::new(DependsFake {})
 }
@@ -48,11 +68,27 @@ impl io::Depends for DependsFake {
         Arc::new(PolicyServiceFake {})
     }
 
+    fn provide_calendar(&self) -> Arc<dyn calendar::Api + Send + Sync> {
+        Arc::new(CalendarServiceFake {})
+    }
+
     fn provide_network_ticker_details(&self) -> Arc<dyn network::Api + Send + Sync> {
         Arc::new(NetServiceFake {})
     }
 
+    fn provide_network_cache(&self) -> Option<Arc<dyn cache::Api + Send + Sync>> {
+        Some(Arc::new(CacheServiceFake {}))
+    }
+
     fn provide_database_ticker_details(&self) -> Arc<dyn database::Api + Send + Sync> {
         Arc::new(DBServiceFake {})
     }
+
+    fn provide_database_raw_responses(&self) -> Arc<dyn raw::Api + Send + Sync> {
+        Arc::new(RawServiceFake {})
+    }
+
+    fn provide_database_ticker_events(&self) -> Arc<dyn ticker_events_database::Api + Send + Sync> {
+        Arc::new(TickerEventsServiceFake {})
+    }
 }
@@ -1,8 +1,12 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use calendar_mod_io::calendar;
 use chrono::NaiveDate;
+use database_mod_raw_responses_io::raw_responses as raw;
 use database_mod_ticker_details_io::ticker_details as database;
+use database_mod_ticker_events_io::ticker_events as ticker_events_database;
+use network_mod_cache_io::cache;
 use network_mod_ticker_details_io::ticker_details as network;
 use policy_mod_io::policy;
 use snp_mod_io::snp;
@@ -20,6 +24,10 @@ pub enum Error {
     #[error("{0}")]
     Db(#[from] database::Error),
     #[error("{0}")]
+    TickerEvents(#[from] ticker_events_database::Error),
+    #[error("{0}")]
+    Raw(#[from] raw::Error),
+    #[error("{0}")]
     Net(#[from] network::Error),
     #[error("{0}")]
     Policy(#[from] policy::Error),
@@ -32,19 +40,32 @@ pub type Result<T> = std::result::Result<T, Error>;
 /// The API of Ticker-details, which defines all the functionality of Ticker-details modules.
 #[async_trait]
 pub trait Api {
+    /// Stores the details of the ticker on the date, unless it's stored already or the market
+    /// is closed. The request is by the symbol of the ticker on the date, e.g. `FB` for `META`
+    /// before June 9, 2022.
     async fn crawl_ticker_details(
         &self,
         deps: Arc<dyn Depends + Send + Sync>,
         data: &Data,
         policy_data: &mut policy::Data,
     ) -> Result<()>;
+
+    /// Rebuilds the ticker details of a date from the archived raw responses, without requests.
+    async fn reprocess_ticker_details(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        date: &NaiveDate,
+    ) -> Result<()>;
 }
 
 /// All the dependencies of the Ticker-details modules to other modules.
 pub trait Depends {
     fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync>;
     fn provide_policy(&self) -> Arc<dyn policy::Api + Send + Sync>;
+    fn provide_calendar(&self) -> Arc<dyn calendar::Api + Send + Sync>;
     fn provide_network_ticker_details(&self) -> Arc<dyn network::Api + Send + Sync>;
+    fn provide_network_cache(&self) -> Option<Arc<dyn cache::Api + Send + Sync>>;
     fn provide_database_ticker_details(&self) -> Arc<dyn database::Api + Send + Sync>;
+    fn provide_database_raw_responses(&self) -> Arc<dyn raw::Api + Send + Sync>;
+    fn provide_database_ticker_events(&self) -> Arc<dyn ticker_events_database::Api + Send + Sync>;
 }
-
@@ -0,0 +1,88 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use snp_mod_io::snp;
+
+use crawler_mod_ticker_events_io::crawler_ticker_events as io;
+use database_mod_ticker_events_io::ticker_events as ticker_events_database;
+use network_mod_ticker_events_io::ticker_events as ticker_events_network;
+use policy_mod_io::policy;
+
+#[derive(Debug)]
+pub struct Service;
+
+/// The service to implement the API of Ticker-events.
+impl Service {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Default for Service {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl io::Api for Service {
+    async fn crawl_ticker_events(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        data: &io::Data,
+        policy_data: &mut policy::Data,
+    ) -> io::Result<()> {
+        let policy = deps.provide_policy().clone();
+
+        let snp = deps.provide_snp().clone();
+        let network_depends = Arc::new(NetworkDepends { snp: snp.clone() });
+        let database_depends = Arc::new(DatabaseDepends { snp: snp.clone() });
+
+        let symbol = snp.get_symbol(&data.ticker);
+        let response = deps
+            .provide_network_ticker_events()
+            .get_ticker_events(network_depends.clone(), &symbol)
+            .await;
+        policy.handle_request_rate(policy_data).await?;
+        let events = match response {
+            Ok(events) => events,
+            // Nothing to store for a ticker that Polygon.io doesn't know.
+            Err(ticker_events_network::Error::NotFound(_)) => return Ok(()),
+            Err(e) => return Err(e.into()),
+        };
+        let changes: Vec<ticker_events_database::TickerChange> = events
+            .ticker_changes
+            .into_iter()
+            .map(|change| ticker_events_database::TickerChange {
+                composite_figi: events.composite_figi.clone(),
+                date: change.date,
+                ticker: change.ticker,
+            })
+            .collect();
+        deps.provide_database_ticker_events()
+            .save_ticker_changes(database_depends.clone(), &changes)
+            .await?;
+
+        Ok(())
+    }
+}
+
+struct NetworkDepends {
+    snp: Arc<dyn snp::Api + Send + Sync>,
+}
+
+impl ticker_events_network::Depends for NetworkDepends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        self.snp.clone()
+    }
+}
+
+struct DatabaseDepends {
+    snp: Arc<dyn snp::Api + Send + Sync>,
+}
+
+impl ticker_events_database::Depends for DatabaseDepends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        self.snp.clone()
+    }
+}
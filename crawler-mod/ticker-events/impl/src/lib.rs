@@ -0,0 +1,5 @@
+#[path = "crawler-ticker-events-impl.rs"]
+pub mod crawler_ticker_events_impl;
+
+#[cfg(test)]
+mod test;
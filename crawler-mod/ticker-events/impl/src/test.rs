@@ -0,0 +1,122 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::crawler_ticker_events_impl::Service;
+use crawler_mod_ticker_events_io::crawler_ticker_events as io;
+use database_mod_ticker_events_fixture::ticker_events_fixture::ServiceFake as TickerEventsDBServiceFake;
+use database_mod_ticker_events_io::ticker_events as ticker_events_database;
+use io::Api;
+use network_mod_ticker_events_fixture::ticker_events_fixture::ServiceFake as TickerEventsNetServiceFake;
+use network_mod_ticker_events_io::ticker_events as ticker_events_network;
+use policy_mod_fixture::policy_fixture::ServiceFake as PolicyServiceFake;
+use policy_mod_io::policy;
+use snp_mod_fixture::snp_fixture::ServiceFake as SnPServiceFake;
+use snp_mod_io::snp;
+
+#[tokio::test]
+async fn call_crawl_ticker_events() -> io::Result<()> {
+    let service = Service::new();
+    let data = io::Data {
+        ticker: snp::SnP::AAPL,
+    };
+    let deps = given_deps(Arc::new(TickerEventsNetServiceFake {}));
+    let mut policy_data = policy::Data::default();
+
+    service
+        .crawl_ticker_events(deps, &data, &mut policy_data)
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn skip_a_ticker_not_found() -> io::Result<()> {
+    let service = Service::new();
+    let data = io::Data {
+        ticker: snp::SnP::AAPL,
+    };
+    let deps = given_deps(Arc::new(NetworkError { not_found: true }));
+    let mut policy_data = policy::Data::default();
+
+    service
+        .crawl_ticker_events(deps, &data, &mut policy_data)
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn return_the_error_of_the_request() {
+    let service = Service::new();
+    let data = io::Data {
+        ticker: snp::SnP::AAPL,
+    };
+    let deps = given_deps(Arc::new(NetworkError { not_found: false }));
+    let mut policy_data = policy::Data::default();
+
+    let response = service
+        .crawl_ticker_events(deps, &data, &mut policy_data)
+        .await;
+
+    assert!(
+        matches!(
+            response,
+            Err(io::Error::Net(ticker_events_network::Error::Server(_, _)))
+        ),
+        "{:?}",
+        response
+    );
+}
+
+/// Fails every request, with a not found or a server error.
+struct NetworkError {
+    not_found: bool,
+}
+
+#[async_trait]
+impl ticker_events_network::Api for NetworkError {
+    async fn get_ticker_events(
+        &self,
+        _deps: Arc<dyn ticker_events_network::Depends + Send + Sync>,
+        id: &str,
+    ) -> ticker_events_network::Result<ticker_events_network::Data> {
+        let url = format!("/vX/reference/tickers/{id}/events");
+        if self.not_found {
+            Err(ticker_events_network::Error::NotFound(url))
+        } else {
+            Err(ticker_events_network::Error::Server(
+                url,
+                "Unknown API Key".into(),
+            ))
+        }
+    }
+}
+
+fn given_deps(
+    network: Arc<dyn ticker_events_network::Api + Send + Sync>,
+) -> Arc<dyn io::Depends + Send + Sync> {
+    Arc::new(DependsFake { network })
+}
+
+struct DependsFake {
+    network: Arc<dyn ticker_events_network::Api + Send + Sync>,
+}
+
+impl io::Depends for DependsFake {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        Arc::new(SnPServiceFake {})
+    }
+
+    fn provide_policy(&self) -> Arc<dyn policy::Api + Send + Sync> {
+        Arc::new(PolicyServiceFake {})
+    }
+
+    fn provide_network_ticker_events(&self) -> Arc<dyn ticker_events_network::Api + Send + Sync> {
+        self.network.clone()
+    }
+
+    fn provide_database_ticker_events(&self) -> Arc<dyn ticker_events_database::Api + Send + Sync> {
+        Arc::new(TickerEventsDBServiceFake {})
+    }
+}
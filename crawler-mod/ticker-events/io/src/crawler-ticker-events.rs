@@ -0,0 +1,47 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use database_mod_ticker_events_io::ticker_events as ticker_events_database;
+use network_mod_ticker_events_io::ticker_events as ticker_events_network;
+use policy_mod_io::policy;
+use snp_mod_io::snp;
+
+/// The data that is needed to interact with the Ticker-events modules.
+#[derive(Debug)]
+pub struct Data {
+    pub ticker: snp::SnP,
+}
+
+/// All possible errors of Ticker-events modules.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("{0}")]
+    Db(#[from] ticker_events_database::Error),
+    #[error("{0}")]
+    Net(#[from] ticker_events_network::Error),
+    #[error("{0}")]
+    Policy(#[from] policy::Error),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The API of Ticker-events, which defines all the functionality of Ticker-events modules.
+#[async_trait]
+pub trait Api {
+    /// Requests the symbol changes of the ticker, and stores them. A ticker that Polygon.io
+    /// doesn't know is skipped, but the other errors of the request are returned.
+    async fn crawl_ticker_events(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        data: &Data,
+        policy_data: &mut policy::Data,
+    ) -> Result<()>;
+}
+
+/// All the dependencies of the Ticker-events modules to other modules.
+pub trait Depends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync>;
+    fn provide_policy(&self) -> Arc<dyn policy::Api + Send + Sync>;
+    fn provide_network_ticker_events(&self) -> Arc<dyn ticker_events_network::Api + Send + Sync>;
+    fn provide_database_ticker_events(&self) -> Arc<dyn ticker_events_database::Api + Send + Sync>;
+}
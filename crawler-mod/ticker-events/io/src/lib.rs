@@ -0,0 +1,2 @@
+#[path = "crawler-ticker-events.rs"]
+pub mod crawler_ticker_events;
@@ -0,0 +1,101 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use snp_mod_io::snp;
+
+use crawler_mod_tickers_io::crawler_tickers as io;
+use database_mod_tickers_io::tickers as tickers_database;
+use network_mod_tickers_io::tickers as tickers_network;
+use policy_mod_io::policy;
+
+#[derive(Debug)]
+pub struct Service;
+
+/// The service to implement the API of Tickers.
+impl Service {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Default for Service {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl io::Api for Service {
+    async fn crawl_tickers(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        data: &io::Data,
+        policy_data: &mut policy::Data,
+    ) -> io::Result<()> {
+        let policy = deps.provide_policy().clone();
+
+        let snp = deps.provide_snp().clone();
+        let network_depends = Arc::new(NetworkDepends { snp: snp.clone() });
+        let database_depends = Arc::new(DatabaseDepends { snp: snp.clone() });
+
+        let request = tickers_network::Request {
+            market: data.market.clone(),
+            ticker_type: data.ticker_type.clone(),
+            exchange: data.exchange.clone(),
+            active: data.active,
+            date: data.date,
+        };
+        let response = deps
+            .provide_network_tickers()
+            .get_tickers(network_depends.clone(), &request)
+            .await;
+        policy.handle_request_rate(policy_data).await?;
+        if let Ok(tickers) = response {
+            let tickers: Vec<tickers_database::Ticker> =
+                tickers.into_iter().map(to_ticker).collect();
+            deps.provide_database_tickers()
+                .save_tickers(database_depends.clone(), &tickers)
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+fn to_ticker(data: tickers_network::Data) -> tickers_database::Ticker {
+    tickers_database::Ticker {
+        ticker: data.ticker,
+        name: data.name,
+        market: data.market,
+        locale: data.locale,
+        primary_exchange: data.primary_exchange,
+        ticker_type: data.ticker_type,
+        active: data.active,
+        currency_name: data.currency_name,
+        cik: data.cik,
+        composite_figi: data.composite_figi,
+        share_class_figi: data.share_class_figi,
+        last_updated_utc: data.last_updated_utc,
+        delisted_utc: data.delisted_utc,
+    }
+}
+
+struct NetworkDepends {
+    snp: Arc<dyn snp::Api + Send + Sync>,
+}
+
+impl tickers_network::Depends for NetworkDepends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        self.snp.clone()
+    }
+}
+
+struct DatabaseDepends {
+    snp: Arc<dyn snp::Api + Send + Sync>,
+}
+
+impl tickers_database::Depends for DatabaseDepends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        self.snp.clone()
+    }
+}
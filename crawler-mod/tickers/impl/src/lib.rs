@@ -0,0 +1,5 @@
+#[path = "crawler-tickers-impl.rs"]
+pub mod crawler_tickers_impl;
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,55 @@
+use std::sync::Arc;
+
+use crate::crawler_tickers_impl::Service;
+use crawler_mod_tickers_io::crawler_tickers as io;
+use database_mod_tickers_fixture::tickers_fixture::ServiceFake as TickersDBServiceFake;
+use database_mod_tickers_io::tickers as tickers_database;
+use io::Api;
+use network_mod_tickers_fixture::tickers_fixture::ServiceFake as TickersNetServiceFake;
+use network_mod_tickers_io::tickers as tickers_network;
+use policy_mod_fixture::policy_fixture::ServiceFake as PolicyServiceFake;
+use policy_mod_io::policy;
+use snp_mod_fixture::snp_fixture::ServiceFake as SnPServiceFake;
+use snp_mod_io::snp;
+
+#[tokio::test]
+async fn call_crawl_tickers() -> io::Result<()> {
+    let service = Service::new();
+    let data = io::Data {
+        market: Some("stocks".into()),
+        active: Some(true),
+        ..Default::default()
+    };
+    let deps = given_deps();
+    let mut policy_data = policy::Data::default();
+
+    service.crawl_tickers(deps, &data, &mut policy_data).await?;
+
+    Ok(())
+}
+
+fn given_deps() -> Arc<dyn io::Depends + Send + Sync> {
+    Arc::new(DependsFake {})
+}
+
+struct DependsFake {}
+
+impl DependsFake {}
+
+impl io::Depends for DependsFake {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        Arc::new(SnPServiceFake {})
+    }
+
+    fn provide_policy(&self) -> Arc<dyn policy::Api + Send + Sync> {
+        Arc::new(PolicyServiceFake {})
+    }
+
+    fn provide_network_tickers(&self) -> Arc<dyn tickers_network::Api + Send + Sync> {
+        Arc::new(TickersNetServiceFake {})
+    }
+
+    fn provide_database_tickers(&self) -> Arc<dyn tickers_database::Api + Send + Sync> {
+        Arc::new(TickersDBServiceFake {})
+    }
+}
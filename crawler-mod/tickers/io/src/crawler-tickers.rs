@@ -0,0 +1,52 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use database_mod_tickers_io::tickers as tickers_database;
+use network_mod_tickers_io::tickers as tickers_network;
+use policy_mod_io::policy;
+use snp_mod_io::snp;
+
+/// The data that is needed to interact with the Tickers modules, which are the filters of the
+/// universe to crawl, where `None` means no filter.
+#[derive(Debug, Default)]
+pub struct Data {
+    pub market: Option<Arc<str>>,
+    pub ticker_type: Option<Arc<str>>,
+    pub exchange: Option<Arc<str>>,
+    pub active: Option<bool>,
+    pub date: Option<NaiveDate>,
+}
+
+/// All possible errors of Tickers modules.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("{0}")]
+    Db(#[from] tickers_database::Error),
+    #[error("{0}")]
+    Net(#[from] tickers_network::Error),
+    #[error("{0}")]
+    Policy(#[from] policy::Error),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The API of Tickers, which defines all the functionality of Tickers modules.
+#[async_trait]
+pub trait Api {
+    /// Requests all the tickers of the universe, and stores them.
+    async fn crawl_tickers(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        data: &Data,
+        policy_data: &mut policy::Data,
+    ) -> Result<()>;
+}
+
+/// All the dependencies of the Tickers modules to other modules.
+pub trait Depends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync>;
+    fn provide_policy(&self) -> Arc<dyn policy::Api + Send + Sync>;
+    fn provide_network_tickers(&self) -> Arc<dyn tickers_network::Api + Send + Sync>;
+    fn provide_database_tickers(&self) -> Arc<dyn tickers_database::Api + Send + Sync>;
+}
@@ -0,0 +1,2 @@
+#[path = "crawler-tickers.rs"]
+pub mod crawler_tickers;
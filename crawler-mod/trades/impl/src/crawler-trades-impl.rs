@@ -0,0 +1,171 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use snp_mod_io::snp;
+
+use crawler_mod_trades_io::crawler_trades as io;
+use database_mod_ticker_events_io::ticker_events as ticker_events_database;
+use database_mod_trades_io::trades as database;
+use network_mod_trades_io::trades as network;
+use policy_mod_io::policy;
+
+/// The number of trades of a page, which is the maximum of Polygon.io.
+const PAGE_LIMIT: u32 = 50000;
+
+#[derive(Debug)]
+pub struct Service;
+
+/// The service to implement the API of Trades.
+impl Service {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Default for Service {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl io::Api for Service {
+    async fn crawl_trades(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        data: &io::Data,
+        policy_data: &mut policy::Data,
+    ) -> io::Result<()> {
+        if !deps.provide_calendar().is_trading_day(&data.date) {
+            return Ok(());
+        }
+
+        let policy = deps.provide_policy().clone();
+
+        let snp = deps.provide_snp().clone();
+        let network_service = deps.provide_network_trades().clone();
+        let database_service = deps.provide_database_trades().clone();
+        let database_depends = Arc::new(DatabaseDepends { snp: snp.clone() });
+        // Polygon.io knows the old trades by the symbol of their date.
+        let network_depends = Arc::new(NetworkDepends {
+            snp: deps
+                .provide_database_ticker_events()
+                .historical_snp(database_depends.clone(), &data.ticker, &data.date)
+                .await?,
+            names: deps.provide_names(),
+        });
+
+        let mut cursor = match database_service
+            .find_cursor(database_depends.clone(), &data.ticker, &data.date)
+            .await?
+        {
+            // No next page means all the trades of the day are stored.
+            Some(database::Cursor { next_url: None, .. }) => return Ok(()),
+            Some(cursor) => cursor,
+            None => database::Cursor {
+                snp: data.ticker.clone(),
+                date: data.date,
+                next_url: None,
+                last_sequence_number: None,
+            },
+        };
+
+        let mut request = network::Request {
+            date: Some(data.date),
+            limit: Some(PAGE_LIMIT),
+            cursor: cursor.next_url.as_deref().and_then(network::cursor_of),
+            ..Default::default()
+        };
+        loop {
+            let response = network_service
+                .get_trades_page(network_depends.clone(), &data.ticker, &request)
+                .await;
+            policy.handle_request_rate(policy_data).await?;
+            // The cursor of the last stored page is saved, so the next crawl resumes from the
+            // failed page.
+            let page = response?;
+
+            // A page may be requested again after a failure to save its cursor, so the trades
+            // up to the last stored sequence number are skipped.
+            let trades: Vec<database::Trade> = page
+                .trades
+                .iter()
+                .filter(|t| {
+                    cursor
+                        .last_sequence_number
+                        .is_none_or(|last| t.sequence_number > last)
+                })
+                .map(|t| to_trade(t, &data.ticker))
+                .collect();
+            if !trades.is_empty() {
+                database_service
+                    .save_trades(database_depends.clone(), &trades)
+                    .await?;
+            }
+
+            cursor.last_sequence_number = trades
+                .iter()
+                .map(|t| t.sequence_number)
+                .max()
+                .or(cursor.last_sequence_number);
+            cursor.next_url = page.next_url;
+            database_service
+                .save_cursor(database_depends.clone(), &cursor)
+                .await?;
+
+            match cursor.next_url.as_deref().and_then(network::cursor_of) {
+                Some(next) => request.cursor = Some(next),
+                None => return Ok(()),
+            }
+        }
+    }
+}
+
+fn to_trade(trade: &network::Data, snp: &snp::SnP) -> database::Trade {
+    database::Trade {
+        snp: snp.clone(),
+        sip_timestamp: trade.sip_timestamp,
+        sequence_number: trade.sequence_number,
+        polygon_id: trade.id.clone(),
+        price: trade.price,
+        size: trade.size,
+        exchange: trade.exchange,
+        conditions: trade.conditions.clone(),
+        correction: trade.correction,
+        participant_timestamp: trade.participant_timestamp,
+        tape: trade.tape,
+        trf_id: trade.trf_id,
+        trf_timestamp: trade.trf_timestamp,
+    }
+}
+
+struct NetworkDepends {
+    snp: Arc<dyn snp::Api + Send + Sync>,
+    names: Arc<network::Names>,
+}
+
+impl network::Depends for NetworkDepends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        self.snp.clone()
+    }
+
+    fn provide_names(&self) -> Arc<network::Names> {
+        self.names.clone()
+    }
+}
+
+struct DatabaseDepends {
+    snp: Arc<dyn snp::Api + Send + Sync>,
+}
+
+impl database::Depends for DatabaseDepends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        self.snp.clone()
+    }
+}
+
+impl ticker_events_database::Depends for DatabaseDepends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        self.snp.clone()
+    }
+}
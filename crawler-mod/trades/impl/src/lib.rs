@@ -0,0 +1,5 @@
+#[path = "crawler-trades-impl.rs"]
+pub mod crawler_trades_impl;
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,345 @@
+use std::sync::{Arc, Mutex};
+
+use crate::crawler_trades_impl::Service;
+use async_trait::async_trait;
+use calendar_mod_fixture::calendar_fixture::ServiceFake as CalendarServiceFake;
+use calendar_mod_io::calendar;
+use chrono::NaiveDate;
+use crawler_mod_trades_io::crawler_trades as io;
+use database_mod_ticker_events_fixture::ticker_events_fixture::ServiceFake as TickerEventsServiceFake;
+use database_mod_ticker_events_io::ticker_events as ticker_events_database;
+use database_mod_trades_fixture::trades_fixture::ServiceFake as DBServiceFake;
+use database_mod_trades_io::trades as database;
+use io::Api;
+use network_mod_trades_fixture::trades_fixture::ServiceFake as NetServiceFake;
+use network_mod_trades_io::trades as network;
+use policy_mod_fixture::policy_fixture::ServiceFake as PolicyServiceFake;
+use policy_mod_io::policy;
+use snp_mod_fixture::snp_fixture::ServiceFake as SnPServiceFake;
+use snp_mod_io::snp;
+
+const NEXT_URL: &str = "https://api.polygon.io/v3/trades/AAPL?cursor=cGFnZS0y";
+
+#[tokio::test]
+async fn call_crawl_trades() -> io::Result<()> {
+    let service = Service::new();
+    let data = io::Data {
+        ticker: snp::SnP::AAPL,
+        date: NaiveDate::from_ymd_opt(2024, 3, 14).unwrap(),
+    };
+    let deps = Arc::new(DependsFake {
+        network: Arc::new(NetServiceFake {}),
+        database: Arc::new(DBServiceFake {}),
+        database_ticker_events: Arc::new(TickerEventsServiceFake {}),
+    });
+    let mut policy_data = policy::Data::default();
+
+    service.crawl_trades(deps, &data, &mut policy_data).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn resume_from_stored_cursor_and_skip_stored_trades() -> io::Result<()> {
+    let service = Service::new();
+    let date = NaiveDate::from_ymd_opt(2024, 3, 14).unwrap();
+    let data = io::Data {
+        ticker: snp::SnP::AAPL,
+        date,
+    };
+    let network = Arc::new(NetworkRecorder::default());
+    let database = Arc::new(DatabaseRecorder {
+        cursor: Mutex::new(Some(database::Cursor {
+            snp: snp::SnP::AAPL,
+            date,
+            next_url: Some(NEXT_URL.into()),
+            last_sequence_number: Some(2),
+        })),
+        ..Default::default()
+    });
+    let deps = Arc::new(DependsFake {
+        network: network.clone(),
+        database: database.clone(),
+        database_ticker_events: Arc::new(TickerEventsServiceFake {}),
+    });
+    let mut policy_data = policy::Data::default();
+
+    service
+        .crawl_trades(deps.clone(), &data, &mut policy_data)
+        .await?;
+
+    assert_eq!(
+        vec![Some("cGFnZS0y".into())],
+        *network.cursors.lock().unwrap()
+    );
+    let saved: Vec<i64> = database
+        .trades
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|t| t.sequence_number)
+        .collect();
+    assert_eq!(vec![3], saved);
+    let cursor = database.cursor.lock().unwrap().clone().unwrap();
+    assert_eq!(None, cursor.next_url);
+    assert_eq!(Some(3), cursor.last_sequence_number);
+
+    // All the pages are stored, so nothing is requested anymore.
+    service.crawl_trades(deps, &data, &mut policy_data).await?;
+    assert_eq!(1, network.cursors.lock().unwrap().len());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn return_the_error_of_a_failed_page() {
+    let service = Service::new();
+    let date = NaiveDate::from_ymd_opt(2024, 3, 14).unwrap();
+    let data = io::Data {
+        ticker: snp::SnP::AAPL,
+        date,
+    };
+    let database = Arc::new(DatabaseRecorder::default());
+    let deps = Arc::new(DependsFake {
+        network: Arc::new(NetworkFailingSecondPage {}),
+        database: database.clone(),
+        database_ticker_events: Arc::new(TickerEventsServiceFake {}),
+    });
+    let mut policy_data = policy::Data::default();
+
+    let response = service.crawl_trades(deps, &data, &mut policy_data).await;
+
+    assert!(
+        matches!(response, Err(io::Error::Net(network::Error::Server(_, _)))),
+        "{:?}",
+        response
+    );
+    // The next crawl resumes from the failed page.
+    let cursor = database.cursor.lock().unwrap().clone().unwrap();
+    assert_eq!(Some(NEXT_URL.into()), cursor.next_url);
+    assert_eq!(Some(1), cursor.last_sequence_number);
+}
+
+#[tokio::test]
+async fn request_the_symbol_of_the_date() -> io::Result<()> {
+    let service = Service::new();
+    let data = io::Data {
+        ticker: snp::SnP::AAPL,
+        date: NaiveDate::from_ymd_opt(2022, 3, 14).unwrap(),
+    };
+    let network = Arc::new(NetworkRecorder::default());
+    let deps = Arc::new(DependsFake {
+        network: network.clone(),
+        database: Arc::new(DatabaseRecorder::default()),
+        database_ticker_events: Arc::new(TickerEventsFB {}),
+    });
+    let mut policy_data = policy::Data::default();
+
+    service.crawl_trades(deps, &data, &mut policy_data).await?;
+
+    assert_eq!(vec![Arc::from("FB")], *network.symbols.lock().unwrap());
+
+    Ok(())
+}
+
+/// Returns the first page, then fails the next one.
+struct NetworkFailingSecondPage {}
+
+#[async_trait]
+impl network::Api for NetworkFailingSecondPage {
+    async fn get_trades(
+        &self,
+        _deps: Arc<dyn network::Depends + Send + Sync>,
+        _ticker: &snp::SnP,
+        _request: &network::Request,
+    ) -> network::Result<()> {
+        Ok(())
+    }
+
+    async fn get_trades_page(
+        &self,
+        _deps: Arc<dyn network::Depends + Send + Sync>,
+        _ticker: &snp::SnP,
+        request: &network::Request,
+    ) -> network::Result<network::Page> {
+        match request.cursor {
+            None => Ok(network::Page {
+                trades: vec![given_trade(1)],
+                next_url: Some(NEXT_URL.into()),
+            }),
+            Some(_) => Err(network::Error::Server(
+                NEXT_URL.into(),
+                "You've exceeded the maximum requests".into(),
+            )),
+        }
+    }
+}
+
+/// Returns the last page, with the trade of sequence number 2 that is stored before.
+#[derive(Default)]
+struct NetworkRecorder {
+    cursors: Mutex<Vec<Option<Arc<str>>>>,
+    symbols: Mutex<Vec<Arc<str>>>,
+}
+
+#[async_trait]
+impl network::Api for NetworkRecorder {
+    async fn get_trades(
+        &self,
+        _deps: Arc<dyn network::Depends + Send + Sync>,
+        _ticker: &snp::SnP,
+        _request: &network::Request,
+    ) -> network::Result<()> {
+        Ok(())
+    }
+
+    async fn get_trades_page(
+        &self,
+        deps: Arc<dyn network::Depends + Send + Sync>,
+        ticker: &snp::SnP,
+        request: &network::Request,
+    ) -> network::Result<network::Page> {
+        self.cursors.lock().unwrap().push(request.cursor.clone());
+        self.symbols
+            .lock()
+            .unwrap()
+            .push(deps.provide_snp().get_symbol(ticker));
+        Ok(network::Page {
+            trades: vec![given_trade(2), given_trade(3)],
+            next_url: None,
+        })
+    }
+}
+
+#[derive(Default)]
+struct DatabaseRecorder {
+    trades: Mutex<Vec<database::Trade>>,
+    cursor: Mutex<Option<database::Cursor>>,
+}
+
+#[async_trait]
+impl database::Api for DatabaseRecorder {
+    async fn save_trades(
+        &self,
+        _deps: Arc<dyn database::Depends + Send + Sync>,
+        trades: &[database::Trade],
+    ) -> database::Result<()> {
+        self.trades.lock().unwrap().extend_from_slice(trades);
+        Ok(())
+    }
+
+    async fn find_trades(
+        &self,
+        _deps: Arc<dyn database::Depends + Send + Sync>,
+        _snp: &snp::SnP,
+        _from: i64,
+        _to: i64,
+    ) -> database::Result<Vec<database::Trade>> {
+        Ok(self.trades.lock().unwrap().clone())
+    }
+
+    async fn save_cursor(
+        &self,
+        _deps: Arc<dyn database::Depends + Send + Sync>,
+        cursor: &database::Cursor,
+    ) -> database::Result<()> {
+        *self.cursor.lock().unwrap() = Some(cursor.clone());
+        Ok(())
+    }
+
+    async fn find_cursor(
+        &self,
+        _deps: Arc<dyn database::Depends + Send + Sync>,
+        _snp: &snp::SnP,
+        _date: &NaiveDate,
+    ) -> database::Result<Option<database::Cursor>> {
+        Ok(self.cursor.lock().unwrap().clone())
+    }
+}
+
+fn given_trade(sequence_number: i64) -> network::Data {
+    network::Data {
+        id: sequence_number.to_string().into(),
+        price: 172.5,
+        size: 100,
+        participant_timestamp: None,
+        sip_timestamp: 1710403200010698000 + sequence_number,
+        sequence_number,
+        exchange: 11,
+        exchange_name: None,
+        conditions: vec![],
+        condition_names: vec![],
+        correction: None,
+        tape: Some(3),
+        trf_id: None,
+        trf_name: None,
+        trf_timestamp: None,
+    }
+}
+
+/// Returns `FB` as the historical symbol of any ticker.
+struct TickerEventsFB;
+
+#[async_trait]
+impl ticker_events_database::Api for TickerEventsFB {
+    async fn save_ticker_changes(
+        &self,
+        _deps: Arc<dyn ticker_events_database::Depends + Send + Sync>,
+        _changes: &[ticker_events_database::TickerChange],
+    ) -> ticker_events_database::Result<()> {
+        Ok(())
+    }
+
+    async fn find_ticker_changes(
+        &self,
+        _deps: Arc<dyn ticker_events_database::Depends + Send + Sync>,
+        _composite_figi: &str,
+    ) -> ticker_events_database::Result<Vec<ticker_events_database::TickerChange>> {
+        Ok(vec![])
+    }
+
+    async fn find_historical_symbol(
+        &self,
+        _deps: Arc<dyn ticker_events_database::Depends + Send + Sync>,
+        _symbol: &str,
+        _date: &NaiveDate,
+    ) -> ticker_events_database::Result<Arc<str>> {
+        Ok(Arc::from("FB"))
+    }
+}
+
+struct DependsFake {
+    network: Arc<dyn network::Api + Send + Sync>,
+    database: Arc<dyn database::Api + Send + Sync>,
+    database_ticker_events: Arc<dyn ticker_events_database::Api + Send + Sync>,
+}
+
+impl io::Depends for DependsFake {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        Arc::new(SnPServiceFake {})
+    }
+
+    fn provide_policy(&self) -> Arc<dyn policy::Api + Send + Sync> {
+        Arc::new(PolicyServiceFake {})
+    }
+
+    fn provide_calendar(&self) -> Arc<dyn calendar::Api + Send + Sync> {
+        Arc::new(CalendarServiceFake {})
+    }
+
+    fn provide_network_trades(&self) -> Arc<dyn network::Api + Send + Sync> {
+        self.network.clone()
+    }
+
+    fn provide_names(&self) -> Arc<network::Names> {
+        Arc::new(network::Names::default())
+    }
+
+    fn provide_database_trades(&self) -> Arc<dyn database::Api + Send + Sync> {
+        self.database.clone()
+    }
+
+    fn provide_database_ticker_events(&self) -> Arc<dyn ticker_events_database::Api + Send + Sync> {
+        self.database_ticker_events.clone()
+    }
+}
@@ -0,0 +1,59 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use calendar_mod_io::calendar;
+use chrono::NaiveDate;
+use database_mod_ticker_events_io::ticker_events as ticker_events_database;
+use database_mod_trades_io::trades as database;
+use network_mod_trades_io::trades as network;
+use policy_mod_io::policy;
+use snp_mod_io::snp;
+
+/// The data that is needed to interact with the Trades modules.
+#[derive(Debug)]
+pub struct Data {
+    pub ticker: snp::SnP,
+    pub date: NaiveDate,
+}
+
+/// All possible errors of Trades modules.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("{0}")]
+    Db(#[from] database::Error),
+    #[error("{0}")]
+    Net(#[from] network::Error),
+    #[error("{0}")]
+    Policy(#[from] policy::Error),
+    #[error("{0}")]
+    SnP(#[from] snp::Error),
+    #[error("{0}")]
+    TickerEvents(#[from] ticker_events_database::Error),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The API of Trades, which defines all the functionality of Trades modules.
+#[async_trait]
+pub trait Api {
+    /// Stores the trades of the ticker on the date page by page, resuming from the stored
+    /// cursor, if any, and skipping the trades that are stored before. The error of a failed
+    /// page is returned, after the pages before it are stored.
+    async fn crawl_trades(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        data: &Data,
+        policy_data: &mut policy::Data,
+    ) -> Result<()>;
+}
+
+/// All the dependencies of the Trades modules to other modules.
+pub trait Depends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync>;
+    fn provide_policy(&self) -> Arc<dyn policy::Api + Send + Sync>;
+    fn provide_calendar(&self) -> Arc<dyn calendar::Api + Send + Sync>;
+    fn provide_network_trades(&self) -> Arc<dyn network::Api + Send + Sync>;
+    fn provide_names(&self) -> Arc<network::Names>;
+    fn provide_database_trades(&self) -> Arc<dyn database::Api + Send + Sync>;
+    fn provide_database_ticker_events(&self) -> Arc<dyn ticker_events_database::Api + Send + Sync>;
+}
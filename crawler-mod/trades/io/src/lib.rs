@@ -0,0 +1,2 @@
+#[path = "crawler-trades.rs"]
+pub mod crawler_trades;
@@ -0,0 +1,47 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use database_mod_aggregates_io::aggregates as io;
+use snp_mod_io::snp;
+
+/// Stores nothing, so every bar looks missing, and nothing looks fetched.
+pub struct ServiceFake;
+
+#[async_trait]
+impl io::Api for ServiceFake {
+    async fn save_bars(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        _bars: &[io::Bar],
+    ) -> io::Result<()> {
+        Ok(())
+    }
+
+    async fn find_bars(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        _snp: &snp::SnP,
+        _timespan: io::Timespan,
+        _from: &DateTime<Utc>,
+        _to: &DateTime<Utc>,
+    ) -> io::Result<Vec<io::Bar>> {
+        Ok(vec![])
+    }
+
+    async fn save_fetch(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        _fetch: &io::Fetch,
+    ) -> io::Result<()> {
+        Ok(())
+    }
+
+    async fn is_fetched(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        _fetch: &io::Fetch,
+    ) -> io::Result<bool> {
+        Ok(false)
+    }
+}
@@ -0,0 +1,153 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tokio_postgres::Client;
+
+use database_mod_aggregates_io::aggregates as io;
+use snp_mod_io::snp;
+
+/// The service to implement the API of Aggregates.
+pub struct Service {
+    client: Arc<Client>,
+}
+
+impl Service {
+    pub fn new(client: Arc<Client>) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl io::Api for Service {
+    async fn save_bars(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        bars: &[io::Bar],
+    ) -> io::Result<()> {
+        let snp = deps.provide_snp();
+        let tickers: Vec<String> = bars
+            .iter()
+            .map(|b| snp.get_symbol(&b.snp).to_string())
+            .collect();
+        let timespans: Vec<&str> = bars.iter().map(|b| b.timespan.as_str()).collect();
+        let starts: Vec<DateTime<Utc>> = bars.iter().map(|b| b.start).collect();
+        let opens: Vec<f64> = bars.iter().map(|b| b.open).collect();
+        let highs: Vec<f64> = bars.iter().map(|b| b.high).collect();
+        let lows: Vec<f64> = bars.iter().map(|b| b.low).collect();
+        let closes: Vec<f64> = bars.iter().map(|b| b.close).collect();
+        let volumes: Vec<f64> = bars.iter().map(|b| b.volume).collect();
+        let vwaps: Vec<Option<f64>> = bars.iter().map(|b| b.vwap).collect();
+        let transactions: Vec<Option<i64>> = bars.iter().map(|b| b.transactions).collect();
+
+        // One statement for all the bars, since a day has hundreds of minute bars.
+        self.client
+            .execute(
+                "INSERT INTO bars (
+                ticker, timespan, start, open, high, low, close, volume, vwap, transactions)
+             SELECT * FROM UNNEST(
+                $1::VARCHAR[], $2::VARCHAR[], $3::TIMESTAMPTZ[], $4::FLOAT8[], $5::FLOAT8[],
+                $6::FLOAT8[], $7::FLOAT8[], $8::FLOAT8[], $9::FLOAT8[], $10::BIGINT[])
+             ON CONFLICT (ticker, timespan, start) DO UPDATE SET
+                open = EXCLUDED.open,
+                high = EXCLUDED.high,
+                low = EXCLUDED.low,
+                close = EXCLUDED.close,
+                volume = EXCLUDED.volume,
+                vwap = EXCLUDED.vwap,
+                transactions = EXCLUDED.transactions",
+                &[
+                    &tickers,
+                    &timespans,
+                    &starts,
+                    &opens,
+                    &highs,
+                    &lows,
+                    &closes,
+                    &volumes,
+                    &vwaps,
+                    &transactions,
+                ],
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn find_bars(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        snp: &snp::SnP,
+        timespan: io::Timespan,
+        from: &DateTime<Utc>,
+        to: &DateTime<Utc>,
+    ) -> io::Result<Vec<io::Bar>> {
+        let snp_api = deps.provide_snp();
+        let snp_symbol_rc = snp_api.get_symbol(snp).clone();
+        let snp_symbol = &snp_symbol_rc[..];
+
+        let list = self
+            .client
+            .query(
+                "SELECT start, open, high, low, close, volume, vwap, transactions
+                 FROM bars WHERE ticker = $1 AND timespan = $2 AND start >= $3 AND start < $4
+                 ORDER BY start",
+                &[&snp_symbol, &timespan.as_str(), from, to],
+            )
+            .await?;
+
+        Ok(list
+            .into_iter()
+            .map(|row| io::Bar {
+                snp: snp.clone(),
+                timespan,
+                start: row.get::<usize, DateTime<Utc>>(0),
+                open: row.get::<usize, f64>(1),
+                high: row.get::<usize, f64>(2),
+                low: row.get::<usize, f64>(3),
+                close: row.get::<usize, f64>(4),
+                volume: row.get::<usize, f64>(5),
+                vwap: row.get::<usize, Option<f64>>(6),
+                transactions: row.get::<usize, Option<i64>>(7),
+            })
+            .collect())
+    }
+
+    async fn save_fetch(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        fetch: &io::Fetch,
+    ) -> io::Result<()> {
+        let snp = deps.provide_snp();
+        let ticker = snp.get_symbol(&fetch.snp);
+
+        self.client
+            .execute(
+                "INSERT INTO bars_fetches (ticker, timespan, date) VALUES ($1, $2, $3)
+                 ON CONFLICT (ticker, timespan, date) DO NOTHING",
+                &[&ticker.as_ref(), &fetch.timespan.as_str(), &fetch.date],
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn is_fetched(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        fetch: &io::Fetch,
+    ) -> io::Result<bool> {
+        let snp = deps.provide_snp();
+        let ticker = snp.get_symbol(&fetch.snp);
+
+        let list = self
+            .client
+            .query(
+                "SELECT 1 FROM bars_fetches WHERE ticker = $1 AND timespan = $2 AND date = $3",
+                &[&ticker.as_ref(), &fetch.timespan.as_str(), &fetch.date],
+            )
+            .await?;
+
+        Ok(!list.is_empty())
+    }
+}
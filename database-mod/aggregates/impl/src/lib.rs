@@ -0,0 +1,5 @@
+#[path = "aggregates-impl.rs"]
+pub mod aggregates_impl;
+
+#[cfg(test)]
+mod test;
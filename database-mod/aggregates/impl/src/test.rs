@@ -0,0 +1,122 @@
+use chrono::{Duration, NaiveDate, TimeZone, Utc};
+use std::env;
+use std::sync::Arc;
+use tokio_postgres::{Client, NoTls};
+
+use crate::aggregates_impl::Service;
+use database_mod_aggregates_io::aggregates as io;
+use io::Api;
+use snp_mod_fixture::snp_fixture::ServiceFake;
+use snp_mod_io::snp;
+
+#[tokio::test]
+async fn save_to_database_and_find() -> std::result::Result<(), io::Error> {
+    let db = Arc::new(init().await?);
+    let service = Service::new(db);
+    let deps = given_deps();
+
+    let start = Utc.with_ymd_and_hms(2024, 4, 10, 13, 30, 0).unwrap();
+    let bars = vec![
+        given_bar(start, io::Timespan::Minute),
+        given_bar(
+            start + Duration::try_minutes(1).unwrap(),
+            io::Timespan::Minute,
+        ),
+        given_bar(start, io::Timespan::Day),
+    ];
+
+    service.save_bars(deps.clone(), &bars).await?;
+    service.save_bars(deps.clone(), &bars[..1]).await?;
+    let list = service
+        .find_bars(
+            deps.clone(),
+            &snp::SnP::AAPL,
+            io::Timespan::Minute,
+            &start,
+            &(start + Duration::try_hours(1).unwrap()),
+        )
+        .await?;
+
+    assert_eq!(bars[..2], list[..]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn save_fetch_and_check() -> std::result::Result<(), io::Error> {
+    let db = Arc::new(init().await?);
+    let service = Service::new(db);
+    let deps = given_deps();
+
+    let fetch = io::Fetch {
+        snp: snp::SnP::AAPL,
+        timespan: io::Timespan::Minute,
+        date: NaiveDate::from_ymd_opt(2024, 4, 10).unwrap(),
+    };
+    let day = io::Fetch {
+        timespan: io::Timespan::Day,
+        ..fetch.clone()
+    };
+
+    service.save_fetch(deps.clone(), &fetch).await?;
+    service.save_fetch(deps.clone(), &fetch).await?;
+
+    assert!(service.is_fetched(deps.clone(), &fetch).await?);
+    assert!(!service.is_fetched(deps.clone(), &day).await?);
+
+    Ok(())
+}
+
+fn given_bar(start: chrono::DateTime<Utc>, timespan: io::Timespan) -> io::Bar {
+    io::Bar {
+        snp: snp::SnP::AAPL,
+        timespan,
+        start,
+        open: 168.8,
+        high: 169.1,
+        low: 168.7,
+        close: 169.0,
+        volume: 35412.0,
+        vwap: Some(168.93),
+        transactions: None,
+    }
+}
+
+async fn init() -> io::Result<Client> {
+    let db_user = env::var("DB_USER").unwrap();
+    let db_password = env::var("DB_PASSWORD").unwrap();
+    let db_port = env::var("DB_PORT").unwrap().parse::<u16>().unwrap();
+    let db_name = env::var("DB_TEST_NAME").unwrap();
+
+    let (client, connection) = tokio_postgres::Config::new()
+        .user(&db_user)
+        .password(&db_password)
+        .host("localhost")
+        .port(db_port)
+        .dbname(&db_name)
+        .connect(NoTls)
+        .await?;
+
+    // Spawn connection
+    tokio::spawn(async move {
+        if let Err(error) = connection.await {
+            eprintln!("Connection error: {}", error);
+        }
+    });
+
+    Ok(client)
+}
+
+fn given_deps() -> Arc<dyn io::Depends + Send + Sync> {
+    Arc::new(DependsFake {})
+}
+
+struct DependsFake {}
+
+impl DependsFake {}
+
+impl io::Depends for DependsFake {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        Arc::new(ServiceFake {})
+    }
+}
@@ -0,0 +1,89 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use snp_mod_io::snp;
+
+/// The size of the time window of each bar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Timespan {
+    Minute,
+    Hour,
+    Day,
+}
+
+impl Timespan {
+    /// The name of the timespan in the `bars` table.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Timespan::Minute => "minute",
+            Timespan::Hour => "hour",
+            Timespan::Day => "day",
+        }
+    }
+}
+
+/// The data that is needed to interact with the Aggregates modules.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bar {
+    pub snp: snp::SnP,
+    pub timespan: Timespan,
+    pub start: DateTime<Utc>,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub volume: f64,
+    pub vwap: Option<f64>,
+    pub transactions: Option<i64>,
+}
+
+/// The bars of a ticker in a timespan on a date, which are requested successfully, so a bar that
+/// is missing among them doesn't exist, like a minute without any trade.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fetch {
+    pub snp: snp::SnP,
+    pub timespan: Timespan,
+    pub date: NaiveDate,
+}
+
+/// All possible errors of Aggregates modules.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// An error with an embedded storage engine
+    #[error("{0}")]
+    Db(#[from] tokio_postgres::Error),
+    #[error("{0}")]
+    SnP(#[from] snp::Error),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The API of Aggregates, which defines all the functionality of Aggregates modules.
+#[async_trait]
+pub trait Api {
+    /// Replaces the bars of the same ticker, timespan and start, if any.
+    async fn save_bars(&self, deps: Arc<dyn Depends + Send + Sync>, bars: &[Bar]) -> Result<()>;
+
+    /// Returns the bars starting in `[from, to)`, in ascending order of start.
+    async fn find_bars(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        snp: &snp::SnP,
+        timespan: Timespan,
+        from: &DateTime<Utc>,
+        to: &DateTime<Utc>,
+    ) -> Result<Vec<Bar>>;
+
+    /// Marks the bars of the fetch as requested.
+    async fn save_fetch(&self, deps: Arc<dyn Depends + Send + Sync>, fetch: &Fetch) -> Result<()>;
+
+    /// Returns whether the bars of the fetch are requested before.
+    async fn is_fetched(&self, deps: Arc<dyn Depends + Send + Sync>, fetch: &Fetch)
+        -> Result<bool>;
+}
+
+/// All the dependencies of the Aggregates modules to other modules.
+pub trait Depends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync>;
+}
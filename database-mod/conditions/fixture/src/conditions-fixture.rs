@@ -0,0 +1,25 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use database_mod_conditions_io::conditions as io;
+
+pub struct ServiceFake;
+
+#[async_trait]
+impl io::Api for ServiceFake {
+    async fn save_conditions(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        _conditions: &[io::Condition],
+    ) -> io::Result<()> {
+        Ok(())
+    }
+
+    async fn find_conditions(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        _asset_class: &str,
+    ) -> io::Result<Vec<io::Condition>> {
+        Ok(vec![])
+    }
+}
@@ -0,0 +1,99 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tokio_postgres::Client;
+
+use database_mod_conditions_io::conditions as io;
+
+/// The service to implement the API of Conditions.
+pub struct Service {
+    client: Arc<Client>,
+}
+
+impl Service {
+    pub fn new(client: Arc<Client>) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl io::Api for Service {
+    async fn save_conditions(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        conditions: &[io::Condition],
+    ) -> io::Result<()> {
+        for condition in conditions {
+            let data_types: Vec<&str> = condition.data_types.iter().map(|d| d.as_ref()).collect();
+            self.client
+                .execute(
+                    "INSERT INTO trade_conditions (
+                    id, asset_class, condition_type, name, abbreviation, data_types, legacy,
+                    updates_high_low, updates_open_close, updates_volume)
+                 VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+                 ON CONFLICT (asset_class, id) DO UPDATE SET
+                    condition_type = EXCLUDED.condition_type,
+                    name = EXCLUDED.name,
+                    abbreviation = EXCLUDED.abbreviation,
+                    data_types = EXCLUDED.data_types,
+                    legacy = EXCLUDED.legacy,
+                    updates_high_low = EXCLUDED.updates_high_low,
+                    updates_open_close = EXCLUDED.updates_open_close,
+                    updates_volume = EXCLUDED.updates_volume",
+                    &[
+                        &condition.id,
+                        &condition.asset_class.as_ref(),
+                        &condition.condition_type.as_ref(),
+                        &condition.name.as_ref(),
+                        &condition.abbreviation.as_deref(),
+                        &data_types,
+                        &condition.legacy,
+                        &condition.updates_high_low,
+                        &condition.updates_open_close,
+                        &condition.updates_volume,
+                    ],
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn find_conditions(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        asset_class: &str,
+    ) -> io::Result<Vec<io::Condition>> {
+        let list = self
+            .client
+            .query(
+                "SELECT id, condition_type, name, abbreviation, asset_class, data_types, legacy,
+                    updates_high_low, updates_open_close, updates_volume
+                 FROM trade_conditions
+                 WHERE asset_class = $1
+                 ORDER BY id",
+                &[&asset_class],
+            )
+            .await?;
+
+        Ok(list
+            .into_iter()
+            .map(|row| io::Condition {
+                id: row.get::<usize, i32>(0),
+                condition_type: row.get::<usize, &str>(1).into(),
+                name: row.get::<usize, &str>(2).into(),
+                abbreviation: row.get::<usize, Option<&str>>(3).map(|a| a.into()),
+                asset_class: row.get::<usize, &str>(4).into(),
+                data_types: row
+                    .get::<usize, Vec<&str>>(5)
+                    .into_iter()
+                    .map(|d| d.into())
+                    .collect(),
+                legacy: row.get::<usize, bool>(6),
+                updates_high_low: row.get::<usize, bool>(7),
+                updates_open_close: row.get::<usize, bool>(8),
+                updates_volume: row.get::<usize, bool>(9),
+            })
+            .collect())
+    }
+}
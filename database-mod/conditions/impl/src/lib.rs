@@ -0,0 +1,5 @@
+#[path = "conditions-impl.rs"]
+pub mod conditions_impl;
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,99 @@
+use std::env;
+use std::sync::Arc;
+use tokio_postgres::{Client, NoTls};
+
+use crate::conditions_impl::Service;
+use database_mod_conditions_io::conditions as io;
+use io::Api;
+use snp_mod_fixture::snp_fixture::ServiceFake;
+use snp_mod_io::snp;
+
+#[tokio::test]
+async fn save_to_database_and_find() -> std::result::Result<(), io::Error> {
+    let db = Arc::new(init().await?);
+    let service = Service::new(db);
+    let deps = given_deps();
+
+    let odd_lot = io::Condition {
+        id: 37,
+        condition_type: "sale_condition".into(),
+        name: "Odd Lot Trade".into(),
+        abbreviation: None,
+        asset_class: "stocks".into(),
+        data_types: vec!["trade".into()],
+        legacy: false,
+        updates_high_low: false,
+        updates_open_close: false,
+        updates_volume: true,
+    };
+    let sweep = io::Condition {
+        id: 14,
+        condition_type: "sale_condition".into(),
+        name: "Intermarket Sweep".into(),
+        abbreviation: Some("ISO".into()),
+        asset_class: "stocks".into(),
+        data_types: vec!["trade".into()],
+        legacy: false,
+        updates_high_low: true,
+        updates_open_close: true,
+        updates_volume: true,
+    };
+    let mut legacy = odd_lot.clone();
+    legacy.legacy = true;
+
+    service
+        .save_conditions(deps.clone(), &[odd_lot, sweep.clone()])
+        .await?;
+    service
+        .save_conditions(deps.clone(), std::slice::from_ref(&legacy))
+        .await?;
+    let list = service.find_conditions(deps.clone(), "stocks").await?;
+
+    assert_eq!(
+        vec![&sweep, &legacy],
+        list.iter()
+            .filter(|c| c.id == 14 || c.id == 37)
+            .collect::<Vec<_>>()
+    );
+
+    Ok(())
+}
+
+async fn init() -> io::Result<Client> {
+    let db_user = env::var("DB_USER").unwrap();
+    let db_password = env::var("DB_PASSWORD").unwrap();
+    let db_port = env::var("DB_PORT").unwrap().parse::<u16>().unwrap();
+    let db_name = env::var("DB_TEST_NAME").unwrap();
+
+    let (client, connection) = tokio_postgres::Config::new()
+        .user(&db_user)
+        .password(&db_password)
+        .host("localhost")
+        .port(db_port)
+        .dbname(&db_name)
+        .connect(NoTls)
+        .await?;
+
+    // Spawn connection
+    tokio::spawn(async move {
+        if let Err(error) = connection.await {
+            eprintln!("Connection error: {}", error);
+        }
+    });
+
+    Ok(client)
+}
+
+fn given_deps() -> Arc<dyn io::Depends + Send + Sync> {
+    Arc::new(DependsFake {})
+}
+
+struct DependsFake {}
+
+impl DependsFake {}
+
+impl io::Depends for DependsFake {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        Arc::new(ServiceFake {})
+    }
+}
@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use snp_mod_io::snp;
+
+/// The data that is needed to interact with the Conditions modules, which are the conditions
+/// of the trades.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Condition {
+    /// The ID that the trades refer to, in their `conditions`.
+    pub id: i32,
+    pub condition_type: Arc<str>,
+    pub name: Arc<str>,
+    pub abbreviation: Option<Arc<str>>,
+    pub asset_class: Arc<str>,
+    pub data_types: Vec<Arc<str>>,
+    pub legacy: bool,
+    /// Whether a trade of this condition updates the consolidated high and low, where odd-lot
+    /// and out-of-sequence trades do not.
+    pub updates_high_low: bool,
+    pub updates_open_close: bool,
+    pub updates_volume: bool,
+}
+
+/// All possible errors of Conditions modules.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// An error with an embedded storage engine
+    #[error("{0}")]
+    Db(#[from] tokio_postgres::Error),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The API of Conditions, which defines all the functionality of Conditions modules.
+#[async_trait]
+pub trait Api {
+    /// Replaces the conditions of the same asset class and ID, if any.
+    async fn save_conditions(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        conditions: &[Condition],
+    ) -> Result<()>;
+
+    /// Returns the conditions of the asset class in ascending order of ID.
+    async fn find_conditions(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        asset_class: &str,
+    ) -> Result<Vec<Condition>>;
+}
+
+/// All the dependencies of the Conditions modules to other modules.
+pub trait Depends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync>;
+}
@@ -0,0 +1,26 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use database_mod_dividends_io::dividends as io;
+use snp_mod_io::snp;
+
+pub struct ServiceFake;
+
+#[async_trait]
+impl io::Api for ServiceFake {
+    async fn save_dividends(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        _dividends: &[io::Dividend],
+    ) -> io::Result<()> {
+        Ok(())
+    }
+
+    async fn find_dividends(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        _snp: &snp::SnP,
+    ) -> io::Result<Vec<io::Dividend>> {
+        Ok(vec![])
+    }
+}
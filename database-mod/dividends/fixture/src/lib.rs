@@ -0,0 +1,2 @@
+#[path = "dividends-fixture.rs"]
+pub mod dividends_fixture;
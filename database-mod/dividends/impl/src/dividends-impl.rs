@@ -0,0 +1,102 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use tokio_postgres::Client;
+
+use database_mod_dividends_io::dividends as io;
+use snp_mod_io::snp;
+
+/// The service to implement the API of Dividends.
+pub struct Service {
+    client: Arc<Client>,
+}
+
+impl Service {
+    pub fn new(client: Arc<Client>) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl io::Api for Service {
+    async fn save_dividends(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        dividends: &[io::Dividend],
+    ) -> io::Result<()> {
+        let snp = deps.provide_snp();
+        for dividend in dividends {
+            let snp_symbol_rc = snp.get_symbol(&dividend.snp).clone();
+            let snp_symbol = &snp_symbol_rc[..];
+            self.client
+                .execute(
+                    "INSERT INTO dividends (
+                    polygon_id, ticker, cash_amount, currency, declaration_date, dividend_type,
+                    ex_dividend_date, frequency, pay_date, record_date)
+                 VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+                 ON CONFLICT (polygon_id) DO UPDATE SET
+                    ticker = EXCLUDED.ticker,
+                    cash_amount = EXCLUDED.cash_amount,
+                    currency = EXCLUDED.currency,
+                    declaration_date = EXCLUDED.declaration_date,
+                    dividend_type = EXCLUDED.dividend_type,
+                    ex_dividend_date = EXCLUDED.ex_dividend_date,
+                    frequency = EXCLUDED.frequency,
+                    pay_date = EXCLUDED.pay_date,
+                    record_date = EXCLUDED.record_date",
+                    &[
+                        &dividend.polygon_id.as_ref(),
+                        &snp_symbol,
+                        &dividend.cash_amount,
+                        &dividend.currency.as_deref(),
+                        &dividend.declaration_date,
+                        &dividend.dividend_type.as_ref(),
+                        &dividend.ex_dividend_date,
+                        &dividend.frequency,
+                        &dividend.pay_date,
+                        &dividend.record_date,
+                    ],
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn find_dividends(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        snp: &snp::SnP,
+    ) -> io::Result<Vec<io::Dividend>> {
+        let snp_api = deps.provide_snp();
+        let snp_symbol_rc = snp_api.get_symbol(snp).clone();
+        let snp_symbol = &snp_symbol_rc[..];
+
+        let list = self
+            .client
+            .query(
+                "SELECT polygon_id, cash_amount, currency, declaration_date, dividend_type,
+                    ex_dividend_date, frequency, pay_date, record_date
+                 FROM dividends WHERE ticker = $1 ORDER BY ex_dividend_date",
+                &[&snp_symbol],
+            )
+            .await?;
+
+        Ok(list
+            .into_iter()
+            .map(|row| io::Dividend {
+                polygon_id: row.get::<usize, &str>(0).into(),
+                snp: snp.clone(),
+                cash_amount: row.get::<usize, f64>(1),
+                currency: row.get::<usize, Option<&str>>(2).map(|c| c.into()),
+                declaration_date: row.get::<usize, Option<NaiveDate>>(3),
+                dividend_type: row.get::<usize, &str>(4).into(),
+                ex_dividend_date: row.get::<usize, NaiveDate>(5),
+                frequency: row.get::<usize, i32>(6),
+                pay_date: row.get::<usize, Option<NaiveDate>>(7),
+                record_date: row.get::<usize, Option<NaiveDate>>(8),
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,5 @@
+#[path = "dividends-impl.rs"]
+pub mod dividends_impl;
+
+#[cfg(test)]
+mod test;
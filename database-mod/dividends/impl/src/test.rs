@@ -0,0 +1,89 @@
+use chrono::NaiveDate;
+use std::env;
+use std::sync::Arc;
+use tokio_postgres::{Client, NoTls};
+
+use crate::dividends_impl::Service;
+use database_mod_dividends_io::dividends as io;
+use io::Api;
+use snp_mod_fixture::snp_fixture::ServiceFake;
+use snp_mod_io::snp;
+
+#[tokio::test]
+async fn save_to_database_and_find() -> std::result::Result<(), io::Error> {
+    let db = Arc::new(init().await?);
+    let service = Service::new(db);
+    let deps = given_deps();
+
+    let announced = io::Dividend {
+        polygon_id: "E8e3c4f794613e9205e2f178a36c53fcc57cdabb55e1988c87b33f9e52e221444".into(),
+        snp: snp::SnP::AAPL,
+        cash_amount: 0.24,
+        currency: Some("USD".into()),
+        declaration_date: NaiveDate::from_ymd_opt(2024, 2, 1),
+        dividend_type: "CD".into(),
+        ex_dividend_date: NaiveDate::from_ymd_opt(2024, 2, 9).unwrap(),
+        frequency: 4,
+        pay_date: NaiveDate::from_ymd_opt(2024, 2, 15),
+        record_date: NaiveDate::from_ymd_opt(2024, 2, 12),
+    };
+    let mut pending = announced.clone();
+    pending.pay_date = None;
+
+    service.save_dividends(deps.clone(), &[pending]).await?;
+    // The pay date is known later, so saving it again replaces the dividend.
+    service
+        .save_dividends(deps.clone(), std::slice::from_ref(&announced))
+        .await?;
+    let list = service
+        .find_dividends(deps.clone(), &snp::SnP::AAPL)
+        .await?;
+
+    assert_eq!(
+        vec![&announced],
+        list.iter()
+            .filter(|d| d.polygon_id == announced.polygon_id)
+            .collect::<Vec<_>>()
+    );
+
+    Ok(())
+}
+
+async fn init() -> io::Result<Client> {
+    let db_user = env::var("DB_USER").unwrap();
+    let db_password = env::var("DB_PASSWORD").unwrap();
+    let db_port = env::var("DB_PORT").unwrap().parse::<u16>().unwrap();
+    let db_name = env::var("DB_TEST_NAME").unwrap();
+
+    let (client, connection) = tokio_postgres::Config::new()
+        .user(&db_user)
+        .password(&db_password)
+        .host("localhost")
+        .port(db_port)
+        .dbname(&db_name)
+        .connect(NoTls)
+        .await?;
+
+    // Spawn connection
+    tokio::spawn(async move {
+        if let Err(error) = connection.await {
+            eprintln!("Connection error: {}", error);
+        }
+    });
+
+    Ok(client)
+}
+
+fn given_deps() -> Arc<dyn io::Depends + Send + Sync> {
+    Arc::new(DependsFake {})
+}
+
+struct DependsFake {}
+
+impl DependsFake {}
+
+impl io::Depends for DependsFake {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        Arc::new(ServiceFake {})
+    }
+}
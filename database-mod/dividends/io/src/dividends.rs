@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use snp_mod_io::snp;
+
+/// The data that is needed to interact with the Dividends modules.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Dividend {
+    /// The unique identifier of the dividend in Polygon.io.
+    pub polygon_id: Arc<str>,
+    pub snp: snp::SnP,
+    pub cash_amount: f64,
+    pub currency: Option<Arc<str>>,
+    pub declaration_date: Option<NaiveDate>,
+    pub dividend_type: Arc<str>,
+    pub ex_dividend_date: NaiveDate,
+    pub frequency: i32,
+    pub pay_date: Option<NaiveDate>,
+    pub record_date: Option<NaiveDate>,
+}
+
+/// All possible errors of Dividends modules.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// An error with an embedded storage engine
+    #[error("{0}")]
+    Db(#[from] tokio_postgres::Error),
+    #[error("{0}")]
+    SnP(#[from] snp::Error),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The API of Dividends, which defines all the functionality of Dividends modules.
+#[async_trait]
+pub trait Api {
+    /// Replaces the dividends with the same Polygon.io identifier, if any.
+    async fn save_dividends(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        dividends: &[Dividend],
+    ) -> Result<()>;
+
+    /// Returns the dividends of the ticker in ascending order of ex-dividend date.
+    async fn find_dividends(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        snp: &snp::SnP,
+    ) -> Result<Vec<Dividend>>;
+}
+
+/// All the dependencies of the Dividends modules to other modules.
+pub trait Depends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync>;
+}
@@ -0,0 +1,25 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use database_mod_exchanges_io::exchanges as io;
+
+pub struct ServiceFake;
+
+#[async_trait]
+impl io::Api for ServiceFake {
+    async fn save_exchanges(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        _exchanges: &[io::Exchange],
+    ) -> io::Result<()> {
+        Ok(())
+    }
+
+    async fn find_exchanges(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        _asset_class: &str,
+    ) -> io::Result<Vec<io::Exchange>> {
+        Ok(vec![])
+    }
+}
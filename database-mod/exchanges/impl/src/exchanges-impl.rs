@@ -0,0 +1,94 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tokio_postgres::Client;
+
+use database_mod_exchanges_io::exchanges as io;
+
+/// The service to implement the API of Exchanges.
+pub struct Service {
+    client: Arc<Client>,
+}
+
+impl Service {
+    pub fn new(client: Arc<Client>) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl io::Api for Service {
+    async fn save_exchanges(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        exchanges: &[io::Exchange],
+    ) -> io::Result<()> {
+        for exchange in exchanges {
+            self.client
+                .execute(
+                    "INSERT INTO exchanges (
+                    id, asset_class, exchange_type, locale, name, acronym, mic, operating_mic,
+                    participant_id, url)
+                 VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+                 ON CONFLICT (asset_class, id) DO UPDATE SET
+                    exchange_type = EXCLUDED.exchange_type,
+                    locale = EXCLUDED.locale,
+                    name = EXCLUDED.name,
+                    acronym = EXCLUDED.acronym,
+                    mic = EXCLUDED.mic,
+                    operating_mic = EXCLUDED.operating_mic,
+                    participant_id = EXCLUDED.participant_id,
+                    url = EXCLUDED.url",
+                    &[
+                        &exchange.id,
+                        &exchange.asset_class.as_ref(),
+                        &exchange.exchange_type.as_ref(),
+                        &exchange.locale.as_ref(),
+                        &exchange.name.as_ref(),
+                        &exchange.acronym.as_deref(),
+                        &exchange.mic.as_deref(),
+                        &exchange.operating_mic.as_deref(),
+                        &exchange.participant_id.as_deref(),
+                        &exchange.url.as_deref(),
+                    ],
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn find_exchanges(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        asset_class: &str,
+    ) -> io::Result<Vec<io::Exchange>> {
+        let list = self
+            .client
+            .query(
+                "SELECT id, exchange_type, asset_class, locale, name, acronym, mic, operating_mic,
+                    participant_id, url
+                 FROM exchanges
+                 WHERE asset_class = $1
+                 ORDER BY id",
+                &[&asset_class],
+            )
+            .await?;
+
+        Ok(list
+            .into_iter()
+            .map(|row| io::Exchange {
+                id: row.get::<usize, i32>(0),
+                exchange_type: row.get::<usize, &str>(1).into(),
+                asset_class: row.get::<usize, &str>(2).into(),
+                locale: row.get::<usize, &str>(3).into(),
+                name: row.get::<usize, &str>(4).into(),
+                acronym: row.get::<usize, Option<&str>>(5).map(|a| a.into()),
+                mic: row.get::<usize, Option<&str>>(6).map(|m| m.into()),
+                operating_mic: row.get::<usize, Option<&str>>(7).map(|m| m.into()),
+                participant_id: row.get::<usize, Option<&str>>(8).map(|p| p.into()),
+                url: row.get::<usize, Option<&str>>(9).map(|u| u.into()),
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,99 @@
+use std::env;
+use std::sync::Arc;
+use tokio_postgres::{Client, NoTls};
+
+use crate::exchanges_impl::Service;
+use database_mod_exchanges_io::exchanges as io;
+use io::Api;
+use snp_mod_fixture::snp_fixture::ServiceFake;
+use snp_mod_io::snp;
+
+#[tokio::test]
+async fn save_to_database_and_find() -> std::result::Result<(), io::Error> {
+    let db = Arc::new(init().await?);
+    let service = Service::new(db);
+    let deps = given_deps();
+
+    let nasdaq = io::Exchange {
+        id: 12,
+        exchange_type: "exchange".into(),
+        asset_class: "stocks".into(),
+        locale: "us".into(),
+        name: "Nasdaq".into(),
+        acronym: Some("Nasdaq".into()),
+        mic: Some("XNAS".into()),
+        operating_mic: Some("XNAS".into()),
+        participant_id: Some("T".into()),
+        url: Some("https://www.nasdaq.com".into()),
+    };
+    let trf = io::Exchange {
+        id: 201,
+        exchange_type: "TRF".into(),
+        asset_class: "stocks".into(),
+        locale: "us".into(),
+        name: "FINRA NYSE TRF".into(),
+        acronym: None,
+        mic: None,
+        operating_mic: Some("FINR".into()),
+        participant_id: Some("L".into()),
+        url: None,
+    };
+    let mut renamed = nasdaq.clone();
+    renamed.name = "Nasdaq Stock Market".into();
+
+    service
+        .save_exchanges(deps.clone(), &[nasdaq, trf.clone()])
+        .await?;
+    service
+        .save_exchanges(deps.clone(), std::slice::from_ref(&renamed))
+        .await?;
+    let list = service.find_exchanges(deps.clone(), "stocks").await?;
+
+    assert_eq!(
+        vec![&renamed, &trf],
+        list.iter()
+            .filter(|e| e.id == 12 || e.id == 201)
+            .collect::<Vec<_>>()
+    );
+
+    Ok(())
+}
+
+async fn init() -> io::Result<Client> {
+    let db_user = env::var("DB_USER").unwrap();
+    let db_password = env::var("DB_PASSWORD").unwrap();
+    let db_port = env::var("DB_PORT").unwrap().parse::<u16>().unwrap();
+    let db_name = env::var("DB_TEST_NAME").unwrap();
+
+    let (client, connection) = tokio_postgres::Config::new()
+        .user(&db_user)
+        .password(&db_password)
+        .host("localhost")
+        .port(db_port)
+        .dbname(&db_name)
+        .connect(NoTls)
+        .await?;
+
+    // Spawn connection
+    tokio::spawn(async move {
+        if let Err(error) = connection.await {
+            eprintln!("Connection error: {}", error);
+        }
+    });
+
+    Ok(client)
+}
+
+fn given_deps() -> Arc<dyn io::Depends + Send + Sync> {
+    Arc::new(DependsFake {})
+}
+
+struct DependsFake {}
+
+impl DependsFake {}
+
+impl io::Depends for DependsFake {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        Arc::new(ServiceFake {})
+    }
+}
@@ -0,0 +1,53 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use snp_mod_io::snp;
+
+/// The data that is needed to interact with the Exchanges modules.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Exchange {
+    /// The ID that the trades and the quotes refer to, in their `exchange` or `trf_id`.
+    pub id: i32,
+    pub exchange_type: Arc<str>,
+    pub asset_class: Arc<str>,
+    pub locale: Arc<str>,
+    pub name: Arc<str>,
+    pub acronym: Option<Arc<str>>,
+    pub mic: Option<Arc<str>>,
+    pub operating_mic: Option<Arc<str>>,
+    pub participant_id: Option<Arc<str>>,
+    pub url: Option<Arc<str>>,
+}
+
+/// All possible errors of Exchanges modules.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// An error with an embedded storage engine
+    #[error("{0}")]
+    Db(#[from] tokio_postgres::Error),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The API of Exchanges, which defines all the functionality of Exchanges modules.
+#[async_trait]
+pub trait Api {
+    /// Replaces the exchanges of the same asset class and ID, if any.
+    async fn save_exchanges(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        exchanges: &[Exchange],
+    ) -> Result<()>;
+
+    /// Returns the exchanges of the asset class in ascending order of ID.
+    async fn find_exchanges(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        asset_class: &str,
+    ) -> Result<Vec<Exchange>>;
+}
+
+/// All the dependencies of the Exchanges modules to other modules.
+pub trait Depends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync>;
+}
@@ -0,0 +1,26 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use database_mod_financials_io::financials as io;
+
+pub struct ServiceFake;
+
+#[async_trait]
+impl io::Api for ServiceFake {
+    async fn save_line_items(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        _line_items: &[io::LineItem],
+    ) -> io::Result<()> {
+        Ok(())
+    }
+
+    async fn find_line_items(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        _cik: &str,
+        _concept: &str,
+    ) -> io::Result<Vec<io::LineItem>> {
+        Ok(vec![])
+    }
+}
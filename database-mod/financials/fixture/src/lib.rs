@@ -0,0 +1,2 @@
+#[path = "financials-fixture.rs"]
+pub mod financials_fixture;
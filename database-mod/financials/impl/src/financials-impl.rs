@@ -0,0 +1,121 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use tokio_postgres::Client;
+
+use database_mod_financials_io::financials as io;
+
+/// The service to implement the API of Financials.
+pub struct Service {
+    client: Arc<Client>,
+}
+
+impl Service {
+    pub fn new(client: Arc<Client>) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl io::Api for Service {
+    async fn save_line_items(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        line_items: &[io::LineItem],
+    ) -> io::Result<()> {
+        let ciks: Vec<&str> = line_items.iter().map(|i| i.cik.as_ref()).collect();
+        let timeframes: Vec<&str> = line_items.iter().map(|i| i.timeframe.as_ref()).collect();
+        let start_dates: Vec<NaiveDate> = line_items.iter().map(|i| i.start_date).collect();
+        let end_dates: Vec<NaiveDate> = line_items.iter().map(|i| i.end_date).collect();
+        let fiscal_years: Vec<Option<&str>> = line_items
+            .iter()
+            .map(|i| i.fiscal_year.as_deref())
+            .collect();
+        let fiscal_periods: Vec<&str> = line_items
+            .iter()
+            .map(|i| i.fiscal_period.as_ref())
+            .collect();
+        let filing_dates: Vec<Option<NaiveDate>> =
+            line_items.iter().map(|i| i.filing_date).collect();
+        let statements: Vec<&str> = line_items.iter().map(|i| i.statement.as_str()).collect();
+        let concepts: Vec<&str> = line_items.iter().map(|i| i.concept.as_ref()).collect();
+        let labels: Vec<&str> = line_items.iter().map(|i| i.label.as_ref()).collect();
+        let units: Vec<&str> = line_items.iter().map(|i| i.unit.as_ref()).collect();
+        let values: Vec<f64> = line_items.iter().map(|i| i.value).collect();
+
+        // One statement for all the line items, since a filing has hundreds of them.
+        self.client
+            .execute(
+                "INSERT INTO financial_line_items (
+                cik, timeframe, start_date, end_date, fiscal_year, fiscal_period, filing_date,
+                statement, concept, label, unit, value)
+             SELECT * FROM UNNEST(
+                $1::VARCHAR[], $2::VARCHAR[], $3::DATE[], $4::DATE[], $5::VARCHAR[],
+                $6::VARCHAR[], $7::DATE[], $8::VARCHAR[], $9::VARCHAR[], $10::VARCHAR[],
+                $11::VARCHAR[], $12::FLOAT8[])
+             ON CONFLICT (cik, timeframe, start_date, end_date, statement, concept)
+             DO UPDATE SET
+                fiscal_year = EXCLUDED.fiscal_year,
+                fiscal_period = EXCLUDED.fiscal_period,
+                filing_date = EXCLUDED.filing_date,
+                label = EXCLUDED.label,
+                unit = EXCLUDED.unit,
+                value = EXCLUDED.value",
+                &[
+                    &ciks,
+                    &timeframes,
+                    &start_dates,
+                    &end_dates,
+                    &fiscal_years,
+                    &fiscal_periods,
+                    &filing_dates,
+                    &statements,
+                    &concepts,
+                    &labels,
+                    &units,
+                    &values,
+                ],
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn find_line_items(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        cik: &str,
+        concept: &str,
+    ) -> io::Result<Vec<io::LineItem>> {
+        let list = self
+            .client
+            .query(
+                "SELECT cik, timeframe, start_date, end_date, fiscal_year, fiscal_period,
+                    filing_date, statement, concept, label, unit, value
+                 FROM financial_line_items WHERE cik = $1 AND concept = $2
+                 ORDER BY end_date, start_date DESC, timeframe, statement",
+                &[&cik, &concept],
+            )
+            .await?;
+
+        list.into_iter()
+            .map(|row| {
+                Ok(io::LineItem {
+                    cik: row.get::<usize, &str>(0).into(),
+                    timeframe: row.get::<usize, &str>(1).into(),
+                    start_date: row.get::<usize, NaiveDate>(2),
+                    end_date: row.get::<usize, NaiveDate>(3),
+                    fiscal_year: row.get::<usize, Option<&str>>(4).map(|y| y.into()),
+                    fiscal_period: row.get::<usize, &str>(5).into(),
+                    filing_date: row.get::<usize, Option<NaiveDate>>(6),
+                    statement: io::Statement::from_name(row.get::<usize, &str>(7))?,
+                    concept: row.get::<usize, &str>(8).into(),
+                    label: row.get::<usize, &str>(9).into(),
+                    unit: row.get::<usize, &str>(10).into(),
+                    value: row.get::<usize, f64>(11),
+                })
+            })
+            .collect()
+    }
+}
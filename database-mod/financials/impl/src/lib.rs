@@ -0,0 +1,5 @@
+#[path = "financials-impl.rs"]
+pub mod financials_impl;
+
+#[cfg(test)]
+mod test;
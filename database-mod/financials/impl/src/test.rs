@@ -0,0 +1,92 @@
+use chrono::NaiveDate;
+use std::env;
+use std::sync::Arc;
+use tokio_postgres::{Client, NoTls};
+
+use crate::financials_impl::Service;
+use database_mod_financials_io::financials as io;
+use io::Api;
+use snp_mod_fixture::snp_fixture::ServiceFake;
+use snp_mod_io::snp;
+
+#[tokio::test]
+async fn save_to_database_and_find() -> std::result::Result<(), io::Error> {
+    let db = Arc::new(init().await?);
+    let service = Service::new(db);
+    let deps = given_deps();
+
+    let quarter = io::LineItem {
+        cik: "0000320193".into(),
+        timeframe: "quarterly".into(),
+        start_date: NaiveDate::from_ymd_opt(2023, 10, 1).unwrap(),
+        end_date: NaiveDate::from_ymd_opt(2023, 12, 30).unwrap(),
+        fiscal_year: Some("2024".into()),
+        fiscal_period: "Q1".into(),
+        filing_date: NaiveDate::from_ymd_opt(2024, 2, 2),
+        statement: io::Statement::IncomeStatement,
+        concept: "revenues".into(),
+        label: "Revenues".into(),
+        unit: "USD".into(),
+        value: 119575000000.0,
+    };
+    let mut trailing = quarter.clone();
+    trailing.timeframe = "ttm".into();
+    trailing.start_date = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+    trailing.fiscal_period = "TTM".into();
+    trailing.value = 385706000000.0;
+    let mut assets = quarter.clone();
+    assets.statement = io::Statement::BalanceSheet;
+    assets.concept = "assets".into();
+    assets.label = "Assets".into();
+    assets.value = 353514000000.0;
+    let line_items = vec![quarter.clone(), trailing.clone(), assets];
+
+    service.save_line_items(deps.clone(), &line_items).await?;
+    service.save_line_items(deps.clone(), &line_items).await?;
+    let list = service
+        .find_line_items(deps.clone(), "0000320193", "revenues")
+        .await?;
+
+    assert_eq!(vec![quarter, trailing], list);
+
+    Ok(())
+}
+
+async fn init() -> io::Result<Client> {
+    let db_user = env::var("DB_USER").unwrap();
+    let db_password = env::var("DB_PASSWORD").unwrap();
+    let db_port = env::var("DB_PORT").unwrap().parse::<u16>().unwrap();
+    let db_name = env::var("DB_TEST_NAME").unwrap();
+
+    let (client, connection) = tokio_postgres::Config::new()
+        .user(&db_user)
+        .password(&db_password)
+        .host("localhost")
+        .port(db_port)
+        .dbname(&db_name)
+        .connect(NoTls)
+        .await?;
+
+    // Spawn connection
+    tokio::spawn(async move {
+        if let Err(error) = connection.await {
+            eprintln!("Connection error: {}", error);
+        }
+    });
+
+    Ok(client)
+}
+
+fn given_deps() -> Arc<dyn io::Depends + Send + Sync> {
+    Arc::new(DependsFake {})
+}
+
+struct DependsFake {}
+
+impl DependsFake {}
+
+impl io::Depends for DependsFake {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        Arc::new(ServiceFake {})
+    }
+}
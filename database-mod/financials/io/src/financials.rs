@@ -0,0 +1,93 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use snp_mod_io::snp;
+
+/// The financial statement of a line item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Statement {
+    IncomeStatement,
+    BalanceSheet,
+    CashFlowStatement,
+    ComprehensiveIncome,
+}
+
+impl Statement {
+    /// The name of the statement in the `financial_line_items` table.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Statement::IncomeStatement => "income_statement",
+            Statement::BalanceSheet => "balance_sheet",
+            Statement::CashFlowStatement => "cash_flow_statement",
+            Statement::ComprehensiveIncome => "comprehensive_income",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Result<Self> {
+        match name {
+            "income_statement" => Ok(Statement::IncomeStatement),
+            "balance_sheet" => Ok(Statement::BalanceSheet),
+            "cash_flow_statement" => Ok(Statement::CashFlowStatement),
+            "comprehensive_income" => Ok(Statement::ComprehensiveIncome),
+            _ => Err(Error::UnknownStatement(name.to_string())),
+        }
+    }
+}
+
+/// The data that is needed to interact with the Financials modules. The period of a line item
+/// is its timeframe, start date and end date.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LineItem {
+    pub cik: Arc<str>,
+    /// One of `quarterly`, `annual` or `ttm`, trailing twelve months.
+    pub timeframe: Arc<str>,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub fiscal_year: Option<Arc<str>>,
+    pub fiscal_period: Arc<str>,
+    pub filing_date: Option<NaiveDate>,
+    pub statement: Statement,
+    /// The key of the value in Polygon.io, like `revenues`.
+    pub concept: Arc<str>,
+    pub label: Arc<str>,
+    pub unit: Arc<str>,
+    pub value: f64,
+}
+
+/// All possible errors of Financials modules.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// An error with an embedded storage engine
+    #[error("{0}")]
+    Db(#[from] tokio_postgres::Error),
+    #[error("Unknown statement '{0}'.")]
+    UnknownStatement(String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The API of Financials, which defines all the functionality of Financials modules.
+#[async_trait]
+pub trait Api {
+    /// Replaces the line items of the same CIK, period, statement and concept, if any.
+    async fn save_line_items(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        line_items: &[LineItem],
+    ) -> Result<()>;
+
+    /// Returns the values of the concept in every period of the company, in ascending order of
+    /// end date.
+    async fn find_line_items(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        cik: &str,
+        concept: &str,
+    ) -> Result<Vec<LineItem>>;
+}
+
+/// All the dependencies of the Financials modules to other modules.
+pub trait Depends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync>;
+}
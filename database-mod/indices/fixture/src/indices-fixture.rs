@@ -0,0 +1,49 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use database_mod_aggregates_io::aggregates::Timespan;
+use database_mod_indices_io::indices as io;
+use snp_mod_io::snp;
+
+pub struct ServiceFake;
+
+#[async_trait]
+impl io::Api for ServiceFake {
+    async fn save_bars(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        _bars: &[io::Bar],
+    ) -> io::Result<()> {
+        Ok(())
+    }
+
+    async fn find_bars(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        _index: snp::Index,
+        _timespan: Timespan,
+        _from: &DateTime<Utc>,
+        _to: &DateTime<Utc>,
+    ) -> io::Result<Vec<io::Bar>> {
+        Ok(vec![])
+    }
+
+    async fn save_snapshots(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        _snapshots: &[io::Snapshot],
+    ) -> io::Result<()> {
+        Ok(())
+    }
+
+    async fn find_snapshots(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        _index: snp::Index,
+        _from: &DateTime<Utc>,
+        _to: &DateTime<Utc>,
+    ) -> io::Result<Vec<io::Snapshot>> {
+        Ok(vec![])
+    }
+}
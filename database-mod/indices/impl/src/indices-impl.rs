@@ -0,0 +1,174 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use database_mod_aggregates_io::aggregates::Timespan;
+use tokio_postgres::Client;
+
+use database_mod_indices_io::indices as io;
+use snp_mod_io::snp;
+
+/// The service to implement the API of Indices.
+pub struct Service {
+    client: Arc<Client>,
+}
+
+impl Service {
+    pub fn new(client: Arc<Client>) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl io::Api for Service {
+    async fn save_bars(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        bars: &[io::Bar],
+    ) -> io::Result<()> {
+        let tickers: Vec<&str> = bars.iter().map(|b| b.index.ticker()).collect();
+        let timespans: Vec<&str> = bars.iter().map(|b| b.timespan.as_str()).collect();
+        let starts: Vec<DateTime<Utc>> = bars.iter().map(|b| b.start).collect();
+        let opens: Vec<f64> = bars.iter().map(|b| b.open).collect();
+        let highs: Vec<f64> = bars.iter().map(|b| b.high).collect();
+        let lows: Vec<f64> = bars.iter().map(|b| b.low).collect();
+        let closes: Vec<f64> = bars.iter().map(|b| b.close).collect();
+
+        // One statement for all the bars, since a day has hundreds of minute bars.
+        self.client
+            .execute(
+                "INSERT INTO index_bars (ticker, timespan, start, open, high, low, close)
+             SELECT * FROM UNNEST(
+                $1::VARCHAR[], $2::VARCHAR[], $3::TIMESTAMPTZ[], $4::FLOAT8[], $5::FLOAT8[],
+                $6::FLOAT8[], $7::FLOAT8[])
+             ON CONFLICT (ticker, timespan, start) DO UPDATE SET
+                open = EXCLUDED.open,
+                high = EXCLUDED.high,
+                low = EXCLUDED.low,
+                close = EXCLUDED.close",
+                &[
+                    &tickers, &timespans, &starts, &opens, &highs, &lows, &closes,
+                ],
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn find_bars(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        index: snp::Index,
+        timespan: Timespan,
+        from: &DateTime<Utc>,
+        to: &DateTime<Utc>,
+    ) -> io::Result<Vec<io::Bar>> {
+        let list = self
+            .client
+            .query(
+                "SELECT start, open, high, low, close
+                 FROM index_bars WHERE ticker = $1 AND timespan = $2 AND start >= $3 AND start < $4
+                 ORDER BY start",
+                &[&index.ticker(), &timespan.as_str(), from, to],
+            )
+            .await?;
+
+        Ok(list
+            .into_iter()
+            .map(|row| io::Bar {
+                index,
+                timespan,
+                start: row.get::<usize, DateTime<Utc>>(0),
+                open: row.get::<usize, f64>(1),
+                high: row.get::<usize, f64>(2),
+                low: row.get::<usize, f64>(3),
+                close: row.get::<usize, f64>(4),
+            })
+            .collect())
+    }
+
+    async fn save_snapshots(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        snapshots: &[io::Snapshot],
+    ) -> io::Result<()> {
+        for snapshot in snapshots {
+            let session = snapshot.session.as_ref();
+            self.client
+                .execute(
+                    "INSERT INTO index_snapshots (
+                    ticker, updated, value, market_status,
+                    session_open, session_high, session_low, session_close,
+                    session_previous_close, session_change, session_change_percent)
+                 VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+                 ON CONFLICT (ticker, updated) DO UPDATE SET
+                    value = EXCLUDED.value,
+                    market_status = EXCLUDED.market_status,
+                    session_open = EXCLUDED.session_open,
+                    session_high = EXCLUDED.session_high,
+                    session_low = EXCLUDED.session_low,
+                    session_close = EXCLUDED.session_close,
+                    session_previous_close = EXCLUDED.session_previous_close,
+                    session_change = EXCLUDED.session_change,
+                    session_change_percent = EXCLUDED.session_change_percent",
+                    &[
+                        &snapshot.index.ticker(),
+                        &snapshot.updated,
+                        &snapshot.value,
+                        &snapshot.market_status.as_deref(),
+                        &session.map(|s| s.open),
+                        &session.map(|s| s.high),
+                        &session.map(|s| s.low),
+                        &session.map(|s| s.close),
+                        &session.map(|s| s.previous_close),
+                        &session.map(|s| s.change),
+                        &session.map(|s| s.change_percent),
+                    ],
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn find_snapshots(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        index: snp::Index,
+        from: &DateTime<Utc>,
+        to: &DateTime<Utc>,
+    ) -> io::Result<Vec<io::Snapshot>> {
+        let list = self
+            .client
+            .query(
+                "SELECT updated, value, market_status,
+                    session_open, session_high, session_low, session_close,
+                    session_previous_close, session_change, session_change_percent
+                 FROM index_snapshots
+                 WHERE ticker = $1 AND updated >= $2 AND updated < $3
+                 ORDER BY updated",
+                &[&index.ticker(), from, to],
+            )
+            .await?;
+
+        Ok(list
+            .into_iter()
+            .map(|row| io::Snapshot {
+                index,
+                updated: row.get::<usize, DateTime<Utc>>(0),
+                value: row.get::<usize, f64>(1),
+                market_status: row.get::<usize, Option<&str>>(2).map(|s| s.into()),
+                // A missing open means the snapshot had no session.
+                session: row.get::<usize, Option<f64>>(3).map(|open| io::Session {
+                    open,
+                    high: row.get::<usize, f64>(4),
+                    low: row.get::<usize, f64>(5),
+                    close: row.get::<usize, f64>(6),
+                    previous_close: row.get::<usize, f64>(7),
+                    change: row.get::<usize, f64>(8),
+                    change_percent: row.get::<usize, f64>(9),
+                }),
+            })
+            .collect())
+    }
+}
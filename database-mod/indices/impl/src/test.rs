@@ -0,0 +1,136 @@
+use chrono::{Duration, TimeZone, Utc};
+use database_mod_aggregates_io::aggregates::Timespan;
+use std::env;
+use std::sync::Arc;
+use tokio_postgres::{Client, NoTls};
+
+use crate::indices_impl::Service;
+use database_mod_indices_io::indices as io;
+use io::Api;
+use snp_mod_fixture::snp_fixture::ServiceFake;
+use snp_mod_io::snp;
+
+#[tokio::test]
+async fn save_to_database_and_find() -> std::result::Result<(), io::Error> {
+    let db = Arc::new(init().await?);
+    let service = Service::new(db);
+    let deps = given_deps();
+
+    let start = Utc.with_ymd_and_hms(2024, 3, 14, 13, 30, 0).unwrap();
+    let bars = vec![
+        given_bar(snp::Index::SPX, start, Timespan::Minute),
+        given_bar(
+            snp::Index::SPX,
+            start + Duration::try_minutes(1).unwrap(),
+            Timespan::Minute,
+        ),
+        given_bar(snp::Index::SPX, start, Timespan::Day),
+        given_bar(snp::Index::NDX, start, Timespan::Minute),
+    ];
+
+    service.save_bars(deps.clone(), &bars).await?;
+    service.save_bars(deps.clone(), &bars[..1]).await?;
+    let list = service
+        .find_bars(
+            deps.clone(),
+            snp::Index::SPX,
+            Timespan::Minute,
+            &start,
+            &(start + Duration::try_hours(1).unwrap()),
+        )
+        .await?;
+
+    assert_eq!(bars[..2], list[..]);
+
+    let updated = Utc.with_ymd_and_hms(2024, 3, 14, 20, 0, 0).unwrap();
+    let snapshots = vec![
+        io::Snapshot {
+            index: snp::Index::SPX,
+            updated,
+            value: 5150.48,
+            market_status: Some("closed".into()),
+            session: Some(io::Session {
+                open: 5175.14,
+                high: 5176.85,
+                low: 5123.3,
+                close: 5150.48,
+                previous_close: 5165.31,
+                change: -14.83,
+                change_percent: -0.287,
+            }),
+        },
+        io::Snapshot {
+            index: snp::Index::SPX,
+            updated: updated + Duration::try_minutes(5).unwrap(),
+            value: 5150.5,
+            market_status: None,
+            session: None,
+        },
+    ];
+
+    service.save_snapshots(deps.clone(), &snapshots).await?;
+    service
+        .save_snapshots(deps.clone(), &snapshots[..1])
+        .await?;
+    let list = service
+        .find_snapshots(
+            deps.clone(),
+            snp::Index::SPX,
+            &updated,
+            &(updated + Duration::try_hours(1).unwrap()),
+        )
+        .await?;
+
+    assert_eq!(snapshots, list);
+
+    Ok(())
+}
+
+fn given_bar(index: snp::Index, start: chrono::DateTime<Utc>, timespan: Timespan) -> io::Bar {
+    io::Bar {
+        index,
+        timespan,
+        start,
+        open: 5175.14,
+        high: 5176.85,
+        low: 5167.93,
+        close: 5168.21,
+    }
+}
+
+async fn init() -> io::Result<Client> {
+    let db_user = env::var("DB_USER").unwrap();
+    let db_password = env::var("DB_PASSWORD").unwrap();
+    let db_port = env::var("DB_PORT").unwrap().parse::<u16>().unwrap();
+    let db_name = env::var("DB_TEST_NAME").unwrap();
+
+    let (client, connection) = tokio_postgres::Config::new()
+        .user(&db_user)
+        .password(&db_password)
+        .host("localhost")
+        .port(db_port)
+        .dbname(&db_name)
+        .connect(NoTls)
+        .await?;
+
+    // Spawn connection
+    tokio::spawn(async move {
+        if let Err(error) = connection.await {
+            eprintln!("Connection error: {}", error);
+        }
+    });
+
+    Ok(client)
+}
+
+fn given_deps() -> Arc<dyn io::Depends + Send + Sync> {
+    Arc::new(DependsFake {})
+}
+
+struct DependsFake {}
+
+impl io::Depends for DependsFake {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        Arc::new(ServiceFake {})
+    }
+}
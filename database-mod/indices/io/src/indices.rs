@@ -0,0 +1,88 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use database_mod_aggregates_io::aggregates::Timespan;
+use snp_mod_io::snp;
+
+/// A bar of an index, which has no volume.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bar {
+    pub index: snp::Index,
+    pub timespan: Timespan,
+    pub start: DateTime<Utc>,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+}
+
+/// The values of the session of an index, as of a snapshot.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Session {
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub previous_close: f64,
+    pub change: f64,
+    pub change_percent: f64,
+}
+
+/// The value of an index when it was last updated.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Snapshot {
+    pub index: snp::Index,
+    pub updated: DateTime<Utc>,
+    pub value: f64,
+    pub market_status: Option<Arc<str>>,
+    pub session: Option<Session>,
+}
+
+/// All possible errors of Indices modules.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// An error with an embedded storage engine
+    #[error("{0}")]
+    Db(#[from] tokio_postgres::Error),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The API of Indices, which defines all the functionality of Indices modules.
+#[async_trait]
+pub trait Api {
+    /// Replaces the bars of the same index, timespan and start, if any.
+    async fn save_bars(&self, deps: Arc<dyn Depends + Send + Sync>, bars: &[Bar]) -> Result<()>;
+
+    /// Returns the bars starting in `[from, to)`, in ascending order of start.
+    async fn find_bars(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        index: snp::Index,
+        timespan: Timespan,
+        from: &DateTime<Utc>,
+        to: &DateTime<Utc>,
+    ) -> Result<Vec<Bar>>;
+
+    /// Replaces the snapshots of the same index and update time, if any.
+    async fn save_snapshots(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        snapshots: &[Snapshot],
+    ) -> Result<()>;
+
+    /// Returns the snapshots updated in `[from, to)`, in ascending order of update.
+    async fn find_snapshots(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        index: snp::Index,
+        from: &DateTime<Utc>,
+        to: &DateTime<Utc>,
+    ) -> Result<Vec<Snapshot>>;
+}
+
+/// All the dependencies of the Indices modules to other modules.
+pub trait Depends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync>;
+}
@@ -0,0 +1,46 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use database_mod_news_io::news as io;
+use snp_mod_io::snp;
+
+pub struct ServiceFake;
+
+#[async_trait]
+impl io::Api for ServiceFake {
+    async fn save_articles(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        _articles: &[io::Article],
+    ) -> io::Result<()> {
+        Ok(())
+    }
+
+    async fn find_articles(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        _snp: &snp::SnP,
+        _from: &DateTime<Utc>,
+        _to: &DateTime<Utc>,
+    ) -> io::Result<Vec<io::Article>> {
+        Ok(vec![])
+    }
+
+    async fn save_last_published(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        _snp: &snp::SnP,
+        _published_utc: &DateTime<Utc>,
+    ) -> io::Result<()> {
+        Ok(())
+    }
+
+    async fn find_last_published(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        _snp: &snp::SnP,
+    ) -> io::Result<Option<DateTime<Utc>>> {
+        Ok(None)
+    }
+}
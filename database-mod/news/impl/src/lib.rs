@@ -0,0 +1,5 @@
+#[path = "news-impl.rs"]
+pub mod news_impl;
+
+#[cfg(test)]
+mod test;
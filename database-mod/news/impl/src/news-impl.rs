@@ -0,0 +1,199 @@
+use std::{collections::HashMap, sync::Arc};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use snp_mod_io::snp;
+use tokio_postgres::Client;
+
+use database_mod_news_io::news as io;
+
+/// The service to implement the API of News.
+pub struct Service {
+    client: Arc<Client>,
+}
+
+impl Service {
+    pub fn new(client: Arc<Client>) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl io::Api for Service {
+    async fn save_articles(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        articles: &[io::Article],
+    ) -> io::Result<()> {
+        let snp = deps.provide_snp();
+        for article in articles {
+            let keywords: Vec<&str> = article.keywords.iter().map(|k| k.as_ref()).collect();
+            let tickers: Vec<Arc<str>> = article
+                .tickers
+                .iter()
+                .map(|link| snp.get_symbol(&link.snp))
+                .collect();
+            let tickers: Vec<&str> = tickers.iter().map(|t| t.as_ref()).collect();
+            let sentiments: Vec<Option<&str>> = article
+                .tickers
+                .iter()
+                .map(|link| link.sentiment.as_deref())
+                .collect();
+            let reasonings: Vec<Option<&str>> = article
+                .tickers
+                .iter()
+                .map(|link| link.sentiment_reasoning.as_deref())
+                .collect();
+
+            // One statement for the article and its tickers, so a failure never stores half an
+            // article.
+            self.client
+                .execute(
+                    "WITH article AS (
+                    INSERT INTO news_articles (
+                        polygon_id, publisher, title, author, published_utc, article_url,
+                        description, keywords)
+                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                    ON CONFLICT (polygon_id) DO UPDATE SET
+                        publisher = EXCLUDED.publisher,
+                        title = EXCLUDED.title,
+                        author = EXCLUDED.author,
+                        published_utc = EXCLUDED.published_utc,
+                        article_url = EXCLUDED.article_url,
+                        description = EXCLUDED.description,
+                        keywords = EXCLUDED.keywords
+                    RETURNING id)
+                 INSERT INTO news_article_tickers (
+                    article_id, ticker, sentiment, sentiment_reasoning)
+                 SELECT article.id, t.ticker, t.sentiment, t.sentiment_reasoning
+                 FROM article, UNNEST($9::VARCHAR[], $10::VARCHAR[], $11::TEXT[])
+                    AS t (ticker, sentiment, sentiment_reasoning)
+                 ON CONFLICT (article_id, ticker) DO UPDATE SET
+                    sentiment = EXCLUDED.sentiment,
+                    sentiment_reasoning = EXCLUDED.sentiment_reasoning",
+                    &[
+                        &article.polygon_id.as_ref(),
+                        &article.publisher.as_ref(),
+                        &article.title.as_ref(),
+                        &article.author.as_deref(),
+                        &article.published_utc,
+                        &article.article_url.as_ref(),
+                        &article.description.as_deref(),
+                        &keywords,
+                        &tickers,
+                        &sentiments,
+                        &reasonings,
+                    ],
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn find_articles(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        snp: &snp::SnP,
+        from: &DateTime<Utc>,
+        to: &DateTime<Utc>,
+    ) -> io::Result<Vec<io::Article>> {
+        let snp_api = deps.provide_snp();
+        let snp_symbol_rc = snp_api.get_symbol(snp).clone();
+        let snp_symbol = &snp_symbol_rc[..];
+        let list = self
+            .client
+            .query(
+                "SELECT a.id, a.polygon_id, a.publisher, a.title, a.author, a.published_utc,
+                    a.article_url, a.description, a.keywords
+                 FROM news_articles a
+                 JOIN news_article_tickers t ON t.article_id = a.id
+                 WHERE t.ticker = $1 AND a.published_utc >= $2 AND a.published_utc < $3
+                 ORDER BY a.published_utc, a.polygon_id",
+                &[&snp_symbol, &from, &to],
+            )
+            .await?;
+
+        let article_ids: Vec<i32> = list.iter().map(|row| row.get::<usize, i32>(0)).collect();
+        let mut links: HashMap<i32, Vec<io::TickerLink>> = HashMap::new();
+        for row in self
+            .client
+            .query(
+                "SELECT article_id, ticker, sentiment, sentiment_reasoning
+                 FROM news_article_tickers WHERE article_id = ANY($1)
+                 ORDER BY article_id, ticker",
+                &[&article_ids],
+            )
+            .await?
+        {
+            links
+                .entry(row.get::<usize, i32>(0))
+                .or_default()
+                .push(io::TickerLink {
+                    snp: snp_api.get_snp(row.get::<usize, &str>(1))?,
+                    sentiment: row.get::<usize, Option<&str>>(2).map(|s| s.into()),
+                    sentiment_reasoning: row.get::<usize, Option<&str>>(3).map(|s| s.into()),
+                });
+        }
+
+        Ok(list
+            .into_iter()
+            .map(|row| io::Article {
+                polygon_id: row.get::<usize, &str>(1).into(),
+                publisher: row.get::<usize, &str>(2).into(),
+                title: row.get::<usize, &str>(3).into(),
+                author: row.get::<usize, Option<&str>>(4).map(|a| a.into()),
+                published_utc: row.get::<usize, DateTime<Utc>>(5),
+                article_url: row.get::<usize, &str>(6).into(),
+                description: row.get::<usize, Option<&str>>(7).map(|d| d.into()),
+                keywords: row
+                    .get::<usize, Vec<&str>>(8)
+                    .into_iter()
+                    .map(|k| k.into())
+                    .collect(),
+                tickers: links.remove(&row.get::<usize, i32>(0)).unwrap_or_default(),
+            })
+            .collect())
+    }
+
+    async fn save_last_published(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        snp: &snp::SnP,
+        published_utc: &DateTime<Utc>,
+    ) -> io::Result<()> {
+        let snp_api = deps.provide_snp();
+        let snp_symbol_rc = snp_api.get_symbol(snp).clone();
+        let snp_symbol = &snp_symbol_rc[..];
+        self.client
+            .execute(
+                "INSERT INTO news_crawl_state (ticker, last_published)
+                 VALUES ($1, $2)
+                 ON CONFLICT (ticker) DO UPDATE SET
+                    last_published = GREATEST(news_crawl_state.last_published, EXCLUDED.last_published)",
+                &[&snp_symbol, published_utc],
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn find_last_published(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        snp: &snp::SnP,
+    ) -> io::Result<Option<DateTime<Utc>>> {
+        let snp_api = deps.provide_snp();
+        let snp_symbol_rc = snp_api.get_symbol(snp).clone();
+        let snp_symbol = &snp_symbol_rc[..];
+        let row = self
+            .client
+            .query_opt(
+                "SELECT last_published FROM news_crawl_state WHERE ticker = $1",
+                &[&snp_symbol],
+            )
+            .await?;
+
+        Ok(row.map(|row| row.get::<usize, DateTime<Utc>>(0)))
+    }
+}
@@ -0,0 +1,241 @@
+use chrono::{NaiveDate, TimeZone, Utc};
+use std::collections::HashMap;
+use std::env;
+use std::sync::Arc;
+use tokio_postgres::{Client, NoTls};
+
+use crate::news_impl::Service;
+use database_mod_news_io::news as io;
+use io::Api;
+use snp_mod_io::snp;
+
+#[tokio::test]
+async fn save_to_database_and_find() -> std::result::Result<(), io::Error> {
+    let db = Arc::new(init().await?);
+    let service = Service::new(db);
+    let deps = given_deps();
+
+    let published_utc = Utc.with_ymd_and_hms(2024, 6, 10, 20, 15, 0).unwrap();
+    let article = io::Article {
+        polygon_id: "8ec638777ca03b553ae516761c2a22ba2fdd2f37befae3ab6fdab74e9e5193eb".into(),
+        publisher: "The Motley Fool".into(),
+        title: "Apple Unveils Apple Intelligence at WWDC".into(),
+        author: Some("Motley Fool Staff".into()),
+        published_utc,
+        article_url: "https://www.fool.com/investing/2024/06/10/apple-intelligence/".into(),
+        description: None,
+        keywords: vec!["WWDC".into(), "Apple Intelligence".into()],
+        tickers: vec![io::TickerLink {
+            snp: snp::SnP::AAPL,
+            sentiment: Some("positive".into()),
+            sentiment_reasoning: Some("The new features can drive an upgrade cycle.".into()),
+        }],
+    };
+    let mut without_insights = article.clone();
+    without_insights.tickers[0].sentiment = None;
+    without_insights.tickers[0].sentiment_reasoning = None;
+
+    service
+        .save_articles(deps.clone(), &[without_insights])
+        .await?;
+    // Saving it again, e.g. by the crawl of another ticker, keeps a single article, and
+    // replaces the sentiment of its tickers.
+    service
+        .save_articles(deps.clone(), std::slice::from_ref(&article))
+        .await?;
+    let list = service
+        .find_articles(
+            deps.clone(),
+            &snp::SnP::AAPL,
+            &Utc.with_ymd_and_hms(2024, 6, 10, 0, 0, 0).unwrap(),
+            &Utc.with_ymd_and_hms(2024, 6, 11, 0, 0, 0).unwrap(),
+        )
+        .await?;
+    service
+        .save_last_published(deps.clone(), &snp::SnP::AAPL, &published_utc)
+        .await?;
+    let last_published = service
+        .find_last_published(deps.clone(), &snp::SnP::AAPL)
+        .await?;
+
+    assert_eq!(
+        vec![&article],
+        list.iter()
+            .filter(|a| a.polygon_id == article.polygon_id)
+            .collect::<Vec<_>>()
+    );
+    assert!(last_published >= Some(published_utc));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn keep_the_watermark_of_the_crawled_ticker() -> std::result::Result<(), io::Error> {
+    let db = Arc::new(init().await?);
+    let service = Service::new(db);
+    let deps = given_deps();
+
+    // Found by the crawl of NVDA, the article links to KO too, whose crawl hasn't reached it.
+    let published_utc = Utc.with_ymd_and_hms(2024, 6, 12, 14, 0, 0).unwrap();
+    let article = io::Article {
+        polygon_id: "4f2d8a1b0c9e7d6f5a4b3c2d1e0f9a8b7c6d5e4f3a2b1c0d9e8f7a6b5c4d3e2f".into(),
+        publisher: "Benzinga".into(),
+        title: "Nvidia And Coca-Cola Lead The Market Movers".into(),
+        author: None,
+        published_utc,
+        article_url: "https://www.benzinga.com/markets/24/06/market-movers".into(),
+        description: None,
+        keywords: vec![],
+        tickers: vec![
+            io::TickerLink {
+                snp: snp::SnP::KO,
+                sentiment: None,
+                sentiment_reasoning: None,
+            },
+            io::TickerLink {
+                snp: snp::SnP::NVDA,
+                sentiment: None,
+                sentiment_reasoning: None,
+            },
+        ],
+    };
+
+    service.save_articles(deps.clone(), &[article]).await?;
+    service
+        .save_last_published(deps.clone(), &snp::SnP::NVDA, &published_utc)
+        .await?;
+    // An earlier article of a later crawl doesn't move the watermark back.
+    service
+        .save_last_published(
+            deps.clone(),
+            &snp::SnP::NVDA,
+            &Utc.with_ymd_and_hms(2024, 6, 11, 14, 0, 0).unwrap(),
+        )
+        .await?;
+
+    assert!(
+        service
+            .find_last_published(deps.clone(), &snp::SnP::NVDA)
+            .await?
+            >= Some(published_utc)
+    );
+    assert!(
+        service
+            .find_last_published(deps.clone(), &snp::SnP::KO)
+            .await?
+            < Some(published_utc)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn store_nothing_of_a_failed_article() -> std::result::Result<(), io::Error> {
+    let db = Arc::new(init().await?);
+    let service = Service::new(db.clone());
+    let deps = given_deps();
+    let link = io::TickerLink {
+        snp: snp::SnP::AAPL,
+        sentiment: None,
+        sentiment_reasoning: None,
+    };
+    // The same ticker twice fails on its tickers, after the article is inserted.
+    let article = io::Article {
+        polygon_id: "failed-article-of-the-same-ticker-twice".into(),
+        publisher: "Benzinga".into(),
+        title: "Apple Shares Are Trading Higher".into(),
+        author: None,
+        published_utc: Utc.with_ymd_and_hms(2024, 6, 11, 14, 0, 0).unwrap(),
+        article_url: "https://www.benzinga.com/news/24/06/apple-shares".into(),
+        description: None,
+        keywords: vec![],
+        tickers: vec![link.clone(), link],
+    };
+
+    let result = service
+        .save_articles(deps, std::slice::from_ref(&article))
+        .await;
+    let stored = db
+        .query(
+            "SELECT id FROM news_articles WHERE polygon_id = $1",
+            &[&article.polygon_id.as_ref()],
+        )
+        .await?;
+
+    assert!(result.is_err());
+    assert!(stored.is_empty());
+    Ok(())
+}
+
+async fn init() -> io::Result<Client> {
+    let db_user = env::var("DB_USER").unwrap();
+    let db_password = env::var("DB_PASSWORD").unwrap();
+    let db_port = env::var("DB_PORT").unwrap().parse::<u16>().unwrap();
+    let db_name = env::var("DB_TEST_NAME").unwrap();
+
+    let (client, connection) = tokio_postgres::Config::new()
+        .user(&db_user)
+        .password(&db_password)
+        .host("localhost")
+        .port(db_port)
+        .dbname(&db_name)
+        .connect(NoTls)
+        .await?;
+
+    // Spawn connection
+    tokio::spawn(async move {
+        if let Err(error) = connection.await {
+            eprintln!("Connection error: {}", error);
+        }
+    });
+
+    Ok(client)
+}
+
+fn given_deps() -> Arc<dyn io::Depends + Send + Sync> {
+    Arc::new(DependsFake {})
+}
+
+struct DependsFake {}
+
+impl DependsFake {}
+
+impl io::Depends for DependsFake {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        Arc::new(SnPFake {})
+    }
+}
+
+/// Tells the tickers of the tests apart, where the fixture calls them all `AAPL`.
+struct SnPFake {}
+
+impl snp::Api for SnPFake {
+    fn get_snp(&self, ticker: &str) -> snp::Result<snp::SnP> {
+        match ticker {
+            "AAPL" => Ok(snp::SnP::AAPL),
+            "KO" => Ok(snp::SnP::KO),
+            "NVDA" => Ok(snp::SnP::NVDA),
+            _ => Err(snp::Error::NotFound(ticker.into())),
+        }
+    }
+
+    fn get_symbol(&self, snp: &snp::SnP) -> Arc<str> {
+        match snp {
+            snp::SnP::KO => Arc::from("KO"),
+            snp::SnP::NVDA => Arc::from("NVDA"),
+            _ => Arc::from("AAPL"),
+        }
+    }
+
+    fn get_snp_map(&self) -> HashMap<snp::SnP, snp::Data> {
+        HashMap::new()
+    }
+
+    fn get_snp_by_date(&self, _date: &NaiveDate) -> Vec<snp::SnP> {
+        vec![snp::SnP::AAPL]
+    }
+
+    fn get_all_snp(&self) -> Vec<snp::SnP> {
+        vec![snp::SnP::AAPL, snp::SnP::KO, snp::SnP::NVDA]
+    }
+}
@@ -0,0 +1,86 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use snp_mod_io::snp;
+
+/// The link of an article to one of the S&P 500 tickers it mentions.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TickerLink {
+    pub snp: snp::SnP,
+    /// One of `positive`, `neutral` or `negative`.
+    pub sentiment: Option<Arc<str>>,
+    pub sentiment_reasoning: Option<Arc<str>>,
+}
+
+/// The data that is needed to interact with the News modules, which is an article.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Article {
+    /// The unique identifier of the article in Polygon.io.
+    pub polygon_id: Arc<str>,
+    pub publisher: Arc<str>,
+    pub title: Arc<str>,
+    pub author: Option<Arc<str>>,
+    pub published_utc: DateTime<Utc>,
+    pub article_url: Arc<str>,
+    pub description: Option<Arc<str>>,
+    pub keywords: Vec<Arc<str>>,
+    /// The S&P 500 tickers of the article, sorted by ticker.
+    pub tickers: Vec<TickerLink>,
+}
+
+/// All possible errors of News modules.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// An error with an embedded storage engine
+    #[error("{0}")]
+    Db(#[from] tokio_postgres::Error),
+    #[error("{0}")]
+    SnP(#[from] snp::Error),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The API of News, which defines all the functionality of News modules.
+#[async_trait]
+pub trait Api {
+    /// Replaces the articles of the same Polygon.io id, if any, and adds their ticker links.
+    async fn save_articles(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        articles: &[Article],
+    ) -> Result<()>;
+
+    /// Returns the articles linked to the ticker that are published in `[from, to)`, in
+    /// ascending order of published time.
+    async fn find_articles(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        snp: &snp::SnP,
+        from: &DateTime<Utc>,
+        to: &DateTime<Utc>,
+    ) -> Result<Vec<Article>>;
+
+    /// Keeps the published time of the latest article that the crawl of the ticker returned,
+    /// unless a later one is kept already.
+    async fn save_last_published(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        snp: &snp::SnP,
+        published_utc: &DateTime<Utc>,
+    ) -> Result<()>;
+
+    /// Returns the published time that is kept by the crawl of the ticker, where an incremental
+    /// crawl continues from. The articles of other tickers that link to the ticker don't move it,
+    /// since the crawl of the ticker may not have reached the articles before them.
+    async fn find_last_published(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        snp: &snp::SnP,
+    ) -> Result<Option<DateTime<Utc>>>;
+}
+
+/// All the dependencies of the News modules to other modules.
+pub trait Depends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync>;
+}
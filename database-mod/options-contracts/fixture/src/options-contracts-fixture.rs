@@ -0,0 +1,29 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use database_mod_options_contracts_io::options_contracts as io;
+use snp_mod_io::snp;
+
+/// Stores nothing, so every chain looks missing.
+pub struct ServiceFake;
+
+#[async_trait]
+impl io::Api for ServiceFake {
+    async fn save_contracts(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        _contracts: &[io::Contract],
+    ) -> io::Result<()> {
+        Ok(())
+    }
+
+    async fn find_chain(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        _snp: &snp::SnP,
+        _as_of: &NaiveDate,
+    ) -> io::Result<Vec<io::Contract>> {
+        Ok(vec![])
+    }
+}
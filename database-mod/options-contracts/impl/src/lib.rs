@@ -0,0 +1,5 @@
+#[path = "options-contracts-impl.rs"]
+pub mod options_contracts_impl;
+
+#[cfg(test)]
+mod test;
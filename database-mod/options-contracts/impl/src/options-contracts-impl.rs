@@ -0,0 +1,121 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use tokio_postgres::Client;
+
+use database_mod_options_contracts_io::options_contracts as io;
+use snp_mod_io::snp;
+
+/// The service to implement the API of Options-contracts.
+pub struct Service {
+    client: Arc<Client>,
+}
+
+impl Service {
+    pub fn new(client: Arc<Client>) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl io::Api for Service {
+    async fn save_contracts(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        contracts: &[io::Contract],
+    ) -> io::Result<()> {
+        let snp = deps.provide_snp();
+        let underlyings: Vec<String> = contracts
+            .iter()
+            .map(|c| snp.get_symbol(&c.snp).to_string())
+            .collect();
+        let as_ofs: Vec<NaiveDate> = contracts.iter().map(|c| c.as_of).collect();
+        let tickers: Vec<&str> = contracts.iter().map(|c| c.ticker.as_ref()).collect();
+        let contract_types: Vec<&str> =
+            contracts.iter().map(|c| c.contract_type.as_ref()).collect();
+        let exercise_styles: Vec<Option<&str>> = contracts
+            .iter()
+            .map(|c| c.exercise_style.as_deref())
+            .collect();
+        let expiration_dates: Vec<NaiveDate> =
+            contracts.iter().map(|c| c.expiration_date).collect();
+        let strike_prices: Vec<f64> = contracts.iter().map(|c| c.strike_price).collect();
+        let shares_per_contracts: Vec<Option<f64>> =
+            contracts.iter().map(|c| c.shares_per_contract).collect();
+        let primary_exchanges: Vec<Option<&str>> = contracts
+            .iter()
+            .map(|c| c.primary_exchange.as_deref())
+            .collect();
+
+        // One statement for the whole chain, since a chain has thousands of contracts.
+        self.client
+            .execute(
+                "INSERT INTO options_contracts (
+                underlying, as_of, ticker, contract_type, exercise_style, expiration_date,
+                strike_price, shares_per_contract, primary_exchange)
+             SELECT * FROM UNNEST(
+                $1::VARCHAR[], $2::DATE[], $3::VARCHAR[], $4::VARCHAR[], $5::VARCHAR[],
+                $6::DATE[], $7::FLOAT8[], $8::FLOAT8[], $9::VARCHAR[])
+             ON CONFLICT (as_of, ticker) DO UPDATE SET
+                underlying = EXCLUDED.underlying,
+                contract_type = EXCLUDED.contract_type,
+                exercise_style = EXCLUDED.exercise_style,
+                expiration_date = EXCLUDED.expiration_date,
+                strike_price = EXCLUDED.strike_price,
+                shares_per_contract = EXCLUDED.shares_per_contract,
+                primary_exchange = EXCLUDED.primary_exchange",
+                &[
+                    &underlyings,
+                    &as_ofs,
+                    &tickers,
+                    &contract_types,
+                    &exercise_styles,
+                    &expiration_dates,
+                    &strike_prices,
+                    &shares_per_contracts,
+                    &primary_exchanges,
+                ],
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn find_chain(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        snp: &snp::SnP,
+        as_of: &NaiveDate,
+    ) -> io::Result<Vec<io::Contract>> {
+        let snp_api = deps.provide_snp();
+        let snp_symbol_rc = snp_api.get_symbol(snp).clone();
+        let snp_symbol = &snp_symbol_rc[..];
+
+        let list = self
+            .client
+            .query(
+                "SELECT ticker, contract_type, exercise_style, expiration_date, strike_price,
+                    shares_per_contract, primary_exchange
+                 FROM options_contracts WHERE underlying = $1 AND as_of = $2
+                 ORDER BY expiration_date, strike_price, contract_type",
+                &[&snp_symbol, as_of],
+            )
+            .await?;
+
+        Ok(list
+            .into_iter()
+            .map(|row| io::Contract {
+                snp: snp.clone(),
+                as_of: *as_of,
+                ticker: row.get::<usize, &str>(0).into(),
+                contract_type: row.get::<usize, &str>(1).into(),
+                exercise_style: row.get::<usize, Option<&str>>(2).map(|s| s.into()),
+                expiration_date: row.get::<usize, NaiveDate>(3),
+                strike_price: row.get::<usize, f64>(4),
+                shares_per_contract: row.get::<usize, Option<f64>>(5),
+                primary_exchange: row.get::<usize, Option<&str>>(6).map(|s| s.into()),
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,99 @@
+use chrono::NaiveDate;
+use std::env;
+use std::sync::Arc;
+use tokio_postgres::{Client, NoTls};
+
+use crate::options_contracts_impl::Service;
+use database_mod_options_contracts_io::options_contracts as io;
+use io::Api;
+use snp_mod_fixture::snp_fixture::ServiceFake;
+use snp_mod_io::snp;
+
+#[tokio::test]
+async fn save_to_database_and_find_chain() -> std::result::Result<(), io::Error> {
+    let db = Arc::new(init().await?);
+    let service = Service::new(db);
+    let deps = given_deps();
+
+    let as_of = NaiveDate::from_ymd_opt(2024, 6, 11).unwrap();
+    let call = io::Contract {
+        snp: snp::SnP::AAPL,
+        as_of,
+        ticker: "O:AAPL240628C00192500".into(),
+        contract_type: "call".into(),
+        exercise_style: Some("american".into()),
+        expiration_date: NaiveDate::from_ymd_opt(2024, 6, 28).unwrap(),
+        strike_price: 192.5,
+        shares_per_contract: Some(100.0),
+        primary_exchange: Some("BATO".into()),
+    };
+    let put = io::Contract {
+        ticker: "O:AAPL240621P00190000".into(),
+        contract_type: "put".into(),
+        expiration_date: NaiveDate::from_ymd_opt(2024, 6, 21).unwrap(),
+        strike_price: 190.0,
+        exercise_style: None,
+        shares_per_contract: None,
+        primary_exchange: None,
+        ..call.clone()
+    };
+    let other_day = io::Contract {
+        as_of: NaiveDate::from_ymd_opt(2024, 6, 10).unwrap(),
+        ..call.clone()
+    };
+
+    let contracts = vec![call, put, other_day];
+
+    service.save_contracts(deps.clone(), &contracts).await?;
+    service
+        .save_contracts(deps.clone(), &contracts[1..2])
+        .await?;
+    let chain = service
+        .find_chain(deps.clone(), &snp::SnP::AAPL, &as_of)
+        .await?;
+
+    assert_eq!(2, chain.len());
+    assert_eq!(contracts[1], chain[0]);
+    assert_eq!(contracts[0], chain[1]);
+
+    Ok(())
+}
+
+async fn init() -> io::Result<Client> {
+    let db_user = env::var("DB_USER").unwrap();
+    let db_password = env::var("DB_PASSWORD").unwrap();
+    let db_port = env::var("DB_PORT").unwrap().parse::<u16>().unwrap();
+    let db_name = env::var("DB_TEST_NAME").unwrap();
+
+    let (client, connection) = tokio_postgres::Config::new()
+        .user(&db_user)
+        .password(&db_password)
+        .host("localhost")
+        .port(db_port)
+        .dbname(&db_name)
+        .connect(NoTls)
+        .await?;
+
+    // Spawn connection
+    tokio::spawn(async move {
+        if let Err(error) = connection.await {
+            eprintln!("Connection error: {}", error);
+        }
+    });
+
+    Ok(client)
+}
+
+fn given_deps() -> Arc<dyn io::Depends + Send + Sync> {
+    Arc::new(DependsFake {})
+}
+
+struct DependsFake {}
+
+impl DependsFake {}
+
+impl io::Depends for DependsFake {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        Arc::new(ServiceFake {})
+    }
+}
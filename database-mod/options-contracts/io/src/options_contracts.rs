@@ -0,0 +1,60 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use snp_mod_io::snp;
+
+/// The data that is needed to interact with the Options-contracts modules, which is a contract
+/// in the chain of an S&P 500 ticker on a date.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Contract {
+    /// The underlying ticker.
+    pub snp: snp::SnP,
+    /// The date of the chain.
+    pub as_of: NaiveDate,
+    pub ticker: Arc<str>,
+    pub contract_type: Arc<str>,
+    pub exercise_style: Option<Arc<str>>,
+    pub expiration_date: NaiveDate,
+    pub strike_price: f64,
+    pub shares_per_contract: Option<f64>,
+    pub primary_exchange: Option<Arc<str>>,
+}
+
+/// All possible errors of Options-contracts modules.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// An error with an embedded storage engine
+    #[error("{0}")]
+    Db(#[from] tokio_postgres::Error),
+    #[error("{0}")]
+    SnP(#[from] snp::Error),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The API of Options-contracts, which defines all the functionality of Options-contracts
+/// modules.
+#[async_trait]
+pub trait Api {
+    /// Replaces the contracts of the same ticker and date, if any.
+    async fn save_contracts(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        contracts: &[Contract],
+    ) -> Result<()>;
+
+    /// Returns the chain of the underlying on the date, in ascending order of expiration date,
+    /// strike price and contract type.
+    async fn find_chain(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        snp: &snp::SnP,
+        as_of: &NaiveDate,
+    ) -> Result<Vec<Contract>>;
+}
+
+/// All the dependencies of the Options-contracts modules to other modules.
+pub trait Depends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync>;
+}
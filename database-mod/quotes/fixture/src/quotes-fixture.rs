@@ -0,0 +1,28 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use database_mod_quotes_io::quotes as io;
+use snp_mod_io::snp;
+
+pub struct ServiceFake;
+
+#[async_trait]
+impl io::Api for ServiceFake {
+    async fn save_quotes(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        _quotes: &[io::Quote],
+    ) -> io::Result<()> {
+        Ok(())
+    }
+
+    async fn find_quotes(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        _snp: &snp::SnP,
+        _from: i64,
+        _to: i64,
+    ) -> io::Result<Vec<io::Quote>> {
+        Ok(vec![])
+    }
+}
@@ -0,0 +1,141 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tokio_postgres::Client;
+
+use database_mod_quotes_io::quotes as io;
+use snp_mod_io::snp;
+
+/// The service to implement the API of Quotes.
+pub struct Service {
+    client: Arc<Client>,
+}
+
+impl Service {
+    pub fn new(client: Arc<Client>) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl io::Api for Service {
+    async fn save_quotes(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        quotes: &[io::Quote],
+    ) -> io::Result<()> {
+        let snp = deps.provide_snp();
+        let tickers: Vec<String> = quotes
+            .iter()
+            .map(|q| snp.get_symbol(&q.snp).to_string())
+            .collect();
+        let sip_timestamps: Vec<i64> = quotes.iter().map(|q| q.sip_timestamp).collect();
+        let sequence_numbers: Vec<i64> = quotes.iter().map(|q| q.sequence_number).collect();
+        let participant_timestamps: Vec<Option<i64>> =
+            quotes.iter().map(|q| q.participant_timestamp).collect();
+        let ask_exchanges: Vec<Option<i32>> = quotes.iter().map(|q| q.ask_exchange).collect();
+        let ask_prices: Vec<Option<f64>> = quotes.iter().map(|q| q.ask_price).collect();
+        let ask_sizes: Vec<Option<f64>> = quotes.iter().map(|q| q.ask_size).collect();
+        let bid_exchanges: Vec<Option<i32>> = quotes.iter().map(|q| q.bid_exchange).collect();
+        let bid_prices: Vec<Option<f64>> = quotes.iter().map(|q| q.bid_price).collect();
+        let bid_sizes: Vec<Option<f64>> = quotes.iter().map(|q| q.bid_size).collect();
+        // Postgres has no array of arrays with different lengths, so each list of conditions
+        // goes as an array literal, like `{1,2}`.
+        let conditions: Vec<String> = quotes
+            .iter()
+            .map(|q| {
+                let codes: Vec<String> = q.conditions.iter().map(|c| c.to_string()).collect();
+                format!("{{{}}}", codes.join(","))
+            })
+            .collect();
+        let tapes: Vec<Option<i32>> = quotes.iter().map(|q| q.tape).collect();
+
+        self.client
+            .execute(
+                "INSERT INTO quotes (
+                ticker, sip_timestamp, sequence_number, participant_timestamp,
+                ask_exchange, ask_price, ask_size, bid_exchange, bid_price, bid_size,
+                conditions, tape)
+             SELECT ticker, sip_timestamp, sequence_number, participant_timestamp,
+                ask_exchange, ask_price, ask_size, bid_exchange, bid_price, bid_size,
+                conditions::INT[], tape
+             FROM UNNEST(
+                $1::VARCHAR[], $2::BIGINT[], $3::BIGINT[], $4::BIGINT[],
+                $5::INT[], $6::FLOAT8[], $7::FLOAT8[], $8::INT[], $9::FLOAT8[], $10::FLOAT8[],
+                $11::TEXT[], $12::INT[])
+             AS q(ticker, sip_timestamp, sequence_number, participant_timestamp,
+                ask_exchange, ask_price, ask_size, bid_exchange, bid_price, bid_size,
+                conditions, tape)
+             ON CONFLICT (ticker, date, sequence_number) DO UPDATE SET
+                participant_timestamp = COALESCE(
+                    EXCLUDED.participant_timestamp, quotes.participant_timestamp),
+                ask_exchange = EXCLUDED.ask_exchange,
+                ask_price = EXCLUDED.ask_price,
+                ask_size = EXCLUDED.ask_size,
+                bid_exchange = EXCLUDED.bid_exchange,
+                bid_price = EXCLUDED.bid_price,
+                bid_size = EXCLUDED.bid_size,
+                conditions = EXCLUDED.conditions,
+                tape = EXCLUDED.tape",
+                &[
+                    &tickers,
+                    &sip_timestamps,
+                    &sequence_numbers,
+                    &participant_timestamps,
+                    &ask_exchanges,
+                    &ask_prices,
+                    &ask_sizes,
+                    &bid_exchanges,
+                    &bid_prices,
+                    &bid_sizes,
+                    &conditions,
+                    &tapes,
+                ],
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn find_quotes(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        snp: &snp::SnP,
+        from: i64,
+        to: i64,
+    ) -> io::Result<Vec<io::Quote>> {
+        let snp_api = deps.provide_snp();
+        let snp_symbol_rc = snp_api.get_symbol(snp).clone();
+        let snp_symbol = &snp_symbol_rc[..];
+
+        let list = self
+            .client
+            .query(
+                "SELECT sip_timestamp, sequence_number, participant_timestamp,
+                    ask_exchange, ask_price, ask_size, bid_exchange, bid_price, bid_size,
+                    conditions, tape
+                 FROM quotes WHERE ticker = $1 AND sip_timestamp >= $2 AND sip_timestamp < $3
+                 ORDER BY sip_timestamp, sequence_number",
+                &[&snp_symbol, &from, &to],
+            )
+            .await?;
+
+        Ok(list
+            .into_iter()
+            .map(|row| io::Quote {
+                snp: snp.clone(),
+                sip_timestamp: row.get::<usize, i64>(0),
+                sequence_number: row.get::<usize, i64>(1),
+                participant_timestamp: row.get::<usize, Option<i64>>(2),
+                ask_exchange: row.get::<usize, Option<i32>>(3),
+                ask_price: row.get::<usize, Option<f64>>(4),
+                ask_size: row.get::<usize, Option<f64>>(5),
+                bid_exchange: row.get::<usize, Option<i32>>(6),
+                bid_price: row.get::<usize, Option<f64>>(7),
+                bid_size: row.get::<usize, Option<f64>>(8),
+                conditions: row.get::<usize, Vec<i32>>(9),
+                tape: row.get::<usize, Option<i32>>(10),
+            })
+            .collect())
+    }
+}
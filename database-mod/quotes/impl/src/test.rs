@@ -0,0 +1,101 @@
+use std::env;
+use std::sync::Arc;
+use tokio_postgres::{Client, NoTls};
+
+use crate::quotes_impl::Service;
+use database_mod_quotes_io::quotes as io;
+use io::Api;
+use snp_mod_fixture::snp_fixture::ServiceFake;
+use snp_mod_io::snp;
+
+#[tokio::test]
+async fn save_to_database_and_find() -> std::result::Result<(), io::Error> {
+    let db = Arc::new(init().await?);
+    let service = Service::new(db);
+    let deps = given_deps();
+
+    let sip_timestamp = 1712755800010342000;
+    let mut one_sided = given_quote(sip_timestamp + 1000, 2140);
+    one_sided.ask_exchange = None;
+    one_sided.ask_price = None;
+    one_sided.ask_size = None;
+    one_sided.conditions = vec![];
+    let quotes = vec![given_quote(sip_timestamp, 2131), one_sided];
+
+    // The stream sends the same quote with a timestamp in milliseconds, and no participant
+    // timestamp.
+    let mut streamed = given_quote(sip_timestamp / 1_000_000 * 1_000_000, 2131);
+    streamed.participant_timestamp = None;
+
+    service.save_quotes(deps.clone(), &quotes).await?;
+    service.save_quotes(deps.clone(), &quotes[..1]).await?;
+    service.save_quotes(deps.clone(), &[streamed]).await?;
+    let list = service
+        .find_quotes(
+            deps.clone(),
+            &snp::SnP::AAPL,
+            sip_timestamp,
+            sip_timestamp + 1_000_000,
+        )
+        .await?;
+
+    assert_eq!(quotes, list);
+
+    Ok(())
+}
+
+fn given_quote(sip_timestamp: i64, sequence_number: i64) -> io::Quote {
+    io::Quote {
+        snp: snp::SnP::AAPL,
+        sip_timestamp,
+        sequence_number,
+        participant_timestamp: Some(sip_timestamp - 321000),
+        ask_exchange: Some(12),
+        ask_price: Some(168.62),
+        ask_size: Some(2.0),
+        bid_exchange: Some(11),
+        bid_price: Some(168.45),
+        bid_size: Some(1.0),
+        conditions: vec![1, 93],
+        tape: Some(3),
+    }
+}
+
+async fn init() -> io::Result<Client> {
+    let db_user = env::var("DB_USER").unwrap();
+    let db_password = env::var("DB_PASSWORD").unwrap();
+    let db_port = env::var("DB_PORT").unwrap().parse::<u16>().unwrap();
+    let db_name = env::var("DB_TEST_NAME").unwrap();
+
+    let (client, connection) = tokio_postgres::Config::new()
+        .user(&db_user)
+        .password(&db_password)
+        .host("localhost")
+        .port(db_port)
+        .dbname(&db_name)
+        .connect(NoTls)
+        .await?;
+
+    // Spawn connection
+    tokio::spawn(async move {
+        if let Err(error) = connection.await {
+            eprintln!("Connection error: {}", error);
+        }
+    });
+
+    Ok(client)
+}
+
+fn given_deps() -> Arc<dyn io::Depends + Send + Sync> {
+    Arc::new(DependsFake {})
+}
+
+struct DependsFake {}
+
+impl DependsFake {}
+
+impl io::Depends for DependsFake {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        Arc::new(ServiceFake {})
+    }
+}
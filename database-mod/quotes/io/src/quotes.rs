@@ -0,0 +1,62 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use snp_mod_io::snp;
+
+/// The data that is needed to interact with the Quotes modules.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Quote {
+    pub snp: snp::SnP,
+    /// The nanosecond Unix timestamp of when the SIP received the quote.
+    pub sip_timestamp: i64,
+    pub sequence_number: i64,
+    pub participant_timestamp: Option<i64>,
+    pub ask_exchange: Option<i32>,
+    pub ask_price: Option<f64>,
+    pub ask_size: Option<f64>,
+    pub bid_exchange: Option<i32>,
+    pub bid_price: Option<f64>,
+    pub bid_size: Option<f64>,
+    pub conditions: Vec<i32>,
+    pub tape: Option<i32>,
+}
+
+/// All possible errors of Quotes modules.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// An error with an embedded storage engine
+    #[error("{0}")]
+    Db(#[from] tokio_postgres::Error),
+    #[error("{0}")]
+    SnP(#[from] snp::Error),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The API of Quotes, which defines all the functionality of Quotes modules.
+#[async_trait]
+pub trait Api {
+    /// Replaces the quotes of the same ticker, New York date and sequence number, if any, but
+    /// keeps the SIP timestamp of the first one. So a quote of the stream, with a timestamp in
+    /// milliseconds, and the same quote of the REST or the flat files, in nanoseconds, are
+    /// stored once.
+    async fn save_quotes(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        quotes: &[Quote],
+    ) -> Result<()>;
+
+    /// Returns the quotes with SIP timestamp in `[from, to)` nanoseconds, in ascending order.
+    async fn find_quotes(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        snp: &snp::SnP,
+        from: i64,
+        to: i64,
+    ) -> Result<Vec<Quote>>;
+}
+
+/// All the dependencies of the Quotes modules to other modules.
+pub trait Depends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync>;
+}
@@ -0,0 +1,2 @@
+#[path = "raw-responses-fixture.rs"]
+pub mod raw_responses_fixture;
@@ -0,0 +1,35 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{NaiveDate, Utc};
+use database_mod_raw_responses_io::raw_responses as io;
+use snp_mod_io::snp;
+
+pub struct ServiceFake;
+
+#[async_trait]
+impl io::Api for ServiceFake {
+    async fn save_raw_response(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        _response: &io::RawResponse,
+    ) -> io::Result<()> {
+        Ok(())
+    }
+
+    async fn find_raw_responses(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        endpoint: &str,
+        date: &NaiveDate,
+    ) -> io::Result<Vec<io::RawResponse>> {
+        Ok(vec![io::RawResponse {
+            endpoint: endpoint.into(),
+            snp: snp::SnP::AAPL,
+            date: *date,
+            request_id: "31d59dda-80e5-4721-8496-d0d32a654afe".into(),
+            fetched_at: Utc::now(),
+            body: "{\"status\":\"OK\"}".into(),
+        }])
+    }
+}
@@ -0,0 +1,5 @@
+#[path = "raw-responses-impl.rs"]
+pub mod raw_responses_impl;
+
+#[cfg(test)]
+mod test;
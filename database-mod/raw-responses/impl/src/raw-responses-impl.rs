@@ -0,0 +1,82 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use tokio_postgres::Client;
+
+use database_mod_raw_responses_io::raw_responses as io;
+
+/// The service to implement the API of Raw_responses.
+pub struct Service {
+    client: Arc<Client>,
+}
+
+impl Service {
+    pub fn new(client: Arc<Client>) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl io::Api for Service {
+    async fn save_raw_response(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        response: &io::RawResponse,
+    ) -> io::Result<()> {
+        let snp = deps.provide_snp();
+        let snp_symbol_rc = snp.get_symbol(&response.snp).clone();
+        let snp_symbol = &snp_symbol_rc[..];
+        self.client
+            .execute(
+                "INSERT INTO raw_responses (
+                endpoint, ticker, date, request_id, fetched_at, body)
+             VALUES ($1, $2, $3, $4, $5, $6::TEXT::JSONB)
+             ON CONFLICT (endpoint, ticker, date) DO UPDATE SET
+                request_id = EXCLUDED.request_id,
+                fetched_at = EXCLUDED.fetched_at,
+                body = EXCLUDED.body",
+                &[
+                    &response.endpoint.as_ref(),
+                    &snp_symbol,
+                    &response.date,
+                    &response.request_id.as_ref(),
+                    &response.fetched_at,
+                    &response.body.as_ref(),
+                ],
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn find_raw_responses(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        endpoint: &str,
+        date: &NaiveDate,
+    ) -> io::Result<Vec<io::RawResponse>> {
+        let snp = deps.provide_snp();
+        let list = self
+            .client
+            .query(
+                "SELECT endpoint, ticker, date, request_id, fetched_at, body::TEXT
+                 FROM raw_responses WHERE endpoint = $1 AND date = $2 ORDER BY ticker",
+                &[&endpoint, &date],
+            )
+            .await?;
+
+        list.into_iter()
+            .map(|row| {
+                Ok(io::RawResponse {
+                    endpoint: row.get::<usize, &str>(0).into(),
+                    snp: snp.get_snp(row.get::<usize, &str>(1))?,
+                    date: row.get::<usize, NaiveDate>(2),
+                    request_id: row.get::<usize, &str>(3).into(),
+                    fetched_at: row.get::<usize, DateTime<Utc>>(4),
+                    body: row.get::<usize, &str>(5).into(),
+                })
+            })
+            .collect()
+    }
+}
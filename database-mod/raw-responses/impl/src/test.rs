@@ -0,0 +1,84 @@
+use chrono::{NaiveDate, TimeZone, Utc};
+use std::env;
+use std::sync::Arc;
+use tokio_postgres::{Client, NoTls};
+
+use crate::raw_responses_impl::Service;
+use database_mod_raw_responses_io::raw_responses as io;
+use io::Api;
+use snp_mod_fixture::snp_fixture::ServiceFake;
+use snp_mod_io::snp;
+
+#[tokio::test]
+async fn save_to_database_and_find() -> std::result::Result<(), io::Error> {
+    let format = "%B %e, %Y";
+    let db = Arc::new(init().await?);
+    let service = Service::new(db);
+    let deps = given_deps();
+
+    let response = io::RawResponse {
+        endpoint: "/v3/reference/tickers/{TICKER}".into(),
+        snp: snp::SnP::AAPL,
+        date: NaiveDate::parse_from_str("April 11, 2024", format).unwrap(),
+        request_id: "31d59dda-80e5-4721-8496-d0d32a654afe".into(),
+        fetched_at: Utc.with_ymd_and_hms(2024, 4, 12, 8, 30, 0).unwrap(),
+        body: "{\"results\": {\"active\": true}, \"status\": \"OK\"}".into(),
+    };
+
+    service.save_raw_response(deps.clone(), &response).await?;
+    service.save_raw_response(deps.clone(), &response).await?;
+    let list = service
+        .find_raw_responses(deps.clone(), &response.endpoint, &response.date)
+        .await?;
+
+    assert_eq!(1, list.len());
+    let found = &list[0];
+    assert_eq!(found.endpoint, response.endpoint);
+    assert_eq!(found.snp, response.snp);
+    assert_eq!(found.date, response.date);
+    assert_eq!(found.request_id, response.request_id);
+    assert_eq!(found.fetched_at, response.fetched_at);
+    let body: &str = &found.body;
+    assert!(body.contains("\"active\": true"), "body {:?}", body);
+
+    Ok(())
+}
+
+async fn init() -> io::Result<Client> {
+    let db_user = env::var("DB_USER").unwrap();
+    let db_password = env::var("DB_PASSWORD").unwrap();
+    let db_port = env::var("DB_PORT").unwrap().parse::<u16>().unwrap();
+    let db_name = env::var("DB_TEST_NAME").unwrap();
+
+    let (client, connection) = tokio_postgres::Config::new()
+        .user(&db_user)
+        .password(&db_password)
+        .host("localhost")
+        .port(db_port)
+        .dbname(&db_name)
+        .connect(NoTls)
+        .await?;
+
+    // Spawn connection
+    tokio::spawn(async move {
+        if let Err(error) = connection.await {
+            eprintln!("Connection error: {}", error);
+        }
+    });
+
+    Ok(client)
+}
+
+fn given_deps() -> Arc<dyn io::Depends + Send + Sync> {
+    Arc::new(DependsFake {})
+}
+
+struct DependsFake {}
+
+impl DependsFake {}
+
+impl io::Depends for DependsFake {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        Arc::new(ServiceFake {})
+    }
+}
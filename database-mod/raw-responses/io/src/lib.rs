@@ -0,0 +1,2 @@
+#[path = "raw-responses.rs"]
+pub mod raw_responses;
@@ -0,0 +1,52 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use snp_mod_io::snp;
+
+/// The data that is needed to interact with the Raw-responses modules.
+#[derive(Debug, Clone)]
+pub struct RawResponse {
+    pub endpoint: Arc<str>,
+    pub snp: snp::SnP,
+    pub date: NaiveDate,
+    pub request_id: Arc<str>,
+    pub fetched_at: DateTime<Utc>,
+    /// The untouched JSON body of the response.
+    pub body: Arc<str>,
+}
+
+/// All possible errors of Raw-responses modules.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// An error with an embedded storage engine
+    #[error("{0}")]
+    Db(#[from] tokio_postgres::Error),
+    #[error("{0}")]
+    SnP(#[from] snp::Error),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The API of Raw-responses, which defines all the functionality of Raw-responses modules.
+#[async_trait]
+pub trait Api {
+    /// Keeps the latest response for each endpoint, ticker and date.
+    async fn save_raw_response(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        response: &RawResponse,
+    ) -> Result<()>;
+
+    async fn find_raw_responses(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        endpoint: &str,
+        date: &NaiveDate,
+    ) -> Result<Vec<RawResponse>>;
+}
+
+/// All the dependencies of the Raw-responses modules to other modules.
+pub trait Depends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync>;
+}
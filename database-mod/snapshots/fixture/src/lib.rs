@@ -0,0 +1,2 @@
+#[path = "snapshots-fixture.rs"]
+pub mod snapshots_fixture;
@@ -0,0 +1,29 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use database_mod_snapshots_io::snapshots as io;
+use snp_mod_io::snp;
+
+pub struct ServiceFake;
+
+#[async_trait]
+impl io::Api for ServiceFake {
+    async fn save_snapshots(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        _snapshots: &[io::Snapshot],
+    ) -> io::Result<()> {
+        Ok(())
+    }
+
+    async fn find_snapshots(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        _snp: &snp::SnP,
+        _from: &DateTime<Utc>,
+        _to: &DateTime<Utc>,
+    ) -> io::Result<Vec<io::Snapshot>> {
+        Ok(vec![])
+    }
+}
@@ -0,0 +1,175 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tokio_postgres::{Client, Row};
+
+use database_mod_snapshots_io::snapshots as io;
+use snp_mod_io::snp;
+
+/// The service to implement the API of Snapshots.
+pub struct Service {
+    client: Arc<Client>,
+}
+
+impl Service {
+    pub fn new(client: Arc<Client>) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl io::Api for Service {
+    async fn save_snapshots(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        snapshots: &[io::Snapshot],
+    ) -> io::Result<()> {
+        let snp = deps.provide_snp();
+        for snapshot in snapshots {
+            let ticker = snp.get_symbol(&snapshot.snp);
+            let day = snapshot.day.as_ref();
+            let prev_day = snapshot.prev_day.as_ref();
+            let last_trade = snapshot.last_trade.as_ref();
+            let last_quote = snapshot.last_quote.as_ref();
+            self.client
+                .execute(
+                    "INSERT INTO snapshots (
+                        ticker, captured_at, updated, todays_change, todays_change_perc,
+                        day_open, day_high, day_low, day_close, day_volume, day_vwap,
+                        prev_day_open, prev_day_high, prev_day_low, prev_day_close,
+                        prev_day_volume, prev_day_vwap,
+                        last_trade_price, last_trade_size, last_trade_exchange, last_trade_at,
+                        bid_price, bid_size, ask_price, ask_size, last_quote_at)
+                     VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15,
+                        $16, $17, $18, $19, $20, $21, $22, $23, $24, $25, $26)
+                     ON CONFLICT (ticker, captured_at) DO UPDATE SET
+                        updated = EXCLUDED.updated,
+                        todays_change = EXCLUDED.todays_change,
+                        todays_change_perc = EXCLUDED.todays_change_perc,
+                        day_open = EXCLUDED.day_open,
+                        day_high = EXCLUDED.day_high,
+                        day_low = EXCLUDED.day_low,
+                        day_close = EXCLUDED.day_close,
+                        day_volume = EXCLUDED.day_volume,
+                        day_vwap = EXCLUDED.day_vwap,
+                        prev_day_open = EXCLUDED.prev_day_open,
+                        prev_day_high = EXCLUDED.prev_day_high,
+                        prev_day_low = EXCLUDED.prev_day_low,
+                        prev_day_close = EXCLUDED.prev_day_close,
+                        prev_day_volume = EXCLUDED.prev_day_volume,
+                        prev_day_vwap = EXCLUDED.prev_day_vwap,
+                        last_trade_price = EXCLUDED.last_trade_price,
+                        last_trade_size = EXCLUDED.last_trade_size,
+                        last_trade_exchange = EXCLUDED.last_trade_exchange,
+                        last_trade_at = EXCLUDED.last_trade_at,
+                        bid_price = EXCLUDED.bid_price,
+                        bid_size = EXCLUDED.bid_size,
+                        ask_price = EXCLUDED.ask_price,
+                        ask_size = EXCLUDED.ask_size,
+                        last_quote_at = EXCLUDED.last_quote_at",
+                    &[
+                        &ticker.as_ref(),
+                        &snapshot.captured_at,
+                        &snapshot.updated,
+                        &snapshot.todays_change,
+                        &snapshot.todays_change_perc,
+                        &day.map(|b| b.open),
+                        &day.map(|b| b.high),
+                        &day.map(|b| b.low),
+                        &day.map(|b| b.close),
+                        &day.map(|b| b.volume),
+                        &day.and_then(|b| b.vwap),
+                        &prev_day.map(|b| b.open),
+                        &prev_day.map(|b| b.high),
+                        &prev_day.map(|b| b.low),
+                        &prev_day.map(|b| b.close),
+                        &prev_day.map(|b| b.volume),
+                        &prev_day.and_then(|b| b.vwap),
+                        &last_trade.map(|t| t.price),
+                        &last_trade.map(|t| t.size),
+                        &last_trade.and_then(|t| t.exchange),
+                        &last_trade.map(|t| t.timestamp),
+                        &last_quote.map(|q| q.bid_price),
+                        &last_quote.map(|q| q.bid_size),
+                        &last_quote.map(|q| q.ask_price),
+                        &last_quote.map(|q| q.ask_size),
+                        &last_quote.map(|q| q.timestamp),
+                    ],
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn find_snapshots(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        snp: &snp::SnP,
+        from: &DateTime<Utc>,
+        to: &DateTime<Utc>,
+    ) -> io::Result<Vec<io::Snapshot>> {
+        let snp_api = deps.provide_snp();
+        let snp_symbol_rc = snp_api.get_symbol(snp).clone();
+        let snp_symbol = &snp_symbol_rc[..];
+
+        let list = self
+            .client
+            .query(
+                "SELECT captured_at, updated, todays_change, todays_change_perc,
+                    day_open, day_high, day_low, day_close, day_volume, day_vwap,
+                    prev_day_open, prev_day_high, prev_day_low, prev_day_close,
+                    prev_day_volume, prev_day_vwap,
+                    last_trade_price, last_trade_size, last_trade_exchange, last_trade_at,
+                    bid_price, bid_size, ask_price, ask_size, last_quote_at
+                 FROM snapshots
+                 WHERE ticker = $1 AND captured_at >= $2 AND captured_at < $3
+                 ORDER BY captured_at",
+                &[&snp_symbol, from, to],
+            )
+            .await?;
+
+        Ok(list
+            .into_iter()
+            .map(|row| io::Snapshot {
+                snp: snp.clone(),
+                captured_at: row.get::<usize, DateTime<Utc>>(0),
+                updated: row.get::<usize, Option<DateTime<Utc>>>(1),
+                todays_change: row.get::<usize, f64>(2),
+                todays_change_perc: row.get::<usize, f64>(3),
+                day: to_bar(&row, 4),
+                prev_day: to_bar(&row, 10),
+                last_trade: row
+                    .get::<usize, Option<f64>>(16)
+                    .map(|price| io::LastTrade {
+                        price,
+                        size: row.get::<usize, f64>(17),
+                        exchange: row.get::<usize, Option<i32>>(18),
+                        timestamp: row.get::<usize, DateTime<Utc>>(19),
+                    }),
+                last_quote: row
+                    .get::<usize, Option<f64>>(20)
+                    .map(|bid_price| io::LastQuote {
+                        bid_price,
+                        bid_size: row.get::<usize, f64>(21),
+                        ask_price: row.get::<usize, f64>(22),
+                        ask_size: row.get::<usize, f64>(23),
+                        timestamp: row.get::<usize, DateTime<Utc>>(24),
+                    }),
+            })
+            .collect())
+    }
+}
+
+/// Reads the bar from the six columns from `index`, where a missing open means no bar.
+fn to_bar(row: &Row, index: usize) -> Option<io::Bar> {
+    row.get::<usize, Option<f64>>(index).map(|open| io::Bar {
+        open,
+        high: row.get::<usize, f64>(index + 1),
+        low: row.get::<usize, f64>(index + 2),
+        close: row.get::<usize, f64>(index + 3),
+        volume: row.get::<usize, f64>(index + 4),
+        vwap: row.get::<usize, Option<f64>>(index + 5),
+    })
+}
@@ -0,0 +1,121 @@
+use chrono::{Duration, TimeZone, Utc};
+use std::env;
+use std::sync::Arc;
+use tokio_postgres::{Client, NoTls};
+
+use crate::snapshots_impl::Service;
+use database_mod_snapshots_io::snapshots as io;
+use io::Api;
+use snp_mod_fixture::snp_fixture::ServiceFake;
+use snp_mod_io::snp;
+
+#[tokio::test]
+async fn save_to_database_and_find() -> std::result::Result<(), io::Error> {
+    let db = Arc::new(init().await?);
+    let service = Service::new(db);
+    let deps = given_deps();
+
+    let captured_at = Utc.with_ymd_and_hms(2024, 6, 11, 15, 0, 0).unwrap();
+    let bar = io::Bar {
+        open: 193.65,
+        high: 197.3,
+        low: 193.0,
+        close: 196.89,
+        volume: 70122748.0,
+        vwap: Some(195.8034),
+    };
+    let snapshots = vec![
+        io::Snapshot {
+            snp: snp::SnP::AAPL,
+            captured_at,
+            updated: Some(captured_at - Duration::try_seconds(1).unwrap()),
+            todays_change: 3.77,
+            todays_change_perc: 1.952,
+            day: Some(bar.clone()),
+            prev_day: Some(io::Bar { vwap: None, ..bar }),
+            last_trade: Some(io::LastTrade {
+                price: 196.89,
+                size: 100.0,
+                exchange: Some(4),
+                timestamp: captured_at - Duration::try_seconds(2).unwrap(),
+            }),
+            last_quote: Some(io::LastQuote {
+                bid_price: 196.88,
+                bid_size: 2.0,
+                ask_price: 196.9,
+                ask_size: 3.0,
+                timestamp: captured_at - Duration::try_seconds(1).unwrap(),
+            }),
+        },
+        // Before the first trade of the day.
+        io::Snapshot {
+            snp: snp::SnP::AAPL,
+            captured_at: captured_at - Duration::try_hours(2).unwrap(),
+            updated: None,
+            todays_change: 0.0,
+            todays_change_perc: 0.0,
+            day: None,
+            prev_day: None,
+            last_trade: None,
+            last_quote: None,
+        },
+    ];
+
+    service.save_snapshots(deps.clone(), &snapshots).await?;
+    service
+        .save_snapshots(deps.clone(), &snapshots[..1])
+        .await?;
+    let list = service
+        .find_snapshots(
+            deps.clone(),
+            &snp::SnP::AAPL,
+            &(captured_at - Duration::try_hours(2).unwrap()),
+            &(captured_at + Duration::try_minutes(1).unwrap()),
+        )
+        .await?;
+
+    assert_eq!(2, list.len());
+    assert_eq!(snapshots[1], list[0]);
+    assert_eq!(snapshots[0], list[1]);
+
+    Ok(())
+}
+
+async fn init() -> io::Result<Client> {
+    let db_user = env::var("DB_USER").unwrap();
+    let db_password = env::var("DB_PASSWORD").unwrap();
+    let db_port = env::var("DB_PORT").unwrap().parse::<u16>().unwrap();
+    let db_name = env::var("DB_TEST_NAME").unwrap();
+
+    let (client, connection) = tokio_postgres::Config::new()
+        .user(&db_user)
+        .password(&db_password)
+        .host("localhost")
+        .port(db_port)
+        .dbname(&db_name)
+        .connect(NoTls)
+        .await?;
+
+    // Spawn connection
+    tokio::spawn(async move {
+        if let Err(error) = connection.await {
+            eprintln!("Connection error: {}", error);
+        }
+    });
+
+    Ok(client)
+}
+
+fn given_deps() -> Arc<dyn io::Depends + Send + Sync> {
+    Arc::new(DependsFake {})
+}
+
+struct DependsFake {}
+
+impl DependsFake {}
+
+impl io::Depends for DependsFake {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        Arc::new(ServiceFake {})
+    }
+}
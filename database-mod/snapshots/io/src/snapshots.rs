@@ -0,0 +1,87 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use snp_mod_io::snp;
+
+/// A bar of the snapshot, e.g. the day so far or the previous day.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bar {
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub volume: f64,
+    pub vwap: Option<f64>,
+}
+
+/// The most recent trade at the capture.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LastTrade {
+    pub price: f64,
+    pub size: f64,
+    pub exchange: Option<i32>,
+    pub timestamp: DateTime<Utc>,
+}
+
+/// The most recent NBBO quote at the capture.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LastQuote {
+    pub bid_price: f64,
+    pub bid_size: f64,
+    pub ask_price: f64,
+    pub ask_size: f64,
+    pub timestamp: DateTime<Utc>,
+}
+
+/// The data that is needed to interact with the Snapshots modules, which is the state of a
+/// ticker captured during the market hours.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Snapshot {
+    pub snp: snp::SnP,
+    pub captured_at: DateTime<Utc>,
+    pub updated: Option<DateTime<Utc>>,
+    pub todays_change: f64,
+    pub todays_change_perc: f64,
+    pub day: Option<Bar>,
+    pub prev_day: Option<Bar>,
+    pub last_trade: Option<LastTrade>,
+    pub last_quote: Option<LastQuote>,
+}
+
+/// All possible errors of Snapshots modules.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// An error with an embedded storage engine
+    #[error("{0}")]
+    Db(#[from] tokio_postgres::Error),
+    #[error("{0}")]
+    SnP(#[from] snp::Error),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The API of Snapshots, which defines all the functionality of Snapshots modules.
+#[async_trait]
+pub trait Api {
+    /// Replaces the snapshots of the same ticker and capture time, if any.
+    async fn save_snapshots(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        snapshots: &[Snapshot],
+    ) -> Result<()>;
+
+    /// Returns the snapshots captured in `[from, to)`, in ascending order of capture.
+    async fn find_snapshots(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        snp: &snp::SnP,
+        from: &DateTime<Utc>,
+        to: &DateTime<Utc>,
+    ) -> Result<Vec<Snapshot>>;
+}
+
+/// All the dependencies of the Snapshots modules to other modules.
+pub trait Depends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync>;
+}
@@ -0,0 +1,2 @@
+#[path = "splits-fixture.rs"]
+pub mod splits_fixture;
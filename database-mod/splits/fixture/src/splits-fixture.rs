@@ -0,0 +1,34 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use database_mod_splits_io::splits as io;
+use snp_mod_io::snp;
+
+/// Knows the last split of AAPL, 4-for-1 on August 31, 2020.
+pub struct ServiceFake;
+
+#[async_trait]
+impl io::Api for ServiceFake {
+    async fn save_splits(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        _splits: &[io::Split],
+    ) -> io::Result<()> {
+        Ok(())
+    }
+
+    async fn find_splits(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        snp: &snp::SnP,
+    ) -> io::Result<Vec<io::Split>> {
+        Ok(vec![io::Split {
+            polygon_id: "E36416cce743c3964c5da63e1ef1626c0aece30fb47302eea5a49c0055c04e8d0".into(),
+            snp: snp.clone(),
+            execution_date: NaiveDate::from_ymd_opt(2020, 8, 31).unwrap(),
+            split_from: 1.0,
+            split_to: 4.0,
+        }])
+    }
+}
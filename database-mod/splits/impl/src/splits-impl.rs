@@ -0,0 +1,85 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use tokio_postgres::Client;
+
+use database_mod_splits_io::splits as io;
+use snp_mod_io::snp;
+
+/// The service to implement the API of Splits.
+pub struct Service {
+    client: Arc<Client>,
+}
+
+impl Service {
+    pub fn new(client: Arc<Client>) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl io::Api for Service {
+    async fn save_splits(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        splits: &[io::Split],
+    ) -> io::Result<()> {
+        let snp = deps.provide_snp();
+        for split in splits {
+            let snp_symbol_rc = snp.get_symbol(&split.snp).clone();
+            let snp_symbol = &snp_symbol_rc[..];
+            self.client
+                .execute(
+                    "INSERT INTO splits (
+                    polygon_id, ticker, execution_date, split_from, split_to)
+                 VALUES ($1, $2, $3, $4, $5)
+                 ON CONFLICT (polygon_id) DO UPDATE SET
+                    ticker = EXCLUDED.ticker,
+                    execution_date = EXCLUDED.execution_date,
+                    split_from = EXCLUDED.split_from,
+                    split_to = EXCLUDED.split_to",
+                    &[
+                        &split.polygon_id.as_ref(),
+                        &snp_symbol,
+                        &split.execution_date,
+                        &split.split_from,
+                        &split.split_to,
+                    ],
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn find_splits(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        snp: &snp::SnP,
+    ) -> io::Result<Vec<io::Split>> {
+        let snp_api = deps.provide_snp();
+        let snp_symbol_rc = snp_api.get_symbol(snp).clone();
+        let snp_symbol = &snp_symbol_rc[..];
+
+        let list = self
+            .client
+            .query(
+                "SELECT polygon_id, execution_date, split_from, split_to
+                 FROM splits WHERE ticker = $1 ORDER BY execution_date",
+                &[&snp_symbol],
+            )
+            .await?;
+
+        Ok(list
+            .into_iter()
+            .map(|row| io::Split {
+                polygon_id: row.get::<usize, &str>(0).into(),
+                snp: snp.clone(),
+                execution_date: row.get::<usize, NaiveDate>(1),
+                split_from: row.get::<usize, f64>(2),
+                split_to: row.get::<usize, f64>(3),
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,81 @@
+use chrono::NaiveDate;
+use std::env;
+use std::sync::Arc;
+use tokio_postgres::{Client, NoTls};
+
+use crate::splits_impl::Service;
+use database_mod_splits_io::splits as io;
+use io::Api;
+use snp_mod_fixture::snp_fixture::ServiceFake;
+use snp_mod_io::snp;
+
+#[tokio::test]
+async fn save_to_database_and_find() -> std::result::Result<(), io::Error> {
+    let db = Arc::new(init().await?);
+    let service = Service::new(db);
+    let deps = given_deps();
+
+    let splits = vec![
+        io::Split {
+            polygon_id: "E5b5f9c3e1a7d3b9f5c1e7a3d9b5f1c7e3a9d5b1f7c3e9a5d1b7f3c9e5a1d7b3f".into(),
+            snp: snp::SnP::AAPL,
+            execution_date: NaiveDate::from_ymd_opt(2014, 6, 9).unwrap(),
+            split_from: 1.0,
+            split_to: 7.0,
+        },
+        io::Split {
+            polygon_id: "E36416cce743c3964c5da63e1ef1626c0aece30fb47302eea5a49c0055c04e8d0".into(),
+            snp: snp::SnP::AAPL,
+            execution_date: NaiveDate::from_ymd_opt(2020, 8, 31).unwrap(),
+            split_from: 1.0,
+            split_to: 4.0,
+        },
+    ];
+
+    service.save_splits(deps.clone(), &splits[1..]).await?;
+    service.save_splits(deps.clone(), &splits).await?;
+    let list = service.find_splits(deps.clone(), &snp::SnP::AAPL).await?;
+
+    assert_eq!(splits, list);
+
+    Ok(())
+}
+
+async fn init() -> io::Result<Client> {
+    let db_user = env::var("DB_USER").unwrap();
+    let db_password = env::var("DB_PASSWORD").unwrap();
+    let db_port = env::var("DB_PORT").unwrap().parse::<u16>().unwrap();
+    let db_name = env::var("DB_TEST_NAME").unwrap();
+
+    let (client, connection) = tokio_postgres::Config::new()
+        .user(&db_user)
+        .password(&db_password)
+        .host("localhost")
+        .port(db_port)
+        .dbname(&db_name)
+        .connect(NoTls)
+        .await?;
+
+    // Spawn connection
+    tokio::spawn(async move {
+        if let Err(error) = connection.await {
+            eprintln!("Connection error: {}", error);
+        }
+    });
+
+    Ok(client)
+}
+
+fn given_deps() -> Arc<dyn io::Depends + Send + Sync> {
+    Arc::new(DependsFake {})
+}
+
+struct DependsFake {}
+
+impl DependsFake {}
+
+impl io::Depends for DependsFake {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        Arc::new(ServiceFake {})
+    }
+}
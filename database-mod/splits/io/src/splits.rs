@@ -0,0 +1,51 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use snp_mod_io::snp;
+
+/// The data that is needed to interact with the Splits modules.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Split {
+    /// The unique identifier of the split in Polygon.io.
+    pub polygon_id: Arc<str>,
+    pub snp: snp::SnP,
+    pub execution_date: NaiveDate,
+    pub split_from: f64,
+    pub split_to: f64,
+}
+
+/// All possible errors of Splits modules.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// An error with an embedded storage engine
+    #[error("{0}")]
+    Db(#[from] tokio_postgres::Error),
+    #[error("{0}")]
+    SnP(#[from] snp::Error),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The API of Splits, which defines all the functionality of Splits modules.
+#[async_trait]
+pub trait Api {
+    /// Replaces the splits with the same Polygon.io identifier, if any.
+    async fn save_splits(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        splits: &[Split],
+    ) -> Result<()>;
+
+    /// Returns the splits of the ticker in ascending order of execution date.
+    async fn find_splits(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        snp: &snp::SnP,
+    ) -> Result<Vec<Split>>;
+}
+
+/// All the dependencies of the Splits modules to other modules.
+pub trait Depends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync>;
+}
@@ -34,4 +34,14 @@ impl io::Api for ServiceFake {
             weighted_shares_outstanding: 533354,
         })
     }
+
+    async fn find_ticker_details_between(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        _snp: &snp::SnP,
+        _from: &NaiveDate,
+        _to: &NaiveDate,
+    ) -> io::Result<Vec<io::Details>> {
+        Ok(vec![])
+    }
 }
@@ -23,12 +23,19 @@ async fn save_to_database() -> std::result::Result<(), io::Error> {
         snp: snp::SnP::AAPL,
         share_class_shares_outstanding: 47539,
         total_employees: 3343,
-        weighted_shares_outstanding: 0885503,
+        weighted_shares_outstanding: 885503,
     };
 
-    let _ = service.save_ticker_details(deps, &details).await?;
+    service.save_ticker_details(deps, &details).await?;
 
-    let list = db_ref.query("SELECT * FROM ticker_details", &[]).await?;
+    // The upsert rewrites an existing row in place, so the saved row isn't always the last one
+    // of the table, then it's read back by its key.
+    let list = db_ref
+        .query(
+            "SELECT * FROM ticker_details WHERE snp = $1 AND date = $2",
+            &[&"AAPL", &details.date],
+        )
+        .await?;
     let result = list.last().unwrap();
 
     assert_eq!(result.get::<usize, bool>(1), details.active);
@@ -59,10 +66,10 @@ async fn save_to_database_and_find() -> std::result::Result<(), io::Error> {
         snp: snp::SnP::AAPL,
         share_class_shares_outstanding: 47539,
         total_employees: 3343,
-        weighted_shares_outstanding: 0885503,
+        weighted_shares_outstanding: 885503,
     };
 
-    let _ = service.save_ticker_details(deps.clone(), &details).await?;
+    service.save_ticker_details(deps.clone(), &details).await?;
     let new_detils = service
         .find_ticker_details(deps.clone(), &details.date, &details.snp)
         .await?;
@@ -83,6 +90,37 @@ async fn save_to_database_and_find() -> std::result::Result<(), io::Error> {
     Ok(())
 }
 
+#[tokio::test]
+async fn save_to_database_and_find_between() -> std::result::Result<(), io::Error> {
+    let format = "%B %e, %Y";
+    let db = Arc::new(init().await?);
+    let service = Service::new(db);
+    let deps = given_deps();
+
+    let details = io::Details {
+        active: true,
+        date: NaiveDate::parse_from_str("April 9, 2024", format).unwrap(),
+        snp: snp::SnP::AAPL,
+        share_class_shares_outstanding: 47539,
+        total_employees: 3343,
+        weighted_shares_outstanding: 885503,
+    };
+
+    service.save_ticker_details(deps.clone(), &details).await?;
+    let list = service
+        .find_ticker_details_between(deps.clone(), &details.snp, &details.date, &details.date)
+        .await?;
+
+    assert_eq!(1, list.len());
+    assert_eq!(list[0].date, details.date);
+    assert_eq!(
+        list[0].weighted_shares_outstanding,
+        details.weighted_shares_outstanding
+    );
+
+    Ok(())
+}
+
 async fn init() -> io::Result<Client> {
     let db_user = env::var("DB_USER").unwrap();
     let db_password = env::var("DB_PASSWORD").unwrap();
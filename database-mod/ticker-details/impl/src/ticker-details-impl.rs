@@ -33,7 +33,12 @@ impl io::Api for Service {
                 "INSERT INTO ticker_details (
                 active, date, snp, share_class_shares_outstanding,
                 total_employees, weighted_shares_outstanding)
-             VALUES ($1, $2, $3, $4, $5, $6)",
+             VALUES ($1, $2, $3, $4, $5, $6)
+             ON CONFLICT (snp, date) DO UPDATE SET
+                active = EXCLUDED.active,
+                share_class_shares_outstanding = EXCLUDED.share_class_shares_outstanding,
+                total_employees = EXCLUDED.total_employees,
+                weighted_shares_outstanding = EXCLUDED.weighted_shares_outstanding",
                 &[
                     &details.active,
                     &details.date,
@@ -76,4 +81,37 @@ impl io::Api for Service {
             weighted_shares_outstanding: result.get::<usize, i64>(6),
         })
     }
+
+    async fn find_ticker_details_between(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        snp: &snp::SnP,
+        from: &NaiveDate,
+        to: &NaiveDate,
+    ) -> io::Result<Vec<io::Details>> {
+        let snp_api = deps.provide_snp();
+        let snp_symbol_rc = snp_api.get_symbol(snp).clone();
+        let snp_symbol = &snp_symbol_rc[..];
+
+        let list = self
+            .client
+            .query(
+                "SELECT * FROM ticker_details WHERE snp = $1 AND date >= $2 AND date <= $3
+                 ORDER BY date",
+                &[&snp_symbol, from, to],
+            )
+            .await?;
+
+        Ok(list
+            .into_iter()
+            .map(|result| io::Details {
+                active: result.get::<usize, bool>(1),
+                date: result.get::<usize, NaiveDate>(2),
+                snp: snp.clone(),
+                share_class_shares_outstanding: result.get::<usize, i64>(4),
+                total_employees: result.get::<usize, i32>(5),
+                weighted_shares_outstanding: result.get::<usize, i64>(6),
+            })
+            .collect())
+    }
 }
@@ -31,6 +31,7 @@ pub type Result<T> = std::result::Result<T, Error>;
 /// The API of Ticker-details, which defines all the functionality of Ticker-details modules.
 #[async_trait]
 pub trait Api {
+    /// Replaces the details of the same ticker and date, if any.
     async fn save_ticker_details(
         &self,
         deps: Arc<dyn Depends + Send + Sync>,
@@ -43,10 +44,19 @@ pub trait Api {
         date: &NaiveDate,
         snp: &snp::SnP,
     ) -> Result<Details>;
+
+    /// Returns the details of the ticker from `from` to `to`, inclusive, in ascending order of
+    /// date.
+    async fn find_ticker_details_between(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        snp: &snp::SnP,
+        from: &NaiveDate,
+        to: &NaiveDate,
+    ) -> Result<Vec<Details>>;
 }
 
 /// All the dependencies of the Ticker-details modules to other modules.
 pub trait Depends {
     fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync>;
 }
-
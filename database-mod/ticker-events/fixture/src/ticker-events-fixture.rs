@@ -0,0 +1,35 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use database_mod_ticker_events_io::ticker_events as io;
+
+pub struct ServiceFake;
+
+#[async_trait]
+impl io::Api for ServiceFake {
+    async fn save_ticker_changes(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        _changes: &[io::TickerChange],
+    ) -> io::Result<()> {
+        Ok(())
+    }
+
+    async fn find_ticker_changes(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        _composite_figi: &str,
+    ) -> io::Result<Vec<io::TickerChange>> {
+        Ok(vec![])
+    }
+
+    async fn find_historical_symbol(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        symbol: &str,
+        _date: &NaiveDate,
+    ) -> io::Result<Arc<str>> {
+        Ok(symbol.into())
+    }
+}
@@ -0,0 +1,5 @@
+#[path = "ticker-events-impl.rs"]
+pub mod ticker_events_impl;
+
+#[cfg(test)]
+mod test;
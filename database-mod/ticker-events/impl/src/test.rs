@@ -0,0 +1,114 @@
+use chrono::NaiveDate;
+use std::env;
+use std::sync::Arc;
+use tokio_postgres::{Client, NoTls};
+
+use crate::ticker_events_impl::Service;
+use database_mod_ticker_events_io::ticker_events as io;
+use io::Api;
+use snp_mod_fixture::snp_fixture::ServiceFake;
+use snp_mod_io::snp;
+
+#[tokio::test]
+async fn save_to_database_and_find_historical_symbol() -> std::result::Result<(), io::Error> {
+    let db = Arc::new(init().await?);
+    let service = Service::new(db);
+    let deps = given_deps();
+
+    let changes = vec![
+        io::TickerChange {
+            composite_figi: "BBG000MM2P62".into(),
+            date: NaiveDate::from_ymd_opt(2012, 5, 18).unwrap(),
+            ticker: "FB".into(),
+        },
+        io::TickerChange {
+            composite_figi: "BBG000MM2P62".into(),
+            date: NaiveDate::from_ymd_opt(2022, 6, 9).unwrap(),
+            ticker: "META".into(),
+        },
+    ];
+
+    service.save_ticker_changes(deps.clone(), &changes).await?;
+    let list = service
+        .find_ticker_changes(deps.clone(), "BBG000MM2P62")
+        .await?;
+    let symbol_on = |date: NaiveDate| {
+        let service = &service;
+        let deps = deps.clone();
+        async move { service.find_historical_symbol(deps, "META", &date).await }
+    };
+
+    assert_eq!(changes, list);
+    assert_eq!(
+        "FB",
+        symbol_on(NaiveDate::from_ymd_opt(2020, 1, 2).unwrap())
+            .await?
+            .as_ref()
+    );
+    assert_eq!(
+        "META",
+        symbol_on(NaiveDate::from_ymd_opt(2022, 6, 9).unwrap())
+            .await?
+            .as_ref()
+    );
+    // Before the listing, it's the first symbol.
+    assert_eq!(
+        "FB",
+        symbol_on(NaiveDate::from_ymd_opt(2010, 1, 4).unwrap())
+            .await?
+            .as_ref()
+    );
+    // The old symbol isn't of any ticker today.
+    assert_eq!(
+        "FB",
+        service
+            .find_historical_symbol(
+                deps.clone(),
+                "FB",
+                &NaiveDate::from_ymd_opt(2024, 1, 2).unwrap()
+            )
+            .await?
+            .as_ref()
+    );
+
+    Ok(())
+}
+
+async fn init() -> io::Result<Client> {
+    let db_user = env::var("DB_USER").unwrap();
+    let db_password = env::var("DB_PASSWORD").unwrap();
+    let db_port = env::var("DB_PORT").unwrap().parse::<u16>().unwrap();
+    let db_name = env::var("DB_TEST_NAME").unwrap();
+
+    let (client, connection) = tokio_postgres::Config::new()
+        .user(&db_user)
+        .password(&db_password)
+        .host("localhost")
+        .port(db_port)
+        .dbname(&db_name)
+        .connect(NoTls)
+        .await?;
+
+    // Spawn connection
+    tokio::spawn(async move {
+        if let Err(error) = connection.await {
+            eprintln!("Connection error: {}", error);
+        }
+    });
+
+    Ok(client)
+}
+
+fn given_deps() -> Arc<dyn io::Depends + Send + Sync> {
+    Arc::new(DependsFake {})
+}
+
+struct DependsFake {}
+
+impl DependsFake {}
+
+impl io::Depends for DependsFake {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        Arc::new(ServiceFake {})
+    }
+}
@@ -0,0 +1,101 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use tokio_postgres::Client;
+
+use database_mod_ticker_events_io::ticker_events as io;
+
+/// The service to implement the API of Ticker-events.
+pub struct Service {
+    client: Arc<Client>,
+}
+
+impl Service {
+    pub fn new(client: Arc<Client>) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl io::Api for Service {
+    async fn save_ticker_changes(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        changes: &[io::TickerChange],
+    ) -> io::Result<()> {
+        for change in changes {
+            self.client
+                .execute(
+                    "INSERT INTO ticker_changes (composite_figi, date, ticker)
+                     VALUES ($1, $2, $3)
+                     ON CONFLICT (composite_figi, date) DO UPDATE SET
+                        ticker = EXCLUDED.ticker",
+                    &[
+                        &change.composite_figi.as_ref(),
+                        &change.date,
+                        &change.ticker.as_ref(),
+                    ],
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn find_ticker_changes(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        composite_figi: &str,
+    ) -> io::Result<Vec<io::TickerChange>> {
+        let list = self
+            .client
+            .query(
+                "SELECT composite_figi, date, ticker FROM ticker_changes
+                 WHERE composite_figi = $1 ORDER BY date",
+                &[&composite_figi],
+            )
+            .await?;
+
+        Ok(list
+            .into_iter()
+            .map(|row| io::TickerChange {
+                composite_figi: row.get::<usize, &str>(0).into(),
+                date: row.get::<usize, NaiveDate>(1),
+                ticker: row.get::<usize, &str>(2).into(),
+            })
+            .collect())
+    }
+
+    async fn find_historical_symbol(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        symbol: &str,
+        date: &NaiveDate,
+    ) -> io::Result<Arc<str>> {
+        // The FIGI is of the ticker that is named `symbol` by its latest change, since a
+        // symbol can be reused by another ticker after a change.
+        let row = self
+            .client
+            .query_opt(
+                "WITH latest AS (
+                    SELECT DISTINCT ON (composite_figi) composite_figi, ticker
+                    FROM ticker_changes ORDER BY composite_figi, date DESC
+                 )
+                 SELECT c.ticker FROM ticker_changes c
+                 JOIN latest l ON l.composite_figi = c.composite_figi
+                 WHERE l.ticker = $1
+                 ORDER BY c.date <= $2 DESC,
+                    CASE WHEN c.date <= $2 THEN c.date END DESC,
+                    c.date
+                 LIMIT 1",
+                &[&symbol, &date],
+            )
+            .await?;
+
+        Ok(match row {
+            Some(row) => row.get::<usize, &str>(0).into(),
+            None => symbol.into(),
+        })
+    }
+}
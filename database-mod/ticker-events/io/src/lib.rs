@@ -0,0 +1 @@
+pub mod ticker_events;
@@ -0,0 +1,112 @@
+use std::{collections::HashMap, sync::Arc};
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use snp_mod_io::snp;
+
+/// The data that is needed to interact with the Ticker-events modules, which is the change of
+/// the symbol of a ticker, effective from the date.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TickerChange {
+    /// The composite FIGI, which stays the same through the changes of the symbol.
+    pub composite_figi: Arc<str>,
+    pub date: NaiveDate,
+    pub ticker: Arc<str>,
+}
+
+/// All possible errors of Ticker-events modules.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// An error with an embedded storage engine
+    #[error("{0}")]
+    Db(#[from] tokio_postgres::Error),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The API of Ticker-events, which defines all the functionality of Ticker-events modules.
+#[async_trait]
+pub trait Api {
+    /// Replaces the changes of the same FIGI and date, if any.
+    async fn save_ticker_changes(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        changes: &[TickerChange],
+    ) -> Result<()>;
+
+    /// Returns the changes of the FIGI in ascending order of date.
+    async fn find_ticker_changes(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        composite_figi: &str,
+    ) -> Result<Vec<TickerChange>>;
+
+    /// Returns the symbol on the date of the ticker that is named `symbol` today, e.g. `FB` for
+    /// `META` before June 9, 2022. It's the first symbol before the listing, and the same
+    /// symbol if no change is stored.
+    async fn find_historical_symbol(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        symbol: &str,
+        date: &NaiveDate,
+    ) -> Result<Arc<str>>;
+
+    /// Returns the SnP of the dependencies, where the ticker is named by its symbol on the
+    /// date, so the network modules request it as it was then.
+    async fn historical_snp(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        ticker: &snp::SnP,
+        date: &NaiveDate,
+    ) -> Result<Arc<dyn snp::Api + Send + Sync>> {
+        let snp = deps.provide_snp();
+        let symbol = self
+            .find_historical_symbol(deps, &snp.get_symbol(ticker), date)
+            .await?;
+        Ok(Arc::new(HistoricalSnP {
+            snp,
+            ticker: ticker.clone(),
+            symbol,
+        }))
+    }
+}
+
+/// All the dependencies of the Ticker-events modules to other modules.
+pub trait Depends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync>;
+}
+
+/// Names the ticker by its historical symbol, and the others as the SnP it wraps does.
+struct HistoricalSnP {
+    snp: Arc<dyn snp::Api + Send + Sync>,
+    ticker: snp::SnP,
+    symbol: Arc<str>,
+}
+
+impl snp::Api for HistoricalSnP {
+    fn get_snp(&self, ticker: &str) -> snp::Result<snp::SnP> {
+        if ticker == self.symbol.as_ref() {
+            return Ok(self.ticker.clone());
+        }
+        self.snp.get_snp(ticker)
+    }
+
+    fn get_symbol(&self, snp: &snp::SnP) -> Arc<str> {
+        if *snp == self.ticker {
+            return self.symbol.clone();
+        }
+        self.snp.get_symbol(snp)
+    }
+
+    fn get_snp_map(&self) -> HashMap<snp::SnP, snp::Data> {
+        self.snp.get_snp_map()
+    }
+
+    fn get_snp_by_date(&self, date: &NaiveDate) -> Vec<snp::SnP> {
+        self.snp.get_snp_by_date(date)
+    }
+
+    fn get_all_snp(&self) -> Vec<snp::SnP> {
+        self.snp.get_all_snp()
+    }
+}
@@ -0,0 +1,2 @@
+#[path = "tickers-fixture.rs"]
+pub mod tickers_fixture;
@@ -0,0 +1,25 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use database_mod_tickers_io::tickers as io;
+
+pub struct ServiceFake;
+
+#[async_trait]
+impl io::Api for ServiceFake {
+    async fn save_tickers(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        _tickers: &[io::Ticker],
+    ) -> io::Result<()> {
+        Ok(())
+    }
+
+    async fn find_tickers(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        _filter: &io::Filter,
+    ) -> io::Result<Vec<io::Ticker>> {
+        Ok(vec![])
+    }
+}
@@ -0,0 +1,5 @@
+#[path = "tickers-impl.rs"]
+pub mod tickers_impl;
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,103 @@
+use chrono::{TimeZone, Utc};
+use std::env;
+use std::sync::Arc;
+use tokio_postgres::{Client, NoTls};
+
+use crate::tickers_impl::Service;
+use database_mod_tickers_io::tickers as io;
+use io::Api;
+use snp_mod_fixture::snp_fixture::ServiceFake;
+use snp_mod_io::snp;
+
+#[tokio::test]
+async fn save_to_database_and_find() -> std::result::Result<(), io::Error> {
+    let db = Arc::new(init().await?);
+    let service = Service::new(db);
+    let deps = given_deps();
+
+    let active = io::Ticker {
+        ticker: "ABL".into(),
+        name: "Abacus Life, Inc. Common Stock".into(),
+        market: "stocks".into(),
+        locale: "us".into(),
+        primary_exchange: Some("XNAS".into()),
+        ticker_type: Some("CS".into()),
+        active: true,
+        currency_name: Some("usd".into()),
+        cik: Some("0001814287".into()),
+        composite_figi: None,
+        share_class_figi: None,
+        last_updated_utc: Some(Utc.with_ymd_and_hms(2024, 6, 11, 0, 0, 0).unwrap()),
+        delisted_utc: None,
+    };
+    let mut delisted = active.clone();
+    delisted.name = "Alpha Beta Labs".into();
+    delisted.active = false;
+    delisted.cik = None;
+    delisted.delisted_utc = Some(Utc.with_ymd_and_hms(2003, 1, 2, 5, 0, 0).unwrap());
+
+    service
+        .save_tickers(deps.clone(), std::slice::from_ref(&active))
+        .await?;
+    // The old listing of the same symbol doesn't replace the current one.
+    service.save_tickers(deps.clone(), &[delisted]).await?;
+    let list = service
+        .find_tickers(
+            deps.clone(),
+            &io::Filter {
+                market: Some("stocks".into()),
+                ticker_type: Some("CS".into()),
+                exchange: Some("XNAS".into()),
+                active: Some(true),
+            },
+        )
+        .await?;
+
+    assert_eq!(
+        vec![&active],
+        list.iter()
+            .filter(|t| t.ticker == active.ticker)
+            .collect::<Vec<_>>()
+    );
+
+    Ok(())
+}
+
+async fn init() -> io::Result<Client> {
+    let db_user = env::var("DB_USER").unwrap();
+    let db_password = env::var("DB_PASSWORD").unwrap();
+    let db_port = env::var("DB_PORT").unwrap().parse::<u16>().unwrap();
+    let db_name = env::var("DB_TEST_NAME").unwrap();
+
+    let (client, connection) = tokio_postgres::Config::new()
+        .user(&db_user)
+        .password(&db_password)
+        .host("localhost")
+        .port(db_port)
+        .dbname(&db_name)
+        .connect(NoTls)
+        .await?;
+
+    // Spawn connection
+    tokio::spawn(async move {
+        if let Err(error) = connection.await {
+            eprintln!("Connection error: {}", error);
+        }
+    });
+
+    Ok(client)
+}
+
+fn given_deps() -> Arc<dyn io::Depends + Send + Sync> {
+    Arc::new(DependsFake {})
+}
+
+struct DependsFake {}
+
+impl DependsFake {}
+
+impl io::Depends for DependsFake {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        Arc::new(ServiceFake {})
+    }
+}
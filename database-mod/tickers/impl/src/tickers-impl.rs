@@ -0,0 +1,144 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tokio_postgres::Client;
+
+use database_mod_tickers_io::tickers as io;
+
+/// The service to implement the API of Tickers.
+pub struct Service {
+    client: Arc<Client>,
+}
+
+impl Service {
+    pub fn new(client: Arc<Client>) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl io::Api for Service {
+    async fn save_tickers(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        tickers: &[io::Ticker],
+    ) -> io::Result<()> {
+        let symbols: Vec<&str> = tickers.iter().map(|t| t.ticker.as_ref()).collect();
+        let names: Vec<&str> = tickers.iter().map(|t| t.name.as_ref()).collect();
+        let markets: Vec<&str> = tickers.iter().map(|t| t.market.as_ref()).collect();
+        let locales: Vec<&str> = tickers.iter().map(|t| t.locale.as_ref()).collect();
+        let primary_exchanges: Vec<Option<&str>> = tickers
+            .iter()
+            .map(|t| t.primary_exchange.as_deref())
+            .collect();
+        let ticker_types: Vec<Option<&str>> =
+            tickers.iter().map(|t| t.ticker_type.as_deref()).collect();
+        let actives: Vec<bool> = tickers.iter().map(|t| t.active).collect();
+        let currency_names: Vec<Option<&str>> =
+            tickers.iter().map(|t| t.currency_name.as_deref()).collect();
+        let ciks: Vec<Option<&str>> = tickers.iter().map(|t| t.cik.as_deref()).collect();
+        let composite_figis: Vec<Option<&str>> = tickers
+            .iter()
+            .map(|t| t.composite_figi.as_deref())
+            .collect();
+        let share_class_figis: Vec<Option<&str>> = tickers
+            .iter()
+            .map(|t| t.share_class_figi.as_deref())
+            .collect();
+        let last_updated_utcs: Vec<Option<DateTime<Utc>>> =
+            tickers.iter().map(|t| t.last_updated_utc).collect();
+        let delisted_utcs: Vec<Option<DateTime<Utc>>> =
+            tickers.iter().map(|t| t.delisted_utc).collect();
+
+        // One statement for all the tickers, since a market has thousands of them.
+        self.client
+            .execute(
+                "INSERT INTO tickers (
+                ticker, name, market, locale, primary_exchange, ticker_type, active,
+                currency_name, cik, composite_figi, share_class_figi, last_updated_utc,
+                delisted_utc)
+             SELECT * FROM UNNEST(
+                $1::VARCHAR[], $2::VARCHAR[], $3::VARCHAR[], $4::VARCHAR[], $5::VARCHAR[],
+                $6::VARCHAR[], $7::BOOLEAN[], $8::VARCHAR[], $9::VARCHAR[], $10::VARCHAR[],
+                $11::VARCHAR[], $12::TIMESTAMPTZ[], $13::TIMESTAMPTZ[])
+             ON CONFLICT (ticker, market) DO UPDATE SET
+                name = EXCLUDED.name,
+                locale = EXCLUDED.locale,
+                primary_exchange = EXCLUDED.primary_exchange,
+                ticker_type = EXCLUDED.ticker_type,
+                active = EXCLUDED.active,
+                currency_name = EXCLUDED.currency_name,
+                cik = EXCLUDED.cik,
+                composite_figi = EXCLUDED.composite_figi,
+                share_class_figi = EXCLUDED.share_class_figi,
+                last_updated_utc = EXCLUDED.last_updated_utc,
+                delisted_utc = EXCLUDED.delisted_utc
+             WHERE EXCLUDED.active OR NOT tickers.active",
+                &[
+                    &symbols,
+                    &names,
+                    &markets,
+                    &locales,
+                    &primary_exchanges,
+                    &ticker_types,
+                    &actives,
+                    &currency_names,
+                    &ciks,
+                    &composite_figis,
+                    &share_class_figis,
+                    &last_updated_utcs,
+                    &delisted_utcs,
+                ],
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn find_tickers(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        filter: &io::Filter,
+    ) -> io::Result<Vec<io::Ticker>> {
+        let list = self
+            .client
+            .query(
+                "SELECT ticker, name, market, locale, primary_exchange, ticker_type, active,
+                    currency_name, cik, composite_figi, share_class_figi, last_updated_utc,
+                    delisted_utc
+                 FROM tickers
+                 WHERE ($1::VARCHAR IS NULL OR market = $1)
+                    AND ($2::VARCHAR IS NULL OR ticker_type = $2)
+                    AND ($3::VARCHAR IS NULL OR primary_exchange = $3)
+                    AND ($4::BOOLEAN IS NULL OR active = $4)
+                 ORDER BY ticker, market",
+                &[
+                    &filter.market.as_deref(),
+                    &filter.ticker_type.as_deref(),
+                    &filter.exchange.as_deref(),
+                    &filter.active,
+                ],
+            )
+            .await?;
+
+        Ok(list
+            .into_iter()
+            .map(|row| io::Ticker {
+                ticker: row.get::<usize, &str>(0).into(),
+                name: row.get::<usize, &str>(1).into(),
+                market: row.get::<usize, &str>(2).into(),
+                locale: row.get::<usize, &str>(3).into(),
+                primary_exchange: row.get::<usize, Option<&str>>(4).map(|e| e.into()),
+                ticker_type: row.get::<usize, Option<&str>>(5).map(|t| t.into()),
+                active: row.get::<usize, bool>(6),
+                currency_name: row.get::<usize, Option<&str>>(7).map(|c| c.into()),
+                cik: row.get::<usize, Option<&str>>(8).map(|c| c.into()),
+                composite_figi: row.get::<usize, Option<&str>>(9).map(|f| f.into()),
+                share_class_figi: row.get::<usize, Option<&str>>(10).map(|f| f.into()),
+                last_updated_utc: row.get::<usize, Option<DateTime<Utc>>>(11),
+                delisted_utc: row.get::<usize, Option<DateTime<Utc>>>(12),
+            })
+            .collect())
+    }
+}
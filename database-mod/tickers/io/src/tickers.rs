@@ -0,0 +1,67 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use snp_mod_io::snp;
+
+/// The data that is needed to interact with the Tickers modules, which is a listing of any
+/// market, not only of the S&P 500.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ticker {
+    pub ticker: Arc<str>,
+    pub name: Arc<str>,
+    pub market: Arc<str>,
+    pub locale: Arc<str>,
+    pub primary_exchange: Option<Arc<str>>,
+    pub ticker_type: Option<Arc<str>>,
+    pub active: bool,
+    pub currency_name: Option<Arc<str>>,
+    pub cik: Option<Arc<str>>,
+    pub composite_figi: Option<Arc<str>>,
+    pub share_class_figi: Option<Arc<str>>,
+    pub last_updated_utc: Option<DateTime<Utc>>,
+    pub delisted_utc: Option<DateTime<Utc>>,
+}
+
+/// The filters of the stored tickers to find, where `None` means no filter.
+#[derive(Debug, Clone, Default)]
+pub struct Filter {
+    pub market: Option<Arc<str>>,
+    pub ticker_type: Option<Arc<str>>,
+    pub exchange: Option<Arc<str>>,
+    pub active: Option<bool>,
+}
+
+/// All possible errors of Tickers modules.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// An error with an embedded storage engine
+    #[error("{0}")]
+    Db(#[from] tokio_postgres::Error),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The API of Tickers, which defines all the functionality of Tickers modules.
+#[async_trait]
+pub trait Api {
+    /// Replaces the tickers of the same symbol and market, if any. A delisted ticker doesn't
+    /// replace an active one, since Polygon.io reuses the symbols.
+    async fn save_tickers(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        tickers: &[Ticker],
+    ) -> Result<()>;
+
+    /// Returns the tickers that match the filter in ascending order of ticker.
+    async fn find_tickers(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        filter: &Filter,
+    ) -> Result<Vec<Ticker>>;
+}
+
+/// All the dependencies of the Tickers modules to other modules.
+pub trait Depends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync>;
+}
@@ -0,0 +1,2 @@
+#[path = "trades-fixture.rs"]
+pub mod trades_fixture;
@@ -0,0 +1,46 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use database_mod_trades_io::trades as io;
+use snp_mod_io::snp;
+
+pub struct ServiceFake;
+
+#[async_trait]
+impl io::Api for ServiceFake {
+    async fn save_trades(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        _trades: &[io::Trade],
+    ) -> io::Result<()> {
+        Ok(())
+    }
+
+    async fn find_trades(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        _snp: &snp::SnP,
+        _from: i64,
+        _to: i64,
+    ) -> io::Result<Vec<io::Trade>> {
+        Ok(vec![])
+    }
+
+    async fn save_cursor(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        _cursor: &io::Cursor,
+    ) -> io::Result<()> {
+        Ok(())
+    }
+
+    async fn find_cursor(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        _snp: &snp::SnP,
+        _date: &NaiveDate,
+    ) -> io::Result<Option<io::Cursor>> {
+        Ok(None)
+    }
+}
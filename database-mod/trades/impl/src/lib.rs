@@ -0,0 +1,5 @@
+#[path = "trades-impl.rs"]
+pub mod trades_impl;
+
+#[cfg(test)]
+mod test;
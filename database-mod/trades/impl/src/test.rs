@@ -0,0 +1,137 @@
+use chrono::NaiveDate;
+use std::env;
+use std::sync::Arc;
+use tokio_postgres::{Client, NoTls};
+
+use crate::trades_impl::Service;
+use database_mod_trades_io::trades as io;
+use io::Api;
+use snp_mod_fixture::snp_fixture::ServiceFake;
+use snp_mod_io::snp;
+
+#[tokio::test]
+async fn save_to_database_and_find() -> std::result::Result<(), io::Error> {
+    let db = Arc::new(init().await?);
+    let service = Service::new(db);
+    let deps = given_deps();
+
+    let sip_timestamp = 1710403200010698000;
+    let mut reported = given_trade(sip_timestamp + 1000, 1064);
+    reported.correction = Some(0);
+    reported.trf_id = Some(201);
+    reported.trf_timestamp = Some(sip_timestamp + 500);
+    let trades = vec![given_trade(sip_timestamp, 1063), reported];
+    let mut changed = trades[0].clone();
+    changed.price = 1.0;
+    // The stream sends the same trade with a timestamp in milliseconds.
+    let streamed = given_trade(sip_timestamp / 1_000_000 * 1_000_000, 1063);
+
+    service.save_trades(deps.clone(), &trades).await?;
+    // The trades are stored once, even if a page is requested again, or streamed.
+    service
+        .save_trades(deps.clone(), &[changed, streamed])
+        .await?;
+    let list = service
+        .find_trades(
+            deps.clone(),
+            &snp::SnP::AAPL,
+            sip_timestamp,
+            sip_timestamp + 1_000_000,
+        )
+        .await?;
+
+    assert_eq!(trades, list);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn save_cursor_to_database_and_find() -> std::result::Result<(), io::Error> {
+    let db = Arc::new(init().await?);
+    let service = Service::new(db);
+    let deps = given_deps();
+    let date = NaiveDate::from_ymd_opt(2024, 3, 14).unwrap();
+
+    let cursor = io::Cursor {
+        snp: snp::SnP::AAPL,
+        date,
+        next_url: Some("https://api.polygon.io/v3/trades/AAPL?cursor=YXA9MiZhcz0".into()),
+        last_sequence_number: Some(1064),
+    };
+    let last = io::Cursor {
+        next_url: None,
+        last_sequence_number: Some(1081),
+        ..cursor.clone()
+    };
+
+    service.save_cursor(deps.clone(), &cursor).await?;
+    let found = service
+        .find_cursor(deps.clone(), &snp::SnP::AAPL, &date)
+        .await?;
+    assert_eq!(Some(cursor), found);
+
+    service.save_cursor(deps.clone(), &last).await?;
+    let found = service
+        .find_cursor(deps.clone(), &snp::SnP::AAPL, &date)
+        .await?;
+    assert_eq!(Some(last), found);
+
+    Ok(())
+}
+
+fn given_trade(sip_timestamp: i64, sequence_number: i64) -> io::Trade {
+    io::Trade {
+        snp: snp::SnP::AAPL,
+        sip_timestamp,
+        sequence_number,
+        polygon_id: sequence_number.to_string().into(),
+        price: 172.5,
+        size: 100,
+        exchange: 11,
+        conditions: vec![12, 37],
+        correction: None,
+        participant_timestamp: Some(sip_timestamp - 336000),
+        tape: Some(3),
+        trf_id: None,
+        trf_timestamp: None,
+    }
+}
+
+async fn init() -> io::Result<Client> {
+    let db_user = env::var("DB_USER").unwrap();
+    let db_password = env::var("DB_PASSWORD").unwrap();
+    let db_port = env::var("DB_PORT").unwrap().parse::<u16>().unwrap();
+    let db_name = env::var("DB_TEST_NAME").unwrap();
+
+    let (client, connection) = tokio_postgres::Config::new()
+        .user(&db_user)
+        .password(&db_password)
+        .host("localhost")
+        .port(db_port)
+        .dbname(&db_name)
+        .connect(NoTls)
+        .await?;
+
+    // Spawn connection
+    tokio::spawn(async move {
+        if let Err(error) = connection.await {
+            eprintln!("Connection error: {}", error);
+        }
+    });
+
+    Ok(client)
+}
+
+fn given_deps() -> Arc<dyn io::Depends + Send + Sync> {
+    Arc::new(DependsFake {})
+}
+
+struct DependsFake {}
+
+impl DependsFake {}
+
+impl io::Depends for DependsFake {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        Arc::new(ServiceFake {})
+    }
+}
@@ -0,0 +1,186 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use tokio_postgres::Client;
+
+use database_mod_trades_io::trades as io;
+use snp_mod_io::snp;
+
+/// The service to implement the API of Trades.
+pub struct Service {
+    client: Arc<Client>,
+}
+
+impl Service {
+    pub fn new(client: Arc<Client>) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl io::Api for Service {
+    async fn save_trades(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        trades: &[io::Trade],
+    ) -> io::Result<()> {
+        let snp = deps.provide_snp();
+        let tickers: Vec<String> = trades
+            .iter()
+            .map(|t| snp.get_symbol(&t.snp).to_string())
+            .collect();
+        let sip_timestamps: Vec<i64> = trades.iter().map(|t| t.sip_timestamp).collect();
+        let sequence_numbers: Vec<i64> = trades.iter().map(|t| t.sequence_number).collect();
+        let polygon_ids: Vec<&str> = trades.iter().map(|t| t.polygon_id.as_ref()).collect();
+        let prices: Vec<f64> = trades.iter().map(|t| t.price).collect();
+        let sizes: Vec<i32> = trades.iter().map(|t| t.size).collect();
+        let exchanges: Vec<i32> = trades.iter().map(|t| t.exchange).collect();
+        // Postgres has no array of arrays with different lengths, so each list of conditions
+        // goes as an array literal, like `{1,2}`.
+        let conditions: Vec<String> = trades
+            .iter()
+            .map(|t| {
+                let codes: Vec<String> = t.conditions.iter().map(|c| c.to_string()).collect();
+                format!("{{{}}}", codes.join(","))
+            })
+            .collect();
+        let corrections: Vec<Option<i32>> = trades.iter().map(|t| t.correction).collect();
+        let participant_timestamps: Vec<Option<i64>> =
+            trades.iter().map(|t| t.participant_timestamp).collect();
+        let tapes: Vec<Option<i32>> = trades.iter().map(|t| t.tape).collect();
+        let trf_ids: Vec<Option<i32>> = trades.iter().map(|t| t.trf_id).collect();
+        let trf_timestamps: Vec<Option<i64>> = trades.iter().map(|t| t.trf_timestamp).collect();
+
+        self.client
+            .execute(
+                "INSERT INTO trades (
+                ticker, sip_timestamp, sequence_number, polygon_id, price, size, exchange,
+                conditions, correction, participant_timestamp, tape, trf_id, trf_timestamp)
+             SELECT ticker, sip_timestamp, sequence_number, polygon_id, price, size, exchange,
+                conditions::INT[], correction, participant_timestamp, tape, trf_id, trf_timestamp
+             FROM UNNEST(
+                $1::VARCHAR[], $2::BIGINT[], $3::BIGINT[], $4::VARCHAR[], $5::FLOAT8[],
+                $6::INT[], $7::INT[], $8::TEXT[], $9::INT[], $10::BIGINT[], $11::INT[],
+                $12::INT[], $13::BIGINT[])
+             AS t(ticker, sip_timestamp, sequence_number, polygon_id, price, size, exchange,
+                conditions, correction, participant_timestamp, tape, trf_id, trf_timestamp)
+             ON CONFLICT (ticker, date, sequence_number) DO NOTHING",
+                &[
+                    &tickers,
+                    &sip_timestamps,
+                    &sequence_numbers,
+                    &polygon_ids,
+                    &prices,
+                    &sizes,
+                    &exchanges,
+                    &conditions,
+                    &corrections,
+                    &participant_timestamps,
+                    &tapes,
+                    &trf_ids,
+                    &trf_timestamps,
+                ],
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn find_trades(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        snp: &snp::SnP,
+        from: i64,
+        to: i64,
+    ) -> io::Result<Vec<io::Trade>> {
+        let snp_api = deps.provide_snp();
+        let snp_symbol_rc = snp_api.get_symbol(snp).clone();
+        let snp_symbol = &snp_symbol_rc[..];
+
+        let list = self
+            .client
+            .query(
+                "SELECT sip_timestamp, sequence_number, polygon_id, price, size, exchange,
+                    conditions, correction, participant_timestamp, tape, trf_id, trf_timestamp
+                 FROM trades WHERE ticker = $1 AND sip_timestamp >= $2 AND sip_timestamp < $3
+                 ORDER BY sip_timestamp, sequence_number",
+                &[&snp_symbol, &from, &to],
+            )
+            .await?;
+
+        Ok(list
+            .into_iter()
+            .map(|row| io::Trade {
+                snp: snp.clone(),
+                sip_timestamp: row.get::<usize, i64>(0),
+                sequence_number: row.get::<usize, i64>(1),
+                polygon_id: row.get::<usize, &str>(2).into(),
+                price: row.get::<usize, f64>(3),
+                size: row.get::<usize, i32>(4),
+                exchange: row.get::<usize, i32>(5),
+                conditions: row.get::<usize, Vec<i32>>(6),
+                correction: row.get::<usize, Option<i32>>(7),
+                participant_timestamp: row.get::<usize, Option<i64>>(8),
+                tape: row.get::<usize, Option<i32>>(9),
+                trf_id: row.get::<usize, Option<i32>>(10),
+                trf_timestamp: row.get::<usize, Option<i64>>(11),
+            })
+            .collect())
+    }
+
+    async fn save_cursor(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        cursor: &io::Cursor,
+    ) -> io::Result<()> {
+        let snp = deps.provide_snp();
+        let snp_symbol_rc = snp.get_symbol(&cursor.snp).clone();
+        let snp_symbol = &snp_symbol_rc[..];
+
+        self.client
+            .execute(
+                "INSERT INTO trades_cursors (ticker, date, next_url, last_sequence_number)
+                 VALUES ($1, $2, $3, $4)
+                 ON CONFLICT (ticker, date) DO UPDATE SET
+                    next_url = EXCLUDED.next_url,
+                    last_sequence_number = EXCLUDED.last_sequence_number",
+                &[
+                    &snp_symbol,
+                    &cursor.date,
+                    &cursor.next_url.as_deref(),
+                    &cursor.last_sequence_number,
+                ],
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn find_cursor(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        snp: &snp::SnP,
+        date: &NaiveDate,
+    ) -> io::Result<Option<io::Cursor>> {
+        let snp_api = deps.provide_snp();
+        let snp_symbol_rc = snp_api.get_symbol(snp).clone();
+        let snp_symbol = &snp_symbol_rc[..];
+
+        let row = self
+            .client
+            .query_opt(
+                "SELECT next_url, last_sequence_number
+                 FROM trades_cursors WHERE ticker = $1 AND date = $2",
+                &[&snp_symbol, date],
+            )
+            .await?;
+
+        Ok(row.map(|row| io::Cursor {
+            snp: snp.clone(),
+            date: *date,
+            next_url: row.get::<usize, Option<&str>>(0).map(|u| u.into()),
+            last_sequence_number: row.get::<usize, Option<i64>>(1),
+        }))
+    }
+}
@@ -0,0 +1,91 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use snp_mod_io::snp;
+
+/// The data that is needed to interact with the Trades modules, where the exchanges and the
+/// conditions are the IDs of the `exchanges` and the `trade_conditions` tables.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Trade {
+    pub snp: snp::SnP,
+    /// The nanosecond Unix timestamp of when the SIP received the trade.
+    pub sip_timestamp: i64,
+    pub sequence_number: i64,
+    pub polygon_id: Arc<str>,
+    pub price: f64,
+    pub size: i32,
+    pub exchange: i32,
+    pub conditions: Vec<i32>,
+    pub correction: Option<i32>,
+    pub participant_timestamp: Option<i64>,
+    pub tape: Option<i32>,
+    pub trf_id: Option<i32>,
+    pub trf_timestamp: Option<i64>,
+}
+
+/// The progress of crawling the trades of a ticker in a day.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cursor {
+    pub snp: snp::SnP,
+    pub date: NaiveDate,
+    /// The URL of the next page without the `apiKey`, where `None` means all the pages are
+    /// stored.
+    pub next_url: Option<Arc<str>>,
+    /// The greatest sequence number of the stored trades, if any.
+    pub last_sequence_number: Option<i64>,
+}
+
+/// All possible errors of Trades modules.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// An error with an embedded storage engine
+    #[error("{0}")]
+    Db(#[from] tokio_postgres::Error),
+    #[error("{0}")]
+    SnP(#[from] snp::Error),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The API of Trades, which defines all the functionality of Trades modules.
+#[async_trait]
+pub trait Api {
+    /// Skips the trades of the same ticker, New York date and sequence number, which are stored
+    /// before. So a trade of the stream, with a timestamp in milliseconds, and the same trade of
+    /// the REST or the flat files, in nanoseconds, are stored once, by the first one.
+    async fn save_trades(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        trades: &[Trade],
+    ) -> Result<()>;
+
+    /// Returns the trades with SIP timestamp in `[from, to)` nanoseconds, in ascending order.
+    async fn find_trades(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        snp: &snp::SnP,
+        from: i64,
+        to: i64,
+    ) -> Result<Vec<Trade>>;
+
+    /// Replaces the cursor of the same ticker and date, if any.
+    async fn save_cursor(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        cursor: &Cursor,
+    ) -> Result<()>;
+
+    /// Returns the cursor of the ticker and date, where `None` means no page is stored.
+    async fn find_cursor(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        snp: &snp::SnP,
+        date: &NaiveDate,
+    ) -> Result<Option<Cursor>>;
+}
+
+/// All the dependencies of the Trades modules to other modules.
+pub trait Depends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync>;
+}
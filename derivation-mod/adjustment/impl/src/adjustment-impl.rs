@@ -0,0 +1,141 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use chrono_tz::America::New_York;
+use snp_mod_io::snp;
+
+use database_mod_aggregates_io::aggregates as aggregates_database;
+use database_mod_splits_io::splits as splits_database;
+use database_mod_ticker_details_io::ticker_details as details_database;
+use derivation_mod_adjustment_io::adjustment as io;
+
+#[derive(Debug)]
+pub struct Service;
+
+/// The service to implement the API of Adjustment.
+impl Service {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Default for Service {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl io::Api for Service {
+    async fn get_adjusted_shares(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        snp: &snp::SnP,
+        from: &NaiveDate,
+        to: &NaiveDate,
+        as_of: &NaiveDate,
+    ) -> io::Result<Vec<io::Shares>> {
+        let database_depends = Arc::new(DatabaseDepends {
+            snp: deps.provide_snp(),
+        });
+        let splits = deps
+            .provide_database_splits()
+            .find_splits(database_depends.clone(), snp)
+            .await?;
+        let details = deps
+            .provide_database_ticker_details()
+            .find_ticker_details_between(database_depends, snp, from, to)
+            .await?;
+
+        Ok(details
+            .into_iter()
+            .map(|d| {
+                let factor = split_factor(&splits, &d.date, as_of);
+                io::Shares {
+                    date: d.date,
+                    share_class_shares_outstanding: d.share_class_shares_outstanding as f64
+                        * factor,
+                    weighted_shares_outstanding: d.weighted_shares_outstanding as f64 * factor,
+                }
+            })
+            .collect())
+    }
+
+    async fn get_adjusted_bars(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        snp: &snp::SnP,
+        timespan: aggregates_database::Timespan,
+        from: &DateTime<Utc>,
+        to: &DateTime<Utc>,
+        as_of: &NaiveDate,
+    ) -> io::Result<Vec<io::Bar>> {
+        let database_depends = Arc::new(DatabaseDepends {
+            snp: deps.provide_snp(),
+        });
+        let splits = deps
+            .provide_database_splits()
+            .find_splits(database_depends.clone(), snp)
+            .await?;
+        let bars = deps
+            .provide_database_aggregates()
+            .find_bars(database_depends, snp, timespan, from, to)
+            .await?;
+
+        Ok(bars
+            .into_iter()
+            .map(|b| {
+                // Polygon.io starts the bars of a day at midnight in New York.
+                let date = b.start.with_timezone(&New_York).date_naive();
+                let factor = split_factor(&splits, &date, as_of);
+                io::Bar {
+                    start: b.start,
+                    open: b.open / factor,
+                    high: b.high / factor,
+                    low: b.low / factor,
+                    close: b.close / factor,
+                    volume: b.volume * factor,
+                    vwap: b.vwap.map(|v| v / factor),
+                }
+            })
+            .collect())
+    }
+}
+
+/// The number of `as_of` shares for each share of `date`. A split executes at the open of its
+/// execution date, so it's already applied to the values of that date.
+pub fn split_factor(splits: &[splits_database::Split], date: &NaiveDate, as_of: &NaiveDate) -> f64 {
+    splits.iter().fold(1.0, |factor, split| {
+        let ratio = split.split_to / split.split_from;
+        if *date < split.execution_date && split.execution_date <= *as_of {
+            factor * ratio
+        } else if *as_of < split.execution_date && split.execution_date <= *date {
+            factor / ratio
+        } else {
+            factor
+        }
+    })
+}
+
+struct DatabaseDepends {
+    snp: Arc<dyn snp::Api + Send + Sync>,
+}
+
+impl details_database::Depends for DatabaseDepends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        self.snp.clone()
+    }
+}
+
+impl splits_database::Depends for DatabaseDepends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        self.snp.clone()
+    }
+}
+
+impl aggregates_database::Depends for DatabaseDepends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        self.snp.clone()
+    }
+}
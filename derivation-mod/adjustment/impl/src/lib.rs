@@ -0,0 +1,5 @@
+#[path = "adjustment-impl.rs"]
+pub mod adjustment_impl;
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,283 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+use rstest::rstest;
+
+use crate::adjustment_impl::{split_factor, Service};
+use database_mod_aggregates_io::aggregates as aggregates_database;
+use database_mod_splits_io::splits as splits_database;
+use database_mod_ticker_details_io::ticker_details as details_database;
+use derivation_mod_adjustment_io::adjustment as io;
+use io::Api;
+use snp_mod_fixture::snp_fixture::ServiceFake as SnPServiceFake;
+use snp_mod_io::snp;
+
+#[rstest]
+#[case(snp::SnP::AAPL, (2020, 8, 28), (2020, 8, 31), 4.0)]
+#[case(snp::SnP::AAPL, (2020, 8, 31), (2020, 8, 31), 1.0)]
+#[case(snp::SnP::AAPL, (2014, 6, 6), (2024, 1, 2), 28.0)]
+#[case(snp::SnP::AAPL, (2020, 9, 1), (2020, 8, 28), 0.25)]
+#[case(snp::SnP::NVDA, (2024, 6, 7), (2024, 6, 10), 10.0)]
+#[case(snp::SnP::NVDA, (2021, 7, 19), (2024, 6, 10), 40.0)]
+fn split_factor_of_known_splits(
+    #[case] snp: snp::SnP,
+    #[case] date: (i32, u32, u32),
+    #[case] as_of: (i32, u32, u32),
+    #[case] factor: f64,
+) {
+    let splits = given_splits(&snp);
+
+    assert_eq!(factor, split_factor(&splits, &ymd(date), &ymd(as_of)));
+}
+
+#[tokio::test]
+async fn adjust_aapl_shares_over_2020_split() -> io::Result<()> {
+    let service = Service::new();
+
+    let shares = service
+        .get_adjusted_shares(
+            given_deps(),
+            &snp::SnP::AAPL,
+            &ymd((2020, 8, 28)),
+            &ymd((2020, 8, 31)),
+            &ymd((2024, 1, 2)),
+        )
+        .await?;
+
+    assert_eq!(2, shares.len());
+    assert_eq!(17102536000.0, shares[0].share_class_shares_outstanding);
+    assert_eq!(17102536000.0, shares[1].share_class_shares_outstanding);
+    assert_eq!(
+        shares[0].weighted_shares_outstanding,
+        shares[1].weighted_shares_outstanding
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn adjust_nvda_bars_over_2024_split() -> io::Result<()> {
+    let service = Service::new();
+
+    let bars = service
+        .get_adjusted_bars(
+            given_deps(),
+            &snp::SnP::NVDA,
+            aggregates_database::Timespan::Day,
+            &Utc.with_ymd_and_hms(2024, 6, 7, 0, 0, 0).unwrap(),
+            &Utc.with_ymd_and_hms(2024, 6, 11, 0, 0, 0).unwrap(),
+            &ymd((2024, 6, 10)),
+        )
+        .await?;
+
+    assert_eq!(2, bars.len());
+    assert!((bars[0].close - 120.888).abs() < 1e-9, "{:?}", bars[0]);
+    assert_eq!(412385170.0, bars[0].volume);
+    assert_eq!(121.79, bars[1].close);
+    assert_eq!(314162665.0, bars[1].volume);
+
+    // As of the day before, the later bar goes back to the price before the split.
+    let bars = service
+        .get_adjusted_bars(
+            given_deps(),
+            &snp::SnP::NVDA,
+            aggregates_database::Timespan::Day,
+            &Utc.with_ymd_and_hms(2024, 6, 7, 0, 0, 0).unwrap(),
+            &Utc.with_ymd_and_hms(2024, 6, 11, 0, 0, 0).unwrap(),
+            &ymd((2024, 6, 7)),
+        )
+        .await?;
+
+    assert_eq!(1208.88, bars[0].close);
+    assert!((bars[1].close - 1217.9).abs() < 1e-9, "{:?}", bars[1]);
+    Ok(())
+}
+
+fn ymd((year, month, day): (i32, u32, u32)) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+fn given_splits(snp: &snp::SnP) -> Vec<splits_database::Split> {
+    let splits: &[((i32, u32, u32), f64, f64)] = match snp {
+        snp::SnP::AAPL => &[
+            ((1987, 6, 16), 1.0, 2.0),
+            ((2000, 6, 21), 1.0, 2.0),
+            ((2005, 2, 28), 1.0, 2.0),
+            ((2014, 6, 9), 1.0, 7.0),
+            ((2020, 8, 31), 1.0, 4.0),
+        ],
+        snp::SnP::NVDA => &[
+            ((2000, 6, 27), 1.0, 2.0),
+            ((2001, 9, 17), 1.0, 2.0),
+            ((2006, 4, 7), 1.0, 2.0),
+            ((2007, 9, 11), 2.0, 3.0),
+            ((2021, 7, 20), 1.0, 4.0),
+            ((2024, 6, 10), 1.0, 10.0),
+        ],
+        _ => &[],
+    };
+    splits
+        .iter()
+        .map(|(date, split_from, split_to)| splits_database::Split {
+            polygon_id: format!("{:?}-{:?}", snp, date).into(),
+            snp: snp.clone(),
+            execution_date: ymd(*date),
+            split_from: *split_from,
+            split_to: *split_to,
+        })
+        .collect()
+}
+
+fn given_deps() -> Arc<dyn io::Depends + Send + Sync> {
+    Arc::new(DependsFake {})
+}
+
+struct DependsFake {}
+
+impl io::Depends for DependsFake {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        Arc::new(SnPServiceFake {})
+    }
+
+    fn provide_database_ticker_details(&self) -> Arc<dyn details_database::Api + Send + Sync> {
+        Arc::new(DetailsFake {})
+    }
+
+    fn provide_database_splits(&self) -> Arc<dyn splits_database::Api + Send + Sync> {
+        Arc::new(SplitsFake {})
+    }
+
+    fn provide_database_aggregates(&self) -> Arc<dyn aggregates_database::Api + Send + Sync> {
+        Arc::new(AggregatesFake {})
+    }
+}
+
+struct SplitsFake;
+
+#[async_trait]
+impl splits_database::Api for SplitsFake {
+    async fn save_splits(
+        &self,
+        _deps: Arc<dyn splits_database::Depends + Send + Sync>,
+        _splits: &[splits_database::Split],
+    ) -> splits_database::Result<()> {
+        Ok(())
+    }
+
+    async fn find_splits(
+        &self,
+        _deps: Arc<dyn splits_database::Depends + Send + Sync>,
+        snp: &snp::SnP,
+    ) -> splits_database::Result<Vec<splits_database::Split>> {
+        Ok(given_splits(snp))
+    }
+}
+
+/// Knows the AAPL share counts around its 2020 split.
+struct DetailsFake;
+
+#[async_trait]
+impl details_database::Api for DetailsFake {
+    async fn save_ticker_details(
+        &self,
+        _deps: Arc<dyn details_database::Depends + Send + Sync>,
+        _details: &details_database::Details,
+    ) -> details_database::Result<()> {
+        Ok(())
+    }
+
+    async fn find_ticker_details(
+        &self,
+        _deps: Arc<dyn details_database::Depends + Send + Sync>,
+        _date: &NaiveDate,
+        _snp: &snp::SnP,
+    ) -> details_database::Result<details_database::Details> {
+        Err(details_database::Error::NotFound)
+    }
+
+    async fn find_ticker_details_between(
+        &self,
+        _deps: Arc<dyn details_database::Depends + Send + Sync>,
+        snp: &snp::SnP,
+        _from: &NaiveDate,
+        _to: &NaiveDate,
+    ) -> details_database::Result<Vec<details_database::Details>> {
+        let details = |date, shares| details_database::Details {
+            active: true,
+            date: ymd(date),
+            snp: snp.clone(),
+            share_class_shares_outstanding: shares,
+            total_employees: 147000,
+            weighted_shares_outstanding: shares,
+        };
+        Ok(vec![
+            details((2020, 8, 28), 4275634000),
+            details((2020, 8, 31), 17102536000),
+        ])
+    }
+}
+
+/// Knows the NVDA daily bars around its 2024 split.
+struct AggregatesFake;
+
+#[async_trait]
+impl aggregates_database::Api for AggregatesFake {
+    async fn save_bars(
+        &self,
+        _deps: Arc<dyn aggregates_database::Depends + Send + Sync>,
+        _bars: &[aggregates_database::Bar],
+    ) -> aggregates_database::Result<()> {
+        Ok(())
+    }
+
+    async fn find_bars(
+        &self,
+        _deps: Arc<dyn aggregates_database::Depends + Send + Sync>,
+        snp: &snp::SnP,
+        timespan: aggregates_database::Timespan,
+        _from: &DateTime<Utc>,
+        _to: &DateTime<Utc>,
+    ) -> aggregates_database::Result<Vec<aggregates_database::Bar>> {
+        let bar = |start, close, volume| aggregates_database::Bar {
+            snp: snp.clone(),
+            timespan,
+            start,
+            open: close,
+            high: close,
+            low: close,
+            close,
+            volume,
+            vwap: None,
+            transactions: None,
+        };
+        // Midnight in New York, during daylight saving time.
+        Ok(vec![
+            bar(
+                Utc.with_ymd_and_hms(2024, 6, 7, 4, 0, 0).unwrap(),
+                1208.88,
+                41238517.0,
+            ),
+            bar(
+                Utc.with_ymd_and_hms(2024, 6, 10, 4, 0, 0).unwrap(),
+                121.79,
+                314162665.0,
+            ),
+        ])
+    }
+
+    async fn save_fetch(
+        &self,
+        _deps: Arc<dyn aggregates_database::Depends + Send + Sync>,
+        _fetch: &aggregates_database::Fetch,
+    ) -> aggregates_database::Result<()> {
+        Ok(())
+    }
+
+    async fn is_fetched(
+        &self,
+        _deps: Arc<dyn aggregates_database::Depends + Send + Sync>,
+        _fetch: &aggregates_database::Fetch,
+    ) -> aggregates_database::Result<bool> {
+        Ok(false)
+    }
+}
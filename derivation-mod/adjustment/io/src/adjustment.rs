@@ -0,0 +1,76 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use database_mod_aggregates_io::aggregates as aggregates_database;
+use database_mod_splits_io::splits as splits_database;
+use database_mod_ticker_details_io::ticker_details as details_database;
+use snp_mod_io::snp;
+
+/// The share counts of a date, in the shares of the `as_of` date.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Shares {
+    pub date: NaiveDate,
+    pub share_class_shares_outstanding: f64,
+    pub weighted_shares_outstanding: f64,
+}
+
+/// A bar in the prices and volume of the `as_of` date.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bar {
+    pub start: DateTime<Utc>,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub volume: f64,
+    pub vwap: Option<f64>,
+}
+
+/// All possible errors of Adjustment modules.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("{0}")]
+    Details(#[from] details_database::Error),
+    #[error("{0}")]
+    Splits(#[from] splits_database::Error),
+    #[error("{0}")]
+    Aggregates(#[from] aggregates_database::Error),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The API of Adjustment, which defines all the functionality of Adjustment modules. Each value
+/// is adjusted by the splits executed between its date and `as_of`, so a series is comparable
+/// to the values of `as_of`. An `as_of` before the values reverses the adjustment.
+#[async_trait]
+pub trait Api {
+    /// Returns the stored share counts from `from` to `to`, inclusive.
+    async fn get_adjusted_shares(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        snp: &snp::SnP,
+        from: &NaiveDate,
+        to: &NaiveDate,
+        as_of: &NaiveDate,
+    ) -> Result<Vec<Shares>>;
+
+    /// Returns the stored bars starting in `[from, to)`.
+    async fn get_adjusted_bars(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        snp: &snp::SnP,
+        timespan: aggregates_database::Timespan,
+        from: &DateTime<Utc>,
+        to: &DateTime<Utc>,
+        as_of: &NaiveDate,
+    ) -> Result<Vec<Bar>>;
+}
+
+/// All the dependencies of the Adjustment modules to other modules.
+pub trait Depends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync>;
+    fn provide_database_ticker_details(&self) -> Arc<dyn details_database::Api + Send + Sync>;
+    fn provide_database_splits(&self) -> Arc<dyn splits_database::Api + Send + Sync>;
+    fn provide_database_aggregates(&self) -> Arc<dyn aggregates_database::Api + Send + Sync>;
+}
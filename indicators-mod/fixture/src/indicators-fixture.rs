@@ -0,0 +1,28 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use database_mod_aggregates_io::aggregates;
+use indicators_mod_io::indicators as io;
+
+/// Computes nothing, as if there's no stored bar.
+pub struct ServiceFake;
+
+#[async_trait]
+impl io::Api for ServiceFake {
+    fn compute(
+        &self,
+        _bars: &[aggregates::Bar],
+        _indicator: &io::Indicator,
+        _series_type: io::SeriesType,
+    ) -> Vec<io::Value> {
+        vec![]
+    }
+
+    async fn get_indicator(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        _request: &io::Request,
+    ) -> io::Result<Vec<io::Value>> {
+        Ok(vec![])
+    }
+}
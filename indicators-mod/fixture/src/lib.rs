@@ -0,0 +1,2 @@
+#[path = "indicators-fixture.rs"]
+pub mod indicators_fixture;
@@ -0,0 +1,118 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use database_mod_aggregates_io::aggregates;
+use snp_mod_io::snp;
+
+use crate::indicators_math;
+use indicators_mod_io::indicators as io;
+
+/// A value with its optional signal and histogram.
+type Line = (f64, Option<f64>, Option<f64>);
+
+/// The service to implement the API of Indicators.
+#[derive(Debug, Default)]
+pub struct Service;
+
+impl Service {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[async_trait]
+impl io::Api for Service {
+    fn compute(
+        &self,
+        bars: &[aggregates::Bar],
+        indicator: &io::Indicator,
+        series_type: io::SeriesType,
+    ) -> Vec<io::Value> {
+        let series: Vec<f64> = bars
+            .iter()
+            .map(|b| match series_type {
+                io::SeriesType::Open => b.open,
+                io::SeriesType::High => b.high,
+                io::SeriesType::Low => b.low,
+                io::SeriesType::Close => b.close,
+            })
+            .collect();
+
+        let lines: Vec<Option<Line>> = match *indicator {
+            io::Indicator::Sma { window } => {
+                to_lines(indicators_math::sma(&series, window as usize))
+            }
+            io::Indicator::Ema { window } => {
+                to_lines(indicators_math::ema(&series, window as usize))
+            }
+            io::Indicator::Rsi { window } => {
+                to_lines(indicators_math::rsi(&series, window as usize))
+            }
+            io::Indicator::Macd {
+                short_window,
+                long_window,
+                signal_window,
+            } => indicators_math::macd(
+                &series,
+                short_window as usize,
+                long_window as usize,
+                signal_window as usize,
+            )
+            .into_iter()
+            .map(|l| l.map(|(value, signal, histogram)| (value, Some(signal), Some(histogram))))
+            .collect(),
+        };
+
+        bars.iter()
+            .zip(lines)
+            .filter_map(|(bar, line)| {
+                let (value, signal, histogram) = line?;
+                Some(io::Value {
+                    timestamp: bar.start,
+                    value,
+                    signal,
+                    histogram,
+                })
+            })
+            .collect()
+    }
+
+    async fn get_indicator(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        request: &io::Request,
+    ) -> io::Result<Vec<io::Value>> {
+        let database_depends = Arc::new(DatabaseDepends {
+            snp: deps.provide_snp(),
+        });
+        let bars = deps
+            .provide_database_aggregates()
+            .find_bars(
+                database_depends,
+                &request.ticker,
+                request.timespan,
+                &request.from,
+                &request.to,
+            )
+            .await?;
+
+        Ok(self.compute(&bars, &request.indicator, request.series_type))
+    }
+}
+
+fn to_lines(values: Vec<Option<f64>>) -> Vec<Option<Line>> {
+    values
+        .into_iter()
+        .map(|v| v.map(|value| (value, None, None)))
+        .collect()
+}
+
+struct DatabaseDepends {
+    snp: Arc<dyn snp::Api + Send + Sync>,
+}
+
+impl aggregates::Depends for DatabaseDepends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        self.snp.clone()
+    }
+}
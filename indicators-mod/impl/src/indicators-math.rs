@@ -0,0 +1,93 @@
+/// Returns the simple moving averages, where the one at `i` is of the `window` values up to
+/// `i`, or `None` before the window is filled.
+pub fn sma(values: &[f64], window: usize) -> Vec<Option<f64>> {
+    if window == 0 {
+        return vec![None; values.len()];
+    }
+    let mut result = vec![None; values.len()];
+    let mut sum = 0.0;
+    for (i, value) in values.iter().enumerate() {
+        sum += value;
+        if i >= window {
+            sum -= values[i - window];
+        }
+        if i + 1 >= window {
+            result[i] = Some(sum / window as f64);
+        }
+    }
+    result
+}
+
+/// Returns the exponential moving averages with the smoothing of `2 / (window + 1)`, which
+/// starts by the simple moving average of the first window.
+pub fn ema(values: &[f64], window: usize) -> Vec<Option<f64>> {
+    let mut result = vec![None; values.len()];
+    if window == 0 || values.len() < window {
+        return result;
+    }
+    let alpha = 2.0 / (window as f64 + 1.0);
+    let mut average = values[..window].iter().sum::<f64>() / window as f64;
+    result[window - 1] = Some(average);
+    for i in window..values.len() {
+        average = alpha * values[i] + (1.0 - alpha) * average;
+        result[i] = Some(average);
+    }
+    result
+}
+
+/// Returns the relative strength indexes by the smoothing of Wilder, which starts by the
+/// simple averages of the gains and the losses of the first window of changes.
+pub fn rsi(values: &[f64], window: usize) -> Vec<Option<f64>> {
+    let mut result = vec![None; values.len()];
+    if window == 0 || values.len() <= window {
+        return result;
+    }
+    let changes: Vec<f64> = values.windows(2).map(|w| w[1] - w[0]).collect();
+    let mut gain = changes[..window].iter().map(|c| c.max(0.0)).sum::<f64>() / window as f64;
+    let mut loss = changes[..window].iter().map(|c| (-c).max(0.0)).sum::<f64>() / window as f64;
+    result[window] = Some(to_rsi(gain, loss));
+    for (i, change) in changes.iter().enumerate().skip(window) {
+        gain = (gain * (window - 1) as f64 + change.max(0.0)) / window as f64;
+        loss = (loss * (window - 1) as f64 + (-change).max(0.0)) / window as f64;
+        result[i + 1] = Some(to_rsi(gain, loss));
+    }
+    result
+}
+
+fn to_rsi(gain: f64, loss: f64) -> f64 {
+    if loss == 0.0 {
+        return 100.0;
+    }
+    100.0 - 100.0 / (1.0 + gain / loss)
+}
+
+/// Returns the MACD lines as `(value, signal, histogram)`, where the value is the difference
+/// of the short and the long EMAs, and the signal is the EMA of the value.
+pub fn macd(
+    values: &[f64],
+    short_window: usize,
+    long_window: usize,
+    signal_window: usize,
+) -> Vec<Option<(f64, f64, f64)>> {
+    let short = ema(values, short_window);
+    let long = ema(values, long_window);
+    let lines: Vec<Option<f64>> = short
+        .iter()
+        .zip(long.iter())
+        .map(|(s, l)| Some((*s)? - (*l)?))
+        .collect();
+
+    let mut result = vec![None; values.len()];
+    let Some(start) = lines.iter().position(|l| l.is_some()) else {
+        return result;
+    };
+    let filled: Vec<f64> = lines[start..].iter().flatten().copied().collect();
+    let signals = ema(&filled, signal_window);
+    for (j, signal) in signals.into_iter().enumerate() {
+        if let Some(signal) = signal {
+            let value = filled[j];
+            result[start + j] = Some((value, signal, value - signal));
+        }
+    }
+    result
+}
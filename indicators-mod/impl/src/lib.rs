@@ -0,0 +1,7 @@
+#[path = "indicators-impl.rs"]
+pub mod indicators_impl;
+#[path = "indicators-math.rs"]
+pub mod indicators_math;
+
+#[cfg(test)]
+mod test;
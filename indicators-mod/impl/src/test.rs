@@ -0,0 +1,199 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, TimeZone, Utc};
+use database_mod_aggregates_io::aggregates;
+use rstest::rstest;
+use snp_mod_fixture::snp_fixture::ServiceFake as SnPServiceFake;
+use snp_mod_io::snp;
+
+use crate::indicators_impl::Service;
+use crate::indicators_math;
+use indicators_mod_io::indicators as io;
+use io::Api;
+
+#[rstest]
+#[case(vec![1.0, 2.0, 3.0, 4.0, 5.0], 3, vec![None, None, Some(2.0), Some(3.0), Some(4.0)])]
+#[case(vec![1.0, 2.0], 3, vec![None, None])]
+fn compute_sma(
+    #[case] values: Vec<f64>,
+    #[case] window: usize,
+    #[case] expected: Vec<Option<f64>>,
+) {
+    assert_eq!(expected, indicators_math::sma(&values, window));
+}
+
+#[rstest]
+#[case(vec![1.0, 2.0, 3.0, 4.0], 2, vec![None, Some(1.5), Some(2.5), Some(3.5)])]
+#[case(vec![2.0, 2.0, 2.0], 3, vec![None, None, Some(2.0)])]
+fn compute_ema(
+    #[case] values: Vec<f64>,
+    #[case] window: usize,
+    #[case] expected: Vec<Option<f64>>,
+) {
+    let result = indicators_math::ema(&values, window);
+
+    assert_eq!(expected.len(), result.len());
+    for (e, r) in expected.iter().zip(result.iter()) {
+        match (e, r) {
+            (Some(e), Some(r)) => assert!((e - r).abs() < 1e-9, "{} != {}", e, r),
+            (e, r) => assert_eq!(e, r),
+        }
+    }
+}
+
+#[rstest]
+#[case(vec![1.0, 2.0, 3.0, 4.0], 2, Some(100.0))]
+#[case(vec![4.0, 3.0, 2.0, 1.0], 2, Some(0.0))]
+#[case(vec![1.0, 2.0, 1.0], 2, Some(50.0))]
+fn compute_rsi(#[case] values: Vec<f64>, #[case] window: usize, #[case] expected: Option<f64>) {
+    assert_eq!(
+        expected,
+        *indicators_math::rsi(&values, window).last().unwrap()
+    );
+}
+
+#[test]
+fn compute_macd_of_constant_series() {
+    let values = vec![10.0; 6];
+
+    let lines = indicators_math::macd(&values, 2, 3, 2);
+
+    assert_eq!(vec![None, None, None], lines[..3].to_vec());
+    assert_eq!(Some((0.0, 0.0, 0.0)), lines[3]);
+}
+
+// Polygon.io seeds an EMA by the bars before the range, while the computation seeds it by the
+// first window of the range. The difference of the two seeds shrinks by `1 - 2 / (window + 1)`
+// per bar, so it's under 2% of the first one after `2 * window` bars.
+#[rstest]
+#[case(10)]
+#[case(26)]
+fn forget_the_seed_of_the_ema(#[case] window: usize) {
+    let values: Vec<f64> = (0..200)
+        .map(|i| 170.0 + 5.0 * (i as f64 * 0.3).sin() + 0.1 * i as f64)
+        .collect();
+    let seeded_before = indicators_math::ema(&values, window);
+    let seeded_in_range = indicators_math::ema(&values[window..], window);
+    let decay = 1.0 - 2.0 / (window as f64 + 1.0);
+
+    let first = 2 * window - 1;
+    let difference =
+        |i: usize| (seeded_before[i].unwrap() - seeded_in_range[i - window].unwrap()).abs();
+    let seed_difference = difference(first);
+    assert!(seed_difference > 0.0);
+    for i in first..values.len() {
+        let bound = seed_difference * decay.powi((i - first) as i32);
+        assert!(
+            difference(i) <= bound + 1e-9,
+            "{} > {}",
+            difference(i),
+            bound
+        );
+    }
+    assert!(difference(first + 2 * window) < 0.02 * seed_difference);
+}
+
+#[tokio::test]
+async fn get_indicator_from_stored_bars() -> io::Result<()> {
+    let service = Service::new();
+    let start = Utc.with_ymd_and_hms(2024, 4, 10, 13, 30, 0).unwrap();
+    let request = io::Request {
+        ticker: snp::SnP::AAPL,
+        indicator: io::Indicator::Sma { window: 2 },
+        timespan: aggregates::Timespan::Minute,
+        series_type: io::SeriesType::Close,
+        from: start,
+        to: start + chrono::Duration::try_minutes(3).unwrap(),
+    };
+
+    let values = service.get_indicator(given_deps(), &request).await?;
+
+    assert_eq!(2, values.len());
+    assert_eq!(
+        start + chrono::Duration::try_minutes(1).unwrap(),
+        values[0].timestamp
+    );
+    assert_eq!(170.5, values[0].value);
+    assert_eq!(171.5, values[1].value);
+    Ok(())
+}
+
+fn given_bar(start: DateTime<Utc>, close: f64) -> aggregates::Bar {
+    aggregates::Bar {
+        snp: snp::SnP::AAPL,
+        timespan: aggregates::Timespan::Day,
+        start,
+        open: close,
+        high: close,
+        low: close,
+        close,
+        volume: 1000.0,
+        vwap: None,
+        transactions: None,
+    }
+}
+
+fn given_deps() -> Arc<dyn io::Depends + Send + Sync> {
+    Arc::new(DependsFake {})
+}
+
+struct DependsFake {}
+
+impl io::Depends for DependsFake {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        Arc::new(SnPServiceFake {})
+    }
+
+    fn provide_database_aggregates(&self) -> Arc<dyn aggregates::Api + Send + Sync> {
+        Arc::new(BarsFake {})
+    }
+}
+
+/// Stores three minute bars, closing at 170, 171 and 172.
+struct BarsFake {}
+
+#[async_trait]
+impl aggregates::Api for BarsFake {
+    async fn save_bars(
+        &self,
+        _deps: Arc<dyn aggregates::Depends + Send + Sync>,
+        _bars: &[aggregates::Bar],
+    ) -> aggregates::Result<()> {
+        Ok(())
+    }
+
+    async fn find_bars(
+        &self,
+        _deps: Arc<dyn aggregates::Depends + Send + Sync>,
+        _snp: &snp::SnP,
+        _timespan: aggregates::Timespan,
+        from: &DateTime<Utc>,
+        _to: &DateTime<Utc>,
+    ) -> aggregates::Result<Vec<aggregates::Bar>> {
+        Ok((0..3)
+            .map(|i| {
+                given_bar(
+                    *from + chrono::Duration::try_minutes(i).unwrap(),
+                    170.0 + i as f64,
+                )
+            })
+            .collect())
+    }
+
+    async fn save_fetch(
+        &self,
+        _deps: Arc<dyn aggregates::Depends + Send + Sync>,
+        _fetch: &aggregates::Fetch,
+    ) -> aggregates::Result<()> {
+        Ok(())
+    }
+
+    async fn is_fetched(
+        &self,
+        _deps: Arc<dyn aggregates::Depends + Send + Sync>,
+        _fetch: &aggregates::Fetch,
+    ) -> aggregates::Result<bool> {
+        Ok(false)
+    }
+}
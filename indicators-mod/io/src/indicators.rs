@@ -0,0 +1,94 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use database_mod_aggregates_io::aggregates;
+use snp_mod_io::snp;
+
+/// The technical indicator with its windows in number of bars.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Indicator {
+    Sma {
+        window: u32,
+    },
+    Ema {
+        window: u32,
+    },
+    Rsi {
+        window: u32,
+    },
+    Macd {
+        short_window: u32,
+        long_window: u32,
+        signal_window: u32,
+    },
+}
+
+/// The price of the bars that the indicator is computed from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SeriesType {
+    Open,
+    High,
+    Low,
+    Close,
+}
+
+/// The values to compute, of the stored bars starting in `[from, to)`.
+#[derive(Debug, Clone)]
+pub struct Request {
+    pub ticker: snp::SnP,
+    pub indicator: Indicator,
+    pub timespan: aggregates::Timespan,
+    pub series_type: SeriesType,
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+}
+
+/// A value of the indicator at the start of a bar.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Value {
+    pub timestamp: DateTime<Utc>,
+    pub value: f64,
+    /// The signal line of MACD.
+    pub signal: Option<f64>,
+    /// The histogram of MACD.
+    pub histogram: Option<f64>,
+}
+
+/// All possible errors of Indicators modules.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("{0}")]
+    Db(#[from] aggregates::Error),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The API of Indicators, which defines all the functionality of Indicators modules.
+#[async_trait]
+pub trait Api {
+    /// Computes the indicator by the formulas of Polygon.io, from the bars in ascending order of
+    /// start. There's no value for the first bars, until the windows are filled. The averages
+    /// are seeded by the first window of the bars, instead of the bars before them, so they
+    /// differ from the ones of Polygon.io until the seed fades.
+    fn compute(
+        &self,
+        bars: &[aggregates::Bar],
+        indicator: &Indicator,
+        series_type: SeriesType,
+    ) -> Vec<Value>;
+
+    /// Computes the indicator from the stored bars, so the range needs to include the bars
+    /// of the windows before the first expected value.
+    async fn get_indicator(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        request: &Request,
+    ) -> Result<Vec<Value>>;
+}
+
+/// All the dependencies of the Indicators modules to other modules.
+pub trait Depends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync>;
+    fn provide_database_aggregates(&self) -> Arc<dyn aggregates::Api + Send + Sync>;
+}
@@ -1,27 +1,165 @@
 
-use std::{sync::Arc, env, convert::Infallible, num::{TryFromIntError, ParseIntError}};
+use std::{sync::Arc, env, convert::Infallible, num::{TryFromIntError, ParseIntError}, path::PathBuf};
 
-use chrono::{NaiveDate, Utc, Duration, Datelike};
+use chrono::{NaiveDate, Utc, Duration};
 use snp_mod_io::snp;
 use snp_mod_impl::snp_impl::{self, WrapSnP};
 use policy_mod_io::policy;
 use policy_mod_impl::policy_impl;
+use calendar_mod_io::calendar;
+use calendar_mod_impl::calendar_impl;
 use network_mod_ticker_details_io::ticker_details as network;
 use network_mod_ticker_details_impl::ticker_details_impl as network_impl;
+use network_mod_aggregates_io::aggregates as aggregates_network;
+use network_mod_aggregates_impl::aggregates_impl as aggregates_network_impl;
+use network_mod_dividends_io::dividends as dividends_network;
+use network_mod_dividends_impl::dividends_impl as dividends_network_impl;
+use network_mod_splits_io::splits as splits_network;
+use network_mod_splits_impl::splits_impl as splits_network_impl;
+use network_mod_news_io::news as news_network;
+use network_mod_news_impl::news_impl as news_network_impl;
+use network_mod_market_status_io::market_status as market_status_network;
+use network_mod_market_status_impl::market_status_impl as market_status_network_impl;
+use network_mod_tickers_io::tickers as tickers_network;
+use network_mod_tickers_impl::tickers_impl as tickers_network_impl;
+use network_mod_ticker_events_io::ticker_events as ticker_events_network;
+use network_mod_ticker_events_impl::ticker_events_impl as ticker_events_network_impl;
+use network_mod_snapshots_io::snapshots as snapshots_network;
+use network_mod_snapshots_impl::snapshots_impl as snapshots_network_impl;
+use network_mod_options_contracts_io::options_contracts as options_contracts_network;
+use network_mod_options_contracts_impl::options_contracts_impl as options_contracts_network_impl;
+use network_mod_exchanges_io::exchanges as exchanges_network;
+use network_mod_exchanges_impl::exchanges_impl as exchanges_network_impl;
+use network_mod_conditions_io::conditions as conditions_network;
+use network_mod_conditions_impl::conditions_impl as conditions_network_impl;
+use network_mod_trades_io::trades as trades_network;
+use network_mod_trades_impl::trades_impl as trades_network_impl;
+use network_mod_flat_files_io::flat_files as flat_files_network;
+use network_mod_flat_files_impl::flat_files_impl as flat_files_network_impl;
+use network_mod_indices_io::indices as indices_network;
+use network_mod_indices_impl::indices_impl as indices_network_impl;
+use network_mod_financials_io::financials as financials_network;
+use network_mod_financials_impl::financials_impl as financials_network_impl;
+use network_mod_stream_io::stream as stream_network;
+use network_mod_stream_impl::stream_impl as stream_network_impl;
+use network_mod_cache_io::cache;
+use network_mod_cache_impl::cache_impl;
 use database_mod_ticker_details_io::ticker_details as database;
 use database_mod_ticker_details_impl::ticker_details_impl as database_impl;
+use database_mod_raw_responses_io::raw_responses as raw;
+use database_mod_raw_responses_impl::raw_responses_impl as raw_impl;
+use database_mod_aggregates_io::aggregates as aggregates_database;
+use database_mod_aggregates_impl::aggregates_impl as aggregates_database_impl;
+use database_mod_dividends_io::dividends as dividends_database;
+use database_mod_dividends_impl::dividends_impl as dividends_database_impl;
+use database_mod_splits_io::splits as splits_database;
+use database_mod_splits_impl::splits_impl as splits_database_impl;
+use database_mod_news_io::news as news_database;
+use database_mod_news_impl::news_impl as news_database_impl;
+use database_mod_tickers_io::tickers as tickers_database;
+use database_mod_tickers_impl::tickers_impl as tickers_database_impl;
+use database_mod_ticker_events_io::ticker_events as ticker_events_database;
+use database_mod_ticker_events_impl::ticker_events_impl as ticker_events_database_impl;
+use database_mod_snapshots_io::snapshots as snapshots_database;
+use database_mod_snapshots_impl::snapshots_impl as snapshots_database_impl;
+use database_mod_options_contracts_io::options_contracts as options_contracts_database;
+use database_mod_options_contracts_impl::options_contracts_impl as options_contracts_database_impl;
+use database_mod_exchanges_io::exchanges as exchanges_database;
+use database_mod_exchanges_impl::exchanges_impl as exchanges_database_impl;
+use database_mod_conditions_io::conditions as conditions_database;
+use database_mod_conditions_impl::conditions_impl as conditions_database_impl;
+use database_mod_trades_io::trades as trades_database;
+use database_mod_trades_impl::trades_impl as trades_database_impl;
+use database_mod_quotes_io::quotes as quotes_database;
+use database_mod_quotes_impl::quotes_impl as quotes_database_impl;
+use database_mod_indices_io::indices as indices_database;
+use database_mod_indices_impl::indices_impl as indices_database_impl;
+use database_mod_financials_io::financials as financials_database;
+use database_mod_financials_impl::financials_impl as financials_database_impl;
 use crawler_mod_ticker_details_io::crawler_ticker_details as crawler;
 use crawler_mod_ticker_details_impl::crawler_ticker_details_impl as crawler_impl;
+use crawler_mod_aggregates_io::crawler_aggregates as aggregates_crawler;
+use crawler_mod_aggregates_impl::crawler_aggregates_impl as aggregates_crawler_impl;
 use crawler::Api;
+use crawler_mod_corporate_actions_io::crawler_corporate_actions as corporate_actions_crawler;
+use crawler_mod_corporate_actions_impl::crawler_corporate_actions_impl as corporate_actions_crawler_impl;
+use crawler_mod_news_io::crawler_news as news_crawler;
+use crawler_mod_news_impl::crawler_news_impl as news_crawler_impl;
+use aggregates_crawler::Api as _;
+use corporate_actions_crawler::Api as _;
+use crawler_mod_tickers_io::crawler_tickers as tickers_crawler;
+use crawler_mod_tickers_impl::crawler_tickers_impl as tickers_crawler_impl;
+use crawler_mod_ticker_events_io::crawler_ticker_events as ticker_events_crawler;
+use crawler_mod_ticker_events_impl::crawler_ticker_events_impl as ticker_events_crawler_impl;
+use crawler_mod_snapshots_io::crawler_snapshots as snapshots_crawler;
+use crawler_mod_snapshots_impl::crawler_snapshots_impl as snapshots_crawler_impl;
+use crawler_mod_options_contracts_io::crawler_options_contracts as options_contracts_crawler;
+use crawler_mod_options_contracts_impl::crawler_options_contracts_impl as options_contracts_crawler_impl;
+use crawler_mod_references_io::crawler_references as references_crawler;
+use crawler_mod_references_impl::crawler_references_impl as references_crawler_impl;
+use crawler_mod_trades_io::crawler_trades as trades_crawler;
+use crawler_mod_trades_impl::crawler_trades_impl as trades_crawler_impl;
+use crawler_mod_flat_files_io::crawler_flat_files as flat_files_crawler;
+use crawler_mod_flat_files_impl::crawler_flat_files_impl as flat_files_crawler_impl;
+use crawler_mod_indices_io::crawler_indices as indices_crawler;
+use crawler_mod_indices_impl::crawler_indices_impl as indices_crawler_impl;
+use crawler_mod_financials_io::crawler_financials as financials_crawler;
+use crawler_mod_financials_impl::crawler_financials_impl as financials_crawler_impl;
+use crawler_mod_stream_io::crawler_stream as stream_crawler;
+use crawler_mod_stream_impl::crawler_stream_impl as stream_crawler_impl;
+use news_crawler::Api as _;
+use tickers_crawler::Api as _;
+use ticker_events_crawler::Api as _;
+use snapshots_crawler::Api as _;
+use options_contracts_crawler::Api as _;
+use references_crawler::Api as _;
+use trades_crawler::Api as _;
+use flat_files_crawler::Api as _;
+use indices_crawler::Api as _;
+use financials_crawler::Api as _;
+use stream_crawler::Api as _;
+use stream_network::Api as _;
 use tokio_postgres::NoTls;
 
-/// The main procedure to run the crawler.
+/// The main procedure to run the crawler. Passing `reprocess` rebuilds the ticker details from
+/// the archived raw responses, instead of crawling. Passing `corporate-actions` crawls the
+/// dividends and splits of every ticker that has ever been in the S&P 500. Passing `news` crawls
+/// the articles published since the last crawl for the current constituents. Passing `tickers`
+/// stores all the active tickers of the stock market, beyond the S&P 500. Passing `ticker-events`
+/// stores the symbol changes of every ticker that has ever been in the S&P 500, by which the
+/// ticker details are requested with the symbol of the date. Passing `snapshots` captures the
+/// snapshots of the current constituents every five minutes, until the market closes today.
+/// Passing `options-contracts` stores the options chains of the constituents of every date.
+/// Passing `references` stores the exchanges and the trade conditions of the stock market.
+/// Passing `trades` stores the trades of the constituents of every date, resuming from the last
+/// stored page of each ticker and date. Passing `flat-files` bulk loads the daily files of the
+/// trades, quotes and bars of every date, keeping the constituents of the date. Passing `indices`
+/// stores the daily and minute bars of the S&P 500, the Nasdaq-100 and the Dow Jones of every
+/// date, then captures their snapshots. Passing `financials` stores the line items of the filings
+/// of the current constituents. Passing `stream` stores the trades, the quotes and the minute bars
+/// of the current constituents from the real-time stream, until the market closes today. Passing
+/// `aggregates` stores the daily and minute bars of the constituents of every date, requesting a
+/// fetched date again only for a gap in its minute bars.
 #[tokio::main]
 async fn main() -> Result<(), Error> {
+    let reprocess = env::args().any(|arg| arg == "reprocess");
+    let corporate_actions = env::args().any(|arg| arg == "corporate-actions");
+    let news = env::args().any(|arg| arg == "news");
+    let tickers = env::args().any(|arg| arg == "tickers");
+    let ticker_events = env::args().any(|arg| arg == "ticker-events");
+    let snapshots = env::args().any(|arg| arg == "snapshots");
+    let options_contracts = env::args().any(|arg| arg == "options-contracts");
+    let references = env::args().any(|arg| arg == "references");
+    let trades = env::args().any(|arg| arg == "trades");
+    let flat_files = env::args().any(|arg| arg == "flat-files");
+    let indices = env::args().any(|arg| arg == "indices");
+    let financials = env::args().any(|arg| arg == "financials");
+    let stream = env::args().any(|arg| arg == "stream");
+    let aggregates = env::args().any(|arg| arg == "aggregates");
 
     let service = crawler_impl::Service::new();
     let deps = provide_dependencies().await?;
-    let snp = deps.provide_snp().clone();
+    let snp = crawler::Depends::provide_snp(deps.as_ref());
 
     let format = "%B %e, %Y";
     let start_date = NaiveDate::parse_from_str("April 17, 2024", format).unwrap();
@@ -30,12 +168,217 @@ async fn main() -> Result<(), Error> {
     let duration = end_date - start_date;
     let days: usize = duration.num_days().try_into()?;
     let mut policy_data = policy::Data::default();
+    refresh_calendar(deps.clone(), &mut policy_data).await?;
+    let calendar = crawler::Depends::provide_calendar(deps.as_ref());
+
+    if corporate_actions {
+        let corporate_actions_service = corporate_actions_crawler_impl::Service::new();
+        for ticker in snp.get_all_snp() {
+            println!("corporate actions of ticker: {0}", &Into::<WrapSnP>::into(ticker.clone()));
+            let corporate_actions_data = corporate_actions_crawler::Data { ticker };
+            corporate_actions_service.crawl_corporate_actions(
+                deps.clone(), &corporate_actions_data, &mut policy_data
+            ).await?;
+        }
+        return Ok(());
+    }
+
+    if news {
+        let news_service = news_crawler_impl::Service::new();
+        for ticker in snp.get_snp_by_date(&end_date) {
+            println!("news of ticker: {0}", &Into::<WrapSnP>::into(ticker.clone()));
+            let news_data = news_crawler::Data { ticker };
+            news_service.crawl_news(deps.clone(), &news_data, &mut policy_data).await?;
+        }
+        return Ok(());
+    }
+
+    if tickers {
+        let tickers_service = tickers_crawler_impl::Service::new();
+        let tickers_data = tickers_crawler::Data {
+            market: Some("stocks".into()),
+            active: Some(true),
+            ..Default::default()
+        };
+        tickers_service.crawl_tickers(deps.clone(), &tickers_data, &mut policy_data).await?;
+        return Ok(());
+    }
+
+    if references {
+        let references_service = references_crawler_impl::Service::new();
+        let references_data = references_crawler::Data { asset_class: "stocks".into() };
+        references_service.crawl_references(deps.clone(), &references_data, &mut policy_data).await?;
+        return Ok(());
+    }
+
+    if flat_files {
+        let flat_files_service = flat_files_crawler_impl::Service::new();
+        let datasets = [
+            flat_files_network::Dataset::Trades,
+            flat_files_network::Dataset::Quotes,
+            flat_files_network::Dataset::MinuteAggregates,
+            flat_files_network::Dataset::DayAggregates,
+        ];
+        for date in end_date.iter_days().rev().take(days) {
+            for dataset in datasets {
+                let flat_files_data = flat_files_crawler::Data { dataset, date };
+                let saved = flat_files_service.ingest_flat_file(deps.clone(), &flat_files_data).await?;
+                println!("flat file of dataset: {0}, date: {1}, rows: {2}", dataset.as_str(), &date, saved);
+            }
+        }
+        return Ok(());
+    }
+
+    if indices {
+        let indices_service = indices_crawler_impl::Service::new();
+        for date in end_date.iter_days().rev().take(days) {
+            for index in snp::Index::ALL {
+                println!("index: {0}, date: {1}", index.ticker(), &date);
+                let indices_data = indices_crawler::Data { index, date };
+                indices_service.crawl_index_aggregates(deps.clone(), &indices_data, &mut policy_data).await?;
+            }
+        }
+        let saved = indices_service.capture_snapshots(deps.clone(), &mut policy_data).await?;
+        println!("snapshots of indices: {0}", saved);
+        return Ok(());
+    }
+
+    if financials {
+        let financials_service = financials_crawler_impl::Service::new();
+        let snp_map = snp.get_snp_map();
+        let ciks: Vec<(snp::SnP, Arc<str>)> = snp.get_snp_by_date(&end_date).into_iter()
+            .filter_map(|ticker| {
+                let cik = snp_map.get(&ticker)?.cik.as_ref().into();
+                Some((ticker, cik))
+            })
+            .collect();
+        drop(snp_map);
+        for (ticker, cik) in ciks {
+            let financials_data = financials_crawler::Data { cik };
+            let skipped = financials_service.crawl_financials(deps.clone(), &financials_data, &mut policy_data).await?;
+            println!("financials of ticker: {0}, skipped filings: {1}", &Into::<WrapSnP>::into(ticker), skipped);
+        }
+        return Ok(());
+    }
+
+    if stream {
+        let stream_service = stream_crawler_impl::Service::new();
+        let date = Utc::now().date_naive();
+        let Some(session) = calendar.get_session(&date) else {
+            return Ok(());
+        };
+        let network_stream = Arc::new(stream_network_impl::Service::new(&env::var("API_KEY").unwrap())?);
+        let channels = [
+            stream_network::Channel::Trades,
+            stream_network::Channel::Quotes,
+            stream_network::Channel::MinuteBars,
+        ];
+        let subscriptions: Vec<stream_network::Subscription> = snp.get_snp_by_date(&date).into_iter()
+            .flat_map(|ticker| channels.map(|channel| stream_network::Subscription {
+                channel,
+                ticker: Some(ticker.clone()),
+            }))
+            .collect();
+        let handle = tokio::spawn({
+            let network_stream = network_stream.clone();
+            let deps = deps.clone();
+            async move { network_stream.stream(deps, &subscriptions).await }
+        });
+        while Utc::now() < session.close && !handle.is_finished() {
+            tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
+            let events: Vec<stream_network::Event> = network_stream.channel.try_iter().collect();
+            let saved = stream_service.save_events(deps.clone(), &events).await?;
+            println!("streamed events: {0}", saved);
+        }
+        network_stream.close().await?;
+        handle.await??;
+        return Ok(());
+    }
+
+    if aggregates {
+        let aggregates_service = aggregates_crawler_impl::Service::new();
+        for date in end_date.iter_days().rev().take(days) {
+            for ticker in snp.get_snp_by_date(&date) {
+                println!(
+                    "aggregates of ticker: {0}, date: {1}",
+                    &Into::<WrapSnP>::into(ticker.clone()), &date
+                );
+                let aggregates_data = aggregates_crawler::Data { ticker, date };
+                aggregates_service.crawl_aggregates(deps.clone(), &aggregates_data, &mut policy_data).await?;
+            }
+        }
+        return Ok(());
+    }
+
+    if trades {
+        let trades_service = trades_crawler_impl::Service::new();
+        for date in end_date.iter_days().rev().take(days) {
+            for ticker in snp.get_snp_by_date(&date) {
+                println!(
+                    "trades of ticker: {0}, date: {1}",
+                    &Into::<WrapSnP>::into(ticker.clone()), &date
+                );
+                let trades_data = trades_crawler::Data { ticker, date };
+                trades_service.crawl_trades(deps.clone(), &trades_data, &mut policy_data).await?;
+            }
+        }
+        return Ok(());
+    }
+
+    if ticker_events {
+        let ticker_events_service = ticker_events_crawler_impl::Service::new();
+        for ticker in snp.get_all_snp() {
+            println!("ticker events of ticker: {0}", &Into::<WrapSnP>::into(ticker.clone()));
+            let ticker_events_data = ticker_events_crawler::Data { ticker };
+            ticker_events_service.crawl_ticker_events(
+                deps.clone(), &ticker_events_data, &mut policy_data
+            ).await?;
+        }
+        return Ok(());
+    }
+
+    if snapshots {
+        let snapshots_service = snapshots_crawler_impl::Service::new();
+        // The trading session of New York never crosses midnight in UTC.
+        let date = Utc::now().date_naive();
+        let Some(session) = calendar.get_session(&date) else {
+            return Ok(());
+        };
+        while Utc::now() < session.close {
+            let snapshots_data = snapshots_crawler::Data { date, captured_at: Utc::now() };
+            println!("snapshots at: {0}", &snapshots_data.captured_at);
+            snapshots_service.crawl_snapshots(deps.clone(), &snapshots_data, &mut policy_data).await?;
+            tokio::time::sleep(tokio::time::Duration::from_secs(5 * 60)).await;
+        }
+        return Ok(());
+    }
+
+    if options_contracts {
+        let options_contracts_service = options_contracts_crawler_impl::Service::new();
+        for date in end_date.iter_days().rev().take(days) {
+            for ticker in snp.get_snp_by_date(&date) {
+                println!(
+                    "options contracts of ticker: {0}, date: {1}",
+                    &Into::<WrapSnP>::into(ticker.clone()), &date
+                );
+                let options_contracts_data = options_contracts_crawler::Data { ticker, date };
+                options_contracts_service.crawl_options_contracts(
+                    deps.clone(), &options_contracts_data, &mut policy_data
+                ).await?;
+            }
+        }
+        return Ok(());
+    }
 
     for date in end_date.iter_days().rev().take(days) {
-        match date.weekday() {
-            chrono::Weekday::Sat|
-            chrono::Weekday::Sun => continue,
-            _ => ()
+        if !calendar.is_trading_day(&date) {
+            continue;
+        }
+
+        if reprocess {
+            println!("reprocess date: {0}", &date);
+            service.reprocess_ticker_details(deps.clone(), &date).await?;
+            continue;
         }
 
         for ticker in snp.get_snp_by_date(&date) {
@@ -52,7 +395,7 @@ async fn main() -> Result<(), Error> {
     Ok(())
 }
 
-async fn provide_dependencies() -> Result<Arc<dyn crawler::Depends + Send + Sync>, Error> {
+async fn provide_dependencies() -> Result<Arc<CrawlerDepends>, Error> {
     let api_key = env::var("API_KEY").unwrap();
     let cool_down_time: u64 = env::var("POLICY_COOL_DOWN_TIME").unwrap().parse()?;
     let allowed_requests: usize = env::var("POLICY_ALLOWED_REQUESTS").unwrap().parse()?;
@@ -60,23 +403,230 @@ async fn provide_dependencies() -> Result<Arc<dyn crawler::Depends + Send + Sync
     let database_client = Arc::new(init_database().await?);
     let snp = Arc::new(snp_impl::Service::new());
     let policy = Arc::new(policy_impl::Service::new(cool_down_time, allowed_requests));
+    let calendar = Arc::new(calendar_impl::Service::new());
     let network_ticker_details = Arc::new(network_impl::Service::new(
-        network_client, api_key.into()
+        network_client.clone(), api_key.clone().into()
+    )?);
+    let network_aggregates = Arc::new(aggregates_network_impl::Service::new(
+        network_client.clone(), &api_key
+    )?);
+    let network_dividends = Arc::new(dividends_network_impl::Service::new(
+        network_client.clone(), &api_key
+    )?);
+    let network_splits = Arc::new(splits_network_impl::Service::new(
+        network_client.clone(), &api_key
     )?);
-    let database_ticker_details = Arc::new(database_impl::Service::new(database_client));
+    let network_news = Arc::new(news_network_impl::Service::new(
+        network_client.clone(), &api_key
+    )?);
+    let network_market_status = Arc::new(market_status_network_impl::Service::new(
+        network_client.clone(), &api_key
+    )?);
+    let network_tickers = Arc::new(tickers_network_impl::Service::new(
+        network_client.clone(), &api_key
+    )?);
+    let network_ticker_events = Arc::new(ticker_events_network_impl::Service::new(
+        network_client.clone(), &api_key
+    )?);
+    let network_snapshots = Arc::new(snapshots_network_impl::Service::new(
+        network_client.clone(), &api_key
+    )?);
+    let network_options_contracts = Arc::new(options_contracts_network_impl::Service::new(
+        network_client.clone(), &api_key
+    )?);
+    let network_exchanges = Arc::new(exchanges_network_impl::Service::new(
+        network_client.clone(), &api_key
+    )?);
+    let network_conditions = Arc::new(conditions_network_impl::Service::new(
+        network_client.clone(), &api_key
+    )?);
+    let network_flat_files = provide_network_flat_files(network_client.clone())?;
+    let network_trades = Arc::new(trades_network_impl::Service::new(
+        network_client.clone(), &api_key
+    )?);
+    let network_indices = Arc::new(indices_network_impl::Service::new(
+        network_client.clone(), &api_key
+    )?);
+    let network_financials = Arc::new(financials_network_impl::Service::new(
+        network_client, &api_key
+    )?);
+    let database_ticker_details = Arc::new(database_impl::Service::new(database_client.clone()));
+    let database_raw_responses = Arc::new(raw_impl::Service::new(database_client.clone()));
+    let database_aggregates = Arc::new(aggregates_database_impl::Service::new(database_client.clone()));
+    let database_dividends = Arc::new(dividends_database_impl::Service::new(database_client.clone()));
+    let database_splits = Arc::new(splits_database_impl::Service::new(database_client.clone()));
+    let database_news = Arc::new(news_database_impl::Service::new(database_client.clone()));
+    let database_tickers = Arc::new(tickers_database_impl::Service::new(database_client.clone()));
+    let database_ticker_events = Arc::new(ticker_events_database_impl::Service::new(database_client.clone()));
+    let database_snapshots = Arc::new(snapshots_database_impl::Service::new(database_client.clone()));
+    let database_options_contracts = Arc::new(options_contracts_database_impl::Service::new(database_client.clone()));
+    let database_exchanges = Arc::new(exchanges_database_impl::Service::new(database_client.clone()));
+    let database_conditions = Arc::new(conditions_database_impl::Service::new(database_client.clone()));
+    let database_trades = Arc::new(trades_database_impl::Service::new(database_client.clone()));
+    let database_quotes = Arc::new(quotes_database_impl::Service::new(database_client.clone()));
+    let database_indices = Arc::new(indices_database_impl::Service::new(database_client.clone()));
+    let database_financials = Arc::new(financials_database_impl::Service::new(database_client));
+    let names = Arc::new(load_names(snp.clone(), database_exchanges.clone(), database_conditions.clone()).await?);
     Ok(Arc::new(CrawlerDepends {
         snp,
         policy,
+        calendar,
         network_ticker_details,
+        network_cache: provide_network_cache(),
+        network_aggregates,
+        network_dividends,
+        network_splits,
+        network_news,
+        network_market_status,
+        network_tickers,
+        network_ticker_events,
+        network_snapshots,
+        network_options_contracts,
+        network_exchanges,
+        network_conditions,
+        network_trades,
+        network_flat_files,
+        network_indices,
+        network_financials,
         database_ticker_details,
+        database_raw_responses,
+        database_aggregates,
+        database_dividends,
+        database_splits,
+        database_news,
+        database_tickers,
+        database_ticker_events,
+        database_snapshots,
+        database_options_contracts,
+        database_exchanges,
+        database_conditions,
+        database_trades,
+        database_quotes,
+        database_indices,
+        database_financials,
+        names,
     }))
 }
 
+/// Loads the names of the exchanges and the trade conditions once, to decode the trades of all
+/// the modes. They're empty until the `references` mode stores them.
+async fn load_names(
+    snp: Arc<dyn snp::Api + Send + Sync>,
+    database_exchanges: Arc<dyn exchanges_database::Api + Send + Sync>,
+    database_conditions: Arc<dyn conditions_database::Api + Send + Sync>,
+) -> Result<trades_network::Names, Error> {
+    let deps = Arc::new(ReferencesDepends { snp });
+    let exchanges = database_exchanges.find_exchanges(deps.clone(), "stocks").await?;
+    let conditions = database_conditions.find_conditions(deps, "stocks").await?;
+    Ok(trades_network::Names {
+        exchanges: exchanges.into_iter().map(|e| (e.id, e.name)).collect(),
+        conditions: conditions.into_iter().map(|c| (c.id, c.name)).collect(),
+    })
+}
+
+struct ReferencesDepends {
+    snp: Arc<dyn snp::Api + Send + Sync>,
+}
+
+impl exchanges_database::Depends for ReferencesDepends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        self.snp.clone()
+    }
+}
+
+impl conditions_database::Depends for ReferencesDepends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        self.snp.clone()
+    }
+}
+
+/// Adds the upcoming holidays and early closes of the NYSE that Polygon.io announces, like an
+/// unscheduled closure, to the calendar. The calendar still knows the regular ones without it.
+async fn refresh_calendar(deps: Arc<CrawlerDepends>, policy_data: &mut policy::Data) -> Result<(), Error> {
+    let response = deps.network_market_status.get_upcoming(deps.clone()).await;
+    deps.policy.handle_request_rate(policy_data).await?;
+    match response {
+        Ok(upcoming) => {
+            let holidays: Vec<calendar::Holiday> = upcoming.into_iter()
+                .filter(|u| u.exchange.as_ref() == "NYSE")
+                .filter_map(|u| Some(calendar::Holiday {
+                    status: match u.status.as_ref() {
+                        "closed" => calendar::Status::Closed,
+                        "early-close" => calendar::Status::EarlyClose,
+                        _ => return None,
+                    },
+                    date: u.date,
+                    name: u.name,
+                }))
+                .collect();
+            deps.calendar.update_holidays(&holidays);
+        }
+        Err(e) => eprintln!("Failed to refresh the calendar: {}", e),
+    }
+    Ok(())
+}
+
+/// The flat files are read from `FLAT_FILES_DIR`, and they're downloaded there from Polygon.io
+/// first, if `FLAT_FILES_ACCESS_KEY` and `FLAT_FILES_SECRET_KEY` are set.
+fn provide_network_flat_files(
+    client: Arc<reqwest::Client>,
+) -> Result<Arc<dyn flat_files_network::Api + Send + Sync>, Error> {
+    let dir: PathBuf = env::var("FLAT_FILES_DIR").unwrap_or_else(|_| "flat-files".into()).into();
+    let access_key = env::var("FLAT_FILES_ACCESS_KEY").unwrap_or_default();
+    let secret_key = env::var("FLAT_FILES_SECRET_KEY").unwrap_or_default();
+    if access_key.is_empty() && secret_key.is_empty() {
+        return Ok(Arc::new(flat_files_network_impl::Service::from_dir(&dir)));
+    }
+    Ok(Arc::new(flat_files_network_impl::Service::new(client, &access_key, &secret_key, &dir)?))
+}
+
+/// The cache is optional, and it's enabled by setting `CACHE_DIR`.
+fn provide_network_cache() -> Option<Arc<dyn cache::Api + Send + Sync>> {
+    let cache_dir = env::var("CACHE_DIR").ok().filter(|d| !d.is_empty())?;
+    // The ticker details of a past date never change, and the crawler never asks for today.
+    let cache = cache_impl::Service::new(cache_dir.into())
+        .with_ttl(network::ENDPOINT, cache::Ttl::Forever);
+    Some(Arc::new(cache))
+}
+
 struct CrawlerDepends {
     snp: Arc<dyn snp::Api + Send + Sync>,
     policy: Arc<dyn policy::Api + Send + Sync>,
+    calendar: Arc<dyn calendar::Api + Send + Sync>,
     network_ticker_details: Arc<dyn network::Api + Send + Sync>,
+    network_cache: Option<Arc<dyn cache::Api + Send + Sync>>,
+    network_aggregates: Arc<dyn aggregates_network::Api + Send + Sync>,
+    network_dividends: Arc<dyn dividends_network::Api + Send + Sync>,
+    network_splits: Arc<dyn splits_network::Api + Send + Sync>,
+    network_news: Arc<dyn news_network::Api + Send + Sync>,
+    network_market_status: Arc<dyn market_status_network::Api + Send + Sync>,
+    network_tickers: Arc<dyn tickers_network::Api + Send + Sync>,
+    network_ticker_events: Arc<dyn ticker_events_network::Api + Send + Sync>,
+    network_snapshots: Arc<dyn snapshots_network::Api + Send + Sync>,
+    network_options_contracts: Arc<dyn options_contracts_network::Api + Send + Sync>,
+    network_exchanges: Arc<dyn exchanges_network::Api + Send + Sync>,
+    network_conditions: Arc<dyn conditions_network::Api + Send + Sync>,
+    network_trades: Arc<dyn trades_network::Api + Send + Sync>,
+    network_flat_files: Arc<dyn flat_files_network::Api + Send + Sync>,
+    network_indices: Arc<dyn indices_network::Api + Send + Sync>,
+    network_financials: Arc<dyn financials_network::Api + Send + Sync>,
     database_ticker_details: Arc<dyn database::Api + Send + Sync>,
+    database_raw_responses: Arc<dyn raw::Api + Send + Sync>,
+    database_aggregates: Arc<dyn aggregates_database::Api + Send + Sync>,
+    database_dividends: Arc<dyn dividends_database::Api + Send + Sync>,
+    database_splits: Arc<dyn splits_database::Api + Send + Sync>,
+    database_news: Arc<dyn news_database::Api + Send + Sync>,
+    database_tickers: Arc<dyn tickers_database::Api + Send + Sync>,
+    database_ticker_events: Arc<dyn ticker_events_database::Api + Send + Sync>,
+    database_snapshots: Arc<dyn snapshots_database::Api + Send + Sync>,
+    database_options_contracts: Arc<dyn options_contracts_database::Api + Send + Sync>,
+    database_exchanges: Arc<dyn exchanges_database::Api + Send + Sync>,
+    database_conditions: Arc<dyn conditions_database::Api + Send + Sync>,
+    database_trades: Arc<dyn trades_database::Api + Send + Sync>,
+    database_quotes: Arc<dyn quotes_database::Api + Send + Sync>,
+    database_indices: Arc<dyn indices_database::Api + Send + Sync>,
+    database_financials: Arc<dyn financials_database::Api + Send + Sync>,
+    names: Arc<trades_network::Names>,
 }
 
 impl CrawlerDepends {}
@@ -90,13 +640,343 @@ impl crawler::Depends for CrawlerDepends {
         self.policy.clone()
     }
 
+    fn provide_calendar(&self) -> Arc<dyn calendar::Api + Send + Sync> {
+        self.calendar.clone()
+    }
+
     fn provide_network_ticker_details(&self) -> Arc<dyn network::Api + Send + Sync> {
         self.network_ticker_details.clone()
     }
 
+    fn provide_network_cache(&self) -> Option<Arc<dyn cache::Api + Send + Sync>> {
+        self.network_cache.clone()
+    }
+
     fn provide_database_ticker_details(&self) -> Arc<dyn database::Api + Send + Sync> {
         self.database_ticker_details.clone()
     }
+
+    fn provide_database_raw_responses(&self) -> Arc<dyn raw::Api + Send + Sync> {
+        self.database_raw_responses.clone()
+    }
+
+    fn provide_database_ticker_events(&self) -> Arc<dyn ticker_events_database::Api + Send + Sync> {
+        self.database_ticker_events.clone()
+    }
+}
+
+impl aggregates_crawler::Depends for CrawlerDepends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        self.snp.clone()
+    }
+
+    fn provide_policy(&self) -> Arc<dyn policy::Api + Send + Sync> {
+        self.policy.clone()
+    }
+
+    fn provide_calendar(&self) -> Arc<dyn calendar::Api + Send + Sync> {
+        self.calendar.clone()
+    }
+
+    fn provide_network_aggregates(&self) -> Arc<dyn aggregates_network::Api + Send + Sync> {
+        self.network_aggregates.clone()
+    }
+
+    fn provide_database_aggregates(&self) -> Arc<dyn aggregates_database::Api + Send + Sync> {
+        self.database_aggregates.clone()
+    }
+
+    fn provide_database_ticker_events(&self) -> Arc<dyn ticker_events_database::Api + Send + Sync> {
+        self.database_ticker_events.clone()
+    }
+}
+
+impl corporate_actions_crawler::Depends for CrawlerDepends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        self.snp.clone()
+    }
+
+    fn provide_policy(&self) -> Arc<dyn policy::Api + Send + Sync> {
+        self.policy.clone()
+    }
+
+    fn provide_network_dividends(&self) -> Arc<dyn dividends_network::Api + Send + Sync> {
+        self.network_dividends.clone()
+    }
+
+    fn provide_network_splits(&self) -> Arc<dyn splits_network::Api + Send + Sync> {
+        self.network_splits.clone()
+    }
+
+    fn provide_database_dividends(&self) -> Arc<dyn dividends_database::Api + Send + Sync> {
+        self.database_dividends.clone()
+    }
+
+    fn provide_database_splits(&self) -> Arc<dyn splits_database::Api + Send + Sync> {
+        self.database_splits.clone()
+    }
+}
+
+impl news_crawler::Depends for CrawlerDepends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        self.snp.clone()
+    }
+
+    fn provide_policy(&self) -> Arc<dyn policy::Api + Send + Sync> {
+        self.policy.clone()
+    }
+
+    fn provide_network_news(&self) -> Arc<dyn news_network::Api + Send + Sync> {
+        self.network_news.clone()
+    }
+
+    fn provide_database_news(&self) -> Arc<dyn news_database::Api + Send + Sync> {
+        self.database_news.clone()
+    }
+}
+
+impl tickers_crawler::Depends for CrawlerDepends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        self.snp.clone()
+    }
+
+    fn provide_policy(&self) -> Arc<dyn policy::Api + Send + Sync> {
+        self.policy.clone()
+    }
+
+    fn provide_network_tickers(&self) -> Arc<dyn tickers_network::Api + Send + Sync> {
+        self.network_tickers.clone()
+    }
+
+    fn provide_database_tickers(&self) -> Arc<dyn tickers_database::Api + Send + Sync> {
+        self.database_tickers.clone()
+    }
+}
+
+impl ticker_events_crawler::Depends for CrawlerDepends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        self.snp.clone()
+    }
+
+    fn provide_policy(&self) -> Arc<dyn policy::Api + Send + Sync> {
+        self.policy.clone()
+    }
+
+    fn provide_network_ticker_events(&self) -> Arc<dyn ticker_events_network::Api + Send + Sync> {
+        self.network_ticker_events.clone()
+    }
+
+    fn provide_database_ticker_events(&self) -> Arc<dyn ticker_events_database::Api + Send + Sync> {
+        self.database_ticker_events.clone()
+    }
+}
+
+impl snapshots_crawler::Depends for CrawlerDepends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        self.snp.clone()
+    }
+
+    fn provide_policy(&self) -> Arc<dyn policy::Api + Send + Sync> {
+        self.policy.clone()
+    }
+
+    fn provide_calendar(&self) -> Arc<dyn calendar::Api + Send + Sync> {
+        self.calendar.clone()
+    }
+
+    fn provide_network_snapshots(&self) -> Arc<dyn snapshots_network::Api + Send + Sync> {
+        self.network_snapshots.clone()
+    }
+
+    fn provide_database_snapshots(&self) -> Arc<dyn snapshots_database::Api + Send + Sync> {
+        self.database_snapshots.clone()
+    }
+}
+
+impl options_contracts_crawler::Depends for CrawlerDepends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        self.snp.clone()
+    }
+
+    fn provide_policy(&self) -> Arc<dyn policy::Api + Send + Sync> {
+        self.policy.clone()
+    }
+
+    fn provide_calendar(&self) -> Arc<dyn calendar::Api + Send + Sync> {
+        self.calendar.clone()
+    }
+
+    fn provide_network_options_contracts(&self) -> Arc<dyn options_contracts_network::Api + Send + Sync> {
+        self.network_options_contracts.clone()
+    }
+
+    fn provide_database_options_contracts(&self) -> Arc<dyn options_contracts_database::Api + Send + Sync> {
+        self.database_options_contracts.clone()
+    }
+}
+
+impl references_crawler::Depends for CrawlerDepends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        self.snp.clone()
+    }
+
+    fn provide_policy(&self) -> Arc<dyn policy::Api + Send + Sync> {
+        self.policy.clone()
+    }
+
+    fn provide_network_exchanges(&self) -> Arc<dyn exchanges_network::Api + Send + Sync> {
+        self.network_exchanges.clone()
+    }
+
+    fn provide_network_conditions(&self) -> Arc<dyn conditions_network::Api + Send + Sync> {
+        self.network_conditions.clone()
+    }
+
+    fn provide_database_exchanges(&self) -> Arc<dyn exchanges_database::Api + Send + Sync> {
+        self.database_exchanges.clone()
+    }
+
+    fn provide_database_conditions(&self) -> Arc<dyn conditions_database::Api + Send + Sync> {
+        self.database_conditions.clone()
+    }
+}
+
+impl trades_crawler::Depends for CrawlerDepends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        self.snp.clone()
+    }
+
+    fn provide_policy(&self) -> Arc<dyn policy::Api + Send + Sync> {
+        self.policy.clone()
+    }
+
+    fn provide_calendar(&self) -> Arc<dyn calendar::Api + Send + Sync> {
+        self.calendar.clone()
+    }
+
+    fn provide_network_trades(&self) -> Arc<dyn trades_network::Api + Send + Sync> {
+        self.network_trades.clone()
+    }
+
+    fn provide_names(&self) -> Arc<trades_network::Names> {
+        self.names.clone()
+    }
+
+    fn provide_database_trades(&self) -> Arc<dyn trades_database::Api + Send + Sync> {
+        self.database_trades.clone()
+    }
+
+    fn provide_database_ticker_events(&self) -> Arc<dyn ticker_events_database::Api + Send + Sync> {
+        self.database_ticker_events.clone()
+    }
+}
+
+impl flat_files_crawler::Depends for CrawlerDepends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        self.snp.clone()
+    }
+
+    fn provide_calendar(&self) -> Arc<dyn calendar::Api + Send + Sync> {
+        self.calendar.clone()
+    }
+
+    fn provide_network_flat_files(&self) -> Arc<dyn flat_files_network::Api + Send + Sync> {
+        self.network_flat_files.clone()
+    }
+
+    fn provide_names(&self) -> Arc<trades_network::Names> {
+        self.names.clone()
+    }
+
+    fn provide_database_trades(&self) -> Arc<dyn trades_database::Api + Send + Sync> {
+        self.database_trades.clone()
+    }
+
+    fn provide_database_quotes(&self) -> Arc<dyn quotes_database::Api + Send + Sync> {
+        self.database_quotes.clone()
+    }
+
+    fn provide_database_aggregates(&self) -> Arc<dyn aggregates_database::Api + Send + Sync> {
+        self.database_aggregates.clone()
+    }
+
+    fn provide_database_ticker_events(&self) -> Arc<dyn ticker_events_database::Api + Send + Sync> {
+        self.database_ticker_events.clone()
+    }
+}
+
+impl indices_crawler::Depends for CrawlerDepends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        self.snp.clone()
+    }
+
+    fn provide_policy(&self) -> Arc<dyn policy::Api + Send + Sync> {
+        self.policy.clone()
+    }
+
+    fn provide_calendar(&self) -> Arc<dyn calendar::Api + Send + Sync> {
+        self.calendar.clone()
+    }
+
+    fn provide_network_indices(&self) -> Arc<dyn indices_network::Api + Send + Sync> {
+        self.network_indices.clone()
+    }
+
+    fn provide_database_indices(&self) -> Arc<dyn indices_database::Api + Send + Sync> {
+        self.database_indices.clone()
+    }
+}
+
+impl financials_crawler::Depends for CrawlerDepends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        self.snp.clone()
+    }
+
+    fn provide_policy(&self) -> Arc<dyn policy::Api + Send + Sync> {
+        self.policy.clone()
+    }
+
+    fn provide_network_financials(&self) -> Arc<dyn financials_network::Api + Send + Sync> {
+        self.network_financials.clone()
+    }
+
+    fn provide_database_financials(&self) -> Arc<dyn financials_database::Api + Send + Sync> {
+        self.database_financials.clone()
+    }
+}
+
+impl stream_crawler::Depends for CrawlerDepends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        self.snp.clone()
+    }
+
+    fn provide_database_trades(&self) -> Arc<dyn trades_database::Api + Send + Sync> {
+        self.database_trades.clone()
+    }
+
+    fn provide_database_quotes(&self) -> Arc<dyn quotes_database::Api + Send + Sync> {
+        self.database_quotes.clone()
+    }
+
+    fn provide_database_aggregates(&self) -> Arc<dyn aggregates_database::Api + Send + Sync> {
+        self.database_aggregates.clone()
+    }
+}
+
+impl stream_network::Depends for CrawlerDepends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        self.snp.clone()
+    }
+
+    fn provide_names(&self) -> Arc<trades_network::Names> {
+        self.names.clone()
+    }
+}
+
+impl market_status_network::Depends for CrawlerDepends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        self.snp.clone()
+    }
 }
 
 async fn init_database() -> database::Result<tokio_postgres::Client> {
@@ -132,8 +1012,74 @@ pub enum Error {
     #[error("{0}")]
     Crawler(#[from] crawler::Error),
     #[error("{0}")]
+    AggregatesCrawler(#[from] aggregates_crawler::Error),
+    #[error("{0}")]
+    CorporateActionsCrawler(#[from] corporate_actions_crawler::Error),
+    #[error("{0}")]
+    NewsCrawler(#[from] news_crawler::Error),
+    #[error("{0}")]
+    TickersCrawler(#[from] tickers_crawler::Error),
+    #[error("{0}")]
+    TickerEventsCrawler(#[from] ticker_events_crawler::Error),
+    #[error("{0}")]
+    SnapshotsCrawler(#[from] snapshots_crawler::Error),
+    #[error("{0}")]
+    OptionsContractsCrawler(#[from] options_contracts_crawler::Error),
+    #[error("{0}")]
+    ReferencesCrawler(#[from] references_crawler::Error),
+    #[error("{0}")]
+    TradesCrawler(#[from] trades_crawler::Error),
+    #[error("{0}")]
+    FlatFilesCrawler(#[from] flat_files_crawler::Error),
+    #[error("{0}")]
+    IndicesCrawler(#[from] indices_crawler::Error),
+    #[error("{0}")]
+    FinancialsCrawler(#[from] financials_crawler::Error),
+    #[error("{0}")]
+    StreamCrawler(#[from] stream_crawler::Error),
+    #[error("{0}")]
+    ExchangesDbError(#[from] exchanges_database::Error),
+    #[error("{0}")]
+    ConditionsDbError(#[from] conditions_database::Error),
+    #[error("{0}")]
     NetError(#[from] network::Error),
     #[error("{0}")]
+    AggregatesNetError(#[from] aggregates_network::Error),
+    #[error("{0}")]
+    DividendsNetError(#[from] dividends_network::Error),
+    #[error("{0}")]
+    SplitsNetError(#[from] splits_network::Error),
+    #[error("{0}")]
+    NewsNetError(#[from] news_network::Error),
+    #[error("{0}")]
+    MarketStatusNetError(#[from] market_status_network::Error),
+    #[error("{0}")]
+    TickersNetError(#[from] tickers_network::Error),
+    #[error("{0}")]
+    TickerEventsNetError(#[from] ticker_events_network::Error),
+    #[error("{0}")]
+    SnapshotsNetError(#[from] snapshots_network::Error),
+    #[error("{0}")]
+    OptionsContractsNetError(#[from] options_contracts_network::Error),
+    #[error("{0}")]
+    ExchangesNetError(#[from] exchanges_network::Error),
+    #[error("{0}")]
+    ConditionsNetError(#[from] conditions_network::Error),
+    #[error("{0}")]
+    TradesNetError(#[from] trades_network::Error),
+    #[error("{0}")]
+    FlatFilesNetError(#[from] flat_files_network::Error),
+    #[error("{0}")]
+    IndicesNetError(#[from] indices_network::Error),
+    #[error("{0}")]
+    FinancialsNetError(#[from] financials_network::Error),
+    #[error("{0}")]
+    StreamNetError(#[from] stream_network::Error),
+    #[error("{0}")]
+    StreamTask(#[from] tokio::task::JoinError),
+    #[error("{0}")]
+    Policy(#[from] policy::Error),
+    #[error("{0}")]
     DurationDay(String),
     #[error("{0}")]
     DaysTooLong(#[from] TryFromIntError),
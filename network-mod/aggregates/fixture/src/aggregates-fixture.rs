@@ -0,0 +1,26 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use network_mod_aggregates_io::aggregates as io;
+
+pub struct ServiceFake;
+
+#[async_trait]
+impl io::Api for ServiceFake {
+    async fn get_aggregates(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        _request: &io::Request,
+    ) -> io::Result<Vec<io::Data>> {
+        Ok(vec![io::Data {
+            open: 172.9,
+            high: 173.2,
+            low: 172.8,
+            close: 173.0,
+            volume: 43215.0,
+            vwap: Some(173.01),
+            timestamp: 1710423000000,
+            transactions: Some(512),
+        }])
+    }
+}
@@ -0,0 +1,2 @@
+#[path = "aggregates-fixture.rs"]
+pub mod aggregates_fixture;
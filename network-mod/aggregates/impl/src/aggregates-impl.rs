@@ -0,0 +1,156 @@
+use std::{collections::HashMap, sync::Arc};
+
+use async_trait::async_trait;
+use reqwest::{Client, StatusCode};
+use serde::Deserialize;
+
+use network_mod_aggregates_io::aggregates as io;
+
+/// The result structure of result in Polygon.io API.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct AggregatesResult {
+    // The close price for the symbol in the given time period.
+    c: f64,
+    // The highest price for the symbol in the given time period.
+    h: f64,
+    // The lowest price for the symbol in the given time period.
+    l: f64,
+    // The number of transactions in the aggregate window.
+    n: Option<i64>,
+    // The open price for the symbol in the given time period.
+    o: f64,
+    // Whether or not this aggregate is for an OTC ticker.
+    otc: Option<bool>,
+    // The Unix Msec timestamp for the start of the aggregate window.
+    t: i64,
+    // The trading volume of the symbol in the given time period.
+    v: f64,
+    // The volume weighted average price.
+    vw: Option<f64>,
+}
+
+/// The response structure of Polygon.io API.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+#[serde(rename_all = "camelCase")]
+pub struct AggregatesResponse {
+    ticker: Arc<str>,
+    adjusted: bool,
+    query_count: i64,
+    results_count: i64,
+    #[serde(rename = "next_url")]
+    next_url: Option<Arc<str>>,
+    #[serde(rename = "request_id")]
+    request_id: Arc<str>,
+    status: Arc<str>,
+    // It's missing when there's no bar in the range, e.g. on a holiday.
+    #[serde(default)]
+    results: Vec<AggregatesResult>,
+}
+
+/// The error structure of Polygon.io API.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct AggregatesError {
+    status: Arc<str>,
+    request_id: Arc<str>,
+    // Polygon.io names it `error` for authorization and rate limit failures.
+    #[serde(alias = "error")]
+    message: Arc<str>,
+}
+
+/// The service to implement the API of Aggregates.
+#[derive(Debug)]
+pub struct Service {
+    url: Arc<str>,
+    api_key: Arc<str>,
+    client: Arc<Client>,
+}
+
+impl Service {
+    pub fn new(client: Arc<Client>, api_key: &str) -> io::Result<Self> {
+        Self::with_host(client, api_key, "https://api.polygon.io")
+    }
+
+    /// Creates the service against another host, e.g. a local mock server of Polygon.io.
+    pub fn with_host(client: Arc<Client>, api_key: &str, host: &str) -> io::Result<Self> {
+        if api_key.is_empty() {
+            return Err(io::Error::ApiKeyIsEmpty);
+        }
+
+        Ok(Self {
+            client,
+            api_key: api_key.into(),
+            url: format!(
+                "{}/v2/aggs/ticker/{{TICKER}}/range/{{MULTIPLIER}}/{{TIMESPAN}}/{{FROM}}/{{TO}}",
+                host
+            )
+            .into(),
+        })
+    }
+}
+
+#[async_trait]
+impl io::Api for Service {
+    async fn get_aggregates(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        request: &io::Request,
+    ) -> io::Result<Vec<io::Data>> {
+        let symbol = request.ticker.ticker(deps.provide_snp().as_ref());
+
+        let mut params = HashMap::new();
+        params.insert("apiKey", self.api_key.as_ref());
+        params.insert("adjusted", if request.adjusted { "true" } else { "false" });
+        params.insert("sort", "asc");
+        params.insert("limit", "50000");
+
+        let client = &self.client;
+        let mut url: String = self
+            .url
+            .replace("{TICKER}", &symbol)
+            .replace("{MULTIPLIER}", &request.multiplier.to_string())
+            .replace("{TIMESPAN}", request.timespan.as_str())
+            .replace("{FROM}", &request.from.format("%Y-%m-%d").to_string())
+            .replace("{TO}", &request.to.format("%Y-%m-%d").to_string());
+        let mut bars = vec![];
+
+        while !url.is_empty() {
+            let response = client.get(&url).query(&params).send().await?;
+            let status_code = response.status();
+
+            let response_body = &response.text().await?;
+
+            if status_code == StatusCode::OK {
+                let aggregates_data: AggregatesResponse = serde_json::from_str(response_body)?;
+
+                bars.extend(aggregates_data.results.into_iter().map(|b| io::Data {
+                    open: b.o,
+                    high: b.h,
+                    low: b.l,
+                    close: b.c,
+                    volume: b.v,
+                    vwap: b.vw,
+                    timestamp: b.t,
+                    transactions: b.n,
+                }));
+
+                // The `next_url` keeps the other params in its cursor.
+                params.retain(|k, _| *k == "apiKey");
+                url = match aggregates_data.next_url {
+                    Some(u) => u.to_string(),
+                    None => "".to_string(),
+                };
+            } else {
+                let error_data: AggregatesError = serde_json::from_str(response_body)?;
+
+                return Err(io::Error::Server(
+                    url.to_string(),
+                    error_data.message.to_string(),
+                ));
+            }
+        }
+        Ok(bars)
+    }
+}
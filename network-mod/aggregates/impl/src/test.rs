@@ -0,0 +1,238 @@
+use std::env;
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use network_mod_mock_server::{
+    mock_server::{MockServer, Route},
+    responses,
+};
+use rstest::rstest;
+use snp_mod_fixture::snp_fixture::ServiceFake;
+use snp_mod_io::snp;
+
+use crate::aggregates_impl::Service;
+use io::Api;
+use network_mod_aggregates_io::aggregates as io;
+
+// It's not quite fit into the definiton of unit test, because it requests through network,
+// but we need it to stay here for future fast check.
+#[ignore]
+#[tokio::test]
+async fn call_aapl_aggregates() -> io::Result<()> {
+    let api_key = env::var("API_KEY").unwrap();
+    let client = reqwest::Client::new();
+    let service = Service::new(Arc::new(client), &api_key).unwrap();
+
+    let response = service
+        .get_aggregates(given_deps(), &given_request(io::Timespan::Day, true))
+        .await;
+    match response {
+        Ok(r) => {
+            dbg!(r);
+        }
+        Err(e) => {
+            dbg!(e);
+        }
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_paginated_aggregates_from_mock_server() -> io::Result<()> {
+    let server = MockServer::start(vec![
+        Route::new(
+            "/v2/aggs/ticker/AAPL/range/1/minute/1710423060000/1710460800000",
+            200,
+            responses::AGGREGATES_AAPL_MINUTE_PAGE_2,
+        )
+        .with_query("cursor", "bGltaXQ9MiZzb3J0PWFzYw"),
+        Route::new(
+            "/v2/aggs/ticker/AAPL/range/1/minute/2024-03-14/2024-03-14",
+            200,
+            responses::AGGREGATES_AAPL_MINUTE_PAGE_1,
+        )
+        .with_query("adjusted", "true")
+        .with_query("sort", "asc"),
+    ])
+    .await
+    .unwrap();
+    let service = given_service(&server);
+
+    let bars = service
+        .get_aggregates(given_deps(), &given_request(io::Timespan::Minute, true))
+        .await?;
+
+    assert_eq!(3, bars.len());
+    assert_eq!(1710423000000, bars[0].timestamp);
+    assert_eq!(172.91, bars[0].open);
+    assert_eq!(173.23, bars[0].high);
+    assert_eq!(172.8, bars[0].low);
+    assert_eq!(172.97, bars[0].close);
+    assert_eq!(1243215.0, bars[0].volume);
+    assert_eq!(Some(5120), bars[0].transactions);
+    assert_eq!(None, bars[2].vwap);
+    assert_eq!(2, server.requests().len());
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_unadjusted_empty_aggregates() -> io::Result<()> {
+    let server = MockServer::start(vec![Route::new(
+        "/v2/aggs/ticker/AAPL/range/1/day/2024-03-14/2024-03-14",
+        200,
+        responses::AGGREGATES_EMPTY,
+    )
+    .with_query("adjusted", "false")])
+    .await
+    .unwrap();
+    let service = given_service(&server);
+
+    let bars = service
+        .get_aggregates(given_deps(), &given_request(io::Timespan::Day, false))
+        .await?;
+
+    assert!(bars.is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_options_contract_aggregates() -> io::Result<()> {
+    let server = MockServer::start(vec![Route::new(
+        "/v2/aggs/ticker/O:AAPL240621C00190000/range/1/day/2024-03-14/2024-03-14",
+        200,
+        responses::AGGREGATES_EMPTY,
+    )])
+    .await
+    .unwrap();
+    let service = given_service(&server);
+    let request = io::Request {
+        ticker: snp::Instrument::Option("O:AAPL240621C00190000".into()),
+        ..given_request(io::Timespan::Day, true)
+    };
+
+    let bars = service.get_aggregates(given_deps(), &request).await?;
+
+    assert!(bars.is_empty());
+    assert_eq!(1, server.requests().len());
+    Ok(())
+}
+
+#[rstest]
+#[case(
+    snp::Instrument::Crypto { base: "BTC".into(), quote: "USD".into() },
+    "/v2/aggs/ticker/X:BTCUSD/range/1/day/2024-03-14/2024-03-14",
+    responses::AGGREGATES_X_BTCUSD_DAY,
+    71470.56
+)]
+#[case(
+    snp::Instrument::Forex { base: "EUR".into(), quote: "USD".into() },
+    "/v2/aggs/ticker/C:EURUSD/range/1/day/2024-03-14/2024-03-14",
+    responses::AGGREGATES_C_EURUSD_DAY,
+    1.08825
+)]
+#[tokio::test]
+async fn get_crypto_and_forex_aggregates(
+    #[case] ticker: snp::Instrument,
+    #[case] path: &str,
+    #[case] body: &str,
+    #[case] close: f64,
+) -> io::Result<()> {
+    let server = MockServer::start(vec![Route::new(path, 200, body)])
+        .await
+        .unwrap();
+    let service = given_service(&server);
+    let request = io::Request {
+        ticker,
+        ..given_request(io::Timespan::Day, true)
+    };
+
+    let bars = service.get_aggregates(given_deps(), &request).await?;
+
+    assert_eq!(1, bars.len());
+    assert_eq!(close, bars[0].close);
+    assert_eq!(1710374400000, bars[0].timestamp);
+    Ok(())
+}
+
+#[rstest]
+#[case(401, responses::ERROR_UNAUTHORIZED, "Unknown API Key")]
+#[case(
+    429,
+    responses::ERROR_TOO_MANY_REQUESTS,
+    "You've exceeded the maximum requests"
+)]
+#[tokio::test]
+async fn get_aggregates_server_error(
+    #[case] status: u16,
+    #[case] body: &str,
+    #[case] message: &str,
+) {
+    let server = MockServer::start(vec![Route::new(
+        "/v2/aggs/ticker/AAPL/range/1/hour/2024-03-14/2024-03-14",
+        status,
+        body,
+    )])
+    .await
+    .unwrap();
+    let service = given_service(&server);
+
+    let response = service
+        .get_aggregates(given_deps(), &given_request(io::Timespan::Hour, true))
+        .await;
+
+    match response {
+        Err(io::Error::Server(_, m)) => assert!(m.starts_with(message), "message {:?}", m),
+        r => panic!("unexpected response {:?}", r),
+    }
+}
+
+#[tokio::test]
+async fn get_aggregates_malformed_json() {
+    let server = MockServer::start(vec![Route::new(
+        "/v2/aggs/ticker/AAPL/range/1/day/2024-03-14/2024-03-14",
+        200,
+        responses::MALFORMED,
+    )])
+    .await
+    .unwrap();
+    let service = given_service(&server);
+
+    let response = service
+        .get_aggregates(given_deps(), &given_request(io::Timespan::Day, true))
+        .await;
+
+    assert!(
+        matches!(response, Err(io::Error::JsonParse(_))),
+        "{:?}",
+        response
+    );
+}
+
+fn given_service(server: &MockServer) -> Service {
+    let client = reqwest::Client::new();
+    Service::with_host(Arc::new(client), "key", &server.host()).unwrap()
+}
+
+fn given_request(timespan: io::Timespan, adjusted: bool) -> io::Request {
+    let date = NaiveDate::from_ymd_opt(2024, 3, 14).unwrap();
+    io::Request {
+        ticker: snp::Instrument::Stock(snp::SnP::AAPL),
+        multiplier: 1,
+        timespan,
+        from: date,
+        to: date,
+        adjusted,
+    }
+}
+
+fn given_deps() -> Arc<dyn io::Depends + Send + Sync> {
+    Arc::new(DependsFake {})
+}
+
+struct DependsFake {}
+
+impl io::Depends for DependsFake {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        Arc::new(ServiceFake {})
+    }
+}
@@ -0,0 +1,85 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use snp_mod_io::snp;
+
+/// The size of the time window of each bar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Timespan {
+    Minute,
+    Hour,
+    Day,
+}
+
+impl Timespan {
+    /// The name of the timespan in Polygon.io API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Timespan::Minute => "minute",
+            Timespan::Hour => "hour",
+            Timespan::Day => "day",
+        }
+    }
+}
+
+/// The bars to request, from the start of `from` to the end of `to`.
+#[derive(Debug, Clone)]
+pub struct Request {
+    /// Any instrument, like a stock, an options contract, a crypto or a currency pair.
+    pub ticker: snp::Instrument,
+    pub multiplier: u32,
+    pub timespan: Timespan,
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    /// Whether the results are adjusted for splits.
+    pub adjusted: bool,
+}
+
+/// The data that is needed to interact with the Aggregates modules, which is a bar.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Data {
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub volume: f64,
+    pub vwap: Option<f64>,
+    /// The millisecond Unix timestamp of the start of the bar.
+    pub timestamp: i64,
+    pub transactions: Option<i64>,
+}
+
+/// All possible errors of Aggregates modules.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Failed to create an API client; can be invalid 'api_key'.")]
+    ApiKeyIsEmpty,
+
+    #[error("Failed to send a request to the service provider; can be invalid 'url', or 'api_key', or network IO problem: {0}")]
+    Request(#[from] reqwest::Error),
+
+    #[error("Can't process the body text from the response: {0}")]
+    JsonParse(#[from] serde_json::Error),
+
+    #[error("Provider server response error '{1}' for this url '{0}'.")]
+    Server(String, String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The API of Aggregates, which defines all the functionality of Aggregates modules.
+#[async_trait]
+pub trait Api {
+    /// Follows all the pages, and returns the bars in ascending order of time.
+    async fn get_aggregates(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        request: &Request,
+    ) -> Result<Vec<Data>>;
+}
+
+/// All the dependencies of the Aggregates modules to other modules.
+pub trait Depends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync>;
+}
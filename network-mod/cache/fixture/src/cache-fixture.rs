@@ -0,0 +1,21 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use network_mod_cache_io::cache as io;
+
+pub struct ServiceFake;
+
+#[async_trait]
+impl io::Api for ServiceFake {
+    async fn load(&self, _key: &io::Key) -> io::Result<Option<Arc<str>>> {
+        Ok(None)
+    }
+
+    async fn store(&self, _key: &io::Key, _body: &str) -> io::Result<()> {
+        Ok(())
+    }
+
+    async fn remove(&self, _key: &io::Key) -> io::Result<()> {
+        Ok(())
+    }
+}
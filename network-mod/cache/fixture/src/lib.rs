@@ -0,0 +1,2 @@
+#[path = "cache-fixture.rs"]
+pub mod cache_fixture;
@@ -0,0 +1,94 @@
+use std::{collections::HashMap, fmt::Write, io::ErrorKind, path::PathBuf, sync::Arc};
+
+use async_trait::async_trait;
+use sha2::{Digest, Sha256};
+use tokio::fs;
+
+use network_mod_cache_io::cache as io;
+
+/// The service to implement the API of Cache on disk. Each response is a file named by the hash
+/// of its key, and only the endpoints with a TTL rule are cached.
+#[derive(Debug)]
+pub struct Service {
+    directory: PathBuf,
+    rules: HashMap<Arc<str>, io::Ttl>,
+}
+
+impl Service {
+    pub fn new(directory: PathBuf) -> Self {
+        Self {
+            directory,
+            rules: HashMap::new(),
+        }
+    }
+
+    pub fn with_ttl(mut self, endpoint: &str, ttl: io::Ttl) -> Self {
+        self.rules.insert(endpoint.into(), ttl);
+        self
+    }
+
+    fn path(&self, key: &io::Key) -> PathBuf {
+        self.directory.join(format!("{}.json", address(key)))
+    }
+}
+
+/// The content address of a key, which is the hex SHA-256 of its canonical form.
+pub fn address(key: &io::Key) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(key.endpoint.as_bytes());
+    for (k, v) in &key.params {
+        hasher.update(format!("\n{}={}", k, v).as_bytes());
+    }
+    hasher
+        .finalize()
+        .iter()
+        .fold(String::new(), |mut hex, byte| {
+            let _ = write!(hex, "{:02x}", byte);
+            hex
+        })
+}
+
+#[async_trait]
+impl io::Api for Service {
+    async fn load(&self, key: &io::Key) -> io::Result<Option<Arc<str>>> {
+        let ttl = match self.rules.get(&key.endpoint) {
+            Some(ttl) => ttl,
+            None => return Ok(None),
+        };
+        let path = self.path(key);
+        let metadata = match fs::metadata(&path).await {
+            Ok(metadata) => metadata,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+        if let io::Ttl::For(duration) = ttl {
+            let age = metadata.modified()?.elapsed().unwrap_or_default();
+            if age > *duration {
+                return Ok(None);
+            }
+        }
+
+        Ok(Some(fs::read_to_string(&path).await?.into()))
+    }
+
+    async fn store(&self, key: &io::Key, body: &str) -> io::Result<()> {
+        if !self.rules.contains_key(&key.endpoint) {
+            return Ok(());
+        }
+        fs::create_dir_all(&self.directory).await?;
+        let path = self.path(key);
+        // Renaming is atomic, so a crash never leaves a half written response behind.
+        let temporary = path.with_extension("json.tmp");
+        fs::write(&temporary, body).await?;
+        fs::rename(&temporary, &path).await?;
+        Ok(())
+    }
+
+    async fn remove(&self, key: &io::Key) -> io::Result<()> {
+        match fs::remove_file(self.path(key)).await {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
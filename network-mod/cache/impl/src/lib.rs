@@ -0,0 +1,5 @@
+#[path = "cache-impl.rs"]
+pub mod cache_impl;
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,91 @@
+use std::{env, path::PathBuf, time::Duration};
+
+use crate::cache_impl::{address, Service};
+use io::Api;
+use network_mod_cache_io::cache as io;
+
+#[tokio::test]
+async fn store_and_load() -> io::Result<()> {
+    let service = Service::new(given_directory("store_and_load"))
+        .with_ttl("/v3/reference/tickers/{TICKER}", io::Ttl::Forever);
+    let key = given_key();
+
+    assert_eq!(None, service.load(&key).await?);
+    service.store(&key, "{\"status\":\"OK\"}").await?;
+
+    assert_eq!(
+        Some("{\"status\":\"OK\"}".into()),
+        service.load(&key).await?
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn remove_the_response() -> io::Result<()> {
+    let service = Service::new(given_directory("remove_the_response"))
+        .with_ttl("/v3/reference/tickers/{TICKER}", io::Ttl::Forever);
+    let key = given_key();
+
+    service.store(&key, "{\"status\":").await?;
+    service.remove(&key).await?;
+    service.remove(&key).await?;
+
+    assert_eq!(None, service.load(&key).await?);
+    Ok(())
+}
+
+#[tokio::test]
+async fn skip_endpoint_without_rule() -> io::Result<()> {
+    let service = Service::new(given_directory("skip_endpoint_without_rule"));
+    let key = given_key();
+
+    service.store(&key, "{\"status\":\"OK\"}").await?;
+
+    assert_eq!(None, service.load(&key).await?);
+    Ok(())
+}
+
+#[tokio::test]
+async fn expire_after_ttl() -> io::Result<()> {
+    let service = Service::new(given_directory("expire_after_ttl")).with_ttl(
+        "/v3/reference/tickers/{TICKER}",
+        io::Ttl::For(Duration::from_millis(10)),
+    );
+    let key = given_key();
+
+    service.store(&key, "{\"status\":\"OK\"}").await?;
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    assert_eq!(None, service.load(&key).await?);
+    Ok(())
+}
+
+#[test]
+fn address_ignores_params_order() {
+    let key = given_key();
+    let reordered = io::Key::new(
+        "/v3/reference/tickers/{TICKER}",
+        &[("ticker", "AAPL"), ("date", "2024-03-14")],
+    );
+    let other = io::Key::new(
+        "/v3/reference/tickers/{TICKER}",
+        &[("date", "2024-03-15"), ("ticker", "AAPL")],
+    );
+
+    assert_eq!(address(&key), address(&reordered));
+    assert_ne!(address(&key), address(&other));
+    assert_eq!(64, address(&key).len());
+}
+
+fn given_key() -> io::Key {
+    io::Key::new(
+        "/v3/reference/tickers/{TICKER}",
+        &[("date", "2024-03-14"), ("ticker", "AAPL")],
+    )
+}
+
+fn given_directory(name: &str) -> PathBuf {
+    let directory = env::temp_dir().join(format!("cache-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&directory);
+    directory
+}
@@ -0,0 +1,56 @@
+use std::{sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+
+/// The request that a cached response belongs to. The `apiKey` must never be among the params.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Key {
+    pub endpoint: Arc<str>,
+    pub params: Vec<(Arc<str>, Arc<str>)>,
+}
+
+impl Key {
+    /// Sorts the params, so the same request always makes the same key.
+    pub fn new(endpoint: &str, params: &[(&str, &str)]) -> Self {
+        let mut params: Vec<(Arc<str>, Arc<str>)> = params
+            .iter()
+            .map(|(k, v)| (Arc::from(*k), Arc::from(*v)))
+            .collect();
+        params.sort();
+        Self {
+            endpoint: endpoint.into(),
+            params,
+        }
+    }
+}
+
+/// How long the cached responses of an endpoint stay fresh.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ttl {
+    /// The responses never change, e.g. the reference data of a past date.
+    Forever,
+    /// The responses are reused until they get older than this duration.
+    For(Duration),
+}
+
+/// All possible errors of Cache modules.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Can't read or write the cache: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The API of Cache, which defines all the functionality of Cache modules.
+#[async_trait]
+pub trait Api {
+    /// Returns the body of a fresh cached response, if any.
+    async fn load(&self, key: &Key) -> Result<Option<Arc<str>>>;
+
+    /// Keeps the body of a successful response, if its endpoint has a TTL rule.
+    async fn store(&self, key: &Key, body: &str) -> Result<()>;
+
+    /// Drops the cached response, if any, e.g. a body that can't be parsed.
+    async fn remove(&self, key: &Key) -> Result<()>;
+}
@@ -0,0 +1,28 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use network_mod_conditions_io::conditions as io;
+
+pub struct ServiceFake;
+
+#[async_trait]
+impl io::Api for ServiceFake {
+    async fn get_conditions(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        _asset_class: &str,
+    ) -> io::Result<Vec<io::Data>> {
+        Ok(vec![io::Data {
+            id: 37,
+            condition_type: "sale_condition".into(),
+            name: "Odd Lot Trade".into(),
+            abbreviation: None,
+            asset_class: "stocks".into(),
+            data_types: vec!["trade".into()],
+            legacy: false,
+            updates_high_low: false,
+            updates_open_close: false,
+            updates_volume: true,
+        }])
+    }
+}
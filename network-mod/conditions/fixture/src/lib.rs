@@ -0,0 +1,2 @@
+#[path = "conditions-fixture.rs"]
+pub mod conditions_fixture;
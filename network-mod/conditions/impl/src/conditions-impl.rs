@@ -0,0 +1,168 @@
+use std::{collections::HashMap, sync::Arc};
+
+use async_trait::async_trait;
+use reqwest::{Client, StatusCode};
+use serde::Deserialize;
+
+use network_mod_conditions_io::conditions as io;
+
+/// The update rules structure of result in Polygon.io API.
+#[derive(Debug, Default, Deserialize)]
+#[allow(dead_code)]
+pub struct UpdateRules {
+    // Whether or not trades with this condition update the high/low.
+    updates_high_low: bool,
+    // Whether or not trades with this condition update the open/close.
+    updates_open_close: bool,
+    // Whether or not trades with this condition update the volume.
+    updates_volume: bool,
+}
+
+/// The update rules of result in Polygon.io API, for the consolidated and the market center
+/// bars.
+#[derive(Debug, Default, Deserialize)]
+#[allow(dead_code)]
+pub struct ConditionsUpdateRules {
+    #[serde(default)]
+    consolidated: UpdateRules,
+    #[serde(default)]
+    market_center: UpdateRules,
+}
+
+/// The result structure of result in Polygon.io API.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct ConditionsResult {
+    // A commonly-used abbreviation for this condition.
+    abbreviation: Option<Arc<str>>,
+    // An identifier for a group of similar financial instruments.
+    asset_class: Arc<str>,
+    // Data types that this condition applies to.
+    #[serde(default)]
+    data_types: Vec<Arc<str>>,
+    // A short description of the semantics of this condition.
+    description: Option<Arc<str>>,
+    // An identifier used by Polygon.io for this condition. Unique per data type.
+    id: i32,
+    // If present, mapping this condition from a Polygon.io code to a SIP symbol depends on this
+    // attribute.
+    #[serde(default)]
+    legacy: bool,
+    // The name of this condition.
+    name: Arc<str>,
+    // An identifier for a collection of related conditions.
+    #[serde(rename = "type")]
+    condition_type: Arc<str>,
+    // A list of aggregation rules. Polygon.io leaves them out of the conditions that update
+    // nothing.
+    #[serde(default)]
+    update_rules: ConditionsUpdateRules,
+}
+
+/// The response structure of Polygon.io API.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct ConditionsResponse {
+    count: Option<i64>,
+    next_url: Option<Arc<str>>,
+    request_id: Arc<str>,
+    status: Arc<str>,
+    #[serde(default)]
+    results: Vec<ConditionsResult>,
+}
+
+/// The error structure of Polygon.io API.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct ConditionsError {
+    status: Arc<str>,
+    request_id: Arc<str>,
+    // Polygon.io names it `error` for authorization and rate limit failures.
+    #[serde(alias = "error")]
+    message: Arc<str>,
+}
+
+/// The service to implement the API of Conditions.
+#[derive(Debug)]
+pub struct Service {
+    url: Arc<str>,
+    api_key: Arc<str>,
+    client: Arc<Client>,
+}
+
+impl Service {
+    pub fn new(client: Arc<Client>, api_key: &str) -> io::Result<Self> {
+        Self::with_host(client, api_key, "https://api.polygon.io")
+    }
+
+    /// Creates the service against another host, e.g. a local mock server of Polygon.io.
+    pub fn with_host(client: Arc<Client>, api_key: &str, host: &str) -> io::Result<Self> {
+        if api_key.is_empty() {
+            return Err(io::Error::ApiKeyIsEmpty);
+        }
+
+        Ok(Self {
+            client,
+            api_key: api_key.into(),
+            url: format!("{}/v3/reference/conditions", host).into(),
+        })
+    }
+}
+
+#[async_trait]
+impl io::Api for Service {
+    async fn get_conditions(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        asset_class: &str,
+    ) -> io::Result<Vec<io::Data>> {
+        let mut params = HashMap::new();
+        params.insert("apiKey", self.api_key.as_ref());
+        params.insert("asset_class", asset_class);
+        params.insert("data_type", "trade");
+        params.insert("limit", "1000");
+
+        let client = &self.client;
+        let mut url: String = self.url.to_string();
+        let mut conditions = vec![];
+
+        while !url.is_empty() {
+            let response = client.get(&url).query(&params).send().await?;
+            let status_code = response.status();
+
+            let response_body = &response.text().await?;
+
+            if status_code == StatusCode::OK {
+                let conditions_data: ConditionsResponse = serde_json::from_str(response_body)?;
+
+                conditions.extend(conditions_data.results.into_iter().map(|c| io::Data {
+                    id: c.id,
+                    condition_type: c.condition_type,
+                    name: c.name,
+                    abbreviation: c.abbreviation,
+                    asset_class: c.asset_class,
+                    data_types: c.data_types,
+                    legacy: c.legacy,
+                    updates_high_low: c.update_rules.consolidated.updates_high_low,
+                    updates_open_close: c.update_rules.consolidated.updates_open_close,
+                    updates_volume: c.update_rules.consolidated.updates_volume,
+                }));
+
+                // The `next_url` keeps the other params in its cursor.
+                params.retain(|k, _| *k == "apiKey");
+                url = match conditions_data.next_url {
+                    Some(u) => u.to_string(),
+                    None => "".to_string(),
+                };
+            } else {
+                let error_data: ConditionsError = serde_json::from_str(response_body)?;
+
+                return Err(io::Error::Server(
+                    url.to_string(),
+                    error_data.message.to_string(),
+                ));
+            }
+        }
+        Ok(conditions)
+    }
+}
@@ -0,0 +1,144 @@
+use std::env;
+use std::sync::Arc;
+
+use network_mod_mock_server::{
+    mock_server::{MockServer, Route},
+    responses,
+};
+use rstest::rstest;
+use snp_mod_fixture::snp_fixture::ServiceFake;
+use snp_mod_io::snp;
+
+use crate::conditions_impl::Service;
+use io::Api;
+use network_mod_conditions_io::conditions as io;
+
+// It's not quite fit into the definiton of unit test, because it requests through network,
+// but we need it to stay here for future fast check.
+#[ignore]
+#[tokio::test]
+async fn call_stocks_conditions() -> io::Result<()> {
+    let api_key = env::var("API_KEY").unwrap();
+    let client = reqwest::Client::new();
+    let service = Service::new(Arc::new(client), &api_key).unwrap();
+
+    let response = service.get_conditions(given_deps(), "stocks").await;
+    match response {
+        Ok(r) => {
+            dbg!(r);
+        }
+        Err(e) => {
+            dbg!(e);
+        }
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_paginated_conditions_from_mock_server() -> io::Result<()> {
+    let server = MockServer::start(vec![
+        Route::new(
+            "/v3/reference/conditions",
+            200,
+            responses::CONDITIONS_STOCKS_PAGE_2,
+        )
+        .with_query(
+            "cursor",
+            "YXA9MTQmYXNzZXRfY2xhc3M9c3RvY2tzJmRhdGFfdHlwZT10cmFkZSZsaW1pdD0y",
+        ),
+        Route::new(
+            "/v3/reference/conditions",
+            200,
+            responses::CONDITIONS_STOCKS_PAGE_1,
+        )
+        .with_query("asset_class", "stocks")
+        .with_query("data_type", "trade"),
+    ])
+    .await
+    .unwrap();
+    let service = given_service(&server);
+
+    let conditions = service.get_conditions(given_deps(), "stocks").await?;
+
+    assert_eq!(4, conditions.len());
+    let sweep = &conditions[1];
+    assert_eq!(14, sweep.id);
+    assert_eq!("Intermarket Sweep", sweep.name.as_ref());
+    assert_eq!(Some("ISO"), sweep.abbreviation.as_deref());
+    assert_eq!("sale_condition", sweep.condition_type.as_ref());
+    assert!(sweep.updates_high_low);
+    let odd_lot = &conditions[3];
+    assert_eq!(37, odd_lot.id);
+    assert_eq!("Odd Lot Trade", odd_lot.name.as_ref());
+    assert!(!odd_lot.updates_high_low);
+    assert!(!odd_lot.updates_open_close);
+    assert!(odd_lot.updates_volume);
+    assert_eq!(2, server.requests().len());
+    Ok(())
+}
+
+#[rstest]
+#[case(401, responses::ERROR_UNAUTHORIZED, "Unknown API Key")]
+#[case(
+    429,
+    responses::ERROR_TOO_MANY_REQUESTS,
+    "You've exceeded the maximum requests"
+)]
+#[tokio::test]
+async fn get_conditions_server_error(
+    #[case] status: u16,
+    #[case] body: &str,
+    #[case] message: &str,
+) {
+    let server = MockServer::start(vec![Route::new("/v3/reference/conditions", status, body)])
+        .await
+        .unwrap();
+    let service = given_service(&server);
+
+    let response = service.get_conditions(given_deps(), "stocks").await;
+
+    match response {
+        Err(io::Error::Server(url, m)) => {
+            assert!(!url.contains("apiKey"), "url {:?}", url);
+            assert!(m.starts_with(message), "message {:?}", m);
+        }
+        r => panic!("unexpected response {:?}", r),
+    }
+}
+
+#[tokio::test]
+async fn get_conditions_malformed_json() {
+    let server = MockServer::start(vec![Route::new(
+        "/v3/reference/conditions",
+        200,
+        responses::MALFORMED,
+    )])
+    .await
+    .unwrap();
+    let service = given_service(&server);
+
+    let response = service.get_conditions(given_deps(), "stocks").await;
+
+    assert!(
+        matches!(response, Err(io::Error::JsonParse(_))),
+        "{:?}",
+        response
+    );
+}
+
+fn given_service(server: &MockServer) -> Service {
+    let client = reqwest::Client::new();
+    Service::with_host(Arc::new(client), "key", &server.host()).unwrap()
+}
+
+fn given_deps() -> Arc<dyn io::Depends + Send + Sync> {
+    Arc::new(DependsFake {})
+}
+
+struct DependsFake {}
+
+impl io::Depends for DependsFake {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        Arc::new(ServiceFake {})
+    }
+}
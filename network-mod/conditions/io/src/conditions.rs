@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use snp_mod_io::snp;
+
+/// The data that is needed to interact with the Conditions modules.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Data {
+    /// The ID that the trades refer to, in their `conditions`.
+    pub id: i32,
+    /// Like `sale_condition` or `settlement_condition`.
+    pub condition_type: Arc<str>,
+    pub name: Arc<str>,
+    pub abbreviation: Option<Arc<str>>,
+    pub asset_class: Arc<str>,
+    pub data_types: Vec<Arc<str>>,
+    pub legacy: bool,
+    /// Whether a trade of this condition updates the consolidated high and low, where odd-lot
+    /// and out-of-sequence trades do not.
+    pub updates_high_low: bool,
+    pub updates_open_close: bool,
+    pub updates_volume: bool,
+}
+
+/// All possible errors of Conditions modules.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Failed to create an API client; can be invalid 'api_key'.")]
+    ApiKeyIsEmpty,
+
+    #[error("Failed to send a request to the service provider; can be invalid 'url', or 'api_key', or network IO problem: {0}")]
+    Request(#[from] reqwest::Error),
+
+    #[error("Can't process the body text from the response: {0}")]
+    JsonParse(#[from] serde_json::Error),
+
+    #[error("Provider server response error '{1}' for this url '{0}'.")]
+    Server(String, String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The API of Conditions, which defines all the functionality of Conditions modules.
+#[async_trait]
+pub trait Api {
+    /// Follows all the pages, and returns the trade conditions of the asset class, like
+    /// `stocks`.
+    async fn get_conditions(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        asset_class: &str,
+    ) -> Result<Vec<Data>>;
+}
+
+/// All the dependencies of the Conditions modules to other modules.
+pub trait Depends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync>;
+}
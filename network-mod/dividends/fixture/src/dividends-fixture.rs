@@ -0,0 +1,29 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use network_mod_dividends_io::dividends as io;
+use snp_mod_io::snp;
+
+pub struct ServiceFake;
+
+#[async_trait]
+impl io::Api for ServiceFake {
+    async fn get_dividends(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        _ticker: &snp::SnP,
+    ) -> io::Result<Vec<io::Data>> {
+        Ok(vec![io::Data {
+            id: "E8e3c4f794613e9205e2f178a36c53fcc57cdabb55e1988c87b33f9e52e221444".into(),
+            cash_amount: 0.24,
+            currency: Some("USD".into()),
+            declaration_date: NaiveDate::from_ymd_opt(2024, 2, 1),
+            dividend_type: "CD".into(),
+            ex_dividend_date: NaiveDate::from_ymd_opt(2024, 2, 9).unwrap(),
+            frequency: 4,
+            pay_date: NaiveDate::from_ymd_opt(2024, 2, 15),
+            record_date: NaiveDate::from_ymd_opt(2024, 2, 12),
+        }])
+    }
+}
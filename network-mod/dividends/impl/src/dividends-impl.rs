@@ -0,0 +1,151 @@
+use std::{collections::HashMap, sync::Arc};
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use reqwest::{Client, StatusCode};
+use serde::Deserialize;
+use snp_mod_io::snp::SnP;
+
+use network_mod_dividends_io::dividends as io;
+
+/// The result structure of result in Polygon.io API.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct DividendsResult {
+    // The cash amount of the dividend per share owned.
+    cash_amount: f64,
+    // The currency in which the dividend is paid.
+    currency: Option<Arc<str>>,
+    // The date that the dividend was announced.
+    declaration_date: Option<NaiveDate>,
+    // The type of dividend. Dividends that have been paid and/or are expected to be paid on
+    // consistent schedules are denoted as CD. Special Cash dividends that have been paid that
+    // are infrequent or unusual, and/or can not be expected to occur in the future are denoted
+    // as SC. Long-Term and Short-Term capital gain distributions are denoted as LT and ST,
+    // respectively.
+    dividend_type: Arc<str>,
+    // The date that the stock first trades without the dividend, determined by the exchange.
+    ex_dividend_date: NaiveDate,
+    // The number of times per year the dividend is paid. Possible values are 0 (one-time),
+    // 1 (annually), 2 (bi-annually), 4 (quarterly), and 12 (monthly).
+    frequency: i32,
+    // The unique identifier of the dividend.
+    id: Arc<str>,
+    // The date that the dividend is paid out.
+    pay_date: Option<NaiveDate>,
+    // The date that the stock must be held to receive the dividend, set by the company.
+    record_date: Option<NaiveDate>,
+    // The ticker symbol of the dividend.
+    ticker: Arc<str>,
+}
+
+/// The response structure of Polygon.io API.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct DividendsResponse {
+    next_url: Option<Arc<str>>,
+    request_id: Arc<str>,
+    status: Arc<str>,
+    #[serde(default)]
+    results: Vec<DividendsResult>,
+}
+
+/// The error structure of Polygon.io API.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct DividendsError {
+    status: Arc<str>,
+    request_id: Arc<str>,
+    // Polygon.io names it `error` for authorization and rate limit failures.
+    #[serde(alias = "error")]
+    message: Arc<str>,
+}
+
+/// The service to implement the API of Dividends.
+#[derive(Debug)]
+pub struct Service {
+    url: Arc<str>,
+    api_key: Arc<str>,
+    client: Arc<Client>,
+}
+
+impl Service {
+    pub fn new(client: Arc<Client>, api_key: &str) -> io::Result<Self> {
+        Self::with_host(client, api_key, "https://api.polygon.io")
+    }
+
+    /// Creates the service against another host, e.g. a local mock server of Polygon.io.
+    pub fn with_host(client: Arc<Client>, api_key: &str, host: &str) -> io::Result<Self> {
+        if api_key.is_empty() {
+            return Err(io::Error::ApiKeyIsEmpty);
+        }
+
+        Ok(Self {
+            client,
+            api_key: api_key.into(),
+            url: format!("{}/v3/reference/dividends", host).into(),
+        })
+    }
+}
+
+#[async_trait]
+impl io::Api for Service {
+    async fn get_dividends(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        ticker: &SnP,
+    ) -> io::Result<Vec<io::Data>> {
+        let snp = deps.provide_snp();
+        let snp_symbol_rc = snp.get_symbol(ticker).clone();
+        let snp_symbol = &snp_symbol_rc[..];
+
+        let mut params = HashMap::new();
+        params.insert("apiKey", self.api_key.as_ref());
+        params.insert("ticker", snp_symbol);
+        params.insert("sort", "ex_dividend_date");
+        params.insert("order", "asc");
+        params.insert("limit", "1000");
+
+        let client = &self.client;
+        let mut url: String = self.url.to_string();
+        let mut dividends = vec![];
+
+        while !url.is_empty() {
+            let response = client.get(&url).query(&params).send().await?;
+            let status_code = response.status();
+
+            let response_body = &response.text().await?;
+
+            if status_code == StatusCode::OK {
+                let dividends_data: DividendsResponse = serde_json::from_str(response_body)?;
+
+                dividends.extend(dividends_data.results.into_iter().map(|d| io::Data {
+                    id: d.id,
+                    cash_amount: d.cash_amount,
+                    currency: d.currency,
+                    declaration_date: d.declaration_date,
+                    dividend_type: d.dividend_type,
+                    ex_dividend_date: d.ex_dividend_date,
+                    frequency: d.frequency,
+                    pay_date: d.pay_date,
+                    record_date: d.record_date,
+                }));
+
+                // The `next_url` keeps the other params in its cursor.
+                params.retain(|k, _| *k == "apiKey");
+                url = match dividends_data.next_url {
+                    Some(u) => u.to_string(),
+                    None => "".to_string(),
+                };
+            } else {
+                let error_data: DividendsError = serde_json::from_str(response_body)?;
+
+                return Err(io::Error::Server(
+                    url.to_string(),
+                    error_data.message.to_string(),
+                ));
+            }
+        }
+        Ok(dividends)
+    }
+}
@@ -0,0 +1,142 @@
+use std::env;
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use network_mod_mock_server::{
+    mock_server::{MockServer, Route},
+    responses,
+};
+use rstest::rstest;
+use snp_mod_fixture::snp_fixture::ServiceFake;
+use snp_mod_io::snp;
+
+use crate::dividends_impl::Service;
+use io::Api;
+use network_mod_dividends_io::dividends as io;
+
+// It's not quite fit into the definiton of unit test, because it requests through network,
+// but we need it to stay here for future fast check.
+#[ignore]
+#[tokio::test]
+async fn call_aapl_dividends() -> io::Result<()> {
+    let api_key = env::var("API_KEY").unwrap();
+    let client = reqwest::Client::new();
+    let service = Service::new(Arc::new(client), &api_key).unwrap();
+
+    let response = service.get_dividends(given_deps(), &snp::SnP::AAPL).await;
+    match response {
+        Ok(r) => {
+            dbg!(r);
+        }
+        Err(e) => {
+            dbg!(e);
+        }
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_paginated_dividends_from_mock_server() -> io::Result<()> {
+    let server = MockServer::start(vec![
+        Route::new(
+            "/v3/reference/dividends",
+            200,
+            responses::DIVIDENDS_AAPL_PAGE_2,
+        )
+        .with_query(
+            "cursor",
+            "YXA9MjAyNC0wMi0wOSZhcz0mbGltaXQ9MiZvcmRlcj1hc2Mmc29ydD1leF9kaXZpZGVuZF9kYXRlJnRpY2tlcj1BQVBM",
+        ),
+        Route::new(
+            "/v3/reference/dividends",
+            200,
+            responses::DIVIDENDS_AAPL_PAGE_1,
+        )
+        .with_query("ticker", "AAPL")
+        .with_query("sort", "ex_dividend_date"),
+    ])
+    .await
+    .unwrap();
+    let service = given_service(&server);
+
+    let dividends = service.get_dividends(given_deps(), &snp::SnP::AAPL).await?;
+
+    assert_eq!(3, dividends.len());
+    let dividend = &dividends[1];
+    assert_eq!(0.24, dividend.cash_amount);
+    assert_eq!("CD", dividend.dividend_type.as_ref());
+    assert_eq!(4, dividend.frequency);
+    assert_eq!(
+        NaiveDate::from_ymd_opt(2024, 2, 9).unwrap(),
+        dividend.ex_dividend_date
+    );
+    assert_eq!(NaiveDate::from_ymd_opt(2024, 2, 15), dividend.pay_date);
+    // Polygon.io leaves the dates out until they are announced.
+    assert_eq!(None, dividends[2].pay_date);
+    assert_eq!(None, dividends[2].currency);
+    assert_eq!(2, server.requests().len());
+    Ok(())
+}
+
+#[rstest]
+#[case(401, responses::ERROR_UNAUTHORIZED, "Unknown API Key")]
+#[case(
+    429,
+    responses::ERROR_TOO_MANY_REQUESTS,
+    "You've exceeded the maximum requests"
+)]
+#[tokio::test]
+async fn get_dividends_server_error(
+    #[case] status: u16,
+    #[case] body: &str,
+    #[case] message: &str,
+) {
+    let server = MockServer::start(vec![Route::new("/v3/reference/dividends", status, body)])
+        .await
+        .unwrap();
+    let service = given_service(&server);
+
+    let response = service.get_dividends(given_deps(), &snp::SnP::AAPL).await;
+
+    match response {
+        Err(io::Error::Server(_, m)) => assert!(m.starts_with(message), "message {:?}", m),
+        r => panic!("unexpected response {:?}", r),
+    }
+}
+
+#[tokio::test]
+async fn get_dividends_malformed_json() {
+    let server = MockServer::start(vec![Route::new(
+        "/v3/reference/dividends",
+        200,
+        responses::MALFORMED,
+    )])
+    .await
+    .unwrap();
+    let service = given_service(&server);
+
+    let response = service.get_dividends(given_deps(), &snp::SnP::AAPL).await;
+
+    assert!(
+        matches!(response, Err(io::Error::JsonParse(_))),
+        "{:?}",
+        response
+    );
+}
+
+fn given_service(server: &MockServer) -> Service {
+    let client = reqwest::Client::new();
+    Service::with_host(Arc::new(client), "key", &server.host()).unwrap()
+}
+
+fn given_deps() -> Arc<dyn io::Depends + Send + Sync> {
+    Arc::new(DependsFake {})
+}
+
+struct DependsFake {}
+
+impl io::Depends for DependsFake {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        Arc::new(ServiceFake {})
+    }
+}
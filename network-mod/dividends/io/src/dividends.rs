@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use snp_mod_io::snp;
+
+/// The data that is needed to interact with the Dividends modules.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Data {
+    /// The unique identifier of the dividend in Polygon.io.
+    pub id: Arc<str>,
+    pub cash_amount: f64,
+    pub currency: Option<Arc<str>>,
+    pub declaration_date: Option<NaiveDate>,
+    /// One of `CD`, regular cash dividend, `SC`, special cash dividend, `LT` or `ST`, long-term
+    /// or short-term capital gain.
+    pub dividend_type: Arc<str>,
+    pub ex_dividend_date: NaiveDate,
+    /// The number of times per year the dividend is paid, e.g. 4 for quarterly.
+    pub frequency: i32,
+    pub pay_date: Option<NaiveDate>,
+    pub record_date: Option<NaiveDate>,
+}
+
+/// All possible errors of Dividends modules.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Failed to create an API client; can be invalid 'api_key'.")]
+    ApiKeyIsEmpty,
+
+    #[error("Failed to send a request to the service provider; can be invalid 'url', or 'api_key', or network IO problem: {0}")]
+    Request(#[from] reqwest::Error),
+
+    #[error("Can't process the body text from the response: {0}")]
+    JsonParse(#[from] serde_json::Error),
+
+    #[error("Provider server response error '{1}' for this url '{0}'.")]
+    Server(String, String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The API of Dividends, which defines all the functionality of Dividends modules.
+#[async_trait]
+pub trait Api {
+    /// Follows all the pages, and returns the whole dividend history of the ticker in ascending
+    /// order of ex-dividend date.
+    async fn get_dividends(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        ticker: &snp::SnP,
+    ) -> Result<Vec<Data>>;
+}
+
+/// All the dependencies of the Dividends modules to other modules.
+pub trait Depends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync>;
+}
@@ -0,0 +1,28 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use network_mod_exchanges_io::exchanges as io;
+
+pub struct ServiceFake;
+
+#[async_trait]
+impl io::Api for ServiceFake {
+    async fn get_exchanges(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        _asset_class: &str,
+    ) -> io::Result<Vec<io::Data>> {
+        Ok(vec![io::Data {
+            id: 12,
+            exchange_type: "exchange".into(),
+            asset_class: "stocks".into(),
+            locale: "us".into(),
+            name: "Nasdaq".into(),
+            acronym: Some("Nasdaq".into()),
+            mic: Some("XNAS".into()),
+            operating_mic: Some("XNAS".into()),
+            participant_id: Some("Q".into()),
+            url: Some("https://www.nasdaq.com".into()),
+        }])
+    }
+}
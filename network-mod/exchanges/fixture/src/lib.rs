@@ -0,0 +1,2 @@
+#[path = "exchanges-fixture.rs"]
+pub mod exchanges_fixture;
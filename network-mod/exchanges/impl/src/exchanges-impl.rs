@@ -0,0 +1,132 @@
+use std::{collections::HashMap, sync::Arc};
+
+use async_trait::async_trait;
+use reqwest::{Client, StatusCode};
+use serde::Deserialize;
+
+use network_mod_exchanges_io::exchanges as io;
+
+/// The result structure of result in Polygon.io API.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct ExchangesResult {
+    // A commonly used abbreviation for this exchange.
+    acronym: Option<Arc<str>>,
+    // An identifier for a group of similar financial instruments.
+    asset_class: Arc<str>,
+    // A unique identifier used by Polygon.io for this exchange.
+    id: i32,
+    // An identifier for a geographical location.
+    locale: Arc<str>,
+    // The Market Identifier Code of this exchange (see ISO 10383).
+    mic: Option<Arc<str>>,
+    // Name of this exchange.
+    name: Arc<str>,
+    // The MIC of the entity that operates this exchange.
+    operating_mic: Option<Arc<str>>,
+    // The ID used by SIP's to represent this exchange.
+    participant_id: Option<Arc<str>>,
+    // Represents the type of exchange.
+    #[serde(rename = "type")]
+    exchange_type: Arc<str>,
+    // A link to this exchange's website, if one exists.
+    url: Option<Arc<str>>,
+}
+
+/// The response structure of Polygon.io API.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct ExchangesResponse {
+    count: Option<i64>,
+    request_id: Arc<str>,
+    status: Arc<str>,
+    #[serde(default)]
+    results: Vec<ExchangesResult>,
+}
+
+/// The error structure of Polygon.io API.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct ExchangesError {
+    status: Arc<str>,
+    request_id: Arc<str>,
+    // Polygon.io names it `error` for authorization and rate limit failures.
+    #[serde(alias = "error")]
+    message: Arc<str>,
+}
+
+/// The service to implement the API of Exchanges.
+#[derive(Debug)]
+pub struct Service {
+    url: Arc<str>,
+    api_key: Arc<str>,
+    client: Arc<Client>,
+}
+
+impl Service {
+    pub fn new(client: Arc<Client>, api_key: &str) -> io::Result<Self> {
+        Self::with_host(client, api_key, "https://api.polygon.io")
+    }
+
+    /// Creates the service against another host, e.g. a local mock server of Polygon.io.
+    pub fn with_host(client: Arc<Client>, api_key: &str, host: &str) -> io::Result<Self> {
+        if api_key.is_empty() {
+            return Err(io::Error::ApiKeyIsEmpty);
+        }
+
+        Ok(Self {
+            client,
+            api_key: api_key.into(),
+            url: format!("{}/v3/reference/exchanges", host).into(),
+        })
+    }
+}
+
+#[async_trait]
+impl io::Api for Service {
+    async fn get_exchanges(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        asset_class: &str,
+    ) -> io::Result<Vec<io::Data>> {
+        let mut params = HashMap::new();
+        params.insert("apiKey", self.api_key.as_ref());
+        params.insert("asset_class", asset_class);
+        params.insert("locale", "us");
+
+        let url = &self.url;
+
+        let response = self.client.get(url.as_ref()).query(&params).send().await?;
+        let status_code = response.status();
+
+        let response_body = &response.text().await?;
+
+        if status_code == StatusCode::OK {
+            let exchanges_data: ExchangesResponse = serde_json::from_str(response_body)?;
+
+            Ok(exchanges_data
+                .results
+                .into_iter()
+                .map(|e| io::Data {
+                    id: e.id,
+                    exchange_type: e.exchange_type,
+                    asset_class: e.asset_class,
+                    locale: e.locale,
+                    name: e.name,
+                    acronym: e.acronym,
+                    mic: e.mic,
+                    operating_mic: e.operating_mic,
+                    participant_id: e.participant_id,
+                    url: e.url,
+                })
+                .collect())
+        } else {
+            let error_data: ExchangesError = serde_json::from_str(response_body)?;
+
+            Err(io::Error::Server(
+                url.to_string(),
+                error_data.message.to_string(),
+            ))
+        }
+    }
+}
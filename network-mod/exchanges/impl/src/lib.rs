@@ -0,0 +1,5 @@
+#[path = "exchanges-impl.rs"]
+pub mod exchanges_impl;
+
+#[cfg(test)]
+mod test;
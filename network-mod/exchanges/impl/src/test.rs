@@ -0,0 +1,133 @@
+use std::env;
+use std::sync::Arc;
+
+use network_mod_mock_server::{
+    mock_server::{MockServer, Route},
+    responses,
+};
+use rstest::rstest;
+use snp_mod_fixture::snp_fixture::ServiceFake;
+use snp_mod_io::snp;
+
+use crate::exchanges_impl::Service;
+use io::Api;
+use network_mod_exchanges_io::exchanges as io;
+
+// It's not quite fit into the definiton of unit test, because it requests through network,
+// but we need it to stay here for future fast check.
+#[ignore]
+#[tokio::test]
+async fn call_stocks_exchanges() -> io::Result<()> {
+    let api_key = env::var("API_KEY").unwrap();
+    let client = reqwest::Client::new();
+    let service = Service::new(Arc::new(client), &api_key).unwrap();
+
+    let response = service.get_exchanges(given_deps(), "stocks").await;
+    match response {
+        Ok(r) => {
+            dbg!(r);
+        }
+        Err(e) => {
+            dbg!(e);
+        }
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_exchanges_from_mock_server() -> io::Result<()> {
+    let server = MockServer::start(vec![Route::new(
+        "/v3/reference/exchanges",
+        200,
+        responses::EXCHANGES_STOCKS,
+    )
+    .with_query("asset_class", "stocks")
+    .with_query("locale", "us")])
+    .await
+    .unwrap();
+    let service = given_service(&server);
+
+    let exchanges = service.get_exchanges(given_deps(), "stocks").await?;
+
+    assert_eq!(5, exchanges.len());
+    let nasdaq = &exchanges[3];
+    assert_eq!(12, nasdaq.id);
+    assert_eq!("Nasdaq", nasdaq.name.as_ref());
+    assert_eq!("exchange", nasdaq.exchange_type.as_ref());
+    assert_eq!(Some("XNAS"), nasdaq.mic.as_deref());
+    assert_eq!(Some("T"), nasdaq.participant_id.as_deref());
+    // The trade reporting facilities have no MIC of their own.
+    let trf = &exchanges[4];
+    assert_eq!(201, trf.id);
+    assert_eq!("TRF", trf.exchange_type.as_ref());
+    assert_eq!(None, trf.mic);
+    assert_eq!(None, trf.url);
+    assert_eq!(1, server.requests().len());
+    Ok(())
+}
+
+#[rstest]
+#[case(401, responses::ERROR_UNAUTHORIZED, "Unknown API Key")]
+#[case(
+    429,
+    responses::ERROR_TOO_MANY_REQUESTS,
+    "You've exceeded the maximum requests"
+)]
+#[tokio::test]
+async fn get_exchanges_server_error(
+    #[case] status: u16,
+    #[case] body: &str,
+    #[case] message: &str,
+) {
+    let server = MockServer::start(vec![Route::new("/v3/reference/exchanges", status, body)])
+        .await
+        .unwrap();
+    let service = given_service(&server);
+
+    let response = service.get_exchanges(given_deps(), "stocks").await;
+
+    match response {
+        Err(io::Error::Server(url, m)) => {
+            assert!(!url.contains("apiKey"), "url {:?}", url);
+            assert!(m.starts_with(message), "message {:?}", m);
+        }
+        r => panic!("unexpected response {:?}", r),
+    }
+}
+
+#[tokio::test]
+async fn get_exchanges_malformed_json() {
+    let server = MockServer::start(vec![Route::new(
+        "/v3/reference/exchanges",
+        200,
+        responses::MALFORMED,
+    )])
+    .await
+    .unwrap();
+    let service = given_service(&server);
+
+    let response = service.get_exchanges(given_deps(), "stocks").await;
+
+    assert!(
+        matches!(response, Err(io::Error::JsonParse(_))),
+        "{:?}",
+        response
+    );
+}
+
+fn given_service(server: &MockServer) -> Service {
+    let client = reqwest::Client::new();
+    Service::with_host(Arc::new(client), "key", &server.host()).unwrap()
+}
+
+fn given_deps() -> Arc<dyn io::Depends + Send + Sync> {
+    Arc::new(DependsFake {})
+}
+
+struct DependsFake {}
+
+impl io::Depends for DependsFake {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        Arc::new(ServiceFake {})
+    }
+}
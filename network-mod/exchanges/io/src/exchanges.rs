@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use snp_mod_io::snp;
+
+/// The data that is needed to interact with the Exchanges modules.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Data {
+    /// The ID that the trades and the quotes refer to, in their `exchange` or `trf_id`.
+    pub id: i32,
+    /// One of `exchange`, `TRF` or `SIP`.
+    pub exchange_type: Arc<str>,
+    pub asset_class: Arc<str>,
+    pub locale: Arc<str>,
+    pub name: Arc<str>,
+    pub acronym: Option<Arc<str>>,
+    pub mic: Option<Arc<str>>,
+    pub operating_mic: Option<Arc<str>>,
+    /// The ID of the exchange in the SIP feeds, like `Q` for Nasdaq.
+    pub participant_id: Option<Arc<str>>,
+    pub url: Option<Arc<str>>,
+}
+
+/// All possible errors of Exchanges modules.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Failed to create an API client; can be invalid 'api_key'.")]
+    ApiKeyIsEmpty,
+
+    #[error("Failed to send a request to the service provider; can be invalid 'url', or 'api_key', or network IO problem: {0}")]
+    Request(#[from] reqwest::Error),
+
+    #[error("Can't process the body text from the response: {0}")]
+    JsonParse(#[from] serde_json::Error),
+
+    #[error("Provider server response error '{1}' for this url '{0}'.")]
+    Server(String, String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The API of Exchanges, which defines all the functionality of Exchanges modules.
+#[async_trait]
+pub trait Api {
+    /// Returns the exchanges of the US market of the asset class, like `stocks`.
+    async fn get_exchanges(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        asset_class: &str,
+    ) -> Result<Vec<Data>>;
+}
+
+/// All the dependencies of the Exchanges modules to other modules.
+pub trait Depends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync>;
+}
@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use network_mod_financials_io::financials as io;
+
+pub struct ServiceFake;
+
+#[async_trait]
+impl io::Api for ServiceFake {
+    async fn get_financials(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        cik: &str,
+    ) -> io::Result<Vec<io::Data>> {
+        Ok(vec![io::Data {
+            cik: cik.into(),
+            timeframe: "quarterly".into(),
+            fiscal_year: Some("2024".into()),
+            fiscal_period: "Q1".into(),
+            start_date: NaiveDate::from_ymd_opt(2023, 10, 1),
+            end_date: NaiveDate::from_ymd_opt(2023, 12, 30),
+            filing_date: NaiveDate::from_ymd_opt(2024, 2, 2),
+            line_items: vec![io::LineItem {
+                statement: io::Statement::IncomeStatement,
+                concept: "revenues".into(),
+                label: "Revenues".into(),
+                unit: "USD".into(),
+                value: 119575000000.0,
+            }],
+        }])
+    }
+}
@@ -0,0 +1,201 @@
+use std::{collections::HashMap, sync::Arc};
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use reqwest::{Client, StatusCode};
+use serde::Deserialize;
+
+use network_mod_financials_io::financials as io;
+
+/// The structure of a line item in Polygon.io API.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct LineItemResult {
+    // The name of the line item in the filing.
+    label: Arc<str>,
+    // The position of the line item in its statement.
+    order: Option<i32>,
+    // The unit of the value, like `USD` or `USD / shares`.
+    unit: Arc<str>,
+    value: f64,
+}
+
+/// The statements of a filing in Polygon.io API. Each one is missing when the filing doesn't
+/// have it.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct FinancialsStatements {
+    #[serde(default)]
+    income_statement: HashMap<Arc<str>, LineItemResult>,
+    #[serde(default)]
+    balance_sheet: HashMap<Arc<str>, LineItemResult>,
+    #[serde(default)]
+    cash_flow_statement: HashMap<Arc<str>, LineItemResult>,
+    #[serde(default)]
+    comprehensive_income: HashMap<Arc<str>, LineItemResult>,
+}
+
+/// The result structure of result in Polygon.io API.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct FinancialsResult {
+    // The CIK number for the company.
+    cik: Arc<str>,
+    // The company name.
+    company_name: Option<Arc<str>>,
+    // The start date of the period that these financials cover.
+    start_date: Option<NaiveDate>,
+    // The end date of the period that these financials cover.
+    end_date: Option<NaiveDate>,
+    // The date that the SEC filing which these financials were derived from was made available.
+    filing_date: Option<NaiveDate>,
+    // Fiscal period of the report according to the company, like Q1 or FY.
+    fiscal_period: Arc<str>,
+    // Fiscal year of the report according to the company.
+    fiscal_year: Option<Arc<str>>,
+    // The timeframe of the report, like quarterly, annual or ttm.
+    timeframe: Arc<str>,
+    // The URL of the SEC filing that these financials were derived from.
+    source_filing_url: Option<Arc<str>>,
+    financials: FinancialsStatements,
+}
+
+/// The response structure of Polygon.io API.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct FinancialsResponse {
+    next_url: Option<Arc<str>>,
+    request_id: Arc<str>,
+    status: Arc<str>,
+    #[serde(default)]
+    results: Vec<FinancialsResult>,
+}
+
+/// The error structure of Polygon.io API.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct FinancialsError {
+    status: Arc<str>,
+    request_id: Arc<str>,
+    // Polygon.io names it `error` for authorization and rate limit failures.
+    #[serde(alias = "error")]
+    message: Arc<str>,
+}
+
+/// The service to implement the API of Financials.
+#[derive(Debug)]
+pub struct Service {
+    url: Arc<str>,
+    api_key: Arc<str>,
+    client: Arc<Client>,
+}
+
+impl Service {
+    pub fn new(client: Arc<Client>, api_key: &str) -> io::Result<Self> {
+        Self::with_host(client, api_key, "https://api.polygon.io")
+    }
+
+    /// Creates the service against another host, e.g. a local mock server of Polygon.io.
+    pub fn with_host(client: Arc<Client>, api_key: &str, host: &str) -> io::Result<Self> {
+        if api_key.is_empty() {
+            return Err(io::Error::ApiKeyIsEmpty);
+        }
+
+        Ok(Self {
+            client,
+            api_key: api_key.into(),
+            url: format!("{}/vX/reference/financials", host).into(),
+        })
+    }
+}
+
+#[async_trait]
+impl io::Api for Service {
+    async fn get_financials(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        cik: &str,
+    ) -> io::Result<Vec<io::Data>> {
+        let mut params = HashMap::new();
+        params.insert("apiKey", self.api_key.as_ref());
+        params.insert("cik", cik);
+        params.insert("sort", "filing_date");
+        params.insert("order", "asc");
+        params.insert("limit", "100");
+
+        let client = &self.client;
+        let mut url: String = self.url.to_string();
+        let mut filings = vec![];
+
+        while !url.is_empty() {
+            let response = client.get(&url).query(&params).send().await?;
+            let status_code = response.status();
+
+            let response_body = &response.text().await?;
+
+            if status_code == StatusCode::OK {
+                let financials_data: FinancialsResponse = serde_json::from_str(response_body)?;
+
+                filings.extend(financials_data.results.into_iter().map(to_data));
+
+                // The `next_url` keeps the other params in its cursor.
+                params.retain(|k, _| *k == "apiKey");
+                url = match financials_data.next_url {
+                    Some(u) => u.to_string(),
+                    None => "".to_string(),
+                };
+            } else {
+                let error_data: FinancialsError = serde_json::from_str(response_body)?;
+
+                return Err(io::Error::Server(
+                    url.to_string(),
+                    error_data.message.to_string(),
+                ));
+            }
+        }
+        Ok(filings)
+    }
+}
+
+fn to_data(result: FinancialsResult) -> io::Data {
+    let statements = [
+        (
+            io::Statement::IncomeStatement,
+            result.financials.income_statement,
+        ),
+        (io::Statement::BalanceSheet, result.financials.balance_sheet),
+        (
+            io::Statement::CashFlowStatement,
+            result.financials.cash_flow_statement,
+        ),
+        (
+            io::Statement::ComprehensiveIncome,
+            result.financials.comprehensive_income,
+        ),
+    ];
+    let mut line_items = vec![];
+    for (statement, items) in statements {
+        let mut items: Vec<(Arc<str>, LineItemResult)> = items.into_iter().collect();
+        items.sort_by(|(a_concept, a), (b_concept, b)| {
+            (a.order, a_concept).cmp(&(b.order, b_concept))
+        });
+        line_items.extend(items.into_iter().map(|(concept, item)| io::LineItem {
+            statement,
+            concept,
+            label: item.label,
+            unit: item.unit,
+            value: item.value,
+        }));
+    }
+
+    io::Data {
+        cik: result.cik,
+        timeframe: result.timeframe,
+        fiscal_year: result.fiscal_year,
+        fiscal_period: result.fiscal_period,
+        start_date: result.start_date,
+        end_date: result.end_date,
+        filing_date: result.filing_date,
+        line_items,
+    }
+}
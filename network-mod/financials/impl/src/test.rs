@@ -0,0 +1,156 @@
+use std::env;
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use network_mod_mock_server::{
+    mock_server::{MockServer, Route},
+    responses,
+};
+use rstest::rstest;
+use snp_mod_fixture::snp_fixture::ServiceFake;
+use snp_mod_io::snp;
+
+use crate::financials_impl::Service;
+use io::Api;
+use network_mod_financials_io::financials as io;
+
+// It's not quite fit into the definiton of unit test, because it requests through network,
+// but we need it to stay here for future fast check.
+#[ignore]
+#[tokio::test]
+async fn call_aapl_financials() -> io::Result<()> {
+    let api_key = env::var("API_KEY").unwrap();
+    let client = reqwest::Client::new();
+    let service = Service::new(Arc::new(client), &api_key).unwrap();
+
+    let response = service.get_financials(given_deps(), "0000320193").await;
+    match response {
+        Ok(r) => {
+            dbg!(r);
+        }
+        Err(e) => {
+            dbg!(e);
+        }
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_paginated_financials_from_mock_server() -> io::Result<()> {
+    let server = MockServer::start(vec![
+        Route::new(
+            "/vX/reference/financials",
+            200,
+            responses::FINANCIALS_AAPL_PAGE_2,
+        )
+        .with_query(
+            "cursor",
+            "YXA9MjAyNC0wMi0wMiZhcz0mY2lrPTAwMDAzMjAxOTMmbGltaXQ9MSZvcmRlcj1hc2Mmc29ydD1maWxpbmdfZGF0ZQ",
+        ),
+        Route::new(
+            "/vX/reference/financials",
+            200,
+            responses::FINANCIALS_AAPL_PAGE_1,
+        )
+        .with_query("cik", "0000320193"),
+    ])
+    .await
+    .unwrap();
+    let service = given_service(&server);
+
+    let filings = service.get_financials(given_deps(), "0000320193").await?;
+
+    assert_eq!(2, filings.len());
+    let filing = &filings[0];
+    assert_eq!("0000320193", filing.cik.as_ref());
+    assert_eq!("quarterly", filing.timeframe.as_ref());
+    assert_eq!("Q1", filing.fiscal_period.as_ref());
+    assert_eq!(NaiveDate::from_ymd_opt(2023, 10, 1), filing.start_date);
+    assert_eq!(NaiveDate::from_ymd_opt(2023, 12, 30), filing.end_date);
+    let concepts: Vec<(io::Statement, &str)> = filing
+        .line_items
+        .iter()
+        .map(|i| (i.statement, i.concept.as_ref()))
+        .collect();
+    assert_eq!(
+        vec![
+            (io::Statement::IncomeStatement, "revenues"),
+            (io::Statement::IncomeStatement, "basic_earnings_per_share"),
+            (io::Statement::BalanceSheet, "assets"),
+            (io::Statement::BalanceSheet, "equity"),
+            (io::Statement::CashFlowStatement, "net_cash_flow"),
+            (
+                io::Statement::ComprehensiveIncome,
+                "comprehensive_income_loss"
+            ),
+        ],
+        concepts
+    );
+    assert_eq!(119575000000.0, filing.line_items[0].value);
+    assert_eq!("USD / shares", filing.line_items[1].unit.as_ref());
+    assert_eq!(1, filings[1].line_items.len());
+    Ok(())
+}
+
+#[rstest]
+#[case(401, responses::ERROR_UNAUTHORIZED, "Unknown API Key")]
+#[case(
+    429,
+    responses::ERROR_TOO_MANY_REQUESTS,
+    "You've exceeded the maximum requests"
+)]
+#[tokio::test]
+async fn get_financials_server_error(
+    #[case] status: u16,
+    #[case] body: &str,
+    #[case] message: &str,
+) {
+    let server = MockServer::start(vec![Route::new("/vX/reference/financials", status, body)])
+        .await
+        .unwrap();
+    let service = given_service(&server);
+
+    let response = service.get_financials(given_deps(), "0000320193").await;
+
+    match response {
+        Err(io::Error::Server(_, m)) => assert!(m.starts_with(message), "message {:?}", m),
+        r => panic!("unexpected response {:?}", r),
+    }
+}
+
+#[tokio::test]
+async fn get_financials_malformed_json() {
+    let server = MockServer::start(vec![Route::new(
+        "/vX/reference/financials",
+        200,
+        responses::MALFORMED,
+    )])
+    .await
+    .unwrap();
+    let service = given_service(&server);
+
+    let response = service.get_financials(given_deps(), "0000320193").await;
+
+    assert!(
+        matches!(response, Err(io::Error::JsonParse(_))),
+        "{:?}",
+        response
+    );
+}
+
+fn given_service(server: &MockServer) -> Service {
+    let client = reqwest::Client::new();
+    Service::with_host(Arc::new(client), "key", &server.host()).unwrap()
+}
+
+fn given_deps() -> Arc<dyn io::Depends + Send + Sync> {
+    Arc::new(DependsFake {})
+}
+
+struct DependsFake {}
+
+impl io::Depends for DependsFake {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        Arc::new(ServiceFake {})
+    }
+}
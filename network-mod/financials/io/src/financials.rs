@@ -0,0 +1,75 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use snp_mod_io::snp;
+
+/// The financial statement of a line item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Statement {
+    IncomeStatement,
+    BalanceSheet,
+    CashFlowStatement,
+    ComprehensiveIncome,
+}
+
+/// A value of a filing, like `revenues` in the income statement.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LineItem {
+    pub statement: Statement,
+    /// The key of the value in Polygon.io, like `revenues`.
+    pub concept: Arc<str>,
+    pub label: Arc<str>,
+    pub unit: Arc<str>,
+    pub value: f64,
+}
+
+/// The data that is needed to interact with the Financials modules, which is a filing period.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Data {
+    pub cik: Arc<str>,
+    /// One of `quarterly`, `annual` or `ttm`, trailing twelve months.
+    pub timeframe: Arc<str>,
+    pub fiscal_year: Option<Arc<str>>,
+    /// Like `Q1` or `FY`.
+    pub fiscal_period: Arc<str>,
+    pub start_date: Option<NaiveDate>,
+    pub end_date: Option<NaiveDate>,
+    pub filing_date: Option<NaiveDate>,
+    pub line_items: Vec<LineItem>,
+}
+
+/// All possible errors of Financials modules.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Failed to create an API client; can be invalid 'api_key'.")]
+    ApiKeyIsEmpty,
+
+    #[error("Failed to send a request to the service provider; can be invalid 'url', or 'api_key', or network IO problem: {0}")]
+    Request(#[from] reqwest::Error),
+
+    #[error("Can't process the body text from the response: {0}")]
+    JsonParse(#[from] serde_json::Error),
+
+    #[error("Provider server response error '{1}' for this url '{0}'.")]
+    Server(String, String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The API of Financials, which defines all the functionality of Financials modules.
+#[async_trait]
+pub trait Api {
+    /// Follows all the pages, and returns every filing period of the company, e.g. `0000320193`
+    /// for Apple, in ascending order of filing date.
+    async fn get_financials(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        cik: &str,
+    ) -> Result<Vec<Data>>;
+}
+
+/// All the dependencies of the Financials modules to other modules.
+pub trait Depends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync>;
+}
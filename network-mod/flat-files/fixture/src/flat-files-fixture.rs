@@ -0,0 +1,19 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use network_mod_flat_files_io::flat_files as io;
+
+pub struct ServiceFake;
+
+#[async_trait]
+impl io::Api for ServiceFake {
+    async fn get_rows(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        _dataset: io::Dataset,
+        _date: &NaiveDate,
+    ) -> io::Result<Option<io::Rows>> {
+        Ok(None)
+    }
+}
@@ -0,0 +1,2 @@
+#[path = "flat-files-fixture.rs"]
+pub mod flat_files_fixture;
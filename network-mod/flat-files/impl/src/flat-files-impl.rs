@@ -0,0 +1,379 @@
+use std::{
+    fmt::Write as _,
+    fs::{self, File},
+    io::{BufReader, Write as _},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use async_trait::async_trait;
+use chrono::{NaiveDate, Utc};
+use flate2::read::GzDecoder;
+use hmac::{Hmac, Mac};
+use reqwest::{Client, StatusCode, Url};
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+
+use network_mod_aggregates_io::aggregates;
+use network_mod_flat_files_io::flat_files as io;
+use network_mod_quotes_io::quotes;
+use network_mod_trades_io::trades;
+
+/// The row structure of the trades flat files of Polygon.io.
+#[derive(Debug, Deserialize)]
+struct TradeRecord {
+    ticker: Arc<str>,
+    // The condition codes, separated by comma, like `12,37`.
+    conditions: Option<String>,
+    correction: Option<i32>,
+    exchange: i32,
+    id: Arc<str>,
+    participant_timestamp: Option<i64>,
+    price: f64,
+    sequence_number: i64,
+    sip_timestamp: i64,
+    size: i32,
+    tape: Option<i32>,
+    trf_id: Option<i32>,
+    trf_timestamp: Option<i64>,
+}
+
+/// The row structure of the quotes flat files of Polygon.io.
+#[derive(Debug, Deserialize)]
+struct QuoteRecord {
+    ticker: Arc<str>,
+    ask_exchange: Option<i32>,
+    ask_price: Option<f64>,
+    ask_size: Option<f64>,
+    bid_exchange: Option<i32>,
+    bid_price: Option<f64>,
+    bid_size: Option<f64>,
+    // The condition codes, separated by comma.
+    conditions: Option<String>,
+    participant_timestamp: Option<i64>,
+    sequence_number: i64,
+    sip_timestamp: i64,
+    tape: Option<i32>,
+}
+
+/// The row structure of the minute and day aggregates flat files of Polygon.io.
+#[derive(Debug, Deserialize)]
+struct BarRecord {
+    ticker: Arc<str>,
+    volume: f64,
+    open: f64,
+    close: f64,
+    high: f64,
+    low: f64,
+    // The nanosecond Unix timestamp of the start of the bar.
+    window_start: i64,
+    transactions: Option<i64>,
+}
+
+/// Where the files are read from.
+#[derive(Debug)]
+enum Source {
+    /// A directory with the same layout as the bucket.
+    Dir,
+    /// An S3-compatible bucket, whose files are downloaded to the directory before reading.
+    S3 {
+        client: Arc<Client>,
+        url: Arc<str>,
+        access_key: Arc<str>,
+        secret_key: Arc<str>,
+    },
+}
+
+/// The service to implement the API of Flat Files.
+#[derive(Debug)]
+pub struct Service {
+    dir: PathBuf,
+    source: Source,
+}
+
+impl Service {
+    /// Reads the files of Polygon.io, which are downloaded to the directory.
+    pub fn new(
+        client: Arc<Client>,
+        access_key: &str,
+        secret_key: &str,
+        dir: &Path,
+    ) -> io::Result<Self> {
+        Self::with_host(
+            client,
+            access_key,
+            secret_key,
+            dir,
+            "https://files.polygon.io",
+            "flatfiles",
+        )
+    }
+
+    /// Creates the service against another S3-compatible host, e.g. a local mock of the bucket.
+    pub fn with_host(
+        client: Arc<Client>,
+        access_key: &str,
+        secret_key: &str,
+        dir: &Path,
+        host: &str,
+        bucket: &str,
+    ) -> io::Result<Self> {
+        if access_key.is_empty() || secret_key.is_empty() {
+            return Err(io::Error::KeyIsEmpty);
+        }
+
+        Ok(Self {
+            dir: dir.into(),
+            source: Source::S3 {
+                client,
+                url: format!("{}/{}", host, bucket).into(),
+                access_key: access_key.into(),
+                secret_key: secret_key.into(),
+            },
+        })
+    }
+
+    /// Reads the files of a local directory only, with the same layout as the bucket.
+    pub fn from_dir(dir: &Path) -> Self {
+        Self {
+            dir: dir.into(),
+            source: Source::Dir,
+        }
+    }
+
+    /// Downloads the file of the key to the path, returning false if the bucket doesn't have it.
+    async fn download(&self, key: &str, path: &Path) -> io::Result<bool> {
+        let Source::S3 {
+            client,
+            url,
+            access_key,
+            secret_key,
+        } = &self.source
+        else {
+            return Ok(false);
+        };
+        let url = Url::parse(&format!("{}/{}", url, key))
+            .map_err(|e| io::Error::Server(key.into(), e.to_string()))?;
+        let amz_date = Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+
+        let mut response = client
+            .get(url.clone())
+            .header("x-amz-date", &amz_date)
+            .header("x-amz-content-sha256", EMPTY_SHA256)
+            .header(
+                "authorization",
+                authorization(access_key, secret_key, &url, &amz_date),
+            )
+            .send()
+            .await?;
+        let status_code = response.status();
+
+        if status_code == StatusCode::NOT_FOUND {
+            return Ok(false);
+        }
+        if status_code != StatusCode::OK {
+            let body = response.text().await?;
+            return Err(io::Error::Server(key.into(), error_message(&body)));
+        }
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        // A partial download never looks like a downloaded file.
+        let part = PathBuf::from(format!("{}.part", path.display()));
+        let mut file = File::create(&part)?;
+        while let Some(chunk) = response.chunk().await? {
+            file.write_all(&chunk)?;
+        }
+        file.flush()?;
+        fs::rename(&part, path)?;
+        Ok(true)
+    }
+}
+
+#[async_trait]
+impl io::Api for Service {
+    async fn get_rows(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        dataset: io::Dataset,
+        date: &NaiveDate,
+    ) -> io::Result<Option<io::Rows>> {
+        let key = dataset.key(date);
+        let path = self.dir.join(&key);
+        if !path.exists() && !self.download(&key, &path).await? {
+            return Ok(None);
+        }
+
+        let file = File::open(path)?;
+        let names = deps.provide_names();
+        let (sender, rows) = tokio::sync::mpsc::channel(ROWS_BUFFER);
+        // Decompressing and parsing blocks, so it runs on the blocking threads, and the bounded
+        // channel keeps the memory bounded while the rows are stored.
+        tokio::task::spawn_blocking(move || {
+            let mut reader = csv::Reader::from_reader(GzDecoder::new(BufReader::new(file)));
+            let send = |row: io::Result<io::Row>| sender.blocking_send(row).is_ok();
+            match dataset {
+                io::Dataset::Trades => reader
+                    .deserialize()
+                    .map(|record| Ok(to_trade(record?, &names)))
+                    .all(send),
+                io::Dataset::Quotes => reader
+                    .deserialize()
+                    .map(|record| Ok(to_quote(record?)))
+                    .all(send),
+                io::Dataset::MinuteAggregates | io::Dataset::DayAggregates => reader
+                    .deserialize()
+                    .map(|record| Ok(to_bar(record?)))
+                    .all(send),
+            };
+        });
+        Ok(Some(rows))
+    }
+}
+
+/// The number of the read rows that can wait for the consumer.
+const ROWS_BUFFER: usize = 10000;
+
+/// The hex SHA-256 of an empty payload, which is the payload of every GET request.
+const EMPTY_SHA256: &str = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+
+/// Returns the AWS Signature Version 4 of a GET request to the S3-compatible bucket, where
+/// `amz_date` is the `x-amz-date` header, like `20240315T000000Z`.
+pub(crate) fn authorization(
+    access_key: &str,
+    secret_key: &str,
+    url: &Url,
+    amz_date: &str,
+) -> String {
+    let date = &amz_date[..8];
+    let host = match url.port() {
+        Some(port) => format!("{}:{}", url.host_str().unwrap_or_default(), port),
+        None => url.host_str().unwrap_or_default().into(),
+    };
+    let signed_headers = "host;x-amz-content-sha256;x-amz-date";
+    let canonical_request = format!(
+        "GET\n{}\n{}\nhost:{}\nx-amz-content-sha256:{}\nx-amz-date:{}\n\n{}\n{}",
+        url.path(),
+        url.query().unwrap_or_default(),
+        host,
+        EMPTY_SHA256,
+        amz_date,
+        signed_headers,
+        EMPTY_SHA256
+    );
+    let scope = format!("{}/us-east-1/s3/aws4_request", date);
+    let string_to_sign = format!(
+        "AWS4-HMAC-SHA256\n{}\n{}\n{}",
+        amz_date,
+        scope,
+        to_hex(&Sha256::digest(canonical_request.as_bytes()))
+    );
+
+    let key = [date, "us-east-1", "s3", "aws4_request"]
+        .iter()
+        .fold(format!("AWS4{}", secret_key).into_bytes(), |key, part| {
+            hmac(&key, part)
+        });
+    format!(
+        "AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders={}, Signature={}",
+        access_key,
+        scope,
+        signed_headers,
+        to_hex(&hmac(&key, &string_to_sign))
+    )
+}
+
+fn hmac(key: &[u8], data: &str) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC takes a key of any size");
+    mac.update(data.as_bytes());
+    mac.finalize().into_bytes().to_vec()
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().fold(String::new(), |mut hex, byte| {
+        let _ = write!(hex, "{:02x}", byte);
+        hex
+    })
+}
+
+/// Returns the message of an S3 error, like `<Error><Message>Access Denied</Message></Error>`.
+fn error_message(body: &str) -> String {
+    body.split_once("<Message>")
+        .and_then(|(_, rest)| rest.split_once("</Message>"))
+        .map_or(body, |(message, _)| message)
+        .to_string()
+}
+
+fn to_codes(codes: &Option<String>) -> Vec<i32> {
+    codes
+        .iter()
+        .flat_map(|c| c.split(','))
+        .filter_map(|c| c.trim().parse().ok())
+        .collect()
+}
+
+fn to_trade(record: TradeRecord, names: &trades::Names) -> io::Row {
+    let conditions = to_codes(&record.conditions);
+    io::Row::Trade {
+        ticker: record.ticker,
+        data: trades::Data {
+            id: record.id,
+            price: record.price,
+            size: record.size,
+            participant_timestamp: record.participant_timestamp,
+            sip_timestamp: record.sip_timestamp,
+            sequence_number: record.sequence_number,
+            exchange: record.exchange,
+            exchange_name: names.exchanges.get(&record.exchange).cloned(),
+            condition_names: conditions
+                .iter()
+                .map(|c| names.conditions.get(c).cloned())
+                .collect(),
+            conditions,
+            correction: record.correction,
+            tape: record.tape,
+            trf_id: record.trf_id,
+            trf_name: record
+                .trf_id
+                .and_then(|id| names.exchanges.get(&id).cloned()),
+            trf_timestamp: record.trf_timestamp,
+        },
+    }
+}
+
+fn to_quote(record: QuoteRecord) -> io::Row {
+    io::Row::Quote {
+        ticker: record.ticker,
+        data: quotes::Data {
+            ask_exchange: record.ask_exchange,
+            ask_price: record.ask_price,
+            ask_size: record.ask_size,
+            bid_exchange: record.bid_exchange,
+            bid_price: record.bid_price,
+            bid_size: record.bid_size,
+            conditions: to_codes(&record.conditions),
+            participant_timestamp: record.participant_timestamp,
+            sip_timestamp: record.sip_timestamp,
+            sequence_number: record.sequence_number,
+            tape: record.tape,
+        },
+    }
+}
+
+fn to_bar(record: BarRecord) -> io::Row {
+    io::Row::Bar {
+        ticker: record.ticker,
+        data: aggregates::Data {
+            open: record.open,
+            high: record.high,
+            low: record.low,
+            close: record.close,
+            volume: record.volume,
+            vwap: None,
+            // The bars of the REST modules start in milliseconds.
+            timestamp: record.window_start / 1_000_000,
+            transactions: record.transactions,
+        },
+    }
+}
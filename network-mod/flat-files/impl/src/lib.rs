@@ -0,0 +1,5 @@
+#[path = "flat-files-impl.rs"]
+pub mod flat_files_impl;
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,99 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use network_mod_aggregates_io::aggregates;
+use network_mod_quotes_io::quotes;
+use network_mod_trades_io::trades;
+
+/// The datasets of the flat files of the US stocks, where each file has a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dataset {
+    Trades,
+    Quotes,
+    MinuteAggregates,
+    DayAggregates,
+}
+
+impl Dataset {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Dataset::Trades => "trades_v1",
+            Dataset::Quotes => "quotes_v1",
+            Dataset::MinuteAggregates => "minute_aggs_v1",
+            Dataset::DayAggregates => "day_aggs_v1",
+        }
+    }
+
+    /// Returns the key of the file of the date inside the bucket, like
+    /// `us_stocks_sip/trades_v1/2024/03/2024-03-14.csv.gz`.
+    pub fn key(&self, date: &NaiveDate) -> String {
+        format!(
+            "us_stocks_sip/{}/{}/{}.csv.gz",
+            self.as_str(),
+            date.format("%Y/%m"),
+            date.format("%Y-%m-%d")
+        )
+    }
+}
+
+/// A row of a flat file in the same models as the REST modules.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Row {
+    Trade {
+        ticker: Arc<str>,
+        data: trades::Data,
+    },
+    Quote {
+        ticker: Arc<str>,
+        data: quotes::Data,
+    },
+    Bar {
+        ticker: Arc<str>,
+        data: aggregates::Data,
+    },
+}
+
+/// The rows of a file, which are read lazily off the async runtime, since a file of trades can
+/// be gigabytes.
+pub type Rows = tokio::sync::mpsc::Receiver<Result<Row>>;
+
+/// All possible errors of Flat Files modules.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Failed to create an API client; can be invalid 'access_key' or 'secret_key'.")]
+    KeyIsEmpty,
+
+    #[error("Failed to send a request to the service provider; can be invalid 'url', or keys, or network IO problem: {0}")]
+    Request(#[from] reqwest::Error),
+
+    #[error("Can't read or write the file: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Can't process the row of the file: {0}")]
+    Csv(#[from] csv::Error),
+
+    #[error("Provider server response error '{1}' for this key '{0}'.")]
+    Server(String, String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The API of Flat Files, which defines all the functionality of Flat Files modules.
+#[async_trait]
+pub trait Api {
+    /// Returns the rows of the dataset on the date, or `None` if there's no file, like on a
+    /// holiday. A remote file is downloaded first, unless it's downloaded before.
+    async fn get_rows(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        dataset: Dataset,
+        date: &NaiveDate,
+    ) -> Result<Option<Rows>>;
+}
+
+/// All the dependencies of the Flat Files modules to other modules.
+pub trait Depends {
+    /// The names of the stored exchanges and conditions to decode the trades.
+    fn provide_names(&self) -> Arc<trades::Names>;
+}
@@ -0,0 +1,2 @@
+#[path = "flat-files.rs"]
+pub mod flat_files;
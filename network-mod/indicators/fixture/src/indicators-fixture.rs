@@ -0,0 +1,23 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use network_mod_indicators_io::indicators as io;
+
+pub struct ServiceFake;
+
+#[async_trait]
+impl io::Api for ServiceFake {
+    async fn get_indicator(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        request: &io::Request,
+    ) -> io::Result<Vec<io::Data>> {
+        let macd = matches!(request.indicator, io::Indicator::Macd { .. });
+        Ok(vec![io::Data {
+            timestamp: 1709701200000,
+            value: 50.0,
+            signal: if macd { Some(45.0) } else { None },
+            histogram: if macd { Some(5.0) } else { None },
+        }])
+    }
+}
@@ -0,0 +1,161 @@
+use std::{collections::HashMap, sync::Arc};
+
+use async_trait::async_trait;
+use reqwest::{Client, StatusCode};
+use serde::Deserialize;
+
+use network_mod_indicators_io::indicators as io;
+
+/// The result structure of a value in Polygon.io API.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct IndicatorsValue {
+    // The Unix Msec timestamp from the last aggregate used in this calculation.
+    timestamp: i64,
+    // The indicator value for this period.
+    value: f64,
+    // The indicator value for the signal line of MACD.
+    signal: Option<f64>,
+    // The indicator value for the histogram of MACD.
+    histogram: Option<f64>,
+}
+
+/// The result structure of result in Polygon.io API.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct IndicatorsResult {
+    #[serde(default)]
+    values: Vec<IndicatorsValue>,
+}
+
+/// The response structure of Polygon.io API.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct IndicatorsResponse {
+    next_url: Option<Arc<str>>,
+    request_id: Arc<str>,
+    status: Arc<str>,
+    results: Option<IndicatorsResult>,
+}
+
+/// The error structure of Polygon.io API.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct IndicatorsError {
+    status: Arc<str>,
+    request_id: Arc<str>,
+    // Polygon.io names it `error` for authorization and rate limit failures.
+    #[serde(alias = "error")]
+    message: Arc<str>,
+}
+
+/// The service to implement the API of Indicators.
+#[derive(Debug)]
+pub struct Service {
+    url: Arc<str>,
+    api_key: Arc<str>,
+    client: Arc<Client>,
+}
+
+impl Service {
+    pub fn new(client: Arc<Client>, api_key: &str) -> io::Result<Self> {
+        Self::with_host(client, api_key, "https://api.polygon.io")
+    }
+
+    /// Creates the service against another host, e.g. a local mock server of Polygon.io.
+    pub fn with_host(client: Arc<Client>, api_key: &str, host: &str) -> io::Result<Self> {
+        if api_key.is_empty() {
+            return Err(io::Error::ApiKeyIsEmpty);
+        }
+
+        Ok(Self {
+            client,
+            api_key: api_key.into(),
+            url: format!("{}/v1/indicators/{{INDICATOR}}/{{TICKER}}", host).into(),
+        })
+    }
+}
+
+#[async_trait]
+impl io::Api for Service {
+    async fn get_indicator(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        request: &io::Request,
+    ) -> io::Result<Vec<io::Data>> {
+        let snp = deps.provide_snp();
+        let snp_symbol_rc = request.ticker.ticker(snp.as_ref());
+        let snp_symbol = &snp_symbol_rc[..];
+        let from = request.from.format("%Y-%m-%d").to_string();
+        let to = request.to.format("%Y-%m-%d").to_string();
+        let windows: Vec<(&str, String)> = match request.indicator {
+            io::Indicator::Sma { window }
+            | io::Indicator::Ema { window }
+            | io::Indicator::Rsi { window } => vec![("window", window.to_string())],
+            io::Indicator::Macd {
+                short_window,
+                long_window,
+                signal_window,
+            } => vec![
+                ("short_window", short_window.to_string()),
+                ("long_window", long_window.to_string()),
+                ("signal_window", signal_window.to_string()),
+            ],
+        };
+
+        let mut params = HashMap::new();
+        params.insert("apiKey", self.api_key.as_ref());
+        params.insert("timestamp.gte", from.as_ref());
+        params.insert("timestamp.lte", to.as_ref());
+        params.insert("timespan", request.timespan.as_str());
+        params.insert("series_type", request.series_type.as_str());
+        params.insert("adjusted", if request.adjusted { "true" } else { "false" });
+        for (name, window) in &windows {
+            params.insert(name, window.as_ref());
+        }
+        params.insert("order", "asc");
+        params.insert("limit", "5000");
+
+        let client = &self.client;
+        let mut url: String = self
+            .url
+            .replace("{INDICATOR}", request.indicator.as_str())
+            .replace("{TICKER}", snp_symbol);
+        let mut values = vec![];
+
+        while !url.is_empty() {
+            let response = client.get(&url).query(&params).send().await?;
+            let status_code = response.status();
+
+            let response_body = &response.text().await?;
+
+            if status_code == StatusCode::OK {
+                let indicators_data: IndicatorsResponse = serde_json::from_str(response_body)?;
+
+                if let Some(results) = indicators_data.results {
+                    values.extend(results.values.into_iter().map(|v| io::Data {
+                        timestamp: v.timestamp,
+                        value: v.value,
+                        signal: v.signal,
+                        histogram: v.histogram,
+                    }));
+                }
+
+                // The `next_url` keeps the other params in its cursor.
+                params.retain(|k, _| *k == "apiKey");
+                url = match indicators_data.next_url {
+                    Some(u) => u.to_string(),
+                    None => "".to_string(),
+                };
+            } else {
+                let error_data: IndicatorsError = serde_json::from_str(response_body)?;
+
+                return Err(io::Error::Server(
+                    url.to_string(),
+                    error_data.message.to_string(),
+                ));
+            }
+        }
+        Ok(values)
+    }
+}
@@ -0,0 +1,5 @@
+#[path = "indicators-impl.rs"]
+pub mod indicators_impl;
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,217 @@
+use std::env;
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use network_mod_mock_server::{
+    mock_server::{MockServer, Route},
+    responses,
+};
+use rstest::rstest;
+use snp_mod_fixture::snp_fixture::ServiceFake;
+use snp_mod_io::snp;
+
+use crate::indicators_impl::Service;
+use io::Api;
+use network_mod_indicators_io::indicators as io;
+
+// It's not quite fit into the definiton of unit test, because it requests through network,
+// but we need it to stay here for future fast check.
+#[ignore]
+#[tokio::test]
+async fn call_aapl_indicator() -> io::Result<()> {
+    let api_key = env::var("API_KEY").unwrap();
+    let client = reqwest::Client::new();
+    let service = Service::new(Arc::new(client), &api_key).unwrap();
+
+    let response = service
+        .get_indicator(
+            given_deps(),
+            &given_request(io::Indicator::Sma { window: 10 }),
+        )
+        .await;
+    match response {
+        Ok(r) => {
+            dbg!(r);
+        }
+        Err(e) => {
+            dbg!(e);
+        }
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_sma_from_mock_server() -> io::Result<()> {
+    let server = MockServer::start(vec![Route::new(
+        "/v1/indicators/sma/AAPL",
+        200,
+        responses::SYNTHETIC_INDICATORS_AAPL_SMA,
+    )
+    .with_query("window", "10")
+    .with_query("timespan", "day")
+    .with_query("series_type", "close")
+    .with_query("timestamp.gte", "2024-01-02")])
+    .await
+    .unwrap();
+    let service = given_service(&server);
+
+    let values = service
+        .get_indicator(
+            given_deps(),
+            &given_request(io::Indicator::Sma { window: 10 }),
+        )
+        .await?;
+
+    assert_eq!(36, values.len());
+    assert_eq!(1705381200000, values[0].timestamp);
+    assert!(values.windows(2).all(|w| w[0].timestamp < w[1].timestamp));
+    assert_eq!(None, values[0].signal);
+    assert_eq!(1, server.requests().len());
+    Ok(())
+}
+
+#[rstest]
+#[case(snp::Instrument::Crypto { base: "BTC".into(), quote: "USD".into() }, "/v1/indicators/sma/X:BTCUSD")]
+#[case(snp::Instrument::Forex { base: "EUR".into(), quote: "USD".into() }, "/v1/indicators/sma/C:EURUSD")]
+#[tokio::test]
+async fn get_crypto_and_forex_sma(
+    #[case] ticker: snp::Instrument,
+    #[case] path: &str,
+) -> io::Result<()> {
+    // The values are of the same shape for any market, so the body of AAPL stands in.
+    let server = MockServer::start(vec![Route::new(
+        path,
+        200,
+        responses::SYNTHETIC_INDICATORS_AAPL_SMA,
+    )])
+    .await
+    .unwrap();
+    let service = given_service(&server);
+    let request = io::Request {
+        ticker,
+        ..given_request(io::Indicator::Sma { window: 10 })
+    };
+
+    let values = service.get_indicator(given_deps(), &request).await?;
+
+    assert_eq!(36, values.len());
+    assert_eq!(1, server.requests().len());
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_macd_from_mock_server() -> io::Result<()> {
+    let server = MockServer::start(vec![Route::new(
+        "/v1/indicators/macd/AAPL",
+        200,
+        responses::SYNTHETIC_INDICATORS_AAPL_MACD,
+    )
+    .with_query("short_window", "12")
+    .with_query("long_window", "26")
+    .with_query("signal_window", "9")])
+    .await
+    .unwrap();
+    let service = given_service(&server);
+
+    let values = service
+        .get_indicator(
+            given_deps(),
+            &given_request(io::Indicator::Macd {
+                short_window: 12,
+                long_window: 26,
+                signal_window: 9,
+            }),
+        )
+        .await?;
+
+    assert_eq!(12, values.len());
+    let value = &values[0];
+    let histogram = value.value - value.signal.unwrap();
+    assert!((histogram - value.histogram.unwrap()).abs() < 1e-9);
+    Ok(())
+}
+
+#[rstest]
+#[case(401, responses::ERROR_UNAUTHORIZED, "Unknown API Key")]
+#[case(
+    429,
+    responses::ERROR_TOO_MANY_REQUESTS,
+    "You've exceeded the maximum requests"
+)]
+#[tokio::test]
+async fn get_indicator_server_error(
+    #[case] status: u16,
+    #[case] body: &str,
+    #[case] message: &str,
+) {
+    let server = MockServer::start(vec![Route::new("/v1/indicators/rsi/AAPL", status, body)])
+        .await
+        .unwrap();
+    let service = given_service(&server);
+
+    let response = service
+        .get_indicator(
+            given_deps(),
+            &given_request(io::Indicator::Rsi { window: 14 }),
+        )
+        .await;
+
+    match response {
+        Err(io::Error::Server(_, m)) => assert!(m.starts_with(message), "message {:?}", m),
+        r => panic!("unexpected response {:?}", r),
+    }
+}
+
+#[tokio::test]
+async fn get_indicator_malformed_json() {
+    let server = MockServer::start(vec![Route::new(
+        "/v1/indicators/ema/AAPL",
+        200,
+        responses::MALFORMED,
+    )])
+    .await
+    .unwrap();
+    let service = given_service(&server);
+
+    let response = service
+        .get_indicator(
+            given_deps(),
+            &given_request(io::Indicator::Ema { window: 10 }),
+        )
+        .await;
+
+    assert!(
+        matches!(response, Err(io::Error::JsonParse(_))),
+        "{:?}",
+        response
+    );
+}
+
+fn given_service(server: &MockServer) -> Service {
+    let client = reqwest::Client::new();
+    Service::with_host(Arc::new(client), "key", &server.host()).unwrap()
+}
+
+fn given_request(indicator: io::Indicator) -> io::Request {
+    io::Request {
+        ticker: snp::Instrument::Stock(snp::SnP::AAPL),
+        indicator,
+        timespan: io::Timespan::Day,
+        series_type: io::SeriesType::Close,
+        from: NaiveDate::from_ymd_opt(2024, 1, 2).unwrap(),
+        to: NaiveDate::from_ymd_opt(2024, 3, 6).unwrap(),
+        adjusted: true,
+    }
+}
+
+fn given_deps() -> Arc<dyn io::Depends + Send + Sync> {
+    Arc::new(DependsFake {})
+}
+
+struct DependsFake {}
+
+impl io::Depends for DependsFake {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        Arc::new(ServiceFake {})
+    }
+}
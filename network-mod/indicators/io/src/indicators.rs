@@ -0,0 +1,137 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use snp_mod_io::snp;
+
+/// The technical indicator with its windows in number of bars.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Indicator {
+    Sma {
+        window: u32,
+    },
+    Ema {
+        window: u32,
+    },
+    Rsi {
+        window: u32,
+    },
+    Macd {
+        short_window: u32,
+        long_window: u32,
+        signal_window: u32,
+    },
+}
+
+impl Indicator {
+    /// The name of the indicator in Polygon.io API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Indicator::Sma { .. } => "sma",
+            Indicator::Ema { .. } => "ema",
+            Indicator::Rsi { .. } => "rsi",
+            Indicator::Macd { .. } => "macd",
+        }
+    }
+}
+
+/// The size of the time window of each bar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Timespan {
+    Minute,
+    Hour,
+    Day,
+}
+
+impl Timespan {
+    /// The name of the timespan in Polygon.io API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Timespan::Minute => "minute",
+            Timespan::Hour => "hour",
+            Timespan::Day => "day",
+        }
+    }
+}
+
+/// The price of the bars that the indicator is calculated from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SeriesType {
+    Open,
+    High,
+    Low,
+    Close,
+}
+
+impl SeriesType {
+    /// The name of the series type in Polygon.io API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SeriesType::Open => "open",
+            SeriesType::High => "high",
+            SeriesType::Low => "low",
+            SeriesType::Close => "close",
+        }
+    }
+}
+
+/// The values to request, of the bars from the start of `from` to the end of `to`.
+#[derive(Debug, Clone)]
+pub struct Request {
+    /// Any instrument, like a stock, an options contract, a crypto or a currency pair.
+    pub ticker: snp::Instrument,
+    pub indicator: Indicator,
+    pub timespan: Timespan,
+    pub series_type: SeriesType,
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    /// Whether the bars are adjusted for splits.
+    pub adjusted: bool,
+}
+
+/// The data that is needed to interact with the Indicators modules, which is a value of the
+/// indicator.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Data {
+    /// The millisecond Unix timestamp of the start of the bar.
+    pub timestamp: i64,
+    pub value: f64,
+    /// The signal line of MACD.
+    pub signal: Option<f64>,
+    /// The histogram of MACD.
+    pub histogram: Option<f64>,
+}
+
+/// All possible errors of Indicators modules.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Failed to create an API client; can be invalid 'api_key'.")]
+    ApiKeyIsEmpty,
+
+    #[error("Failed to send a request to the service provider; can be invalid 'url', or 'api_key', or network IO problem: {0}")]
+    Request(#[from] reqwest::Error),
+
+    #[error("Can't process the body text from the response: {0}")]
+    JsonParse(#[from] serde_json::Error),
+
+    #[error("Provider server response error '{1}' for this url '{0}'.")]
+    Server(String, String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The API of Indicators, which defines all the functionality of Indicators modules.
+#[async_trait]
+pub trait Api {
+    /// Follows all the pages, and returns the values in ascending order of time.
+    async fn get_indicator(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        request: &Request,
+    ) -> Result<Vec<Data>>;
+}
+
+/// All the dependencies of the Indicators modules to other modules.
+pub trait Depends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync>;
+}
@@ -0,0 +1,26 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use network_mod_indices_io::indices as io;
+use snp_mod_io::snp;
+
+pub struct ServiceFake;
+
+#[async_trait]
+impl io::Api for ServiceFake {
+    async fn get_aggregates(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        _request: &io::Request,
+    ) -> io::Result<Vec<io::Bar>> {
+        Ok(vec![])
+    }
+
+    async fn get_snapshots(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        _indices: &[snp::Index],
+    ) -> io::Result<Vec<io::Snapshot>> {
+        Ok(vec![])
+    }
+}
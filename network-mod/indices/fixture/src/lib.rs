@@ -0,0 +1,2 @@
+#[path = "indices-fixture.rs"]
+pub mod indices_fixture;
@@ -0,0 +1,230 @@
+use std::{collections::HashMap, sync::Arc};
+
+use async_trait::async_trait;
+use reqwest::{Client, StatusCode};
+use serde::Deserialize;
+use snp_mod_io::snp;
+
+use network_mod_indices_io::indices as io;
+
+/// The result structure of a bar of an index in Polygon.io API.
+#[derive(Debug, Deserialize)]
+pub struct IndicesBar {
+    // The close value of the index in the given time period.
+    c: f64,
+    // The highest value of the index in the given time period.
+    h: f64,
+    // The lowest value of the index in the given time period.
+    l: f64,
+    // The open value of the index in the given time period.
+    o: f64,
+    // The Unix Msec timestamp for the start of the aggregate window.
+    t: i64,
+}
+
+/// The response structure of the aggregates of an index in Polygon.io API.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+#[serde(rename_all = "camelCase")]
+pub struct IndicesAggregatesResponse {
+    ticker: Arc<str>,
+    query_count: i64,
+    results_count: i64,
+    #[serde(rename = "next_url")]
+    next_url: Option<Arc<str>>,
+    #[serde(rename = "request_id")]
+    request_id: Arc<str>,
+    status: Arc<str>,
+    // It's missing when there's no bar in the range, e.g. on a holiday.
+    #[serde(default)]
+    results: Vec<IndicesBar>,
+}
+
+/// The session structure of the snapshot of an index in Polygon.io API.
+#[derive(Debug, Deserialize)]
+pub struct IndicesSession {
+    change: f64,
+    change_percent: f64,
+    close: f64,
+    high: f64,
+    low: f64,
+    open: f64,
+    previous_close: f64,
+}
+
+/// The snapshot of an index in Polygon.io API, which has an error instead of the values if the
+/// plan isn't entitled to the index.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct IndicesSnapshotResult {
+    ticker: Arc<str>,
+    name: Option<Arc<str>>,
+    value: Option<f64>,
+    market_status: Option<Arc<str>>,
+    session: Option<IndicesSession>,
+    // The nanosecond Unix timestamp of the value.
+    last_updated: Option<i64>,
+    error: Option<Arc<str>>,
+    message: Option<Arc<str>>,
+}
+
+/// The response structure of the snapshot of the indices in Polygon.io API.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct IndicesSnapshotResponse {
+    request_id: Arc<str>,
+    status: Arc<str>,
+    #[serde(default)]
+    results: Vec<IndicesSnapshotResult>,
+}
+
+/// The error structure of Polygon.io API.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct IndicesError {
+    status: Arc<str>,
+    request_id: Arc<str>,
+    // Polygon.io names it `error` for authorization and rate limit failures.
+    #[serde(alias = "error")]
+    message: Arc<str>,
+}
+
+/// The service to implement the API of Indices.
+#[derive(Debug)]
+pub struct Service {
+    aggregates_url: Arc<str>,
+    snapshot_url: Arc<str>,
+    api_key: Arc<str>,
+    client: Arc<Client>,
+}
+
+impl Service {
+    pub fn new(client: Arc<Client>, api_key: &str) -> io::Result<Self> {
+        Self::with_host(client, api_key, "https://api.polygon.io")
+    }
+
+    /// Creates the service against another host, e.g. a local mock server of Polygon.io.
+    pub fn with_host(client: Arc<Client>, api_key: &str, host: &str) -> io::Result<Self> {
+        if api_key.is_empty() {
+            return Err(io::Error::ApiKeyIsEmpty);
+        }
+
+        Ok(Self {
+            client,
+            api_key: api_key.into(),
+            aggregates_url: format!(
+                "{}/v2/aggs/ticker/{{TICKER}}/range/{{MULTIPLIER}}/{{TIMESPAN}}/{{FROM}}/{{TO}}",
+                host
+            )
+            .into(),
+            snapshot_url: format!("{}/v3/snapshot/indices", host).into(),
+        })
+    }
+
+    /// Sends the request, and returns the body of a successful response.
+    async fn request(&self, url: &str, params: &HashMap<&str, &str>) -> io::Result<String> {
+        let response = self.client.get(url).query(params).send().await?;
+        let status_code = response.status();
+
+        let response_body = response.text().await?;
+
+        if status_code == StatusCode::OK {
+            Ok(response_body)
+        } else {
+            let error_data: IndicesError = serde_json::from_str(&response_body)?;
+
+            Err(io::Error::Server(
+                url.to_string(),
+                error_data.message.to_string(),
+            ))
+        }
+    }
+}
+
+#[async_trait]
+impl io::Api for Service {
+    async fn get_aggregates(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        request: &io::Request,
+    ) -> io::Result<Vec<io::Bar>> {
+        let mut params = HashMap::new();
+        params.insert("apiKey", self.api_key.as_ref());
+        params.insert("sort", "asc");
+        params.insert("limit", "50000");
+
+        let mut url: String = self
+            .aggregates_url
+            .replace("{TICKER}", request.index.ticker())
+            .replace("{MULTIPLIER}", &request.multiplier.to_string())
+            .replace("{TIMESPAN}", request.timespan.as_str())
+            .replace("{FROM}", &request.from.format("%Y-%m-%d").to_string())
+            .replace("{TO}", &request.to.format("%Y-%m-%d").to_string());
+        let mut bars = vec![];
+
+        while !url.is_empty() {
+            let response_body = self.request(&url, &params).await?;
+            let aggregates_data: IndicesAggregatesResponse = serde_json::from_str(&response_body)?;
+
+            bars.extend(aggregates_data.results.into_iter().map(|b| io::Bar {
+                open: b.o,
+                high: b.h,
+                low: b.l,
+                close: b.c,
+                timestamp: b.t,
+            }));
+
+            // The `next_url` keeps the other params in its cursor.
+            params.retain(|k, _| *k == "apiKey");
+            url = match aggregates_data.next_url {
+                Some(u) => u.to_string(),
+                None => "".to_string(),
+            };
+        }
+        Ok(bars)
+    }
+
+    async fn get_snapshots(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        indices: &[snp::Index],
+    ) -> io::Result<Vec<io::Snapshot>> {
+        let tickers = indices
+            .iter()
+            .map(|i| i.ticker())
+            .collect::<Vec<&str>>()
+            .join(",");
+
+        let mut params = HashMap::new();
+        params.insert("apiKey", self.api_key.as_ref());
+        params.insert("ticker.any_of", tickers.as_ref());
+
+        let response_body = self.request(&self.snapshot_url, &params).await?;
+        let snapshot_data: IndicesSnapshotResponse = serde_json::from_str(&response_body)?;
+
+        Ok(snapshot_data
+            .results
+            .into_iter()
+            .filter_map(|s| {
+                if s.error.is_some() {
+                    return None;
+                }
+                Some(io::Snapshot {
+                    index: snp::Index::from_ticker(&s.ticker).ok()?,
+                    value: s.value?,
+                    market_status: s.market_status,
+                    last_updated: s.last_updated?,
+                    session: s.session.map(|session| io::Session {
+                        open: session.open,
+                        high: session.high,
+                        low: session.low,
+                        close: session.close,
+                        previous_close: session.previous_close,
+                        change: session.change,
+                        change_percent: session.change_percent,
+                    }),
+                })
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,5 @@
+#[path = "indices-impl.rs"]
+pub mod indices_impl;
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,175 @@
+use std::env;
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use network_mod_aggregates_io::aggregates;
+use network_mod_mock_server::{
+    mock_server::{MockServer, Route},
+    responses,
+};
+use rstest::rstest;
+use snp_mod_fixture::snp_fixture::ServiceFake;
+use snp_mod_io::snp;
+
+use crate::indices_impl::Service;
+use io::Api;
+use network_mod_indices_io::indices as io;
+
+// It's not quite fit into the definiton of unit test, because it requests through network,
+// but we need it to stay here for future fast check.
+#[ignore]
+#[tokio::test]
+async fn call_spx_aggregates() -> io::Result<()> {
+    let api_key = env::var("API_KEY").unwrap();
+    let client = reqwest::Client::new();
+    let service = Service::new(Arc::new(client), &api_key).unwrap();
+
+    let response = service
+        .get_aggregates(given_deps(), &given_request(aggregates::Timespan::Day))
+        .await;
+    match response {
+        Ok(r) => {
+            dbg!(r);
+        }
+        Err(e) => {
+            dbg!(e);
+        }
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_paginated_index_aggregates() -> io::Result<()> {
+    let server = MockServer::start(vec![
+        Route::new(
+            "/v2/aggs/ticker/I:SPX/range/1/minute/1710423120000/1710460800000",
+            200,
+            responses::INDICES_SPX_MINUTE_PAGE_2,
+        )
+        .with_query("cursor", "bGltaXQ9MiZzb3J0PWFzYw"),
+        Route::new(
+            "/v2/aggs/ticker/I:SPX/range/1/minute/2024-03-14/2024-03-14",
+            200,
+            responses::INDICES_SPX_MINUTE_PAGE_1,
+        )
+        .with_query("sort", "asc"),
+    ])
+    .await
+    .unwrap();
+    let service = given_service(&server);
+
+    let bars = service
+        .get_aggregates(given_deps(), &given_request(aggregates::Timespan::Minute))
+        .await?;
+
+    assert_eq!(3, bars.len());
+    assert_eq!(
+        io::Bar {
+            open: 5175.14,
+            high: 5176.85,
+            low: 5167.93,
+            close: 5168.21,
+            timestamp: 1710423000000,
+        },
+        bars[0]
+    );
+    assert_eq!(1710423120000, bars[2].timestamp);
+    assert_eq!(2, server.requests().len());
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_entitled_snapshots() -> io::Result<()> {
+    let server = MockServer::start(vec![Route::new(
+        "/v3/snapshot/indices",
+        200,
+        responses::INDICES_SNAPSHOT,
+    )
+    .with_query("ticker.any_of", "I:SPX,I:NDX,I:DJI")])
+    .await
+    .unwrap();
+    let service = given_service(&server);
+
+    let snapshots = service
+        .get_snapshots(given_deps(), &snp::Index::ALL)
+        .await?;
+
+    // The plan isn't entitled to I:DJI.
+    assert_eq!(2, snapshots.len());
+    assert_eq!(
+        io::Snapshot {
+            index: snp::Index::SPX,
+            value: 5150.48,
+            market_status: Some("closed".into()),
+            last_updated: 1710460800000000000,
+            session: Some(io::Session {
+                open: 5175.14,
+                high: 5176.85,
+                low: 5123.3,
+                close: 5150.48,
+                previous_close: 5165.31,
+                change: -14.83,
+                change_percent: -0.287,
+            }),
+        },
+        snapshots[0]
+    );
+    assert_eq!(snp::Index::NDX, snapshots[1].index);
+    Ok(())
+}
+
+#[rstest]
+#[case(401, responses::ERROR_UNAUTHORIZED, "Unknown API Key")]
+#[case(
+    429,
+    responses::ERROR_TOO_MANY_REQUESTS,
+    "You've exceeded the maximum requests"
+)]
+#[tokio::test]
+async fn get_snapshots_server_error(
+    #[case] status: u16,
+    #[case] body: &str,
+    #[case] message: &str,
+) {
+    let server = MockServer::start(vec![Route::new("/v3/snapshot/indices", status, body)])
+        .await
+        .unwrap();
+    let service = given_service(&server);
+
+    let response = service
+        .get_snapshots(given_deps(), &[snp::Index::SPX])
+        .await;
+
+    match response {
+        Err(io::Error::Server(_, m)) => assert!(m.starts_with(message), "message {:?}", m),
+        r => panic!("unexpected response {:?}", r),
+    }
+}
+
+fn given_service(server: &MockServer) -> Service {
+    let client = reqwest::Client::new();
+    Service::with_host(Arc::new(client), "key", &server.host()).unwrap()
+}
+
+fn given_request(timespan: aggregates::Timespan) -> io::Request {
+    let date = NaiveDate::from_ymd_opt(2024, 3, 14).unwrap();
+    io::Request {
+        index: snp::Index::SPX,
+        multiplier: 1,
+        timespan,
+        from: date,
+        to: date,
+    }
+}
+
+fn given_deps() -> Arc<dyn io::Depends + Send + Sync> {
+    Arc::new(DependsFake {})
+}
+
+struct DependsFake {}
+
+impl io::Depends for DependsFake {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        Arc::new(ServiceFake {})
+    }
+}
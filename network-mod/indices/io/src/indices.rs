@@ -0,0 +1,93 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use network_mod_aggregates_io::aggregates;
+use snp_mod_io::snp;
+
+/// The bars of an index to request, from the start of `from` to the end of `to`.
+#[derive(Debug, Clone)]
+pub struct Request {
+    pub index: snp::Index,
+    pub multiplier: u32,
+    pub timespan: aggregates::Timespan,
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+}
+
+/// A bar of an index, which has no volume, since an index isn't traded.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bar {
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    /// The millisecond Unix timestamp of the start of the bar.
+    pub timestamp: i64,
+}
+
+/// The values of the current or the last session of an index.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Session {
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub previous_close: f64,
+    pub change: f64,
+    pub change_percent: f64,
+}
+
+/// The data that is needed to interact with the Indices modules, which is the snapshot of an
+/// index.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Snapshot {
+    pub index: snp::Index,
+    pub value: f64,
+    /// Like `open` or `closed`.
+    pub market_status: Option<Arc<str>>,
+    /// The nanosecond Unix timestamp of the value.
+    pub last_updated: i64,
+    pub session: Option<Session>,
+}
+
+/// All possible errors of Indices modules.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Failed to create an API client; can be invalid 'api_key'.")]
+    ApiKeyIsEmpty,
+
+    #[error("Failed to send a request to the service provider; can be invalid 'url', or 'api_key', or network IO problem: {0}")]
+    Request(#[from] reqwest::Error),
+
+    #[error("Can't process the body text from the response: {0}")]
+    JsonParse(#[from] serde_json::Error),
+
+    #[error("Provider server response error '{1}' for this url '{0}'.")]
+    Server(String, String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The API of Indices, which defines all the functionality of Indices modules.
+#[async_trait]
+pub trait Api {
+    /// Follows all the pages, and returns the bars in ascending order of time.
+    async fn get_aggregates(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        request: &Request,
+    ) -> Result<Vec<Bar>>;
+
+    /// Returns the snapshots of the indices, skipping the ones that the plan isn't entitled to.
+    async fn get_snapshots(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        indices: &[snp::Index],
+    ) -> Result<Vec<Snapshot>>;
+}
+
+/// All the dependencies of the Indices modules to other modules.
+pub trait Depends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync>;
+}
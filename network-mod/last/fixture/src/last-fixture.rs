@@ -0,0 +1,40 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use network_mod_last_io::last as io;
+use snp_mod_io::snp;
+
+pub struct ServiceFake;
+
+#[async_trait]
+impl io::Api for ServiceFake {
+    async fn get_last_trade(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        _instrument: &snp::Instrument,
+    ) -> io::Result<io::Trade> {
+        Ok(io::Trade {
+            price: 173.0,
+            size: 25.0,
+            exchange: 4,
+            conditions: vec![37],
+            timestamp: 1710446399999307000,
+        })
+    }
+
+    async fn get_last_quote(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        _instrument: &snp::Instrument,
+    ) -> io::Result<io::Quote> {
+        Ok(io::Quote {
+            bid_price: 172.99,
+            bid_size: Some(1.0),
+            bid_exchange: 11,
+            ask_price: 173.05,
+            ask_size: Some(2.0),
+            ask_exchange: 19,
+            timestamp: 1710446399999512000,
+        })
+    }
+}
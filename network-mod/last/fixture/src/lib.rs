@@ -0,0 +1,2 @@
+#[path = "last-fixture.rs"]
+pub mod last_fixture;
@@ -0,0 +1,233 @@
+use std::{collections::HashMap, sync::Arc};
+
+use async_trait::async_trait;
+use reqwest::{Client, StatusCode};
+use serde::{de::DeserializeOwned, Deserialize};
+use snp_mod_io::snp;
+
+use network_mod_last_io::last as io;
+
+/// The last trade of a stock or an options contract in Polygon.io API.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct StockTrade {
+    // The ticker of the trade.
+    #[serde(rename = "T")]
+    ticker: Arc<str>,
+    // The conditions of the trade.
+    c: Option<Vec<i32>>,
+    // The ID of the trade.
+    i: Arc<str>,
+    // The price of the trade.
+    p: f64,
+    // The sequence number of the trade.
+    q: i64,
+    // The size of the trade.
+    s: f64,
+    // The nanosecond Unix timestamp of when the SIP received the trade.
+    t: i64,
+    // The exchange ID of the trade.
+    x: i32,
+}
+
+/// The last NBBO quote of a stock in Polygon.io API.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct StockQuote {
+    #[serde(rename = "T")]
+    ticker: Arc<str>,
+    // The ask price, size and exchange ID.
+    #[serde(rename = "P")]
+    ask_price: f64,
+    #[serde(rename = "S")]
+    ask_size: f64,
+    #[serde(rename = "X")]
+    ask_exchange: i32,
+    // The bid price, size and exchange ID.
+    p: f64,
+    s: f64,
+    x: i32,
+    // The nanosecond Unix timestamp of when the SIP received the quote.
+    t: i64,
+}
+
+/// The response structure of the stocks endpoints in Polygon.io API.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct StockResponse<T> {
+    request_id: Arc<str>,
+    status: Arc<str>,
+    results: T,
+}
+
+/// The last trade of a crypto pair in Polygon.io API.
+#[derive(Debug, Deserialize)]
+pub struct CryptoTrade {
+    #[serde(default)]
+    conditions: Vec<i32>,
+    exchange: i32,
+    price: f64,
+    size: f64,
+    // The millisecond Unix timestamp of the trade.
+    timestamp: i64,
+}
+
+/// The last quote of a currency pair in Polygon.io API.
+#[derive(Debug, Deserialize)]
+pub struct ForexQuote {
+    ask: f64,
+    bid: f64,
+    exchange: i32,
+    // The millisecond Unix timestamp of the quote.
+    timestamp: i64,
+}
+
+/// The response structure of the crypto and forex endpoints in Polygon.io API.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct PairResponse<T> {
+    request_id: Arc<str>,
+    status: Arc<str>,
+    symbol: Arc<str>,
+    last: T,
+}
+
+/// The error structure of Polygon.io API.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct LastError {
+    status: Arc<str>,
+    request_id: Arc<str>,
+    // Polygon.io names it `error` for authorization and rate limit failures.
+    #[serde(alias = "error")]
+    message: Arc<str>,
+}
+
+/// The service to implement the API of Last.
+#[derive(Debug)]
+pub struct Service {
+    host: Arc<str>,
+    api_key: Arc<str>,
+    client: Arc<Client>,
+}
+
+impl Service {
+    pub fn new(client: Arc<Client>, api_key: &str) -> io::Result<Self> {
+        Self::with_host(client, api_key, "https://api.polygon.io")
+    }
+
+    /// Creates the service against another host, e.g. a local mock server of Polygon.io.
+    pub fn with_host(client: Arc<Client>, api_key: &str, host: &str) -> io::Result<Self> {
+        if api_key.is_empty() {
+            return Err(io::Error::ApiKeyIsEmpty);
+        }
+
+        Ok(Self {
+            client,
+            api_key: api_key.into(),
+            host: host.into(),
+        })
+    }
+
+    /// Sends the request, and parses the body of a successful response.
+    async fn request<T: DeserializeOwned>(&self, path: &str) -> io::Result<T> {
+        let url = format!("{}{}", self.host, path);
+
+        let mut params = HashMap::new();
+        params.insert("apiKey", self.api_key.as_ref());
+
+        let response = self.client.get(&url).query(&params).send().await?;
+        let status_code = response.status();
+
+        let response_body = response.text().await?;
+
+        if status_code == StatusCode::OK {
+            Ok(serde_json::from_str(&response_body)?)
+        } else {
+            let error_data: LastError = serde_json::from_str(&response_body)?;
+
+            Err(io::Error::Server(url, error_data.message.to_string()))
+        }
+    }
+}
+
+#[async_trait]
+impl io::Api for Service {
+    async fn get_last_trade(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        instrument: &snp::Instrument,
+    ) -> io::Result<io::Trade> {
+        let snp = deps.provide_snp();
+        match instrument {
+            snp::Instrument::Stock(_) | snp::Instrument::Option(_) => {
+                let path = format!("/v2/last/trade/{}", instrument.ticker(snp.as_ref()));
+                let response: StockResponse<StockTrade> = self.request(&path).await?;
+                let trade = response.results;
+                Ok(io::Trade {
+                    price: trade.p,
+                    size: trade.s,
+                    exchange: trade.x,
+                    conditions: trade.c.unwrap_or_default(),
+                    timestamp: trade.t,
+                })
+            }
+            snp::Instrument::Crypto { base, quote } => {
+                let path = format!("/v1/last/crypto/{}/{}", base, quote);
+                let response: PairResponse<CryptoTrade> = self.request(&path).await?;
+                let trade = response.last;
+                Ok(io::Trade {
+                    price: trade.price,
+                    size: trade.size,
+                    exchange: trade.exchange,
+                    conditions: trade.conditions,
+                    timestamp: trade.timestamp * 1_000_000,
+                })
+            }
+            _ => Err(io::Error::Unsupported(
+                instrument.ticker(snp.as_ref()).to_string(),
+            )),
+        }
+    }
+
+    async fn get_last_quote(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        instrument: &snp::Instrument,
+    ) -> io::Result<io::Quote> {
+        let snp = deps.provide_snp();
+        match instrument {
+            snp::Instrument::Stock(_) => {
+                let path = format!("/v2/last/nbbo/{}", instrument.ticker(snp.as_ref()));
+                let response: StockResponse<StockQuote> = self.request(&path).await?;
+                let quote = response.results;
+                Ok(io::Quote {
+                    bid_price: quote.p,
+                    bid_size: Some(quote.s),
+                    bid_exchange: quote.x,
+                    ask_price: quote.ask_price,
+                    ask_size: Some(quote.ask_size),
+                    ask_exchange: quote.ask_exchange,
+                    timestamp: quote.t,
+                })
+            }
+            snp::Instrument::Forex { base, quote } => {
+                let path = format!("/v1/last_quote/currencies/{}/{}", base, quote);
+                let response: PairResponse<ForexQuote> = self.request(&path).await?;
+                let quote = response.last;
+                Ok(io::Quote {
+                    bid_price: quote.bid,
+                    bid_size: None,
+                    bid_exchange: quote.exchange,
+                    ask_price: quote.ask,
+                    ask_size: None,
+                    ask_exchange: quote.exchange,
+                    timestamp: quote.timestamp * 1_000_000,
+                })
+            }
+            _ => Err(io::Error::Unsupported(
+                instrument.ticker(snp.as_ref()).to_string(),
+            )),
+        }
+    }
+}
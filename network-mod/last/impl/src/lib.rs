@@ -0,0 +1,5 @@
+#[path = "last-impl.rs"]
+pub mod last_impl;
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,200 @@
+use std::env;
+use std::sync::Arc;
+
+use network_mod_mock_server::{
+    mock_server::{MockServer, Route},
+    responses,
+};
+use rstest::rstest;
+use snp_mod_fixture::snp_fixture::ServiceFake;
+use snp_mod_io::snp;
+
+use crate::last_impl::Service;
+use io::Api;
+use network_mod_last_io::last as io;
+
+// It's not quite fit into the definiton of unit test, because it requests through network,
+// but we need it to stay here for future fast check.
+#[ignore]
+#[tokio::test]
+async fn call_btcusd_last_trade() -> io::Result<()> {
+    let api_key = env::var("API_KEY").unwrap();
+    let client = reqwest::Client::new();
+    let service = Service::new(Arc::new(client), &api_key).unwrap();
+
+    let response = service.get_last_trade(given_deps(), &btcusd()).await;
+    match response {
+        Ok(r) => {
+            dbg!(r);
+        }
+        Err(e) => {
+            dbg!(e);
+        }
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_stock_last_trade_and_quote() -> io::Result<()> {
+    let server = MockServer::start(vec![
+        Route::new("/v2/last/trade/AAPL", 200, responses::LAST_TRADE_AAPL),
+        Route::new("/v2/last/nbbo/AAPL", 200, responses::LAST_QUOTE_AAPL),
+    ])
+    .await
+    .unwrap();
+    let service = given_service(&server);
+    let aapl = snp::Instrument::Stock(snp::SnP::AAPL);
+
+    let trade = service.get_last_trade(given_deps(), &aapl).await?;
+    let quote = service.get_last_quote(given_deps(), &aapl).await?;
+
+    assert_eq!(
+        io::Trade {
+            price: 173.0,
+            size: 25.0,
+            exchange: 4,
+            conditions: vec![37],
+            timestamp: 1710446399999307000,
+        },
+        trade
+    );
+    assert_eq!(
+        io::Quote {
+            bid_price: 172.99,
+            bid_size: Some(1.0),
+            bid_exchange: 11,
+            ask_price: 173.05,
+            ask_size: Some(2.0),
+            ask_exchange: 19,
+            timestamp: 1710446399999512000,
+        },
+        quote
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_crypto_last_trade() -> io::Result<()> {
+    let server = MockServer::start(vec![Route::new(
+        "/v1/last/crypto/BTC/USD",
+        200,
+        responses::LAST_TRADE_X_BTCUSD,
+    )])
+    .await
+    .unwrap();
+    let service = given_service(&server);
+
+    let trade = service.get_last_trade(given_deps(), &btcusd()).await?;
+
+    assert_eq!(71470.56, trade.price);
+    assert_eq!(0.006909, trade.size);
+    assert_eq!(vec![1], trade.conditions);
+    assert_eq!(1710460799987000000, trade.timestamp);
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_forex_last_quote() -> io::Result<()> {
+    let server = MockServer::start(vec![Route::new(
+        "/v1/last_quote/currencies/EUR/USD",
+        200,
+        responses::LAST_QUOTE_C_EURUSD,
+    )])
+    .await
+    .unwrap();
+    let service = given_service(&server);
+
+    let quote = service.get_last_quote(given_deps(), &eurusd()).await?;
+
+    assert_eq!(
+        io::Quote {
+            bid_price: 1.08825,
+            bid_size: None,
+            bid_exchange: 48,
+            ask_price: 1.08831,
+            ask_size: None,
+            ask_exchange: 48,
+            timestamp: 1710460799000000000,
+        },
+        quote
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn no_endpoint_for_instrument() {
+    let server = MockServer::start(vec![]).await.unwrap();
+    let service = given_service(&server);
+
+    let trade = service.get_last_trade(given_deps(), &eurusd()).await;
+    let quote = service.get_last_quote(given_deps(), &btcusd()).await;
+
+    assert!(
+        matches!(trade, Err(io::Error::Unsupported(ref t)) if t == "C:EURUSD"),
+        "{:?}",
+        trade
+    );
+    assert!(
+        matches!(quote, Err(io::Error::Unsupported(ref t)) if t == "X:BTCUSD"),
+        "{:?}",
+        quote
+    );
+    assert!(server.requests().is_empty());
+}
+
+#[rstest]
+#[case(401, responses::ERROR_UNAUTHORIZED, "Unknown API Key")]
+#[case(
+    429,
+    responses::ERROR_TOO_MANY_REQUESTS,
+    "You've exceeded the maximum requests"
+)]
+#[tokio::test]
+async fn get_last_trade_server_error(
+    #[case] status: u16,
+    #[case] body: &str,
+    #[case] message: &str,
+) {
+    let server = MockServer::start(vec![Route::new("/v1/last/crypto/BTC/USD", status, body)])
+        .await
+        .unwrap();
+    let service = given_service(&server);
+
+    let response = service.get_last_trade(given_deps(), &btcusd()).await;
+
+    match response {
+        Err(io::Error::Server(_, m)) => assert!(m.starts_with(message), "message {:?}", m),
+        r => panic!("unexpected response {:?}", r),
+    }
+}
+
+fn btcusd() -> snp::Instrument {
+    snp::Instrument::Crypto {
+        base: "BTC".into(),
+        quote: "USD".into(),
+    }
+}
+
+fn eurusd() -> snp::Instrument {
+    snp::Instrument::Forex {
+        base: "EUR".into(),
+        quote: "USD".into(),
+    }
+}
+
+fn given_service(server: &MockServer) -> Service {
+    let client = reqwest::Client::new();
+    Service::with_host(Arc::new(client), "key", &server.host()).unwrap()
+}
+
+fn given_deps() -> Arc<dyn io::Depends + Send + Sync> {
+    Arc::new(DependsFake {})
+}
+
+struct DependsFake {}
+
+impl io::Depends for DependsFake {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        Arc::new(ServiceFake {})
+    }
+}
@@ -0,0 +1,73 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use snp_mod_io::snp;
+
+/// The most recent trade of an instrument.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Trade {
+    pub price: f64,
+    /// The size can be fractional, like for a crypto pair.
+    pub size: f64,
+    pub exchange: i32,
+    pub conditions: Vec<i32>,
+    /// The nanosecond Unix timestamp of the trade.
+    pub timestamp: i64,
+}
+
+/// The most recent quote of an instrument, where a currency pair has no sizes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Quote {
+    pub bid_price: f64,
+    pub bid_size: Option<f64>,
+    pub bid_exchange: i32,
+    pub ask_price: f64,
+    pub ask_size: Option<f64>,
+    pub ask_exchange: i32,
+    /// The nanosecond Unix timestamp of the quote.
+    pub timestamp: i64,
+}
+
+/// All possible errors of Last modules.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Failed to create an API client; can be invalid 'api_key'.")]
+    ApiKeyIsEmpty,
+
+    #[error("Failed to send a request to the service provider; can be invalid 'url', or 'api_key', or network IO problem: {0}")]
+    Request(#[from] reqwest::Error),
+
+    #[error("Can't process the body text from the response: {0}")]
+    JsonParse(#[from] serde_json::Error),
+
+    #[error("Provider server response error '{1}' for this url '{0}'.")]
+    Server(String, String),
+
+    #[error("The provider has no such endpoint for this instrument '{0}'.")]
+    Unsupported(String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The API of Last, which defines all the functionality of Last modules.
+#[async_trait]
+pub trait Api {
+    /// Returns the last trade of a stock, an options contract or a crypto pair.
+    async fn get_last_trade(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        instrument: &snp::Instrument,
+    ) -> Result<Trade>;
+
+    /// Returns the last quote of a stock or a currency pair.
+    async fn get_last_quote(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        instrument: &snp::Instrument,
+    ) -> Result<Quote>;
+}
+
+/// All the dependencies of the Last modules to other modules.
+pub trait Depends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync>;
+}
@@ -0,0 +1,2 @@
+#[path = "market-status-fixture.rs"]
+pub mod market_status_fixture;
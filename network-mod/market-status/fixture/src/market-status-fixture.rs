@@ -0,0 +1,24 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use network_mod_market_status_io::market_status as io;
+
+pub struct ServiceFake;
+
+#[async_trait]
+impl io::Api for ServiceFake {
+    async fn get_upcoming(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+    ) -> io::Result<Vec<io::Data>> {
+        Ok(vec![io::Data {
+            exchange: "NYSE".into(),
+            name: "Thanksgiving".into(),
+            date: NaiveDate::from_ymd_opt(2024, 11, 28).unwrap(),
+            status: "closed".into(),
+            open: None,
+            close: None,
+        }])
+    }
+}
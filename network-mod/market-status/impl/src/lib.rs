@@ -0,0 +1,5 @@
+#[path = "market-status-impl.rs"]
+pub mod market_status_impl;
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,105 @@
+use std::{collections::HashMap, sync::Arc};
+
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use reqwest::{Client, StatusCode};
+use serde::Deserialize;
+
+use network_mod_market_status_io::market_status as io;
+
+/// The result structure of Polygon.io API, which is a JSON array of them.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct UpcomingResult {
+    // The market time of close on an early close day.
+    close: Option<DateTime<Utc>>,
+    // The date of the holiday.
+    date: NaiveDate,
+    // Which market the record is for.
+    exchange: Arc<str>,
+    // The name of the holiday.
+    name: Arc<str>,
+    // The market time of open on an early close day.
+    open: Option<DateTime<Utc>>,
+    // The status of the market on the holiday.
+    status: Arc<str>,
+}
+
+/// The error structure of Polygon.io API.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct UpcomingError {
+    status: Arc<str>,
+    request_id: Arc<str>,
+    // Polygon.io names it `error` for authorization and rate limit failures.
+    #[serde(alias = "error")]
+    message: Arc<str>,
+}
+
+/// The service to implement the API of Market-status.
+#[derive(Debug)]
+pub struct Service {
+    url: Arc<str>,
+    api_key: Arc<str>,
+    client: Arc<Client>,
+}
+
+impl Service {
+    pub fn new(client: Arc<Client>, api_key: &str) -> io::Result<Self> {
+        Self::with_host(client, api_key, "https://api.polygon.io")
+    }
+
+    /// Creates the service against another host, e.g. a local mock server of Polygon.io.
+    pub fn with_host(client: Arc<Client>, api_key: &str, host: &str) -> io::Result<Self> {
+        if api_key.is_empty() {
+            return Err(io::Error::ApiKeyIsEmpty);
+        }
+
+        Ok(Self {
+            client,
+            api_key: api_key.into(),
+            url: format!("{}/v1/marketstatus/upcoming", host).into(),
+        })
+    }
+}
+
+#[async_trait]
+impl io::Api for Service {
+    async fn get_upcoming(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+    ) -> io::Result<Vec<io::Data>> {
+        let mut params = HashMap::new();
+        params.insert("apiKey", self.api_key.as_ref());
+
+        let url = &self.url;
+
+        let response = self.client.get(url.as_ref()).query(&params).send().await?;
+        let status_code = response.status();
+
+        let response_body = &response.text().await?;
+
+        if status_code == StatusCode::OK {
+            let upcoming_data: Vec<UpcomingResult> = serde_json::from_str(response_body)?;
+
+            Ok(upcoming_data
+                .into_iter()
+                .map(|u| io::Data {
+                    exchange: u.exchange,
+                    name: u.name,
+                    date: u.date,
+                    status: u.status,
+                    open: u.open,
+                    close: u.close,
+                })
+                .collect())
+        } else {
+            let error_data: UpcomingError = serde_json::from_str(response_body)?;
+
+            Err(io::Error::Server(
+                url.to_string(),
+                error_data.message.to_string(),
+            ))
+        }
+    }
+}
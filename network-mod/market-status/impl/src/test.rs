@@ -0,0 +1,125 @@
+use std::env;
+use std::sync::Arc;
+
+use chrono::{NaiveDate, TimeZone, Utc};
+use network_mod_mock_server::{
+    mock_server::{MockServer, Route},
+    responses,
+};
+use rstest::rstest;
+use snp_mod_fixture::snp_fixture::ServiceFake;
+use snp_mod_io::snp;
+
+use crate::market_status_impl::Service;
+use io::Api;
+use network_mod_market_status_io::market_status as io;
+
+// It's not quite fit into the definiton of unit test, because it requests through network,
+// but we need it to stay here for future fast check.
+#[ignore]
+#[tokio::test]
+async fn call_market_status_upcoming() -> io::Result<()> {
+    let api_key = env::var("API_KEY").unwrap();
+    let client = reqwest::Client::new();
+    let service = Service::new(Arc::new(client), &api_key).unwrap();
+
+    let response = service.get_upcoming(given_deps()).await;
+    match response {
+        Ok(r) => {
+            dbg!(r);
+        }
+        Err(e) => {
+            dbg!(e);
+        }
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_upcoming_from_mock_server() -> io::Result<()> {
+    let server = MockServer::start(vec![Route::new(
+        "/v1/marketstatus/upcoming",
+        200,
+        responses::MARKET_STATUS_UPCOMING,
+    )
+    .with_query("apiKey", "key")])
+    .await
+    .unwrap();
+    let service = given_service(&server);
+
+    let upcoming = service.get_upcoming(given_deps()).await?;
+
+    assert_eq!(5, upcoming.len());
+    assert_eq!("NYSE", upcoming[0].exchange.as_ref());
+    assert_eq!(None, upcoming[0].close);
+    let early_close = &upcoming[2];
+    assert_eq!("early-close", early_close.status.as_ref());
+    assert_eq!(
+        NaiveDate::from_ymd_opt(2024, 11, 29).unwrap(),
+        early_close.date
+    );
+    assert_eq!(
+        Some(Utc.with_ymd_and_hms(2024, 11, 29, 18, 0, 0).unwrap()),
+        early_close.close
+    );
+    Ok(())
+}
+
+#[rstest]
+#[case(401, responses::ERROR_UNAUTHORIZED, "Unknown API Key")]
+#[case(
+    429,
+    responses::ERROR_TOO_MANY_REQUESTS,
+    "You've exceeded the maximum requests"
+)]
+#[tokio::test]
+async fn get_upcoming_server_error(#[case] status: u16, #[case] body: &str, #[case] message: &str) {
+    let server = MockServer::start(vec![Route::new("/v1/marketstatus/upcoming", status, body)])
+        .await
+        .unwrap();
+    let service = given_service(&server);
+
+    let response = service.get_upcoming(given_deps()).await;
+
+    match response {
+        Err(io::Error::Server(_, m)) => assert!(m.starts_with(message), "message {:?}", m),
+        r => panic!("unexpected response {:?}", r),
+    }
+}
+
+#[tokio::test]
+async fn get_upcoming_malformed_json() {
+    let server = MockServer::start(vec![Route::new(
+        "/v1/marketstatus/upcoming",
+        200,
+        responses::MALFORMED,
+    )])
+    .await
+    .unwrap();
+    let service = given_service(&server);
+
+    let response = service.get_upcoming(given_deps()).await;
+
+    assert!(
+        matches!(response, Err(io::Error::JsonParse(_))),
+        "{:?}",
+        response
+    );
+}
+
+fn given_service(server: &MockServer) -> Service {
+    let client = reqwest::Client::new();
+    Service::with_host(Arc::new(client), "key", &server.host()).unwrap()
+}
+
+fn given_deps() -> Arc<dyn io::Depends + Send + Sync> {
+    Arc::new(DependsFake {})
+}
+
+struct DependsFake {}
+
+impl io::Depends for DependsFake {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        Arc::new(ServiceFake {})
+    }
+}
@@ -0,0 +1 @@
+pub mod market_status;
@@ -0,0 +1,51 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use snp_mod_io::snp;
+
+/// The data that is needed to interact with the Market-status modules, which is an upcoming
+/// holiday or early close of an exchange.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Data {
+    /// The exchange, like `NYSE`, `NASDAQ` or `OTC`.
+    pub exchange: Arc<str>,
+    pub name: Arc<str>,
+    pub date: NaiveDate,
+    /// One of `closed` or `early-close`.
+    pub status: Arc<str>,
+    /// The open time of an early close.
+    pub open: Option<DateTime<Utc>>,
+    /// The close time of an early close.
+    pub close: Option<DateTime<Utc>>,
+}
+
+/// All possible errors of Market-status modules.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Failed to create an API client; can be invalid 'api_key'.")]
+    ApiKeyIsEmpty,
+
+    #[error("Failed to send a request to the service provider; can be invalid 'url', or 'api_key', or network IO problem: {0}")]
+    Request(#[from] reqwest::Error),
+
+    #[error("Can't process the body text from the response: {0}")]
+    JsonParse(#[from] serde_json::Error),
+
+    #[error("Provider server response error '{1}' for this url '{0}'.")]
+    Server(String, String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The API of Market-status, which defines all the functionality of Market-status modules.
+#[async_trait]
+pub trait Api {
+    /// Returns the upcoming holidays and early closes of all the exchanges.
+    async fn get_upcoming(&self, deps: Arc<dyn Depends + Send + Sync>) -> Result<Vec<Data>>;
+}
+
+/// All the dependencies of the Market-status modules to other modules.
+pub trait Depends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync>;
+}
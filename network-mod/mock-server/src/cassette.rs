@@ -0,0 +1,67 @@
+use std::{fs, path::Path};
+
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+
+use crate::mock_server::{Route, HOST_PLACEHOLDER};
+
+/// The query parameter of Polygon.io API that carries the secret key.
+pub const API_KEY_PARAM: &str = "apiKey";
+
+/// One recorded request and its response, without the `apiKey`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Interaction {
+    pub path: String,
+    pub query: Vec<(String, String)>,
+    pub status: u16,
+    pub body: String,
+}
+
+impl From<Interaction> for Route {
+    fn from(interaction: Interaction) -> Self {
+        interaction.query.iter().fold(
+            Route::new(&interaction.path, interaction.status, &interaction.body),
+            |route, (key, value)| route.with_query(key, value),
+        )
+    }
+}
+
+/// Loads the interactions of a cassette file.
+pub fn load(path: &Path) -> std::io::Result<Vec<Interaction>> {
+    let text = fs::read_to_string(path)?;
+    serde_json::from_str(&text).map_err(std::io::Error::from)
+}
+
+/// Saves the interactions to a cassette file, creating its directory if it's missing.
+pub fn save(path: &Path, interactions: &[Interaction]) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let text = serde_json::to_string_pretty(interactions).map_err(std::io::Error::from)?;
+    fs::write(path, text + "\n")
+}
+
+/// Makes an interaction out of a real response, such that no trace of the `apiKey` remains,
+/// and the links to the upstream point to the host of whichever server replays it.
+pub fn scrub(upstream: &str, url: &Url, status: u16, body: &str) -> Interaction {
+    let api_key = url
+        .query_pairs()
+        .find(|(k, _)| k == API_KEY_PARAM)
+        .map(|(_, v)| v.into_owned());
+    let query = url
+        .query_pairs()
+        .filter(|(k, _)| k != API_KEY_PARAM)
+        .map(|(k, v)| (k.into_owned(), v.into_owned()))
+        .collect();
+    let mut body = body.replace(upstream, HOST_PLACEHOLDER);
+    if let Some(key) = api_key.filter(|k| !k.is_empty()) {
+        body = body.replace(&key, "SCRUBBED");
+    }
+
+    Interaction {
+        path: url.path().to_string(),
+        query,
+        status,
+        body,
+    }
+}
@@ -0,0 +1,15 @@
+#[path = "mock-server.rs"]
+pub mod mock_server;
+
+#[path = "mock-stream.rs"]
+pub mod mock_stream;
+
+#[path = "mock-objects.rs"]
+pub mod mock_objects;
+
+pub mod cassette;
+
+pub mod responses;
+
+#[cfg(test)]
+mod test;
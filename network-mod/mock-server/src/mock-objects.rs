@@ -0,0 +1,123 @@
+use std::{
+    collections::HashMap,
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+};
+
+use tokio::{io::AsyncWriteExt, net::TcpListener, net::TcpStream, task::JoinHandle};
+
+use crate::mock_server::read_head;
+
+/// A local S3-compatible server that plays the role of the flat files bucket of Polygon.io, like
+/// a MinIO. It serves the objects to the requests that are signed by the access key, but it
+/// doesn't verify the signature. The server stops when it's dropped.
+#[derive(Debug)]
+pub struct MockObjects {
+    address: SocketAddr,
+    requests: Arc<Mutex<Vec<String>>>,
+    handle: JoinHandle<()>,
+}
+
+impl MockObjects {
+    /// Binds to a random local port and serves the objects of the bucket by their keys.
+    pub async fn start(
+        bucket: &str,
+        access_key: &str,
+        objects: Vec<(&str, Vec<u8>)>,
+    ) -> std::io::Result<Self> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let address = listener.local_addr()?;
+        let objects: Arc<HashMap<String, Vec<u8>>> = Arc::new(
+            objects
+                .into_iter()
+                .map(|(key, body)| (format!("/{}/{}", bucket, key), body))
+                .collect(),
+        );
+        let credential: Arc<str> = format!("AWS4-HMAC-SHA256 Credential={}/", access_key).into();
+        let requests = Arc::new(Mutex::new(vec![]));
+        let server_requests = requests.clone();
+
+        let handle = tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let objects = objects.clone();
+                let credential = credential.clone();
+                let requests = server_requests.clone();
+                tokio::spawn(async move {
+                    if let Err(error) = serve(stream, &objects, &credential, &requests).await {
+                        eprintln!("Mock objects error: {}", error);
+                    }
+                });
+            }
+        });
+
+        Ok(Self {
+            address,
+            requests,
+            handle,
+        })
+    }
+
+    /// The host to pass to the flat files service instead of `https://files.polygon.io`.
+    pub fn host(&self) -> String {
+        format!("http://{}", self.address)
+    }
+
+    /// The paths of all the requests that this server received, in order.
+    pub fn requests(&self) -> Vec<String> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+impl Drop for MockObjects {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+async fn serve(
+    mut stream: TcpStream,
+    objects: &HashMap<String, Vec<u8>>,
+    credential: &str,
+    requests: &Mutex<Vec<String>>,
+) -> std::io::Result<()> {
+    let head = read_head(&mut stream).await?;
+    let path = head
+        .lines()
+        .next()
+        .and_then(|line| line.split_whitespace().nth(1))
+        .unwrap_or("/")
+        .to_string();
+    requests.lock().unwrap().push(path.clone());
+    let signed = head.lines().any(|line| {
+        line.split_once(':').is_some_and(|(name, value)| {
+            name.eq_ignore_ascii_case("authorization") && value.trim().starts_with(credential)
+        })
+    });
+
+    let (status, body) = match objects.get(&path) {
+        _ if !signed => (
+            "403 Forbidden",
+            error("AccessDenied", "Access Denied.").into_bytes(),
+        ),
+        Some(object) => ("200 OK", object.clone()),
+        None => (
+            "404 Not Found",
+            error("NoSuchKey", "The specified key does not exist.").into_bytes(),
+        ),
+    };
+    let head = format!(
+        "HTTP/1.1 {}\r\nContent-Type: application/octet-stream\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        status,
+        body.len()
+    );
+    stream.write_all(head.as_bytes()).await?;
+    stream.write_all(&body).await?;
+    stream.shutdown().await
+}
+
+fn error(code: &str, message: &str) -> String {
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<Error><Code>{}</Code><Message>{}</Message></Error>",
+        code, message
+    )
+}
@@ -0,0 +1,264 @@
+use std::{
+    env,
+    net::SocketAddr,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use reqwest::{Client, StatusCode, Url};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+    task::JoinHandle,
+};
+
+use crate::cassette::{self, Interaction};
+
+/// The placeholder inside the recorded bodies that is replaced by the host of the running server,
+/// so a recorded `next_url` points back to this server instead of Polygon.io.
+pub const HOST_PLACEHOLDER: &str = "{HOST}";
+
+/// The host of Polygon.io API, which is the upstream of the recording mode.
+pub const POLYGON_HOST: &str = "https://api.polygon.io";
+
+/// Setting this environment variable makes `MockServer::cassette` record instead of replay.
+pub const RECORD_CASSETTES: &str = "RECORD_CASSETTES";
+
+/// A recorded response of Polygon.io API.
+#[derive(Debug, Clone)]
+pub struct Response {
+    pub status: u16,
+    pub body: Arc<str>,
+}
+
+/// A route serves its response when the path and all of its query parameters match a request.
+#[derive(Debug, Clone)]
+pub struct Route {
+    pub path: Arc<str>,
+    pub query: Vec<(Arc<str>, Arc<str>)>,
+    pub response: Response,
+}
+
+impl Route {
+    pub fn new(path: &str, status: u16, body: &str) -> Self {
+        Self {
+            path: path.into(),
+            query: vec![],
+            response: Response {
+                status,
+                body: body.into(),
+            },
+        }
+    }
+
+    pub fn with_query(mut self, key: &str, value: &str) -> Self {
+        self.query.push((key.into(), value.into()));
+        self
+    }
+
+    fn matches(&self, url: &Url) -> bool {
+        url.path() == self.path.as_ref()
+            && self.query.iter().all(|(key, value)| {
+                url.query_pairs()
+                    .any(|(k, v)| k == key.as_ref() && v == value.as_ref())
+            })
+    }
+}
+
+/// How the server answers the requests.
+#[derive(Debug)]
+enum Mode {
+    /// Serves the routes, where the first match wins.
+    Serve(Vec<Route>),
+    /// Forwards the requests to the upstream and saves the scrubbed interactions to a cassette.
+    Record {
+        upstream: Arc<str>,
+        path: PathBuf,
+        client: Client,
+        interactions: Mutex<Vec<Interaction>>,
+    },
+}
+
+/// A local HTTP server that plays the role of Polygon.io for the network modules.
+/// The server stops when it's dropped.
+#[derive(Debug)]
+pub struct MockServer {
+    address: SocketAddr,
+    requests: Arc<Mutex<Vec<Url>>>,
+    handle: JoinHandle<()>,
+}
+
+impl MockServer {
+    /// Binds to a random local port and starts serving the routes, where the first match wins.
+    pub async fn start(routes: Vec<Route>) -> std::io::Result<Self> {
+        Self::start_mode(Mode::Serve(routes)).await
+    }
+
+    /// Serves the interactions of a cassette file that is recorded before.
+    pub async fn replay(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        let mut routes: Vec<Route> = cassette::load(path.as_ref())?
+            .into_iter()
+            .map(Route::from)
+            .collect();
+        // The most specific route should win, e.g. the page with a `cursor` over the first page.
+        routes.sort_by_key(|route| std::cmp::Reverse(route.query.len()));
+        Self::start(routes).await
+    }
+
+    /// Forwards the requests to the upstream, e.g. `POLYGON_HOST`, and saves the responses to a
+    /// cassette file, where the `apiKey` is scrubbed.
+    pub async fn record(upstream: &str, path: impl AsRef<Path>) -> std::io::Result<Self> {
+        Self::start_mode(Mode::Record {
+            upstream: upstream.into(),
+            path: path.as_ref().into(),
+            client: Client::new(),
+            interactions: Mutex::new(vec![]),
+        })
+        .await
+    }
+
+    /// Records from Polygon.io if `RECORD_CASSETTES` is set, otherwise replays the cassette.
+    pub async fn cassette(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        if env::var_os(RECORD_CASSETTES).is_some() {
+            Self::record(POLYGON_HOST, path).await
+        } else {
+            Self::replay(path).await
+        }
+    }
+
+    async fn start_mode(mode: Mode) -> std::io::Result<Self> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let address = listener.local_addr()?;
+        let host: Arc<str> = format!("http://{}", address).into();
+        let mode = Arc::new(mode);
+        let requests = Arc::new(Mutex::new(vec![]));
+        let server_requests = requests.clone();
+
+        let handle = tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let host = host.clone();
+                let mode = mode.clone();
+                let requests = server_requests.clone();
+                tokio::spawn(async move {
+                    if let Err(error) = serve(stream, &host, &mode, &requests).await {
+                        eprintln!("Mock server error: {}", error);
+                    }
+                });
+            }
+        });
+
+        Ok(Self {
+            address,
+            requests,
+            handle,
+        })
+    }
+
+    /// The host to pass to the network services instead of `https://api.polygon.io`.
+    pub fn host(&self) -> String {
+        format!("http://{}", self.address)
+    }
+
+    /// All the requests that this server received, in order.
+    pub fn requests(&self) -> Vec<Url> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+async fn serve(
+    mut stream: TcpStream,
+    host: &str,
+    mode: &Mode,
+    requests: &Mutex<Vec<Url>>,
+) -> std::io::Result<()> {
+    let head = read_head(&mut stream).await?;
+    let target = head
+        .lines()
+        .next()
+        .and_then(|line| line.split_whitespace().nth(1))
+        .unwrap_or("/");
+    let url = Url::parse(&format!("{}{}", host, target))
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+    requests.lock().unwrap().push(url.clone());
+
+    let response = match mode {
+        Mode::Serve(routes) => match routes.iter().find(|route| route.matches(&url)) {
+            Some(route) => route.response.clone(),
+            None => Response {
+                status: 501,
+                body: format!(
+                    "{{\"status\":\"ERROR\",\"request_id\":\"mock\",\"error\":\"No route for '{}'\"}}",
+                    url.path()
+                )
+                .into(),
+            },
+        },
+        Mode::Record {
+            upstream,
+            path,
+            client,
+            interactions,
+        } => {
+            let interaction = forward(client, upstream, target, &url).await?;
+            let response = Response {
+                status: interaction.status,
+                body: interaction.body.as_str().into(),
+            };
+            let mut interactions = interactions.lock().unwrap();
+            interactions.push(interaction);
+            cassette::save(path, &interactions)?;
+            response
+        }
+    };
+
+    let body = response.body.replace(HOST_PLACEHOLDER, host);
+    let reason = StatusCode::from_u16(response.status)
+        .ok()
+        .and_then(|s| s.canonical_reason())
+        .unwrap_or("");
+    let raw = format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        reason,
+        body.len(),
+        body
+    );
+    stream.write_all(raw.as_bytes()).await?;
+    stream.shutdown().await
+}
+
+async fn forward(
+    client: &Client,
+    upstream: &str,
+    target: &str,
+    url: &Url,
+) -> std::io::Result<Interaction> {
+    let to_io = |e: reqwest::Error| std::io::Error::other(e);
+    let response = client
+        .get(format!("{}{}", upstream, target))
+        .send()
+        .await
+        .map_err(to_io)?;
+    let status = response.status().as_u16();
+    let body = response.text().await.map_err(to_io)?;
+    Ok(cassette::scrub(upstream, url, status, &body))
+}
+
+pub(crate) async fn read_head(stream: &mut TcpStream) -> std::io::Result<String> {
+    let mut head = vec![];
+    let mut buffer = [0u8; 1024];
+    while !head.windows(4).any(|w| w == b"\r\n\r\n") {
+        let n = stream.read(&mut buffer).await?;
+        if n == 0 {
+            break;
+        }
+        head.extend_from_slice(&buffer[..n]);
+    }
+    Ok(String::from_utf8_lossy(&head).into_owned())
+}
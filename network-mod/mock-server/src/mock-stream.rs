@@ -0,0 +1,193 @@
+use std::{
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+};
+
+use futures_util::{SinkExt, StreamExt};
+use serde::Deserialize;
+use tokio::{
+    net::{TcpListener, TcpStream},
+    sync::watch,
+    task::JoinHandle,
+};
+use tokio_tungstenite::{accept_async, tungstenite::Message, WebSocketStream};
+
+/// An action that a client sent to the stream, like `{"action":"auth","params":"key"}`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Action {
+    pub action: Arc<str>,
+    pub params: Arc<str>,
+}
+
+/// What the server does with the open connections.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum State {
+    Serve,
+    /// Closes the open connections, like a dropped network.
+    Disconnect,
+    /// Keeps the open connections, but stops reading them, so no pong is sent back.
+    Stall,
+}
+
+/// A local WebSocket server that plays the role of the stocks stream of Polygon.io.
+/// It authenticates the clients with the API key, confirms the (un)subscriptions, and sends the
+/// messages after each subscription. The server stops when it's dropped.
+#[derive(Debug)]
+pub struct MockStream {
+    address: SocketAddr,
+    actions: Arc<Mutex<Vec<Action>>>,
+    connections: Arc<Mutex<usize>>,
+    state: Arc<watch::Sender<State>>,
+    handle: JoinHandle<()>,
+}
+
+impl MockStream {
+    /// Binds to a random local port, where each message is a JSON array of events.
+    pub async fn start(api_key: &str, messages: Vec<&str>) -> std::io::Result<Self> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let address = listener.local_addr()?;
+        let api_key: Arc<str> = api_key.into();
+        let messages: Arc<Vec<Arc<str>>> = Arc::new(messages.into_iter().map(Into::into).collect());
+        let actions = Arc::new(Mutex::new(vec![]));
+        let connections = Arc::new(Mutex::new(0));
+        let state = Arc::new(watch::channel(State::Serve).0);
+
+        let server_actions = actions.clone();
+        let server_connections = connections.clone();
+        let server_state = state.clone();
+        let handle = tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                *server_connections.lock().unwrap() += 1;
+                // A new connection is served, even after a disconnection.
+                server_state.send_replace(State::Serve);
+                let api_key = api_key.clone();
+                let messages = messages.clone();
+                let actions = server_actions.clone();
+                let state = server_state.subscribe();
+                tokio::spawn(async move {
+                    if let Err(error) = serve(stream, &api_key, &messages, &actions, state).await {
+                        eprintln!("Mock stream error: {}", error);
+                    }
+                });
+            }
+        });
+
+        Ok(Self {
+            address,
+            actions,
+            connections,
+            state,
+            handle,
+        })
+    }
+
+    /// The host to pass to the stream service instead of `wss://socket.polygon.io`.
+    pub fn host(&self) -> String {
+        format!("ws://{}", self.address)
+    }
+
+    /// All the actions that this server received, in order.
+    pub fn actions(&self) -> Vec<Action> {
+        self.actions.lock().unwrap().clone()
+    }
+
+    /// The number of accepted connections, including the reconnections.
+    pub fn connections(&self) -> usize {
+        *self.connections.lock().unwrap()
+    }
+
+    /// Closes the open connections.
+    pub fn disconnect(&self) {
+        self.state.send_replace(State::Disconnect);
+    }
+
+    /// Stops answering the open connections, even their pings.
+    pub fn stall(&self) {
+        self.state.send_replace(State::Stall);
+    }
+}
+
+impl Drop for MockStream {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+async fn serve(
+    stream: TcpStream,
+    api_key: &str,
+    messages: &[Arc<str>],
+    actions: &Mutex<Vec<Action>>,
+    mut state: watch::Receiver<State>,
+) -> std::io::Result<()> {
+    let to_io = |e| std::io::Error::other(e);
+    let mut socket = accept_async(stream).await.map_err(to_io)?;
+    send_status(&mut socket, "connected", "Connected Successfully").await?;
+
+    loop {
+        let message = tokio::select! {
+            message = socket.next() => message,
+            changed = state.changed() => {
+                if changed.is_err() {
+                    return Ok(());
+                }
+                let current = *state.borrow_and_update();
+                match current {
+                    State::Serve => continue,
+                    State::Disconnect => return socket.close(None).await.map_err(to_io),
+                    State::Stall => break,
+                }
+            }
+        };
+        let text = match message {
+            Some(Ok(Message::Text(text))) => text,
+            Some(Ok(Message::Close(_))) | None => return Ok(()),
+            Some(Ok(_)) => continue,
+            Some(Err(error)) => return Err(to_io(error)),
+        };
+        let Ok(action) = serde_json::from_str::<Action>(&text) else {
+            send_status(&mut socket, "error", "invalid message").await?;
+            continue;
+        };
+        actions.lock().unwrap().push(action.clone());
+
+        match action.action.as_ref() {
+            "auth" if action.params.as_ref() == api_key => {
+                send_status(&mut socket, "auth_success", "authenticated").await?;
+            }
+            "auth" => {
+                send_status(&mut socket, "auth_failed", "authentication failed").await?;
+                return socket.close(None).await.map_err(to_io);
+            }
+            "subscribe" | "unsubscribe" => {
+                for param in action.params.split(',') {
+                    let message = format!("{}d to: {}", action.action, param);
+                    send_status(&mut socket, "success", &message).await?;
+                }
+                if action.action.as_ref() == "subscribe" {
+                    for message in messages {
+                        let message = Message::Text(message.to_string());
+                        socket.send(message).await.map_err(to_io)?;
+                    }
+                }
+            }
+            _ => send_status(&mut socket, "error", "unknown action").await?,
+        }
+    }
+
+    // Keeps the connection open without reading it, until the server is dropped.
+    while state.changed().await.is_ok() {}
+    Ok(())
+}
+
+async fn send_status(
+    socket: &mut WebSocketStream<TcpStream>,
+    status: &str,
+    message: &str,
+) -> std::io::Result<()> {
+    let event = serde_json::json!([{ "ev": "status", "status": status, "message": message }]);
+    socket
+        .send(Message::Text(event.to_string()))
+        .await
+        .map_err(std::io::Error::other)
+}
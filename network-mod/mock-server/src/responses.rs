@@ -0,0 +1,68 @@
+//! Recorded Polygon.io responses to serve from the mock server.
+
+pub const TICKER_DETAILS_AAPL: &str = include_str!("../responses/ticker-details-aapl.json");
+pub const TRADES_AAPL_PAGE_1: &str = include_str!("../responses/trades-aapl-page-1.json");
+pub const TRADES_AAPL_PAGE_2: &str = include_str!("../responses/trades-aapl-page-2.json");
+pub const AGGREGATES_AAPL_MINUTE_PAGE_1: &str =
+    include_str!("../responses/aggregates-aapl-minute-page-1.json");
+pub const AGGREGATES_AAPL_MINUTE_PAGE_2: &str =
+    include_str!("../responses/aggregates-aapl-minute-page-2.json");
+pub const AGGREGATES_EMPTY: &str = include_str!("../responses/aggregates-empty.json");
+pub const QUOTES_AAPL_PAGE_1: &str = include_str!("../responses/quotes-aapl-page-1.json");
+pub const QUOTES_AAPL_PAGE_2: &str = include_str!("../responses/quotes-aapl-page-2.json");
+pub const DIVIDENDS_AAPL_PAGE_1: &str = include_str!("../responses/dividends-aapl-page-1.json");
+pub const DIVIDENDS_AAPL_PAGE_2: &str = include_str!("../responses/dividends-aapl-page-2.json");
+pub const SPLITS_AAPL: &str = include_str!("../responses/splits-aapl.json");
+pub const FINANCIALS_AAPL_PAGE_1: &str = include_str!("../responses/financials-aapl-page-1.json");
+pub const FINANCIALS_AAPL_PAGE_2: &str = include_str!("../responses/financials-aapl-page-2.json");
+pub const NEWS_AAPL_PAGE_1: &str = include_str!("../responses/news-aapl-page-1.json");
+pub const NEWS_AAPL_PAGE_2: &str = include_str!("../responses/news-aapl-page-2.json");
+pub const MARKET_STATUS_UPCOMING: &str = include_str!("../responses/market-status-upcoming.json");
+pub const TICKERS_NASDAQ_CS_PAGE_1: &str =
+    include_str!("../responses/tickers-nasdaq-cs-page-1.json");
+pub const TICKERS_NASDAQ_CS_PAGE_2: &str =
+    include_str!("../responses/tickers-nasdaq-cs-page-2.json");
+pub const TICKER_EVENTS_META: &str = include_str!("../responses/ticker-events-meta.json");
+pub const SNAPSHOTS_TICKERS: &str = include_str!("../responses/snapshots-tickers.json");
+pub const SNAPSHOT_AAPL: &str = include_str!("../responses/snapshot-aapl.json");
+pub const OPTIONS_CONTRACTS_AAPL_PAGE_1: &str =
+    include_str!("../responses/options-contracts-aapl-page-1.json");
+pub const OPTIONS_CONTRACTS_AAPL_PAGE_2: &str =
+    include_str!("../responses/options-contracts-aapl-page-2.json");
+/// The indicators of AAPL below are written by hand, in the format of Polygon.io, so their
+/// values aren't the real ones.
+pub const SYNTHETIC_INDICATORS_AAPL_SMA: &str =
+    include_str!("../responses/synthetic-indicators-aapl-sma.json");
+pub const SYNTHETIC_INDICATORS_AAPL_EMA: &str =
+    include_str!("../responses/synthetic-indicators-aapl-ema.json");
+pub const SYNTHETIC_INDICATORS_AAPL_RSI: &str =
+    include_str!("../responses/synthetic-indicators-aapl-rsi.json");
+pub const SYNTHETIC_INDICATORS_AAPL_MACD: &str =
+    include_str!("../responses/synthetic-indicators-aapl-macd.json");
+pub const EXCHANGES_STOCKS: &str = include_str!("../responses/exchanges-stocks.json");
+pub const CONDITIONS_STOCKS_PAGE_1: &str =
+    include_str!("../responses/conditions-stocks-page-1.json");
+pub const CONDITIONS_STOCKS_PAGE_2: &str =
+    include_str!("../responses/conditions-stocks-page-2.json");
+pub const STREAM_TRADES_AAPL: &str = include_str!("../responses/stream-trades-aapl.json");
+pub const STREAM_QUOTES_AAPL: &str = include_str!("../responses/stream-quotes-aapl.json");
+pub const STREAM_MINUTE_BARS_AAPL: &str = include_str!("../responses/stream-minute-bars-aapl.json");
+pub const FLAT_FILES_TRADES: &str = include_str!("../responses/flat-files-trades.csv");
+pub const FLAT_FILES_QUOTES: &str = include_str!("../responses/flat-files-quotes.csv");
+pub const FLAT_FILES_MINUTE_AGGS: &str = include_str!("../responses/flat-files-minute-aggs.csv");
+pub const FLAT_FILES_DAY_AGGS: &str = include_str!("../responses/flat-files-day-aggs.csv");
+pub const AGGREGATES_X_BTCUSD_DAY: &str = include_str!("../responses/aggregates-x-btcusd-day.json");
+pub const AGGREGATES_C_EURUSD_DAY: &str = include_str!("../responses/aggregates-c-eurusd-day.json");
+pub const LAST_TRADE_AAPL: &str = include_str!("../responses/last-trade-aapl.json");
+pub const LAST_QUOTE_AAPL: &str = include_str!("../responses/last-quote-aapl.json");
+pub const LAST_TRADE_X_BTCUSD: &str = include_str!("../responses/last-trade-x-btcusd.json");
+pub const LAST_QUOTE_C_EURUSD: &str = include_str!("../responses/last-quote-c-eurusd.json");
+pub const INDICES_SPX_MINUTE_PAGE_1: &str =
+    include_str!("../responses/indices-spx-minute-page-1.json");
+pub const INDICES_SPX_MINUTE_PAGE_2: &str =
+    include_str!("../responses/indices-spx-minute-page-2.json");
+pub const INDICES_SNAPSHOT: &str = include_str!("../responses/indices-snapshot.json");
+pub const ERROR_UNAUTHORIZED: &str = include_str!("../responses/error-unauthorized.json");
+pub const ERROR_NOT_FOUND: &str = include_str!("../responses/error-not-found.json");
+pub const ERROR_TOO_MANY_REQUESTS: &str = include_str!("../responses/error-too-many-requests.json");
+pub const MALFORMED: &str = include_str!("../responses/malformed.json");
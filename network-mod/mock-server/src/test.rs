@@ -0,0 +1,61 @@
+use std::{env, fs};
+
+use crate::{
+    cassette,
+    mock_server::{MockServer, Route},
+    responses,
+};
+
+#[tokio::test]
+async fn record_and_replay_scrubs_api_key() -> std::io::Result<()> {
+    let path = env::temp_dir().join(format!("cassette-{}.json", std::process::id()));
+    let upstream = MockServer::start(vec![
+        Route::new("/v3/trades/AAPL", 200, responses::TRADES_AAPL_PAGE_2).with_query(
+            "cursor",
+            "YXA9MiZhcz0mbGltaXQ9MiZvcmRlcj1hc2Mmc29ydD10aW1lc3RhbXA",
+        ),
+        Route::new("/v3/trades/AAPL", 200, responses::TRADES_AAPL_PAGE_1),
+    ])
+    .await?;
+    let recorder = MockServer::record(&upstream.host(), &path).await?;
+    let client = reqwest::Client::new();
+
+    let first = get(&client, &format!("{}/v3/trades/AAPL", recorder.host())).await;
+    let next_url = first["next_url"].as_str().unwrap().to_string();
+    assert!(
+        next_url.starts_with(&recorder.host()),
+        "next_url {:?}",
+        next_url
+    );
+    get(&client, &next_url).await;
+
+    let interactions = cassette::load(&path)?;
+    assert_eq!(2, interactions.len());
+    let text = fs::read_to_string(&path)?;
+    assert!(!text.contains("secret-key"));
+    assert!(!text.contains(&upstream.host()));
+    assert!(interactions
+        .iter()
+        .all(|i| i.query.iter().all(|(k, _)| k != cassette::API_KEY_PARAM)));
+
+    drop(recorder);
+    drop(upstream);
+    let replayer = MockServer::replay(&path).await?;
+    let replayed = get(&client, &format!("{}/v3/trades/AAPL", replayer.host())).await;
+    assert_eq!(first["results"], replayed["results"]);
+    let next_url = replayed["next_url"].as_str().unwrap().to_string();
+    let last = get(&client, &next_url).await;
+    assert!(last.get("next_url").is_none());
+
+    fs::remove_file(&path)
+}
+
+async fn get(client: &reqwest::Client, url: &str) -> serde_json::Value {
+    let response = client
+        .get(url)
+        .query(&[("apiKey", "secret-key")])
+        .send()
+        .await
+        .unwrap();
+    serde_json::from_str(&response.text().await.unwrap()).unwrap()
+}
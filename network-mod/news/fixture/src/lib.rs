@@ -0,0 +1,2 @@
+#[path = "news-fixture.rs"]
+pub mod news_fixture;
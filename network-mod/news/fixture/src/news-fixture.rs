@@ -0,0 +1,35 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, TimeZone, Utc};
+use network_mod_news_io::news as io;
+use snp_mod_io::snp;
+
+pub struct ServiceFake;
+
+#[async_trait]
+impl io::Api for ServiceFake {
+    async fn get_news(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        _ticker: &snp::SnP,
+        _published_after: Option<DateTime<Utc>>,
+    ) -> io::Result<Vec<io::Data>> {
+        Ok(vec![io::Data {
+            id: "8ec638777ca03b553ae516761c2a22ba2fdd2f37befae3ab6fdab74e9e5193eb".into(),
+            publisher: "The Motley Fool".into(),
+            title: "Apple Unveils Apple Intelligence at WWDC".into(),
+            author: Some("Motley Fool Staff".into()),
+            published_utc: Utc.with_ymd_and_hms(2024, 6, 10, 20, 15, 0).unwrap(),
+            article_url: "https://www.fool.com/investing/2024/06/10/apple-intelligence/".into(),
+            description: None,
+            keywords: vec!["WWDC".into()],
+            tickers: vec!["AAPL".into(), "MSFT".into(), "OPENAI".into()],
+            insights: vec![io::Insight {
+                ticker: "AAPL".into(),
+                sentiment: Some("positive".into()),
+                sentiment_reasoning: Some("The new features can drive an upgrade cycle.".into()),
+            }],
+        }])
+    }
+}
@@ -0,0 +1,194 @@
+use std::{collections::HashMap, sync::Arc};
+
+use async_trait::async_trait;
+use chrono::{DateTime, SecondsFormat, Utc};
+use reqwest::{Client, StatusCode};
+use serde::Deserialize;
+use snp_mod_io::snp::SnP;
+
+use network_mod_news_io::news as io;
+
+/// The publisher structure of result in Polygon.io API.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct NewsPublisher {
+    // The publisher's name.
+    name: Arc<str>,
+    // The publisher's homepage URL.
+    homepage_url: Option<Arc<str>>,
+    // The publisher's logo URL.
+    logo_url: Option<Arc<str>>,
+    // The publisher's favicon URL.
+    favicon_url: Option<Arc<str>>,
+}
+
+/// The insight structure of result in Polygon.io API.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct NewsInsight {
+    // The ticker symbol associated with the insight.
+    ticker: Arc<str>,
+    // The sentiment of the insight.
+    sentiment: Option<Arc<str>>,
+    // The reasoning behind the sentiment.
+    sentiment_reasoning: Option<Arc<str>>,
+}
+
+/// The result structure of result in Polygon.io API.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct NewsResult {
+    // Unique identifier for the article.
+    id: Arc<str>,
+    // The publisher's details.
+    publisher: NewsPublisher,
+    // The title of the news article.
+    title: Arc<str>,
+    // The article's author.
+    author: Option<Arc<str>>,
+    // The date the article was published on.
+    published_utc: DateTime<Utc>,
+    // A link to the news article.
+    article_url: Arc<str>,
+    // The ticker symbols associated with the article.
+    #[serde(default)]
+    tickers: Vec<Arc<str>>,
+    // The article's image URL.
+    image_url: Option<Arc<str>>,
+    // A description of the article.
+    description: Option<Arc<str>>,
+    // The keywords associated with the article.
+    #[serde(default)]
+    keywords: Vec<Arc<str>>,
+    // The insights related to the article.
+    #[serde(default)]
+    insights: Vec<NewsInsight>,
+}
+
+/// The response structure of Polygon.io API.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct NewsResponse {
+    count: Option<i64>,
+    next_url: Option<Arc<str>>,
+    request_id: Arc<str>,
+    status: Arc<str>,
+    #[serde(default)]
+    results: Vec<NewsResult>,
+}
+
+/// The error structure of Polygon.io API.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct NewsError {
+    status: Arc<str>,
+    request_id: Arc<str>,
+    // Polygon.io names it `error` for authorization and rate limit failures.
+    #[serde(alias = "error")]
+    message: Arc<str>,
+}
+
+/// The service to implement the API of News.
+#[derive(Debug)]
+pub struct Service {
+    url: Arc<str>,
+    api_key: Arc<str>,
+    client: Arc<Client>,
+}
+
+impl Service {
+    pub fn new(client: Arc<Client>, api_key: &str) -> io::Result<Self> {
+        Self::with_host(client, api_key, "https://api.polygon.io")
+    }
+
+    /// Creates the service against another host, e.g. a local mock server of Polygon.io.
+    pub fn with_host(client: Arc<Client>, api_key: &str, host: &str) -> io::Result<Self> {
+        if api_key.is_empty() {
+            return Err(io::Error::ApiKeyIsEmpty);
+        }
+
+        Ok(Self {
+            client,
+            api_key: api_key.into(),
+            url: format!("{}/v2/reference/news", host).into(),
+        })
+    }
+}
+
+#[async_trait]
+impl io::Api for Service {
+    async fn get_news(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        ticker: &SnP,
+        published_after: Option<DateTime<Utc>>,
+    ) -> io::Result<Vec<io::Data>> {
+        let snp = deps.provide_snp();
+        let snp_symbol_rc = snp.get_symbol(ticker).clone();
+        let snp_symbol = &snp_symbol_rc[..];
+        let published_after = published_after.map(|p| p.to_rfc3339_opts(SecondsFormat::Secs, true));
+
+        let mut params = HashMap::new();
+        params.insert("apiKey", self.api_key.as_ref());
+        params.insert("ticker", snp_symbol);
+        if let Some(published_after) = &published_after {
+            params.insert("published_utc.gt", published_after.as_ref());
+        }
+        params.insert("sort", "published_utc");
+        params.insert("order", "asc");
+        params.insert("limit", "1000");
+
+        let client = &self.client;
+        let mut url: String = self.url.to_string();
+        let mut articles = vec![];
+
+        while !url.is_empty() {
+            let response = client.get(&url).query(&params).send().await?;
+            let status_code = response.status();
+
+            let response_body = &response.text().await?;
+
+            if status_code == StatusCode::OK {
+                let news_data: NewsResponse = serde_json::from_str(response_body)?;
+
+                articles.extend(news_data.results.into_iter().map(|a| {
+                    io::Data {
+                        id: a.id,
+                        publisher: a.publisher.name,
+                        title: a.title,
+                        author: a.author,
+                        published_utc: a.published_utc,
+                        article_url: a.article_url,
+                        description: a.description,
+                        keywords: a.keywords,
+                        tickers: a.tickers,
+                        insights: a
+                            .insights
+                            .into_iter()
+                            .map(|i| io::Insight {
+                                ticker: i.ticker,
+                                sentiment: i.sentiment,
+                                sentiment_reasoning: i.sentiment_reasoning,
+                            })
+                            .collect(),
+                    }
+                }));
+
+                // The `next_url` keeps the other params in its cursor.
+                params.retain(|k, _| *k == "apiKey");
+                url = match news_data.next_url {
+                    Some(u) => u.to_string(),
+                    None => "".to_string(),
+                };
+            } else {
+                let error_data: NewsError = serde_json::from_str(response_body)?;
+
+                return Err(io::Error::Server(
+                    url.to_string(),
+                    error_data.message.to_string(),
+                ));
+            }
+        }
+        Ok(articles)
+    }
+}
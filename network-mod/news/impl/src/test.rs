@@ -0,0 +1,160 @@
+use std::env;
+use std::sync::Arc;
+
+use chrono::{TimeZone, Utc};
+use network_mod_mock_server::{
+    mock_server::{MockServer, Route},
+    responses,
+};
+use rstest::rstest;
+use snp_mod_fixture::snp_fixture::ServiceFake;
+use snp_mod_io::snp;
+
+use crate::news_impl::Service;
+use io::Api;
+use network_mod_news_io::news as io;
+
+// It's not quite fit into the definiton of unit test, because it requests through network,
+// but we need it to stay here for future fast check.
+#[ignore]
+#[tokio::test]
+async fn call_aapl_news() -> io::Result<()> {
+    let api_key = env::var("API_KEY").unwrap();
+    let client = reqwest::Client::new();
+    let service = Service::new(Arc::new(client), &api_key).unwrap();
+
+    let response = service.get_news(given_deps(), &snp::SnP::AAPL, None).await;
+    match response {
+        Ok(r) => {
+            dbg!(r);
+        }
+        Err(e) => {
+            dbg!(e);
+        }
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_paginated_news_from_mock_server() -> io::Result<()> {
+    let server = MockServer::start(vec![
+        Route::new("/v2/reference/news", 200, responses::NEWS_AAPL_PAGE_2).with_query(
+            "cursor",
+            "YXA9MjAyNC0wNi0xMFQyMCUzQTE1JTNBMDBaJmFzPSZsaW1pdD0yJm9yZGVyPWFzYyZzb3J0PXB1Ymxpc2hlZF91dGMmdGlja2VyPUFBUEw",
+        ),
+        Route::new("/v2/reference/news", 200, responses::NEWS_AAPL_PAGE_1)
+            .with_query("ticker", "AAPL")
+            .with_query("sort", "published_utc"),
+    ])
+    .await
+    .unwrap();
+    let service = given_service(&server);
+
+    let news = service
+        .get_news(given_deps(), &snp::SnP::AAPL, None)
+        .await?;
+
+    assert_eq!(3, news.len());
+    let article = &news[1];
+    assert_eq!("The Motley Fool", article.publisher.as_ref());
+    assert_eq!(
+        Utc.with_ymd_and_hms(2024, 6, 10, 20, 15, 0).unwrap(),
+        article.published_utc
+    );
+    assert_eq!(
+        vec!["AAPL", "MSFT"],
+        article
+            .tickers
+            .iter()
+            .map(|t| t.as_ref())
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(2, article.insights.len());
+    assert_eq!(Some("positive"), article.insights[0].sentiment.as_deref());
+    // Polygon.io leaves the author, description, keywords and insights out of some articles.
+    assert_eq!(None, news[2].author);
+    assert!(news[2].insights.is_empty());
+    assert_eq!(2, server.requests().len());
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_news_published_after() -> io::Result<()> {
+    let server = MockServer::start(vec![Route::new(
+        "/v2/reference/news",
+        200,
+        responses::NEWS_AAPL_PAGE_2,
+    )
+    .with_query("published_utc.gt", "2024-06-10T20:15:00Z")])
+    .await
+    .unwrap();
+    let service = given_service(&server);
+
+    let published_after = Utc.with_ymd_and_hms(2024, 6, 10, 20, 15, 0).unwrap();
+    let news = service
+        .get_news(given_deps(), &snp::SnP::AAPL, Some(published_after))
+        .await?;
+
+    assert_eq!(1, news.len());
+    assert!(news[0].published_utc > published_after);
+    Ok(())
+}
+
+#[rstest]
+#[case(401, responses::ERROR_UNAUTHORIZED, "Unknown API Key")]
+#[case(
+    429,
+    responses::ERROR_TOO_MANY_REQUESTS,
+    "You've exceeded the maximum requests"
+)]
+#[tokio::test]
+async fn get_news_server_error(#[case] status: u16, #[case] body: &str, #[case] message: &str) {
+    let server = MockServer::start(vec![Route::new("/v2/reference/news", status, body)])
+        .await
+        .unwrap();
+    let service = given_service(&server);
+
+    let response = service.get_news(given_deps(), &snp::SnP::AAPL, None).await;
+
+    match response {
+        Err(io::Error::Server(_, m)) => assert!(m.starts_with(message), "message {:?}", m),
+        r => panic!("unexpected response {:?}", r),
+    }
+}
+
+#[tokio::test]
+async fn get_news_malformed_json() {
+    let server = MockServer::start(vec![Route::new(
+        "/v2/reference/news",
+        200,
+        responses::MALFORMED,
+    )])
+    .await
+    .unwrap();
+    let service = given_service(&server);
+
+    let response = service.get_news(given_deps(), &snp::SnP::AAPL, None).await;
+
+    assert!(
+        matches!(response, Err(io::Error::JsonParse(_))),
+        "{:?}",
+        response
+    );
+}
+
+fn given_service(server: &MockServer) -> Service {
+    let client = reqwest::Client::new();
+    Service::with_host(Arc::new(client), "key", &server.host()).unwrap()
+}
+
+fn given_deps() -> Arc<dyn io::Depends + Send + Sync> {
+    Arc::new(DependsFake {})
+}
+
+struct DependsFake {}
+
+impl io::Depends for DependsFake {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        Arc::new(ServiceFake {})
+    }
+}
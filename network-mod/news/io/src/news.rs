@@ -0,0 +1,67 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use snp_mod_io::snp;
+
+/// The sentiment of an article toward one of its tickers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Insight {
+    pub ticker: Arc<str>,
+    /// One of `positive`, `neutral` or `negative`.
+    pub sentiment: Option<Arc<str>>,
+    pub sentiment_reasoning: Option<Arc<str>>,
+}
+
+/// The data that is needed to interact with the News modules, which is an article.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Data {
+    /// The unique identifier of the article in Polygon.io.
+    pub id: Arc<str>,
+    pub publisher: Arc<str>,
+    pub title: Arc<str>,
+    pub author: Option<Arc<str>>,
+    pub published_utc: DateTime<Utc>,
+    pub article_url: Arc<str>,
+    pub description: Option<Arc<str>>,
+    pub keywords: Vec<Arc<str>>,
+    /// All the tickers mentioned in the article, which are not necessarily in the S&P 500.
+    pub tickers: Vec<Arc<str>>,
+    pub insights: Vec<Insight>,
+}
+
+/// All possible errors of News modules.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Failed to create an API client; can be invalid 'api_key'.")]
+    ApiKeyIsEmpty,
+
+    #[error("Failed to send a request to the service provider; can be invalid 'url', or 'api_key', or network IO problem: {0}")]
+    Request(#[from] reqwest::Error),
+
+    #[error("Can't process the body text from the response: {0}")]
+    JsonParse(#[from] serde_json::Error),
+
+    #[error("Provider server response error '{1}' for this url '{0}'.")]
+    Server(String, String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The API of News, which defines all the functionality of News modules.
+#[async_trait]
+pub trait Api {
+    /// Follows all the pages, and returns the articles of the ticker that are published after
+    /// `published_after`, or all of them if it's `None`, in ascending order of published time.
+    async fn get_news(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        ticker: &snp::SnP,
+        published_after: Option<DateTime<Utc>>,
+    ) -> Result<Vec<Data>>;
+}
+
+/// All the dependencies of the News modules to other modules.
+pub trait Depends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync>;
+}
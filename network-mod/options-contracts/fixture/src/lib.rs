@@ -0,0 +1,2 @@
+#[path = "options-contracts-fixture.rs"]
+pub mod options_contracts_fixture;
@@ -0,0 +1,34 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use network_mod_options_contracts_io::options_contracts as io;
+
+pub struct ServiceFake;
+
+#[async_trait]
+impl io::Api for ServiceFake {
+    async fn get_options_contracts(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        _request: &io::Request,
+    ) -> io::Result<Vec<io::Data>> {
+        Ok(vec![
+            given_contract("O:AAPL240621C00190000", "call"),
+            given_contract("O:AAPL240621P00190000", "put"),
+        ])
+    }
+}
+
+fn given_contract(ticker: &str, contract_type: &str) -> io::Data {
+    io::Data {
+        ticker: ticker.into(),
+        underlying_ticker: "AAPL".into(),
+        contract_type: contract_type.into(),
+        exercise_style: Some("american".into()),
+        expiration_date: NaiveDate::from_ymd_opt(2024, 6, 21).unwrap(),
+        strike_price: 190.0,
+        shares_per_contract: Some(100.0),
+        primary_exchange: Some("BATO".into()),
+    }
+}
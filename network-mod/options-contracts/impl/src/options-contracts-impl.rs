@@ -0,0 +1,157 @@
+use std::{collections::HashMap, sync::Arc};
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use reqwest::{Client, StatusCode};
+use serde::Deserialize;
+
+use network_mod_options_contracts_io::options_contracts as io;
+
+/// The result structure of result in Polygon.io API.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct OptionsContractsResult {
+    // The 6 letter CFI code of the contract.
+    cfi: Option<Arc<str>>,
+    // The type of contract. Can be "put", "call", or in some rare cases, "other".
+    contract_type: Arc<str>,
+    // The exercise style of this contract.
+    exercise_style: Option<Arc<str>>,
+    // The contract's expiration date in YYYY-MM-DD format.
+    expiration_date: NaiveDate,
+    // The MIC code of the primary exchange that this contract is listed on.
+    primary_exchange: Option<Arc<str>>,
+    // The number of shares per contract for this contract.
+    shares_per_contract: Option<f64>,
+    // The strike price of the option contract.
+    strike_price: f64,
+    // The ticker for the option contract.
+    ticker: Arc<str>,
+    // The underlying ticker that the option contract relates to.
+    underlying_ticker: Arc<str>,
+}
+
+/// The response structure of Polygon.io API.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct OptionsContractsResponse {
+    next_url: Option<Arc<str>>,
+    request_id: Arc<str>,
+    status: Arc<str>,
+    #[serde(default)]
+    results: Vec<OptionsContractsResult>,
+}
+
+/// The error structure of Polygon.io API.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct OptionsContractsError {
+    status: Arc<str>,
+    request_id: Arc<str>,
+    // Polygon.io names it `error` for authorization and rate limit failures.
+    #[serde(alias = "error")]
+    message: Arc<str>,
+}
+
+/// The service to implement the API of Options-contracts.
+#[derive(Debug)]
+pub struct Service {
+    url: Arc<str>,
+    api_key: Arc<str>,
+    client: Arc<Client>,
+}
+
+impl Service {
+    pub fn new(client: Arc<Client>, api_key: &str) -> io::Result<Self> {
+        Self::with_host(client, api_key, "https://api.polygon.io")
+    }
+
+    /// Creates the service against another host, e.g. a local mock server of Polygon.io.
+    pub fn with_host(client: Arc<Client>, api_key: &str, host: &str) -> io::Result<Self> {
+        if api_key.is_empty() {
+            return Err(io::Error::ApiKeyIsEmpty);
+        }
+
+        Ok(Self {
+            client,
+            api_key: api_key.into(),
+            url: format!("{}/v3/reference/options/contracts", host).into(),
+        })
+    }
+}
+
+#[async_trait]
+impl io::Api for Service {
+    async fn get_options_contracts(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        request: &io::Request,
+    ) -> io::Result<Vec<io::Data>> {
+        let underlying = deps.provide_snp().get_symbol(&request.underlying);
+        let expiration_date = request
+            .expiration_date
+            .map(|d| d.format("%Y-%m-%d").to_string());
+        let strike_price = request.strike_price.map(|p| p.to_string());
+        let as_of = request.as_of.map(|d| d.format("%Y-%m-%d").to_string());
+
+        let mut params = HashMap::new();
+        params.insert("apiKey", self.api_key.as_ref());
+        params.insert("underlying_ticker", underlying.as_ref());
+        if let Some(contract_type) = &request.contract_type {
+            params.insert("contract_type", contract_type.as_ref());
+        }
+        if let Some(expiration_date) = &expiration_date {
+            params.insert("expiration_date", expiration_date.as_ref());
+        }
+        if let Some(strike_price) = &strike_price {
+            params.insert("strike_price", strike_price.as_ref());
+        }
+        if let Some(as_of) = &as_of {
+            params.insert("as_of", as_of.as_ref());
+        }
+        params.insert("sort", "ticker");
+        params.insert("order", "asc");
+        params.insert("limit", "1000");
+
+        let client = &self.client;
+        let mut url: String = self.url.to_string();
+        let mut contracts = vec![];
+
+        while !url.is_empty() {
+            let response = client.get(&url).query(&params).send().await?;
+            let status_code = response.status();
+
+            let response_body = &response.text().await?;
+
+            if status_code == StatusCode::OK {
+                let contracts_data: OptionsContractsResponse = serde_json::from_str(response_body)?;
+
+                contracts.extend(contracts_data.results.into_iter().map(|c| io::Data {
+                    ticker: c.ticker,
+                    underlying_ticker: c.underlying_ticker,
+                    contract_type: c.contract_type,
+                    exercise_style: c.exercise_style,
+                    expiration_date: c.expiration_date,
+                    strike_price: c.strike_price,
+                    shares_per_contract: c.shares_per_contract,
+                    primary_exchange: c.primary_exchange,
+                }));
+
+                // The `next_url` keeps the other params in its cursor.
+                params.retain(|k, _| *k == "apiKey");
+                url = match contracts_data.next_url {
+                    Some(u) => u.to_string(),
+                    None => "".to_string(),
+                };
+            } else {
+                let error_data: OptionsContractsError = serde_json::from_str(response_body)?;
+
+                return Err(io::Error::Server(
+                    url.to_string(),
+                    error_data.message.to_string(),
+                ));
+            }
+        }
+        Ok(contracts)
+    }
+}
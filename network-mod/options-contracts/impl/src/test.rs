@@ -0,0 +1,162 @@
+use std::env;
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use network_mod_mock_server::{
+    mock_server::{MockServer, Route},
+    responses,
+};
+use rstest::rstest;
+use snp_mod_fixture::snp_fixture::ServiceFake;
+use snp_mod_io::snp;
+
+use crate::options_contracts_impl::Service;
+use io::Api;
+use network_mod_options_contracts_io::options_contracts as io;
+
+// It's not quite fit into the definiton of unit test, because it requests through network,
+// but we need it to stay here for future fast check.
+#[ignore]
+#[tokio::test]
+async fn call_aapl_options_contracts() -> io::Result<()> {
+    let api_key = env::var("API_KEY").unwrap();
+    let client = reqwest::Client::new();
+    let service = Service::new(Arc::new(client), &api_key).unwrap();
+
+    let response = service
+        .get_options_contracts(given_deps(), &given_request())
+        .await;
+    match response {
+        Ok(r) => {
+            dbg!(r);
+        }
+        Err(e) => {
+            dbg!(e);
+        }
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_paginated_options_contracts_from_mock_server() -> io::Result<()> {
+    let server = MockServer::start(vec![
+        Route::new(
+            "/v3/reference/options/contracts",
+            200,
+            responses::OPTIONS_CONTRACTS_AAPL_PAGE_2,
+        )
+        .with_query("cursor", "YXA9JTdCJTIySUQlMjIlM0ElMjIyMDI0MDYyMUMwMDE5MDAwMCUyMiU3RCZhcz0mYXNfb2Y9MjAyNC0wNi0xMSZsaW1pdD0yJm9yZGVyPWFzYyZzb3J0PXRpY2tlciZ1bmRlcmx5aW5nX3RpY2tlcj1BQVBM"),
+        Route::new(
+            "/v3/reference/options/contracts",
+            200,
+            responses::OPTIONS_CONTRACTS_AAPL_PAGE_1,
+        )
+        .with_query("underlying_ticker", "AAPL")
+        .with_query("as_of", "2024-06-11"),
+    ])
+    .await
+    .unwrap();
+    let service = given_service(&server);
+
+    let contracts = service
+        .get_options_contracts(given_deps(), &given_request())
+        .await?;
+
+    assert_eq!(3, contracts.len());
+    let contract = &contracts[1];
+    assert_eq!("O:AAPL240621P00190000", contract.ticker.as_ref());
+    assert_eq!("AAPL", contract.underlying_ticker.as_ref());
+    assert_eq!("put", contract.contract_type.as_ref());
+    assert_eq!(Some("american"), contract.exercise_style.as_deref());
+    assert_eq!(
+        NaiveDate::from_ymd_opt(2024, 6, 21).unwrap(),
+        contract.expiration_date
+    );
+    assert_eq!(190.0, contract.strike_price);
+    assert_eq!(Some(100.0), contract.shares_per_contract);
+    assert_eq!(192.5, contracts[2].strike_price);
+    assert_eq!(None, contracts[2].exercise_style);
+    assert_eq!(2, server.requests().len());
+    Ok(())
+}
+
+#[rstest]
+#[case(401, responses::ERROR_UNAUTHORIZED, "Unknown API Key")]
+#[case(
+    429,
+    responses::ERROR_TOO_MANY_REQUESTS,
+    "You've exceeded the maximum requests"
+)]
+#[tokio::test]
+async fn get_options_contracts_server_error(
+    #[case] status: u16,
+    #[case] body: &str,
+    #[case] message: &str,
+) {
+    let server = MockServer::start(vec![Route::new(
+        "/v3/reference/options/contracts",
+        status,
+        body,
+    )])
+    .await
+    .unwrap();
+    let service = given_service(&server);
+
+    let response = service
+        .get_options_contracts(given_deps(), &given_request())
+        .await;
+
+    match response {
+        Err(io::Error::Server(_, m)) => assert!(m.starts_with(message), "message {:?}", m),
+        r => panic!("unexpected response {:?}", r),
+    }
+}
+
+#[tokio::test]
+async fn get_options_contracts_malformed_json() {
+    let server = MockServer::start(vec![Route::new(
+        "/v3/reference/options/contracts",
+        200,
+        responses::MALFORMED,
+    )])
+    .await
+    .unwrap();
+    let service = given_service(&server);
+
+    let response = service
+        .get_options_contracts(given_deps(), &given_request())
+        .await;
+
+    assert!(
+        matches!(response, Err(io::Error::JsonParse(_))),
+        "{:?}",
+        response
+    );
+}
+
+fn given_service(server: &MockServer) -> Service {
+    let client = reqwest::Client::new();
+    Service::with_host(Arc::new(client), "key", &server.host()).unwrap()
+}
+
+fn given_request() -> io::Request {
+    io::Request {
+        underlying: snp::SnP::AAPL,
+        contract_type: None,
+        expiration_date: None,
+        strike_price: None,
+        as_of: NaiveDate::from_ymd_opt(2024, 6, 11),
+    }
+}
+
+fn given_deps() -> Arc<dyn io::Depends + Send + Sync> {
+    Arc::new(DependsFake {})
+}
+
+struct DependsFake {}
+
+impl io::Depends for DependsFake {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        Arc::new(ServiceFake {})
+    }
+}
@@ -0,0 +1,66 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use snp_mod_io::snp;
+
+/// The filters of the contracts to request, where `None` means no filter.
+#[derive(Debug, Clone)]
+pub struct Request {
+    pub underlying: snp::SnP,
+    /// Either `call` or `put`.
+    pub contract_type: Option<Arc<str>>,
+    pub expiration_date: Option<NaiveDate>,
+    pub strike_price: Option<f64>,
+    /// The date of the chain, which is today if `None`.
+    pub as_of: Option<NaiveDate>,
+}
+
+/// The data that is needed to interact with the Options-contracts modules, which is a contract.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Data {
+    /// The ticker of the contract, e.g. `O:AAPL240621C00190000`.
+    pub ticker: Arc<str>,
+    pub underlying_ticker: Arc<str>,
+    pub contract_type: Arc<str>,
+    pub exercise_style: Option<Arc<str>>,
+    pub expiration_date: NaiveDate,
+    pub strike_price: f64,
+    pub shares_per_contract: Option<f64>,
+    pub primary_exchange: Option<Arc<str>>,
+}
+
+/// All possible errors of Options-contracts modules.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Failed to create an API client; can be invalid 'api_key'.")]
+    ApiKeyIsEmpty,
+
+    #[error("Failed to send a request to the service provider; can be invalid 'url', or 'api_key', or network IO problem: {0}")]
+    Request(#[from] reqwest::Error),
+
+    #[error("Can't process the body text from the response: {0}")]
+    JsonParse(#[from] serde_json::Error),
+
+    #[error("Provider server response error '{1}' for this url '{0}'.")]
+    Server(String, String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The API of Options-contracts, which defines all the functionality of Options-contracts
+/// modules.
+#[async_trait]
+pub trait Api {
+    /// Follows all the pages, and returns the contracts of the underlying.
+    async fn get_options_contracts(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        request: &Request,
+    ) -> Result<Vec<Data>>;
+}
+
+/// All the dependencies of the Options-contracts modules to other modules.
+pub trait Depends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync>;
+}
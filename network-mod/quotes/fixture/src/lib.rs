@@ -0,0 +1,2 @@
+#[path = "quotes-fixture.rs"]
+pub mod quotes_fixture;
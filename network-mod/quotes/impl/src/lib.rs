@@ -0,0 +1,5 @@
+#[path = "quotes-impl.rs"]
+pub mod quotes_impl;
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,174 @@
+use std::{collections::HashMap, sync::Arc};
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use crossbeam::channel::{bounded, Receiver, Sender};
+use reqwest::{Client, StatusCode};
+use serde::Deserialize;
+use snp_mod_io::snp::SnP;
+
+use network_mod_quotes_io::quotes as io;
+
+/// The result structure of result in Polygon.io API.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct QuotesResult {
+    // The ask exchange ID.
+    ask_exchange: Option<i32>,
+    // The ask price.
+    ask_price: Option<f64>,
+    // The ask size. This represents the number of round lot orders at the given ask price.
+    ask_size: Option<f64>,
+    // The bid exchange ID.
+    bid_exchange: Option<i32>,
+    // The bid price.
+    bid_price: Option<f64>,
+    // The bid size. This represents the number of round lot orders at the given bid price.
+    bid_size: Option<f64>,
+    // A list of condition codes.
+    #[serde(default)]
+    conditions: Vec<i32>,
+    // A list of indicator codes.
+    #[serde(default)]
+    indicators: Vec<i32>,
+    // The nanosecond accuracy Participant/Exchange Unix Timestamp. This is the timestamp of when
+    // the quote was actually generated at the exchange.
+    participant_timestamp: Option<i64>,
+    // The sequence number represents the sequence in which quote events happened. These are
+    // increasing and unique per ticker symbol, but will not always be sequential. Values reset
+    // after each trading session/day.
+    sequence_number: i64,
+    // The nanosecond accuracy SIP Unix Timestamp. This is the timestamp of when the SIP received
+    // this quote from the exchange which produced it.
+    sip_timestamp: i64,
+    // There are 3 tapes which define which exchange the ticker is listed on.
+    tape: Option<i32>,
+    // The nanosecond accuracy TRF (Trade Reporting Facility) Unix Timestamp.
+    trf_timestamp: Option<i64>,
+}
+
+/// The response structure of Polygon.io API.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct QuotesResponse {
+    next_url: Option<Arc<str>>,
+    request_id: Arc<str>,
+    status: Arc<str>,
+    #[serde(default)]
+    results: Vec<QuotesResult>,
+}
+
+/// The error structure of Polygon.io API.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct QuotesError {
+    status: Arc<str>,
+    request_id: Arc<str>,
+    // Polygon.io names it `error` for authorization and rate limit failures.
+    #[serde(alias = "error")]
+    message: Arc<str>,
+}
+
+/// The service to implement the API of Quotes.
+#[derive(Debug)]
+pub struct Service {
+    url: Arc<str>,
+    api_key: Arc<str>,
+    client: Arc<Client>,
+    sender: Sender<Vec<io::Data>>,
+    pub channel: Receiver<Vec<io::Data>>,
+}
+
+impl Service {
+    pub fn new(client: Arc<Client>, api_key: &str) -> io::Result<Self> {
+        Self::with_host(client, api_key, "https://api.polygon.io")
+    }
+
+    /// Creates the service against another host, e.g. a local mock server of Polygon.io.
+    pub fn with_host(client: Arc<Client>, api_key: &str, host: &str) -> io::Result<Self> {
+        if api_key.is_empty() {
+            return Err(io::Error::ApiKeyIsEmpty);
+        }
+        let (sender, channel) = bounded(0);
+
+        Ok(Self {
+            client,
+            api_key: api_key.into(),
+            url: format!("{}/v3/quotes/{{TICKER}}", host).into(),
+            sender,
+            channel,
+        })
+    }
+}
+
+#[async_trait]
+impl io::Api for Service {
+    async fn get_quotes(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        ticker: &SnP,
+        date: &NaiveDate,
+    ) -> io::Result<()> {
+        let formatted_date = date.format("%Y-%m-%d").to_string();
+
+        let mut params = HashMap::new();
+        params.insert("apiKey", self.api_key.as_ref());
+        params.insert("timestamp", &formatted_date[..]);
+        params.insert("sort", "timestamp");
+        params.insert("order", "asc");
+        params.insert("limit", "50000");
+
+        let snp = deps.provide_snp();
+        let snp_symbol_rc = snp.get_symbol(ticker).clone();
+        let snp_symbol = &snp_symbol_rc[..];
+
+        let client = &self.client;
+        let sender = &self.sender;
+        let mut url: String = self.url.replace("{TICKER}", snp_symbol);
+
+        while !url.is_empty() {
+            let response = client.get(&url).query(&params).send().await?;
+            let status_code = response.status();
+
+            let response_body = &response.text().await?;
+
+            if status_code == StatusCode::OK {
+                let quotes_data: QuotesResponse = serde_json::from_str(response_body)?;
+
+                let result = quotes_data
+                    .results
+                    .into_iter()
+                    .map(|q| io::Data {
+                        ask_exchange: q.ask_exchange,
+                        ask_price: q.ask_price,
+                        ask_size: q.ask_size,
+                        bid_exchange: q.bid_exchange,
+                        bid_price: q.bid_price,
+                        bid_size: q.bid_size,
+                        conditions: q.conditions,
+                        participant_timestamp: q.participant_timestamp,
+                        sip_timestamp: q.sip_timestamp,
+                        sequence_number: q.sequence_number,
+                        tape: q.tape,
+                    })
+                    .collect();
+
+                sender.send(result)?;
+                // The `next_url` keeps the other params in its cursor.
+                params.retain(|k, _| *k == "apiKey");
+                url = match quotes_data.next_url {
+                    Some(u) => u.to_string(),
+                    None => "".to_string(),
+                };
+            } else {
+                let error_data: QuotesError = serde_json::from_str(response_body)?;
+
+                return Err(io::Error::Server(
+                    url.to_string(),
+                    error_data.message.to_string(),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,156 @@
+use std::env;
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use network_mod_mock_server::{
+    mock_server::{MockServer, Route},
+    responses,
+};
+use rstest::rstest;
+use snp_mod_fixture::snp_fixture::ServiceFake;
+use snp_mod_io::snp;
+
+use crate::quotes_impl::Service;
+use io::Api;
+use network_mod_quotes_io::quotes as io;
+
+// It's not quite fit into the definiton of unit test, because it requests through network,
+// but we need it to stay here for future fast check.
+#[ignore]
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn call_aapl_quotes() -> io::Result<()> {
+    let api_key = env::var("API_KEY").unwrap();
+    let client = reqwest::Client::new();
+    let service = Service::new(Arc::new(client), &api_key).unwrap();
+    let channel = service.channel.clone();
+
+    let _handle = tokio::spawn(async move {
+        if let Ok(r) = channel.recv() {
+            dbg!(&r);
+        }
+    });
+
+    let _ = service
+        .get_quotes(given_deps(), &snp::SnP::AAPL, &given_date())
+        .await;
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn stream_paginated_quotes_from_mock_server() -> io::Result<()> {
+    let server = MockServer::start(vec![
+        Route::new("/v3/quotes/AAPL", 200, responses::QUOTES_AAPL_PAGE_2).with_query(
+            "cursor",
+            "YXA9MTcxMDQwMzIwMDAxNzQ4ODAwMCZhcz0mbGltaXQ9MiZvcmRlcj1hc2Mmc29ydD10aW1lc3RhbXA",
+        ),
+        Route::new("/v3/quotes/AAPL", 200, responses::QUOTES_AAPL_PAGE_1)
+            .with_query("timestamp", "2024-03-14")
+            .with_query("order", "asc"),
+    ])
+    .await
+    .unwrap();
+    let service = given_service(&server);
+    let channel = service.channel.clone();
+    let pages = std::thread::spawn(move || channel.iter().collect::<Vec<_>>());
+
+    service
+        .get_quotes(given_deps(), &snp::SnP::AAPL, &given_date())
+        .await?;
+    // Dropping the service closes the channel, so the collector ends.
+    drop(service);
+
+    let pages = pages.join().unwrap();
+    assert_eq!(2, pages.len());
+    assert_eq!(2, pages[0].len());
+    let quote = &pages[0][0];
+    assert_eq!(Some(172.62), quote.ask_price);
+    assert_eq!(Some(2.0), quote.ask_size);
+    assert_eq!(Some(12), quote.ask_exchange);
+    assert_eq!(Some(172.45), quote.bid_price);
+    assert_eq!(vec![1], quote.conditions);
+    assert_eq!(1710403200010342000, quote.sip_timestamp);
+    let one_sided = &pages[1][0];
+    assert_eq!(None, one_sided.ask_price);
+    assert_eq!(None, one_sided.participant_timestamp);
+    assert!(one_sided.conditions.is_empty());
+    let requests = server.requests();
+    assert_eq!(2, requests.len());
+    // The cursor already holds the filters of the first page.
+    assert!(
+        !requests[1].as_str().contains("timestamp="),
+        "{}",
+        requests[1]
+    );
+    Ok(())
+}
+
+#[rstest]
+#[case(401, responses::ERROR_UNAUTHORIZED, "Unknown API Key")]
+#[case(
+    429,
+    responses::ERROR_TOO_MANY_REQUESTS,
+    "You've exceeded the maximum requests"
+)]
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn get_quotes_server_error(#[case] status: u16, #[case] body: &str, #[case] message: &str) {
+    let server = MockServer::start(vec![Route::new("/v3/quotes/AAPL", status, body)])
+        .await
+        .unwrap();
+    let service = given_service(&server);
+
+    let response = service
+        .get_quotes(given_deps(), &snp::SnP::AAPL, &given_date())
+        .await;
+
+    match response {
+        Err(io::Error::Server(url, m)) => {
+            assert!(!url.contains("apiKey"), "url {:?}", url);
+            assert!(m.starts_with(message), "message {:?}", m);
+        }
+        r => panic!("unexpected response {:?}", r),
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn get_quotes_malformed_json() {
+    let server = MockServer::start(vec![Route::new(
+        "/v3/quotes/AAPL",
+        200,
+        responses::MALFORMED,
+    )])
+    .await
+    .unwrap();
+    let service = given_service(&server);
+
+    let response = service
+        .get_quotes(given_deps(), &snp::SnP::AAPL, &given_date())
+        .await;
+
+    assert!(
+        matches!(response, Err(io::Error::JsonParse(_))),
+        "{:?}",
+        response
+    );
+}
+
+fn given_service(server: &MockServer) -> Service {
+    let client = reqwest::Client::new();
+    Service::with_host(Arc::new(client), "key", &server.host()).unwrap()
+}
+
+fn given_date() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2024, 3, 14).unwrap()
+}
+
+fn given_deps() -> Arc<dyn io::Depends + Send + Sync> {
+    Arc::new(DependsFake {})
+}
+
+struct DependsFake {}
+
+impl io::Depends for DependsFake {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        Arc::new(ServiceFake {})
+    }
+}
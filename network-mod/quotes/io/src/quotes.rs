@@ -0,0 +1,62 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use snp_mod_io::snp;
+
+/// The data that is needed to interact with the Quotes modules, which is an NBBO quote.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Data {
+    pub ask_exchange: Option<i32>,
+    pub ask_price: Option<f64>,
+    pub ask_size: Option<f64>,
+    pub bid_exchange: Option<i32>,
+    pub bid_price: Option<f64>,
+    pub bid_size: Option<f64>,
+    pub conditions: Vec<i32>,
+    /// The nanosecond Unix timestamp of when the exchange generated the quote.
+    pub participant_timestamp: Option<i64>,
+    /// The nanosecond Unix timestamp of when the SIP received the quote.
+    pub sip_timestamp: i64,
+    pub sequence_number: i64,
+    pub tape: Option<i32>,
+}
+
+/// All possible errors of Quotes modules.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Failed to create an API client; can be invalid 'api_key'.")]
+    ApiKeyIsEmpty,
+
+    #[error("Failed to send a request to the service provider; can be invalid 'url', or 'api_key', or network IO problem: {0}")]
+    Request(#[from] reqwest::Error),
+
+    #[error("Can't process the body text from the response: {0}")]
+    JsonParse(#[from] serde_json::Error),
+
+    #[error("Can't send the response over channel: {0}")]
+    Channel(#[from] crossbeam::channel::SendError<Vec<Data>>),
+
+    #[error("Provider server response error '{1}' for this url '{0}'.")]
+    Server(String, String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The API of Quotes, which defines all the functionality of Quotes modules.
+#[async_trait]
+pub trait Api {
+    /// Sends the quotes of the date over the channel of the service, one page at a time, since
+    /// a day of quotes doesn't fit in memory.
+    async fn get_quotes(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        ticker: &snp::SnP,
+        date: &NaiveDate,
+    ) -> Result<()>;
+}
+
+/// All the dependencies of the Quotes modules to other modules.
+pub trait Depends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync>;
+}
@@ -0,0 +1,64 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use network_mod_snapshots_io::snapshots as io;
+use snp_mod_io::snp;
+
+pub struct ServiceFake;
+
+#[async_trait]
+impl io::Api for ServiceFake {
+    async fn get_snapshots(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        tickers: &[snp::SnP],
+    ) -> io::Result<Vec<io::Data>> {
+        let mut snapshots = vec![];
+        for ticker in tickers {
+            snapshots.push(self.get_snapshot(deps.clone(), ticker).await?);
+        }
+        Ok(snapshots)
+    }
+
+    async fn get_snapshot(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        ticker: &snp::SnP,
+    ) -> io::Result<io::Data> {
+        Ok(io::Data {
+            ticker: deps.provide_snp().get_symbol(ticker),
+            todays_change: 1.23,
+            todays_change_perc: 0.63,
+            updated: 1718121600000000000,
+            day: Some(io::Bar {
+                open: 193.65,
+                high: 197.3,
+                low: 193.0,
+                close: 196.89,
+                volume: 70_000_000.0,
+                vwap: Some(195.8),
+            }),
+            prev_day: Some(io::Bar {
+                open: 196.9,
+                high: 197.3,
+                low: 192.15,
+                close: 193.12,
+                volume: 97_000_000.0,
+                vwap: Some(194.3),
+            }),
+            last_trade: Some(io::LastTrade {
+                price: 196.89,
+                size: 100.0,
+                exchange: Some(4),
+                timestamp: 1718121599000000000,
+            }),
+            last_quote: Some(io::LastQuote {
+                bid_price: 196.88,
+                bid_size: 2.0,
+                ask_price: 196.9,
+                ask_size: 3.0,
+                timestamp: 1718121599500000000,
+            }),
+        })
+    }
+}
@@ -0,0 +1,5 @@
+#[path = "snapshots-impl.rs"]
+pub mod snapshots_impl;
+
+#[cfg(test)]
+mod test;
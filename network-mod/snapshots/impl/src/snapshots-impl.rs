@@ -0,0 +1,245 @@
+use std::{collections::HashMap, sync::Arc};
+
+use async_trait::async_trait;
+use reqwest::{Client, StatusCode};
+use serde::Deserialize;
+use snp_mod_io::snp;
+
+use network_mod_snapshots_io::snapshots as io;
+
+/// The result structure of a bar in Polygon.io API.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct SnapshotBar {
+    // The open price for the symbol in the given time period.
+    o: f64,
+    // The highest price for the symbol in the given time period.
+    h: f64,
+    // The lowest price for the symbol in the given time period.
+    l: f64,
+    // The close price for the symbol in the given time period.
+    c: f64,
+    // The trading volume of the symbol in the given time period.
+    v: f64,
+    // The volume weighted average price.
+    vw: Option<f64>,
+}
+
+/// The result structure of the last trade in Polygon.io API.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct SnapshotTrade {
+    // The trade conditions.
+    c: Option<Vec<i32>>,
+    // The Trade ID which uniquely identifies a trade.
+    i: Option<Arc<str>>,
+    // The price of the trade.
+    p: f64,
+    // The size of the trade.
+    s: f64,
+    // The nanosecond accuracy SIP Unix Timestamp.
+    t: i64,
+    // The exchange ID.
+    x: Option<i32>,
+}
+
+/// The result structure of the last quote in Polygon.io API.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct SnapshotQuote {
+    // The ask price.
+    #[serde(rename = "P")]
+    ask_price: f64,
+    // The ask size in lots.
+    #[serde(rename = "S")]
+    ask_size: f64,
+    // The bid price.
+    p: f64,
+    // The bid size in lots.
+    s: f64,
+    // The nanosecond accuracy SIP Unix Timestamp.
+    t: i64,
+}
+
+/// The result structure of a ticker in Polygon.io API.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct SnapshotTicker {
+    // The most recent daily bar for this ticker.
+    day: Option<SnapshotBar>,
+    // The most recent quote for this ticker.
+    last_quote: Option<SnapshotQuote>,
+    // The most recent trade for this ticker.
+    last_trade: Option<SnapshotTrade>,
+    // The most recent minute bar for this ticker.
+    min: Option<SnapshotBar>,
+    // The previous day bar for this ticker.
+    prev_day: Option<SnapshotBar>,
+    // The exchange symbol that this item is traded under.
+    ticker: Arc<str>,
+    // The value of the change from the previous day.
+    #[serde(default)]
+    todays_change: f64,
+    // The percentage change since the previous day.
+    #[serde(default)]
+    todays_change_perc: f64,
+    // The last updated timestamp.
+    #[serde(default)]
+    updated: i64,
+}
+
+/// The response structure of the full market snapshot in Polygon.io API.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct SnapshotsResponse {
+    count: Option<i64>,
+    status: Arc<str>,
+    #[serde(default)]
+    tickers: Vec<SnapshotTicker>,
+}
+
+/// The response structure of the ticker snapshot in Polygon.io API.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct SnapshotResponse {
+    request_id: Arc<str>,
+    status: Arc<str>,
+    ticker: SnapshotTicker,
+}
+
+/// The error structure of Polygon.io API.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct SnapshotsError {
+    status: Arc<str>,
+    request_id: Arc<str>,
+    // Polygon.io names it `error` for authorization and rate limit failures.
+    #[serde(alias = "error")]
+    message: Arc<str>,
+}
+
+/// The service to implement the API of Snapshots.
+#[derive(Debug)]
+pub struct Service {
+    url: Arc<str>,
+    api_key: Arc<str>,
+    client: Arc<Client>,
+}
+
+impl Service {
+    pub fn new(client: Arc<Client>, api_key: &str) -> io::Result<Self> {
+        Self::with_host(client, api_key, "https://api.polygon.io")
+    }
+
+    /// Creates the service against another host, e.g. a local mock server of Polygon.io.
+    pub fn with_host(client: Arc<Client>, api_key: &str, host: &str) -> io::Result<Self> {
+        if api_key.is_empty() {
+            return Err(io::Error::ApiKeyIsEmpty);
+        }
+
+        Ok(Self {
+            client,
+            api_key: api_key.into(),
+            url: format!("{}/v2/snapshot/locale/us/markets/stocks/tickers", host).into(),
+        })
+    }
+
+    /// Sends the request, and returns the body of a successful response.
+    async fn request(&self, url: &str, params: &HashMap<&str, &str>) -> io::Result<String> {
+        let response = self.client.get(url).query(params).send().await?;
+        let status_code = response.status();
+
+        let response_body = response.text().await?;
+
+        if status_code == StatusCode::OK {
+            Ok(response_body)
+        } else {
+            let error_data: SnapshotsError = serde_json::from_str(&response_body)?;
+
+            Err(io::Error::Server(
+                url.to_string(),
+                error_data.message.to_string(),
+            ))
+        }
+    }
+}
+
+#[async_trait]
+impl io::Api for Service {
+    async fn get_snapshots(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        tickers: &[snp::SnP],
+    ) -> io::Result<Vec<io::Data>> {
+        let snp = deps.provide_snp();
+        let symbols = tickers
+            .iter()
+            .map(|t| snp.get_symbol(t).to_string())
+            .collect::<Vec<String>>()
+            .join(",");
+
+        let mut params = HashMap::new();
+        params.insert("apiKey", self.api_key.as_ref());
+        if !symbols.is_empty() {
+            params.insert("tickers", symbols.as_ref());
+        }
+
+        let response_body = self.request(&self.url, &params).await?;
+        let snapshots_data: SnapshotsResponse = serde_json::from_str(&response_body)?;
+
+        Ok(snapshots_data.tickers.into_iter().map(to_data).collect())
+    }
+
+    async fn get_snapshot(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        ticker: &snp::SnP,
+    ) -> io::Result<io::Data> {
+        let symbol = deps.provide_snp().get_symbol(ticker);
+        let url = format!("{}/{}", self.url, symbol);
+
+        let mut params = HashMap::new();
+        params.insert("apiKey", self.api_key.as_ref());
+
+        let response_body = self.request(&url, &params).await?;
+        let snapshot_data: SnapshotResponse = serde_json::from_str(&response_body)?;
+
+        Ok(to_data(snapshot_data.ticker))
+    }
+}
+
+fn to_data(t: SnapshotTicker) -> io::Data {
+    io::Data {
+        ticker: t.ticker,
+        todays_change: t.todays_change,
+        todays_change_perc: t.todays_change_perc,
+        updated: t.updated,
+        day: t.day.map(to_bar),
+        prev_day: t.prev_day.map(to_bar),
+        last_trade: t.last_trade.map(|trade| io::LastTrade {
+            price: trade.p,
+            size: trade.s,
+            exchange: trade.x,
+            timestamp: trade.t,
+        }),
+        last_quote: t.last_quote.map(|quote| io::LastQuote {
+            bid_price: quote.p,
+            bid_size: quote.s,
+            ask_price: quote.ask_price,
+            ask_size: quote.ask_size,
+            timestamp: quote.t,
+        }),
+    }
+}
+
+fn to_bar(bar: SnapshotBar) -> io::Bar {
+    io::Bar {
+        open: bar.o,
+        high: bar.h,
+        low: bar.l,
+        close: bar.c,
+        volume: bar.v,
+        vwap: bar.vw,
+    }
+}
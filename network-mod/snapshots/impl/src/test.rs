@@ -0,0 +1,155 @@
+use std::env;
+use std::sync::Arc;
+
+use network_mod_mock_server::{
+    mock_server::{MockServer, Route},
+    responses,
+};
+use rstest::rstest;
+use snp_mod_fixture::snp_fixture::ServiceFake;
+use snp_mod_io::snp;
+
+use crate::snapshots_impl::Service;
+use io::Api;
+use network_mod_snapshots_io::snapshots as io;
+
+// It's not quite fit into the definiton of unit test, because it requests through network,
+// but we need it to stay here for future fast check.
+#[ignore]
+#[tokio::test]
+async fn call_aapl_snapshot() -> io::Result<()> {
+    let api_key = env::var("API_KEY").unwrap();
+    let client = reqwest::Client::new();
+    let service = Service::new(Arc::new(client), &api_key).unwrap();
+
+    let response = service.get_snapshot(given_deps(), &snp::SnP::AAPL).await;
+    match response {
+        Ok(r) => {
+            dbg!(r);
+        }
+        Err(e) => {
+            dbg!(e);
+        }
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_snapshots_by_one_request() -> io::Result<()> {
+    let server = MockServer::start(vec![Route::new(
+        "/v2/snapshot/locale/us/markets/stocks/tickers",
+        200,
+        responses::SNAPSHOTS_TICKERS,
+    )
+    .with_query("tickers", "AAPL")])
+    .await
+    .unwrap();
+    let service = given_service(&server);
+
+    let snapshots = service
+        .get_snapshots(given_deps(), &[snp::SnP::AAPL])
+        .await?;
+
+    assert_eq!(2, snapshots.len());
+    let snapshot = &snapshots[0];
+    assert_eq!("AAPL", snapshot.ticker.as_ref());
+    assert_eq!(3.77, snapshot.todays_change);
+    assert_eq!(1718121599512345678, snapshot.updated);
+    assert_eq!(Some(196.89), snapshot.day.as_ref().map(|d| d.close));
+    assert_eq!(Some(193.12), snapshot.prev_day.as_ref().map(|d| d.close));
+    let last_trade = snapshot.last_trade.as_ref().unwrap();
+    assert_eq!(100.0, last_trade.size);
+    assert_eq!(Some(4), last_trade.exchange);
+    let last_quote = snapshot.last_quote.as_ref().unwrap();
+    assert_eq!(196.88, last_quote.bid_price);
+    assert_eq!(196.9, last_quote.ask_price);
+    assert_eq!(3.0, last_quote.ask_size);
+    // Before the first trade of the day, there's no last trade or quote.
+    assert_eq!(None, snapshots[1].last_trade);
+    assert_eq!(None, snapshots[1].last_quote);
+    assert_eq!(1, server.requests().len());
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_snapshot_of_ticker() -> io::Result<()> {
+    let server = MockServer::start(vec![Route::new(
+        "/v2/snapshot/locale/us/markets/stocks/tickers/AAPL",
+        200,
+        responses::SNAPSHOT_AAPL,
+    )])
+    .await
+    .unwrap();
+    let service = given_service(&server);
+
+    let snapshot = service.get_snapshot(given_deps(), &snp::SnP::AAPL).await?;
+
+    assert_eq!("AAPL", snapshot.ticker.as_ref());
+    assert_eq!(1.952, snapshot.todays_change_perc);
+    assert_eq!(Some(Some(195.8034)), snapshot.day.as_ref().map(|d| d.vwap));
+    Ok(())
+}
+
+#[rstest]
+#[case(401, responses::ERROR_UNAUTHORIZED, "Unknown API Key")]
+#[case(404, responses::ERROR_NOT_FOUND, "Ticker not found")]
+#[case(
+    429,
+    responses::ERROR_TOO_MANY_REQUESTS,
+    "You've exceeded the maximum requests"
+)]
+#[tokio::test]
+async fn get_snapshot_server_error(#[case] status: u16, #[case] body: &str, #[case] message: &str) {
+    let server = MockServer::start(vec![Route::new(
+        "/v2/snapshot/locale/us/markets/stocks/tickers/AAPL",
+        status,
+        body,
+    )])
+    .await
+    .unwrap();
+    let service = given_service(&server);
+
+    let response = service.get_snapshot(given_deps(), &snp::SnP::AAPL).await;
+
+    match response {
+        Err(io::Error::Server(_, m)) => assert!(m.starts_with(message), "message {:?}", m),
+        r => panic!("unexpected response {:?}", r),
+    }
+}
+
+#[tokio::test]
+async fn get_snapshots_malformed_json() {
+    let server = MockServer::start(vec![Route::new(
+        "/v2/snapshot/locale/us/markets/stocks/tickers",
+        200,
+        responses::MALFORMED,
+    )])
+    .await
+    .unwrap();
+    let service = given_service(&server);
+
+    let response = service.get_snapshots(given_deps(), &[snp::SnP::AAPL]).await;
+
+    assert!(
+        matches!(response, Err(io::Error::JsonParse(_))),
+        "{:?}",
+        response
+    );
+}
+
+fn given_service(server: &MockServer) -> Service {
+    let client = reqwest::Client::new();
+    Service::with_host(Arc::new(client), "key", &server.host()).unwrap()
+}
+
+fn given_deps() -> Arc<dyn io::Depends + Send + Sync> {
+    Arc::new(DependsFake {})
+}
+
+struct DependsFake {}
+
+impl io::Depends for DependsFake {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        Arc::new(ServiceFake {})
+    }
+}
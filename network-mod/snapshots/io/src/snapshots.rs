@@ -0,0 +1,94 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use snp_mod_io::snp;
+
+/// A bar of the snapshot, which is all zeros before the first trade of the day.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bar {
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub volume: f64,
+    pub vwap: Option<f64>,
+}
+
+/// The most recent trade of the ticker.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LastTrade {
+    pub price: f64,
+    pub size: f64,
+    pub exchange: Option<i32>,
+    /// The nanosecond Unix timestamp of the trade.
+    pub timestamp: i64,
+}
+
+/// The most recent NBBO quote of the ticker.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LastQuote {
+    pub bid_price: f64,
+    pub bid_size: f64,
+    pub ask_price: f64,
+    pub ask_size: f64,
+    /// The nanosecond Unix timestamp of the quote.
+    pub timestamp: i64,
+}
+
+/// The data that is needed to interact with the Snapshots modules, which is the current-day
+/// state of a ticker.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Data {
+    pub ticker: Arc<str>,
+    /// The change of the last trade from the close of the previous day.
+    pub todays_change: f64,
+    pub todays_change_perc: f64,
+    /// The nanosecond Unix timestamp of the last update of the snapshot.
+    pub updated: i64,
+    pub day: Option<Bar>,
+    pub prev_day: Option<Bar>,
+    pub last_trade: Option<LastTrade>,
+    pub last_quote: Option<LastQuote>,
+}
+
+/// All possible errors of Snapshots modules.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Failed to create an API client; can be invalid 'api_key'.")]
+    ApiKeyIsEmpty,
+
+    #[error("Failed to send a request to the service provider; can be invalid 'url', or 'api_key', or network IO problem: {0}")]
+    Request(#[from] reqwest::Error),
+
+    #[error("Can't process the body text from the response: {0}")]
+    JsonParse(#[from] serde_json::Error),
+
+    #[error("Provider server response error '{1}' for this url '{0}'.")]
+    Server(String, String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The API of Snapshots, which defines all the functionality of Snapshots modules.
+#[async_trait]
+pub trait Api {
+    /// Returns the snapshots of the tickers by one request to the full market snapshot, or of
+    /// the whole market if `tickers` is empty.
+    async fn get_snapshots(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        tickers: &[snp::SnP],
+    ) -> Result<Vec<Data>>;
+
+    /// Returns the snapshot of one ticker.
+    async fn get_snapshot(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        ticker: &snp::SnP,
+    ) -> Result<Data>;
+}
+
+/// All the dependencies of the Snapshots modules to other modules.
+pub trait Depends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync>;
+}
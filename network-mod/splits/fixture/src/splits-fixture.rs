@@ -0,0 +1,24 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use network_mod_splits_io::splits as io;
+use snp_mod_io::snp;
+
+pub struct ServiceFake;
+
+#[async_trait]
+impl io::Api for ServiceFake {
+    async fn get_splits(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        _ticker: &snp::SnP,
+    ) -> io::Result<Vec<io::Data>> {
+        Ok(vec![io::Data {
+            id: "E36416cce743c3964c5da63e1ef1626c0aece30fb47302eea5a49c0055c04e8d0".into(),
+            execution_date: NaiveDate::from_ymd_opt(2020, 8, 31).unwrap(),
+            split_from: 1.0,
+            split_to: 4.0,
+        }])
+    }
+}
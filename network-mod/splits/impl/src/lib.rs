@@ -0,0 +1,5 @@
+#[path = "splits-impl.rs"]
+pub mod splits_impl;
+
+#[cfg(test)]
+mod test;
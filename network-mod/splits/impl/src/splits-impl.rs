@@ -0,0 +1,133 @@
+use std::{collections::HashMap, sync::Arc};
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use reqwest::{Client, StatusCode};
+use serde::Deserialize;
+use snp_mod_io::snp::SnP;
+
+use network_mod_splits_io::splits as io;
+
+/// The result structure of result in Polygon.io API.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct SplitsResult {
+    // The execution date of the stock split. On this date the stock split was applied.
+    execution_date: NaiveDate,
+    // The unique identifier of the split.
+    id: Arc<str>,
+    // The second number in the split ratio. For example: In a 2-for-1 split, split_from would
+    // be 1.
+    split_from: f64,
+    // The first number in the split ratio. For example: In a 2-for-1 split, split_to would
+    // be 2.
+    split_to: f64,
+    // The ticker symbol of the stock split.
+    ticker: Arc<str>,
+}
+
+/// The response structure of Polygon.io API.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct SplitsResponse {
+    next_url: Option<Arc<str>>,
+    request_id: Arc<str>,
+    status: Arc<str>,
+    #[serde(default)]
+    results: Vec<SplitsResult>,
+}
+
+/// The error structure of Polygon.io API.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct SplitsError {
+    status: Arc<str>,
+    request_id: Arc<str>,
+    // Polygon.io names it `error` for authorization and rate limit failures.
+    #[serde(alias = "error")]
+    message: Arc<str>,
+}
+
+/// The service to implement the API of Splits.
+#[derive(Debug)]
+pub struct Service {
+    url: Arc<str>,
+    api_key: Arc<str>,
+    client: Arc<Client>,
+}
+
+impl Service {
+    pub fn new(client: Arc<Client>, api_key: &str) -> io::Result<Self> {
+        Self::with_host(client, api_key, "https://api.polygon.io")
+    }
+
+    /// Creates the service against another host, e.g. a local mock server of Polygon.io.
+    pub fn with_host(client: Arc<Client>, api_key: &str, host: &str) -> io::Result<Self> {
+        if api_key.is_empty() {
+            return Err(io::Error::ApiKeyIsEmpty);
+        }
+
+        Ok(Self {
+            client,
+            api_key: api_key.into(),
+            url: format!("{}/v3/reference/splits", host).into(),
+        })
+    }
+}
+
+#[async_trait]
+impl io::Api for Service {
+    async fn get_splits(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        ticker: &SnP,
+    ) -> io::Result<Vec<io::Data>> {
+        let snp = deps.provide_snp();
+        let snp_symbol_rc = snp.get_symbol(ticker).clone();
+        let snp_symbol = &snp_symbol_rc[..];
+
+        let mut params = HashMap::new();
+        params.insert("apiKey", self.api_key.as_ref());
+        params.insert("ticker", snp_symbol);
+        params.insert("sort", "execution_date");
+        params.insert("order", "asc");
+        params.insert("limit", "1000");
+
+        let client = &self.client;
+        let mut url: String = self.url.to_string();
+        let mut splits = vec![];
+
+        while !url.is_empty() {
+            let response = client.get(&url).query(&params).send().await?;
+            let status_code = response.status();
+
+            let response_body = &response.text().await?;
+
+            if status_code == StatusCode::OK {
+                let splits_data: SplitsResponse = serde_json::from_str(response_body)?;
+
+                splits.extend(splits_data.results.into_iter().map(|s| io::Data {
+                    id: s.id,
+                    execution_date: s.execution_date,
+                    split_from: s.split_from,
+                    split_to: s.split_to,
+                }));
+
+                // The `next_url` keeps the other params in its cursor.
+                params.retain(|k, _| *k == "apiKey");
+                url = match splits_data.next_url {
+                    Some(u) => u.to_string(),
+                    None => "".to_string(),
+                };
+            } else {
+                let error_data: SplitsError = serde_json::from_str(response_body)?;
+
+                return Err(io::Error::Server(
+                    url.to_string(),
+                    error_data.message.to_string(),
+                ));
+            }
+        }
+        Ok(splits)
+    }
+}
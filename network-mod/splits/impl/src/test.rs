@@ -0,0 +1,122 @@
+use std::env;
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use network_mod_mock_server::{
+    mock_server::{MockServer, Route},
+    responses,
+};
+use rstest::rstest;
+use snp_mod_fixture::snp_fixture::ServiceFake;
+use snp_mod_io::snp;
+
+use crate::splits_impl::Service;
+use io::Api;
+use network_mod_splits_io::splits as io;
+
+// It's not quite fit into the definiton of unit test, because it requests through network,
+// but we need it to stay here for future fast check.
+#[ignore]
+#[tokio::test]
+async fn call_aapl_splits() -> io::Result<()> {
+    let api_key = env::var("API_KEY").unwrap();
+    let client = reqwest::Client::new();
+    let service = Service::new(Arc::new(client), &api_key).unwrap();
+
+    let response = service.get_splits(given_deps(), &snp::SnP::AAPL).await;
+    match response {
+        Ok(r) => {
+            dbg!(r);
+        }
+        Err(e) => {
+            dbg!(e);
+        }
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_splits_from_mock_server() -> io::Result<()> {
+    let server = MockServer::start(vec![Route::new(
+        "/v3/reference/splits",
+        200,
+        responses::SPLITS_AAPL,
+    )
+    .with_query("ticker", "AAPL")
+    .with_query("sort", "execution_date")])
+    .await
+    .unwrap();
+    let service = given_service(&server);
+
+    let splits = service.get_splits(given_deps(), &snp::SnP::AAPL).await?;
+
+    assert_eq!(5, splits.len());
+    let split = &splits[4];
+    assert_eq!(
+        NaiveDate::from_ymd_opt(2020, 8, 31).unwrap(),
+        split.execution_date
+    );
+    assert_eq!(1.0, split.split_from);
+    assert_eq!(4.0, split.split_to);
+    assert_eq!(7.0, splits[3].split_to);
+    Ok(())
+}
+
+#[rstest]
+#[case(401, responses::ERROR_UNAUTHORIZED, "Unknown API Key")]
+#[case(
+    429,
+    responses::ERROR_TOO_MANY_REQUESTS,
+    "You've exceeded the maximum requests"
+)]
+#[tokio::test]
+async fn get_splits_server_error(#[case] status: u16, #[case] body: &str, #[case] message: &str) {
+    let server = MockServer::start(vec![Route::new("/v3/reference/splits", status, body)])
+        .await
+        .unwrap();
+    let service = given_service(&server);
+
+    let response = service.get_splits(given_deps(), &snp::SnP::AAPL).await;
+
+    match response {
+        Err(io::Error::Server(_, m)) => assert!(m.starts_with(message), "message {:?}", m),
+        r => panic!("unexpected response {:?}", r),
+    }
+}
+
+#[tokio::test]
+async fn get_splits_malformed_json() {
+    let server = MockServer::start(vec![Route::new(
+        "/v3/reference/splits",
+        200,
+        responses::MALFORMED,
+    )])
+    .await
+    .unwrap();
+    let service = given_service(&server);
+
+    let response = service.get_splits(given_deps(), &snp::SnP::AAPL).await;
+
+    assert!(
+        matches!(response, Err(io::Error::JsonParse(_))),
+        "{:?}",
+        response
+    );
+}
+
+fn given_service(server: &MockServer) -> Service {
+    let client = reqwest::Client::new();
+    Service::with_host(Arc::new(client), "key", &server.host()).unwrap()
+}
+
+fn given_deps() -> Arc<dyn io::Depends + Send + Sync> {
+    Arc::new(DependsFake {})
+}
+
+struct DependsFake {}
+
+impl io::Depends for DependsFake {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        Arc::new(ServiceFake {})
+    }
+}
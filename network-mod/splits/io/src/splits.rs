@@ -0,0 +1,51 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use snp_mod_io::snp;
+
+/// The data that is needed to interact with the Splits modules. A 4-for-1 split has
+/// `split_from` of 1 and `split_to` of 4.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Data {
+    /// The unique identifier of the split in Polygon.io.
+    pub id: Arc<str>,
+    pub execution_date: NaiveDate,
+    pub split_from: f64,
+    pub split_to: f64,
+}
+
+/// All possible errors of Splits modules.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Failed to create an API client; can be invalid 'api_key'.")]
+    ApiKeyIsEmpty,
+
+    #[error("Failed to send a request to the service provider; can be invalid 'url', or 'api_key', or network IO problem: {0}")]
+    Request(#[from] reqwest::Error),
+
+    #[error("Can't process the body text from the response: {0}")]
+    JsonParse(#[from] serde_json::Error),
+
+    #[error("Provider server response error '{1}' for this url '{0}'.")]
+    Server(String, String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The API of Splits, which defines all the functionality of Splits modules.
+#[async_trait]
+pub trait Api {
+    /// Follows all the pages, and returns the whole split history of the ticker in ascending
+    /// order of execution date.
+    async fn get_splits(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        ticker: &snp::SnP,
+    ) -> Result<Vec<Data>>;
+}
+
+/// All the dependencies of the Splits modules to other modules.
+pub trait Depends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync>;
+}
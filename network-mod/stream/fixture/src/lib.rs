@@ -0,0 +1,2 @@
+#[path = "stream-fixture.rs"]
+pub mod stream_fixture;
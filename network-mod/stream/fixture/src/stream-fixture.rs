@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use network_mod_stream_io::stream as io;
+
+pub struct ServiceFake;
+
+#[async_trait]
+impl io::Api for ServiceFake {
+    async fn stream(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        _subscriptions: &[io::Subscription],
+    ) -> io::Result<()> {
+        Ok(())
+    }
+
+    async fn subscribe(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        _subscriptions: &[io::Subscription],
+    ) -> io::Result<()> {
+        Ok(())
+    }
+
+    async fn unsubscribe(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        _subscriptions: &[io::Subscription],
+    ) -> io::Result<()> {
+        Ok(())
+    }
+
+    async fn close(&self) -> io::Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,5 @@
+#[path = "stream-impl.rs"]
+pub mod stream_impl;
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,388 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use crossbeam::channel::{unbounded, Receiver, Sender};
+use futures_util::{SinkExt, StreamExt};
+use serde::Deserialize;
+use tokio::{
+    net::TcpStream,
+    sync::{mpsc, Mutex},
+    time::{self, Instant},
+};
+use tokio_tungstenite::{
+    connect_async,
+    tungstenite::{Error, Message},
+    MaybeTlsStream, WebSocketStream,
+};
+
+use network_mod_aggregates_io::aggregates;
+use network_mod_quotes_io::quotes;
+use network_mod_stream_io::stream as io;
+use network_mod_trades_io::trades;
+
+type Socket = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// The message structure of the stocks stream of Polygon.io, which is sent in arrays.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "ev")]
+enum StreamMessage {
+    #[serde(rename = "status")]
+    Status { status: Arc<str>, message: Arc<str> },
+    #[serde(rename = "T")]
+    Trade(TradeMessage),
+    #[serde(rename = "Q")]
+    Quote(QuoteMessage),
+    #[serde(rename = "AM")]
+    MinuteBar(BarMessage),
+    #[serde(other)]
+    Unknown,
+}
+
+/// A trade of the stream, where the timestamps are in milliseconds.
+#[derive(Debug, Deserialize)]
+struct TradeMessage {
+    sym: Arc<str>,
+    // The trade ID.
+    i: Arc<str>,
+    // The exchange ID.
+    x: i32,
+    p: f64,
+    s: i32,
+    #[serde(default)]
+    c: Vec<i32>,
+    // The SIP timestamp.
+    t: i64,
+    // The sequence number.
+    q: i64,
+    // The tape.
+    z: Option<i32>,
+    trfi: Option<i32>,
+    trft: Option<i64>,
+}
+
+/// A quote of the stream, where the timestamp is in milliseconds.
+#[derive(Debug, Deserialize)]
+struct QuoteMessage {
+    sym: Arc<str>,
+    bx: Option<i32>,
+    bp: Option<f64>,
+    bs: Option<f64>,
+    ax: Option<i32>,
+    ap: Option<f64>,
+    #[serde(rename = "as")]
+    a_s: Option<f64>,
+    // The condition of the quote, which is only one in the stream.
+    c: Option<i32>,
+    t: i64,
+    q: i64,
+    z: Option<i32>,
+}
+
+/// A minute bar of the stream.
+#[derive(Debug, Deserialize)]
+struct BarMessage {
+    sym: Arc<str>,
+    o: f64,
+    h: f64,
+    l: f64,
+    c: f64,
+    v: f64,
+    vw: Option<f64>,
+    // The millisecond Unix timestamp of the start of the bar.
+    s: i64,
+}
+
+/// The action structure of the stocks stream of Polygon.io.
+#[derive(Debug, serde::Serialize)]
+struct Action<'a> {
+    action: &'a str,
+    params: &'a str,
+}
+
+#[derive(Debug)]
+enum Command {
+    Subscribe(Vec<Arc<str>>),
+    Unsubscribe(Vec<Arc<str>>),
+    Close,
+}
+
+/// How a connection ended.
+enum End {
+    Closed,
+    Dropped,
+}
+
+/// The service to implement the API of Stream.
+#[derive(Debug)]
+pub struct Service {
+    url: Arc<str>,
+    api_key: Arc<str>,
+    options: io::Options,
+    commands: mpsc::UnboundedSender<Command>,
+    receiver: Mutex<mpsc::UnboundedReceiver<Command>>,
+    sender: Sender<io::Event>,
+    pub channel: Receiver<io::Event>,
+}
+
+impl Service {
+    pub fn new(api_key: &str) -> io::Result<Self> {
+        Self::with_host(api_key, "wss://socket.polygon.io", io::Options::default())
+    }
+
+    /// Creates the service against another host, e.g. a local mock stream of Polygon.io.
+    pub fn with_host(api_key: &str, host: &str, options: io::Options) -> io::Result<Self> {
+        if api_key.is_empty() {
+            return Err(io::Error::ApiKeyIsEmpty);
+        }
+        let (commands, receiver) = mpsc::unbounded_channel();
+        // Unbounded, so a slow consumer never blocks the connection and misses the heartbeat.
+        let (sender, channel) = unbounded();
+
+        Ok(Self {
+            url: format!("{}/stocks", host).into(),
+            api_key: api_key.into(),
+            options,
+            commands,
+            receiver: Mutex::new(receiver),
+            sender,
+            channel,
+        })
+    }
+
+    async fn connect(&self, params: &[Arc<str>]) -> io::Result<Socket> {
+        let (mut socket, _) = connect_async(self.url.as_ref()).await?;
+        send_action(&mut socket, "auth", &self.api_key).await?;
+        loop {
+            let Ok(Some(message)) = time::timeout(self.options.timeout, socket.next()).await else {
+                return Err(Error::ConnectionClosed.into());
+            };
+            let Message::Text(text) = message? else {
+                continue;
+            };
+            for message in serde_json::from_str::<Vec<StreamMessage>>(&text)? {
+                if let StreamMessage::Status { status, message } = message {
+                    match status.as_ref() {
+                        "auth_success" => {
+                            if !params.is_empty() {
+                                send_action(&mut socket, "subscribe", &params.join(",")).await?;
+                            }
+                            return Ok(socket);
+                        }
+                        "auth_failed" => return Err(io::Error::Auth(message.to_string())),
+                        _ => {}
+                    }
+                }
+            }
+        }
+    }
+
+    async fn serve(
+        &self,
+        socket: &mut Socket,
+        commands: &mut mpsc::UnboundedReceiver<Command>,
+        params: &mut Vec<Arc<str>>,
+        names: &trades::Names,
+    ) -> io::Result<End> {
+        let mut heartbeat = time::interval(self.options.heartbeat);
+        let mut last_seen = Instant::now();
+        loop {
+            tokio::select! {
+                message = socket.next() => {
+                    last_seen = Instant::now();
+                    let text = match message {
+                        Some(Ok(Message::Text(text))) => text,
+                        Some(Ok(Message::Close(_))) | Some(Err(_)) | None => {
+                            return Ok(End::Dropped)
+                        }
+                        Some(Ok(_)) => continue,
+                    };
+                    // The unknown events are parsed, so a frame that fails is a broken stream.
+                    let messages: Vec<StreamMessage> = serde_json::from_str(&text)?;
+                    for message in messages {
+                        if let Some(event) = to_event(message, names) {
+                            self.sender.send(event)?;
+                        }
+                    }
+                }
+                _ = heartbeat.tick() => {
+                    if last_seen.elapsed() > self.options.timeout {
+                        return Ok(End::Dropped);
+                    }
+                    if socket.send(Message::Ping(vec![])).await.is_err() {
+                        return Ok(End::Dropped);
+                    }
+                }
+                command = commands.recv() => {
+                    match command {
+                        Some(Command::Subscribe(new)) => {
+                            let new: Vec<Arc<str>> =
+                                new.into_iter().filter(|p| !params.contains(p)).collect();
+                            if !new.is_empty() {
+                                // Kept first, so a reconnection subscribes even if this fails.
+                                params.extend(new.iter().cloned());
+                                send_action(socket, "subscribe", &new.join(",")).await?;
+                            }
+                        }
+                        Some(Command::Unsubscribe(old)) => {
+                            params.retain(|p| !old.contains(p));
+                            send_action(socket, "unsubscribe", &old.join(",")).await?;
+                        }
+                        Some(Command::Close) | None => {
+                            let _ = socket.close(None).await;
+                            return Ok(End::Closed);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl io::Api for Service {
+    async fn stream(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        subscriptions: &[io::Subscription],
+    ) -> io::Result<()> {
+        let mut commands = self.receiver.lock().await;
+        let names = deps.provide_names();
+        let mut params = to_params(deps, subscriptions);
+        let mut failures = 0;
+
+        loop {
+            let result = match self.connect(&params).await {
+                Ok(mut socket) => {
+                    failures = 0;
+                    self.serve(&mut socket, &mut commands, &mut params, &names)
+                        .await
+                }
+                Err(error) => Err(error),
+            };
+            match result {
+                Ok(End::Closed) => return Ok(()),
+                Ok(End::Dropped) | Err(io::Error::Connection(_)) => {}
+                Err(error) => return Err(error),
+            }
+
+            failures += 1;
+            if failures > self.options.max_reconnects {
+                return Err(io::Error::Disconnected(self.options.max_reconnects));
+            }
+            time::sleep(self.options.reconnect_delay).await;
+        }
+    }
+
+    async fn subscribe(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        subscriptions: &[io::Subscription],
+    ) -> io::Result<()> {
+        let _ = self
+            .commands
+            .send(Command::Subscribe(to_params(deps, subscriptions)));
+        Ok(())
+    }
+
+    async fn unsubscribe(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        subscriptions: &[io::Subscription],
+    ) -> io::Result<()> {
+        let _ = self
+            .commands
+            .send(Command::Unsubscribe(to_params(deps, subscriptions)));
+        Ok(())
+    }
+
+    async fn close(&self) -> io::Result<()> {
+        let _ = self.commands.send(Command::Close);
+        Ok(())
+    }
+}
+
+async fn send_action(socket: &mut Socket, action: &str, params: &str) -> io::Result<()> {
+    let text = serde_json::to_string(&Action { action, params })?;
+    socket.send(Message::Text(text)).await?;
+    Ok(())
+}
+
+/// Returns the params of the subscriptions, like `T.AAPL` or `AM.*`.
+fn to_params(
+    deps: Arc<dyn io::Depends + Send + Sync>,
+    subscriptions: &[io::Subscription],
+) -> Vec<Arc<str>> {
+    let snp = deps.provide_snp();
+    subscriptions
+        .iter()
+        .map(|s| {
+            let ticker = match &s.ticker {
+                Some(ticker) => snp.get_symbol(ticker),
+                None => "*".into(),
+            };
+            format!("{}.{}", s.channel.prefix(), ticker).into()
+        })
+        .collect()
+}
+
+/// The stream sends the timestamps in milliseconds, but the models keep nanoseconds.
+const NANOS_PER_MILLI: i64 = 1_000_000;
+
+fn to_event(message: StreamMessage, names: &trades::Names) -> Option<io::Event> {
+    match message {
+        StreamMessage::Trade(t) => Some(io::Event::Trade {
+            ticker: t.sym,
+            data: trades::Data {
+                id: t.i,
+                price: t.p,
+                size: t.s,
+                participant_timestamp: None,
+                sip_timestamp: t.t * NANOS_PER_MILLI,
+                sequence_number: t.q,
+                exchange: t.x,
+                exchange_name: names.exchanges.get(&t.x).cloned(),
+                condition_names: t
+                    .c
+                    .iter()
+                    .map(|c| names.conditions.get(c).cloned())
+                    .collect(),
+                conditions: t.c,
+                correction: None,
+                tape: t.z,
+                trf_id: t.trfi,
+                trf_name: t.trfi.and_then(|id| names.exchanges.get(&id).cloned()),
+                trf_timestamp: t.trft.map(|t| t * NANOS_PER_MILLI),
+            },
+        }),
+        StreamMessage::Quote(q) => Some(io::Event::Quote {
+            ticker: q.sym,
+            data: quotes::Data {
+                ask_exchange: q.ax,
+                ask_price: q.ap,
+                ask_size: q.a_s,
+                bid_exchange: q.bx,
+                bid_price: q.bp,
+                bid_size: q.bs,
+                conditions: q.c.into_iter().collect(),
+                participant_timestamp: None,
+                sip_timestamp: q.t * NANOS_PER_MILLI,
+                sequence_number: q.q,
+                tape: q.z,
+            },
+        }),
+        StreamMessage::MinuteBar(b) => Some(io::Event::MinuteBar {
+            ticker: b.sym,
+            data: aggregates::Data {
+                open: b.o,
+                high: b.h,
+                low: b.l,
+                close: b.c,
+                volume: b.v,
+                vwap: b.vw,
+                timestamp: b.s,
+                transactions: None,
+            },
+        }),
+        StreamMessage::Status { .. } | StreamMessage::Unknown => None,
+    }
+}
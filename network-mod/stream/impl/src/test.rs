@@ -0,0 +1,334 @@
+use std::{collections::HashMap, sync::Arc, time::Duration};
+
+use crate::stream_impl::Service;
+use network_mod_aggregates_io::aggregates;
+use network_mod_mock_server::{
+    mock_stream::{Action, MockStream},
+    responses,
+};
+use network_mod_quotes_io::quotes;
+use network_mod_stream_io::stream as io;
+use network_mod_trades_io::trades;
+use snp_mod_fixture::snp_fixture::ServiceFake;
+use snp_mod_io::snp;
+use tokio::task::JoinHandle;
+
+use io::Api;
+
+#[tokio::test]
+async fn stream_trades_quotes_and_minute_bars() -> io::Result<()> {
+    let server = given_server().await;
+    let service = given_service(&server.host(), io::Options::default());
+    let subscriptions = vec![
+        subscription(io::Channel::Trades, Some(snp::SnP::AAPL)),
+        subscription(io::Channel::Quotes, Some(snp::SnP::AAPL)),
+        subscription(io::Channel::MinuteBars, None),
+    ];
+    let handle = start(service.clone(), subscriptions);
+
+    let first = next_event(&service).await;
+    let second = next_event(&service).await;
+    let quote = next_event(&service).await;
+    let bar = next_event(&service).await;
+
+    assert_eq!(
+        io::Event::Trade {
+            ticker: "AAPL".into(),
+            data: trades::Data {
+                id: "52983525029461".into(),
+                price: 172.5,
+                size: 100,
+                participant_timestamp: None,
+                sip_timestamp: 1710426600010000000,
+                sequence_number: 1063,
+                exchange: 11,
+                exchange_name: Some("NYSE Arca".into()),
+                conditions: vec![12, 37],
+                condition_names: vec![Some("Form T".into()), Some("Odd Lot Trade".into())],
+                correction: None,
+                tape: Some(3),
+                trf_id: None,
+                trf_name: None,
+                trf_timestamp: None,
+            },
+        },
+        first
+    );
+    let io::Event::Trade { data, .. } = second else {
+        panic!("Not a trade: {:?}", second);
+    };
+    assert_eq!(Some("FINRA NYSE TRF".into()), data.trf_name);
+    assert_eq!(Some(1710426600009000000), data.trf_timestamp);
+    assert_eq!(
+        io::Event::Quote {
+            ticker: "AAPL".into(),
+            data: quotes::Data {
+                ask_exchange: Some(11),
+                ask_price: Some(172.52),
+                ask_size: Some(2.0),
+                bid_exchange: Some(12),
+                bid_price: Some(172.49),
+                bid_size: Some(3.0),
+                conditions: vec![1],
+                participant_timestamp: None,
+                sip_timestamp: 1710426600012000000,
+                sequence_number: 1065,
+                tape: Some(3),
+            },
+        },
+        quote
+    );
+    assert_eq!(
+        io::Event::MinuteBar {
+            ticker: "AAPL".into(),
+            data: aggregates::Data {
+                open: 172.5,
+                high: 172.8,
+                low: 172.43,
+                close: 172.71,
+                volume: 412583.0,
+                vwap: Some(172.6417),
+                timestamp: 1710426600000,
+                transactions: None,
+            },
+        },
+        bar
+    );
+    assert_eq!(
+        vec![
+            action("auth", "key"),
+            action("subscribe", "T.AAPL,Q.AAPL,AM.*")
+        ],
+        server.actions()
+    );
+
+    service.close().await?;
+    handle.await.unwrap()
+}
+
+#[tokio::test]
+async fn subscribe_and_unsubscribe_on_running_stream() -> io::Result<()> {
+    let server = given_server().await;
+    let service = given_service(&server.host(), io::Options::default());
+    let handle = start(service.clone(), vec![]);
+    let trades = vec![subscription(io::Channel::Trades, Some(snp::SnP::AAPL))];
+
+    service.subscribe(given_deps(), &trades).await?;
+    next_event(&service).await;
+    service.unsubscribe(given_deps(), &trades).await?;
+    wait_for(|| server.actions().len() == 3).await;
+    service.close().await?;
+    handle.await.unwrap()?;
+
+    assert_eq!(
+        vec![
+            action("auth", "key"),
+            action("subscribe", "T.AAPL"),
+            action("unsubscribe", "T.AAPL"),
+        ],
+        server.actions()
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn resubscribe_after_reconnection() -> io::Result<()> {
+    let server = given_server().await;
+    let service = given_service(&server.host(), given_fast_options());
+    let handle = start(
+        service.clone(),
+        vec![subscription(io::Channel::Trades, Some(snp::SnP::AAPL))],
+    );
+    next_event(&service).await;
+    let minute_bars = vec![subscription(io::Channel::MinuteBars, None)];
+    service.subscribe(given_deps(), &minute_bars).await?;
+    wait_for(|| server.actions().len() == 3).await;
+
+    server.disconnect();
+    wait_for(|| server.actions().len() == 5).await;
+    service.close().await?;
+    handle.await.unwrap()?;
+
+    assert_eq!(2, server.connections());
+    assert_eq!(
+        vec![
+            action("auth", "key"),
+            action("subscribe", "T.AAPL"),
+            action("subscribe", "AM.*"),
+            action("auth", "key"),
+            action("subscribe", "T.AAPL,AM.*"),
+        ],
+        server.actions()
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn reconnect_after_missed_heartbeats() -> io::Result<()> {
+    let server = given_server().await;
+    let service = given_service(&server.host(), given_fast_options());
+    let handle = start(
+        service.clone(),
+        vec![subscription(io::Channel::Trades, Some(snp::SnP::AAPL))],
+    );
+    next_event(&service).await;
+
+    server.stall();
+    wait_for(|| server.connections() == 2 && server.actions().len() == 4).await;
+    service.close().await?;
+    handle.await.unwrap()?;
+
+    assert_eq!(action("subscribe", "T.AAPL"), server.actions()[3]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn fail_to_authenticate() -> io::Result<()> {
+    let server = given_server().await;
+    let service =
+        Arc::new(Service::with_host("wrong-key", &server.host(), io::Options::default()).unwrap());
+
+    let result = service.stream(given_deps(), &[]).await;
+
+    assert!(
+        matches!(result, Err(io::Error::Auth(ref message)) if message == "authentication failed"),
+        "Unexpected {:?}",
+        result
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn return_the_error_of_a_malformed_frame() -> io::Result<()> {
+    let server = MockStream::start("key", vec![responses::MALFORMED])
+        .await
+        .unwrap();
+    let service = given_service(&server.host(), io::Options::default());
+
+    let result = service
+        .stream(
+            given_deps(),
+            &[subscription(io::Channel::Trades, Some(snp::SnP::AAPL))],
+        )
+        .await;
+
+    assert!(
+        matches!(result, Err(io::Error::JsonParse(_))),
+        "Unexpected {:?}",
+        result
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn give_up_after_max_reconnects() -> io::Result<()> {
+    // Nothing listens to the port of a dropped listener.
+    let address = std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap();
+    let host = format!("ws://{}", address);
+    let service = given_service(&host, given_fast_options());
+
+    let result = service.stream(given_deps(), &[]).await;
+
+    assert!(
+        matches!(result, Err(io::Error::Disconnected(2))),
+        "Unexpected {:?}",
+        result
+    );
+    Ok(())
+}
+
+#[test]
+fn empty_api_key() {
+    let result = Service::with_host("", "ws://localhost", io::Options::default());
+
+    assert!(matches!(result, Err(io::Error::ApiKeyIsEmpty)));
+}
+
+async fn given_server() -> MockStream {
+    MockStream::start(
+        "key",
+        vec![
+            responses::STREAM_TRADES_AAPL,
+            responses::STREAM_QUOTES_AAPL,
+            responses::STREAM_MINUTE_BARS_AAPL,
+        ],
+    )
+    .await
+    .unwrap()
+}
+
+fn given_service(host: &str, options: io::Options) -> Arc<Service> {
+    Arc::new(Service::with_host("key", host, options).unwrap())
+}
+
+fn given_fast_options() -> io::Options {
+    io::Options {
+        heartbeat: Duration::from_millis(50),
+        timeout: Duration::from_millis(200),
+        reconnect_delay: Duration::from_millis(10),
+        max_reconnects: 2,
+    }
+}
+
+fn start(
+    service: Arc<Service>,
+    subscriptions: Vec<io::Subscription>,
+) -> JoinHandle<io::Result<()>> {
+    tokio::spawn(async move { service.stream(given_deps(), &subscriptions).await })
+}
+
+async fn next_event(service: &Service) -> io::Event {
+    tokio::time::timeout(Duration::from_secs(5), async {
+        loop {
+            if let Ok(event) = service.channel.try_recv() {
+                return event;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("No event is streamed")
+}
+
+async fn wait_for(condition: impl Fn() -> bool) {
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while !condition() {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("The condition is never met")
+}
+
+fn subscription(channel: io::Channel, ticker: Option<snp::SnP>) -> io::Subscription {
+    io::Subscription { channel, ticker }
+}
+
+fn action(action: &str, params: &str) -> Action {
+    Action {
+        action: action.into(),
+        params: params.into(),
+    }
+}
+
+fn given_deps() -> Arc<dyn io::Depends + Send + Sync> {
+    Arc::new(DependsFake {})
+}
+
+struct DependsFake {}
+
+impl io::Depends for DependsFake {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        Arc::new(ServiceFake {})
+    }
+
+    fn provide_names(&self) -> Arc<trades::Names> {
+        Arc::new(trades::Names {
+            exchanges: HashMap::from([(11, "NYSE Arca".into()), (201, "FINRA NYSE TRF".into())]),
+            conditions: HashMap::from([(12, "Form T".into()), (37, "Odd Lot Trade".into())]),
+        })
+    }
+}
@@ -0,0 +1,2 @@
+#[path = "stream.rs"]
+pub mod stream;
@@ -0,0 +1,149 @@
+use std::{sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use network_mod_aggregates_io::aggregates;
+use network_mod_quotes_io::quotes;
+use network_mod_trades_io::trades;
+use snp_mod_io::snp;
+
+/// The channels of the stocks stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Channel {
+    Trades,
+    Quotes,
+    MinuteBars,
+}
+
+impl Channel {
+    pub fn prefix(&self) -> &'static str {
+        match self {
+            Channel::Trades => "T",
+            Channel::Quotes => "Q",
+            Channel::MinuteBars => "AM",
+        }
+    }
+}
+
+/// A subscription to a channel, where no ticker means all the tickers, like `T.*`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Subscription {
+    pub channel: Channel,
+    pub ticker: Option<snp::SnP>,
+}
+
+/// An event of the stream in the same models as the REST modules, where the ticker is the symbol
+/// that Polygon.io sends, which is not always a constituent for the subscriptions to all.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+    Trade {
+        ticker: Arc<str>,
+        data: trades::Data,
+    },
+    Quote {
+        ticker: Arc<str>,
+        data: quotes::Data,
+    },
+    MinuteBar {
+        ticker: Arc<str>,
+        data: aggregates::Data,
+    },
+}
+
+/// The timing of the connection to the stream.
+#[derive(Debug, Clone)]
+pub struct Options {
+    /// The interval of the pings.
+    pub heartbeat: Duration,
+    /// Reconnects when nothing is received for this long, not even a pong.
+    pub timeout: Duration,
+    /// The wait before each reconnection.
+    pub reconnect_delay: Duration,
+    /// Gives up after this many failed connections in a row.
+    pub max_reconnects: u32,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            heartbeat: Duration::from_secs(30),
+            timeout: Duration::from_secs(90),
+            reconnect_delay: Duration::from_secs(5),
+            max_reconnects: 10,
+        }
+    }
+}
+
+/// All possible errors of Stream modules.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Failed to create an API client; can be invalid 'api_key'.")]
+    ApiKeyIsEmpty,
+
+    #[error("Failed to connect to the stream; can be invalid 'url', or network IO problem: {0}")]
+    Connection(Box<tokio_tungstenite::tungstenite::Error>),
+
+    #[error("Can't process the message from the stream: {0}")]
+    JsonParse(#[from] serde_json::Error),
+
+    #[error("Can't send the event over channel, which is disconnected.")]
+    Channel,
+
+    #[error("Provider stream failed to authenticate: '{0}'.")]
+    Auth(String),
+
+    #[error("Lost the connection to the stream after {0} reconnections.")]
+    Disconnected(u32),
+}
+
+/// The errors of the connection are large, so they're boxed.
+impl From<tokio_tungstenite::tungstenite::Error> for Error {
+    fn from(error: tokio_tungstenite::tungstenite::Error) -> Self {
+        Error::Connection(Box::new(error))
+    }
+}
+
+/// The event that isn't sent is dropped, since it's as large as the connection errors.
+impl From<crossbeam::channel::SendError<Event>> for Error {
+    fn from(_: crossbeam::channel::SendError<Event>) -> Self {
+        Error::Channel
+    }
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The API of Stream, which defines all the functionality of Stream modules.
+#[async_trait]
+pub trait Api {
+    /// Connects, authenticates and subscribes, then sends the events over the channel of the
+    /// service until `close`. It reconnects and subscribes again whenever the connection drops or
+    /// misses the heartbeat, but returns the error of a message that can't be parsed.
+    async fn stream(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        subscriptions: &[Subscription],
+    ) -> Result<()>;
+
+    /// Adds the subscriptions to the running stream, which are kept after a reconnection.
+    async fn subscribe(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        subscriptions: &[Subscription],
+    ) -> Result<()>;
+
+    /// Removes the subscriptions from the running stream.
+    async fn unsubscribe(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        subscriptions: &[Subscription],
+    ) -> Result<()>;
+
+    /// Stops the running stream, which returns then.
+    async fn close(&self) -> Result<()>;
+}
+
+/// All the dependencies of the Stream modules to other modules.
+pub trait Depends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync>;
+    /// The names of the stored exchanges and conditions to decode the trades.
+    fn provide_names(&self) -> Arc<trades::Names>;
+}
@@ -15,11 +15,17 @@ impl io::Api for ServiceFake {
         _ticker: &snp::SnP,
         _date: &NaiveDate,
     ) -> io::Result<io::Data> {
+        self.parse_ticker_details("{\"status\":\"OK\"}")
+    }
+
+    fn parse_ticker_details(&self, body: &str) -> io::Result<io::Data> {
         Ok(io::Data {
             active: true,
             share_class_shares_outstanding: 453,
             total_employees: 5345,
             weighted_shares_outstanding: 7465,
+            request_id: "31d59dda-80e5-4721-8496-d0d32a654afe".into(),
+            body: body.into(),
         })
     }
 }
@@ -2,6 +2,13 @@ use std::env;
 use std::sync::Arc;
 
 use chrono::NaiveDate;
+use network_mod_cache_impl::cache_impl::Service as CacheService;
+use network_mod_cache_io::cache::{self, Api as _};
+use network_mod_mock_server::{
+    mock_server::{MockServer, Route},
+    responses,
+};
+use rstest::rstest;
 use snp_mod_fixture::snp_fixture::ServiceFake;
 use snp_mod_io::snp;
 
@@ -39,11 +46,186 @@ async fn call_aapl_details() -> io::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn get_aapl_details_from_mock_server() -> io::Result<()> {
+    let server = MockServer::start(vec![Route::new(
+        "/v3/reference/tickers/AAPL",
+        200,
+        responses::TICKER_DETAILS_AAPL,
+    )
+    .with_query("date", "2024-03-14")])
+    .await
+    .unwrap();
+    let service = given_service(&server);
+
+    let response = service
+        .get_ticker_details(given_deps(), &snp::SnP::AAPL, &given_date())
+        .await?;
+
+    assert!(response.active);
+    assert_eq!(15441880000, response.share_class_shares_outstanding);
+    assert_eq!(161000, response.total_employees);
+    assert_eq!(15441881000, response.weighted_shares_outstanding);
+    assert_eq!(
+        "31d59dda-80e5-4721-8496-d0d32a654afe",
+        response.request_id.as_ref()
+    );
+    assert_eq!(responses::TICKER_DETAILS_AAPL, response.body.as_ref());
+    let requests = server.requests();
+    assert_eq!(1, requests.len());
+    assert!(requests[0]
+        .query_pairs()
+        .any(|(k, v)| k == "apiKey" && v == "key"));
+    Ok(())
+}
+
+// The cassette is written by hand in the format of the recorder, not recorded from Polygon.io.
+#[tokio::test]
+async fn replay_synthetic_aapl_details_cassette() -> io::Result<()> {
+    let server = MockServer::cassette(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/cassettes/synthetic-aapl-2024-03-14.json"
+    ))
+    .await
+    .unwrap();
+    let api_key = env::var("API_KEY").unwrap_or_else(|_| "key".to_string());
+    let client = reqwest::Client::new();
+    let service = Service::with_host(Arc::new(client), api_key.into(), &server.host()).unwrap();
+
+    let response = service
+        .get_ticker_details(given_deps(), &snp::SnP::AAPL, &given_date())
+        .await?;
+
+    assert_eq!(15441880000, response.share_class_shares_outstanding);
+    assert_eq!(15441881000, response.weighted_shares_outstanding);
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_cached_details_without_request() -> io::Result<()> {
+    let server = MockServer::start(vec![Route::new(
+        "/v3/reference/tickers/AAPL",
+        200,
+        responses::TICKER_DETAILS_AAPL,
+    )])
+    .await
+    .unwrap();
+    let service = given_service(&server);
+    let directory = env::temp_dir().join(format!("ticker-details-cache-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&directory);
+    let cache = Arc::new(CacheService::new(directory).with_ttl(io::ENDPOINT, cache::Ttl::Forever));
+    let deps: Arc<dyn io::Depends + Send + Sync> = Arc::new(DependsFake { cache: Some(cache) });
+
+    let first = service
+        .get_ticker_details(deps.clone(), &snp::SnP::AAPL, &given_date())
+        .await?;
+    let second = service
+        .get_ticker_details(deps.clone(), &snp::SnP::AAPL, &given_date())
+        .await?;
+
+    assert_eq!(
+        first.weighted_shares_outstanding,
+        second.weighted_shares_outstanding
+    );
+    assert_eq!(1, server.requests().len());
+    Ok(())
+}
+
+#[tokio::test]
+async fn request_again_a_corrupt_cached_details() -> io::Result<()> {
+    let server = MockServer::start(vec![Route::new(
+        "/v3/reference/tickers/AAPL",
+        200,
+        responses::TICKER_DETAILS_AAPL,
+    )])
+    .await
+    .unwrap();
+    let service = given_service(&server);
+    let directory = env::temp_dir().join(format!(
+        "ticker-details-corrupt-cache-{}",
+        std::process::id()
+    ));
+    let _ = std::fs::remove_dir_all(&directory);
+    let cache = Arc::new(CacheService::new(directory).with_ttl(io::ENDPOINT, cache::Ttl::Forever));
+    let key = cache::Key::new(io::ENDPOINT, &[("ticker", "AAPL"), ("date", "2024-03-14")]);
+    cache.store(&key, "{\"results\": {\"active\": tr").await?;
+    let deps: Arc<dyn io::Depends + Send + Sync> = Arc::new(DependsFake { cache: Some(cache) });
+
+    let response = service
+        .get_ticker_details(deps.clone(), &snp::SnP::AAPL, &given_date())
+        .await?;
+    service
+        .get_ticker_details(deps.clone(), &snp::SnP::AAPL, &given_date())
+        .await?;
+
+    assert_eq!(15441881000, response.weighted_shares_outstanding);
+    assert_eq!(1, server.requests().len());
+    Ok(())
+}
+
+#[rstest]
+#[case(401, responses::ERROR_UNAUTHORIZED, "Unknown API Key")]
+#[case(404, responses::ERROR_NOT_FOUND, "Ticker not found.")]
+#[case(
+    429,
+    responses::ERROR_TOO_MANY_REQUESTS,
+    "You've exceeded the maximum requests"
+)]
+#[tokio::test]
+async fn get_details_server_error(#[case] status: u16, #[case] body: &str, #[case] message: &str) {
+    let server = MockServer::start(vec![Route::new("/v3/reference/tickers/AAPL", status, body)])
+        .await
+        .unwrap();
+    let service = given_service(&server);
+
+    let response = service
+        .get_ticker_details(given_deps(), &snp::SnP::AAPL, &given_date())
+        .await;
+
+    match response {
+        Err(io::Error::Server(m)) => assert!(m.starts_with(message), "message {:?}", m),
+        r => panic!("unexpected response {:?}", r),
+    }
+}
+
+#[tokio::test]
+async fn get_details_malformed_json() {
+    let server = MockServer::start(vec![Route::new(
+        "/v3/reference/tickers/AAPL",
+        200,
+        responses::MALFORMED,
+    )])
+    .await
+    .unwrap();
+    let service = given_service(&server);
+
+    let response = service
+        .get_ticker_details(given_deps(), &snp::SnP::AAPL, &given_date())
+        .await;
+
+    assert!(
+        matches!(response, Err(io::Error::JsonParse(_))),
+        "{:?}",
+        response
+    );
+}
+
+fn given_service(server: &MockServer) -> Service {
+    let client = reqwest::Client::new();
+    Service::with_host(Arc::new(client), "key".into(), &server.host()).unwrap()
+}
+
+fn given_date() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2024, 3, 14).unwrap()
+}
+
 fn given_deps() -> Arc<dyn io::Depends + Send + Sync> {
-    Arc::new(DependsFake {})
+    Arc::new(DependsFake { cache: None })
 }
 
-struct DependsFake {}
+struct DependsFake {
+    cache: Option<Arc<dyn cache::Api + Send + Sync>>,
+}
 
 impl DependsFake {}
 
@@ -51,4 +233,8 @@ impl io::Depends for DependsFake {
     fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
         Arc::new(ServiceFake {})
     }
+
+    fn provide_cache(&self) -> Option<Arc<dyn cache::Api + Send + Sync>> {
+        self.cache.clone()
+    }
 }
@@ -2,6 +2,7 @@ use std::{collections::HashMap, sync::Arc};
 
 use async_trait::async_trait;
 use chrono::NaiveDate;
+use network_mod_cache_io::cache;
 use reqwest::{Client, StatusCode};
 use serde::Deserialize;
 use snp_mod_io::snp::SnP;
@@ -87,6 +88,8 @@ pub struct TickerDetailsResponse {
 pub struct TickerDetailsError {
     status: Arc<str>,
     request_id: Arc<str>,
+    // Polygon.io names it `error` for authorization and rate limit failures.
+    #[serde(alias = "error")]
     message: Arc<str>,
 }
 
@@ -100,6 +103,11 @@ pub struct Service {
 
 impl Service {
     pub fn new(client: Arc<Client>, api_key: Arc<str>) -> io::Result<Self> {
+        Self::with_host(client, api_key, "https://api.polygon.io")
+    }
+
+    /// Creates the service against another host, e.g. a local mock server of Polygon.io.
+    pub fn with_host(client: Arc<Client>, api_key: Arc<str>, host: &str) -> io::Result<Self> {
         if api_key.is_empty() {
             return Err(io::Error::ApiKeyIsEmpty);
         }
@@ -107,7 +115,7 @@ impl Service {
         Ok(Self {
             client,
             api_key,
-            url: "https://api.polygon.io/v3/reference/tickers/{TICKER}".into(),
+            url: format!("{}{}", host, io::ENDPOINT).into(),
         })
     }
 }
@@ -131,6 +139,21 @@ impl io::Api for Service {
         let snp_symbol_rc = snp.get_symbol(ticker).clone();
         let snp_symbol = &snp_symbol_rc[..];
 
+        let cache = deps.provide_cache();
+        let cache_key = cache::Key::new(
+            io::ENDPOINT,
+            &[("ticker", snp_symbol), ("date", &formatted_date[..])],
+        );
+        if let Some(cache) = &cache {
+            if let Some(body) = cache.load(&cache_key).await? {
+                // A corrupt or truncated body would fail forever, so it's requested again.
+                match parse_ticker_details(&body) {
+                    Ok(data) => return Ok(data),
+                    Err(_) => cache.remove(&cache_key).await?,
+                }
+            }
+        }
+
         let client = &self.client;
         let url = &self.url.to_string().replace("{TICKER}", snp_symbol);
         dbg!(&url);
@@ -144,20 +167,34 @@ impl io::Api for Service {
         dbg!(&response_body);
 
         if status_code == StatusCode::OK {
-            let ticker_data: TickerDetailsResponse = serde_json::from_str(response_body)?;
-
-            let result = ticker_data.results;
-
-            Ok(io::Data {
-                active: result.active,
-                share_class_shares_outstanding: result.share_class_shares_outstanding,
-                total_employees: result.total_employees,
-                weighted_shares_outstanding: result.weighted_shares_outstanding,
-            })
+            let data = parse_ticker_details(response_body)?;
+            if let Some(cache) = &cache {
+                cache.store(&cache_key, response_body).await?;
+            }
+            Ok(data)
         } else {
             let error_data: TickerDetailsError = serde_json::from_str(response_body)?;
 
-            Err(io::Error::Server(error_data.message.to_string()).into())
+            Err(io::Error::Server(error_data.message.to_string()))
         }
     }
+
+    fn parse_ticker_details(&self, body: &str) -> io::Result<io::Data> {
+        parse_ticker_details(body)
+    }
+}
+
+fn parse_ticker_details(body: &str) -> io::Result<io::Data> {
+    let ticker_data: TickerDetailsResponse = serde_json::from_str(body)?;
+
+    let result = ticker_data.results;
+
+    Ok(io::Data {
+        active: result.active,
+        share_class_shares_outstanding: result.share_class_shares_outstanding,
+        total_employees: result.total_employees,
+        weighted_shares_outstanding: result.weighted_shares_outstanding,
+        request_id: ticker_data.request_id,
+        body: body.into(),
+    })
 }
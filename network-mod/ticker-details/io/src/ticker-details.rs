@@ -2,8 +2,12 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 use chrono::NaiveDate;
+use network_mod_cache_io::cache;
 use snp_mod_io::snp;
 
+/// The endpoint of Polygon.io API, which is also the key of its cache rules.
+pub const ENDPOINT: &str = "/v3/reference/tickers/{TICKER}";
+
 /// The data that is needed to interact with the Ticker-details modules.
 #[derive(Debug)]
 pub struct Data {
@@ -11,6 +15,9 @@ pub struct Data {
     pub share_class_shares_outstanding: i64,
     pub total_employees: i32,
     pub weighted_shares_outstanding: i64,
+    pub request_id: Arc<str>,
+    /// The untouched JSON body of the response, to archive it for reprocessing.
+    pub body: Arc<str>,
 }
 
 /// All possible errors of Ticker-details modules.
@@ -27,6 +34,9 @@ pub enum Error {
 
     #[error("Provider server response error '{0}'.")]
     Server(String),
+
+    #[error("{0}")]
+    Cache(#[from] cache::Error),
 }
 
 pub type Result<T> = std::result::Result<T, Error>;
@@ -40,10 +50,14 @@ pub trait Api {
         ticker: &snp::SnP,
         date: &NaiveDate,
     ) -> Result<Data>;
+
+    /// Parses a body of a successful response, that is archived before.
+    fn parse_ticker_details(&self, body: &str) -> Result<Data>;
 }
 
 /// All the dependencies of the Ticker-details modules to other modules.
 pub trait Depends {
     fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync>;
+    /// The responses are cached only if there's a cache.
+    fn provide_cache(&self) -> Option<Arc<dyn cache::Api + Send + Sync>>;
 }
-
@@ -0,0 +1,2 @@
+#[path = "ticker-events-fixture.rs"]
+pub mod ticker_events_fixture;
@@ -0,0 +1,32 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use network_mod_ticker_events_io::ticker_events as io;
+
+pub struct ServiceFake;
+
+#[async_trait]
+impl io::Api for ServiceFake {
+    async fn get_ticker_events(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        _id: &str,
+    ) -> io::Result<io::Data> {
+        Ok(io::Data {
+            name: "Meta Platforms, Inc. Class A Common Stock".into(),
+            composite_figi: "BBG000MM2P62".into(),
+            cik: Some("0001326801".into()),
+            ticker_changes: vec![
+                io::TickerChange {
+                    date: NaiveDate::from_ymd_opt(2012, 5, 18).unwrap(),
+                    ticker: "FB".into(),
+                },
+                io::TickerChange {
+                    date: NaiveDate::from_ymd_opt(2022, 6, 9).unwrap(),
+                    ticker: "META".into(),
+                },
+            ],
+        })
+    }
+}
@@ -0,0 +1,155 @@
+use std::env;
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use network_mod_mock_server::{
+    mock_server::{MockServer, Route},
+    responses,
+};
+use rstest::rstest;
+use snp_mod_fixture::snp_fixture::ServiceFake;
+use snp_mod_io::snp;
+
+use crate::ticker_events_impl::Service;
+use io::Api;
+use network_mod_ticker_events_io::ticker_events as io;
+
+// It's not quite fit into the definiton of unit test, because it requests through network,
+// but we need it to stay here for future fast check.
+#[ignore]
+#[tokio::test]
+async fn call_meta_ticker_events() -> io::Result<()> {
+    let api_key = env::var("API_KEY").unwrap();
+    let client = reqwest::Client::new();
+    let service = Service::new(Arc::new(client), &api_key).unwrap();
+
+    let response = service.get_ticker_events(given_deps(), "META").await;
+    match response {
+        Ok(r) => {
+            dbg!(r);
+        }
+        Err(e) => {
+            dbg!(e);
+        }
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_ticker_events_from_mock_server() -> io::Result<()> {
+    let server = MockServer::start(vec![Route::new(
+        "/vX/reference/tickers/META/events",
+        200,
+        responses::TICKER_EVENTS_META,
+    )
+    .with_query("types", "ticker_change")])
+    .await
+    .unwrap();
+    let service = given_service(&server);
+
+    let events = service.get_ticker_events(given_deps(), "META").await?;
+
+    assert_eq!("BBG000MM2P62", events.composite_figi.as_ref());
+    assert_eq!(Some("0001326801"), events.cik.as_deref());
+    assert_eq!(
+        vec![
+            io::TickerChange {
+                date: NaiveDate::from_ymd_opt(2012, 5, 18).unwrap(),
+                ticker: "FB".into(),
+            },
+            io::TickerChange {
+                date: NaiveDate::from_ymd_opt(2022, 6, 9).unwrap(),
+                ticker: "META".into(),
+            },
+        ],
+        events.ticker_changes
+    );
+    Ok(())
+}
+
+#[rstest]
+#[case(401, responses::ERROR_UNAUTHORIZED, "Unknown API Key")]
+#[case(
+    429,
+    responses::ERROR_TOO_MANY_REQUESTS,
+    "You've exceeded the maximum requests"
+)]
+#[tokio::test]
+async fn get_ticker_events_server_error(
+    #[case] status: u16,
+    #[case] body: &str,
+    #[case] message: &str,
+) {
+    let server = MockServer::start(vec![Route::new(
+        "/vX/reference/tickers/META/events",
+        status,
+        body,
+    )])
+    .await
+    .unwrap();
+    let service = given_service(&server);
+
+    let response = service.get_ticker_events(given_deps(), "META").await;
+
+    match response {
+        Err(io::Error::Server(_, m)) => assert!(m.starts_with(message), "message {:?}", m),
+        r => panic!("unexpected response {:?}", r),
+    }
+}
+
+#[tokio::test]
+async fn get_ticker_events_not_found() {
+    let server = MockServer::start(vec![Route::new(
+        "/vX/reference/tickers/META/events",
+        404,
+        responses::ERROR_NOT_FOUND,
+    )])
+    .await
+    .unwrap();
+    let service = given_service(&server);
+
+    let response = service.get_ticker_events(given_deps(), "META").await;
+
+    assert!(
+        matches!(response, Err(io::Error::NotFound(_))),
+        "{:?}",
+        response
+    );
+}
+
+#[tokio::test]
+async fn get_ticker_events_malformed_json() {
+    let server = MockServer::start(vec![Route::new(
+        "/vX/reference/tickers/META/events",
+        200,
+        responses::MALFORMED,
+    )])
+    .await
+    .unwrap();
+    let service = given_service(&server);
+
+    let response = service.get_ticker_events(given_deps(), "META").await;
+
+    assert!(
+        matches!(response, Err(io::Error::JsonParse(_))),
+        "{:?}",
+        response
+    );
+}
+
+fn given_service(server: &MockServer) -> Service {
+    let client = reqwest::Client::new();
+    Service::with_host(Arc::new(client), "key", &server.host()).unwrap()
+}
+
+fn given_deps() -> Arc<dyn io::Depends + Send + Sync> {
+    Arc::new(DependsFake {})
+}
+
+struct DependsFake {}
+
+impl io::Depends for DependsFake {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        Arc::new(ServiceFake {})
+    }
+}
@@ -0,0 +1,137 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use reqwest::{Client, StatusCode};
+use serde::Deserialize;
+
+use network_mod_ticker_events_io::ticker_events as io;
+
+/// The ticker change structure of an event in Polygon.io API.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct TickerChangeEvent {
+    ticker: Arc<str>,
+}
+
+/// The event structure of result in Polygon.io API.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct TickerEvent {
+    // The date of the event.
+    date: NaiveDate,
+    // The type of the event, where only `ticker_change` exists for now.
+    #[serde(rename = "type")]
+    event_type: Arc<str>,
+    ticker_change: Option<TickerChangeEvent>,
+}
+
+/// The result structure of result in Polygon.io API.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct TickerEventsResult {
+    name: Arc<str>,
+    composite_figi: Arc<str>,
+    cik: Option<Arc<str>>,
+    #[serde(default)]
+    events: Vec<TickerEvent>,
+}
+
+/// The response structure of Polygon.io API.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct TickerEventsResponse {
+    request_id: Arc<str>,
+    status: Arc<str>,
+    results: TickerEventsResult,
+}
+
+/// The error structure of Polygon.io API.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct TickerEventsError {
+    status: Arc<str>,
+    request_id: Arc<str>,
+    // Polygon.io names it `error` for authorization and rate limit failures.
+    #[serde(alias = "error")]
+    message: Arc<str>,
+}
+
+/// The service to implement the API of Ticker-events.
+#[derive(Debug)]
+pub struct Service {
+    url: Arc<str>,
+    api_key: Arc<str>,
+    client: Arc<Client>,
+}
+
+impl Service {
+    pub fn new(client: Arc<Client>, api_key: &str) -> io::Result<Self> {
+        Self::with_host(client, api_key, "https://api.polygon.io")
+    }
+
+    /// Creates the service against another host, e.g. a local mock server of Polygon.io.
+    pub fn with_host(client: Arc<Client>, api_key: &str, host: &str) -> io::Result<Self> {
+        if api_key.is_empty() {
+            return Err(io::Error::ApiKeyIsEmpty);
+        }
+
+        Ok(Self {
+            client,
+            api_key: api_key.into(),
+            url: format!("{}/vX/reference/tickers/{{ID}}/events", host).into(),
+        })
+    }
+}
+
+#[async_trait]
+impl io::Api for Service {
+    async fn get_ticker_events(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        id: &str,
+    ) -> io::Result<io::Data> {
+        let params = [
+            ("apiKey", self.api_key.as_ref()),
+            ("types", "ticker_change"),
+        ];
+        let url = self.url.replace("{ID}", id);
+
+        let response = self.client.get(&url).query(&params).send().await?;
+        let status_code = response.status();
+
+        let response_body = &response.text().await?;
+
+        if status_code == StatusCode::NOT_FOUND {
+            return Err(io::Error::NotFound(url));
+        }
+        if status_code == StatusCode::OK {
+            let events_data: TickerEventsResponse = serde_json::from_str(response_body)?;
+            let result = events_data.results;
+
+            let mut ticker_changes: Vec<io::TickerChange> = result
+                .events
+                .into_iter()
+                .filter_map(|e| {
+                    Some(io::TickerChange {
+                        date: e.date,
+                        ticker: e.ticker_change?.ticker,
+                    })
+                })
+                .collect();
+            // Polygon.io sends the latest event first.
+            ticker_changes.sort_by_key(|c| c.date);
+
+            Ok(io::Data {
+                name: result.name,
+                composite_figi: result.composite_figi,
+                cik: result.cik,
+                ticker_changes,
+            })
+        } else {
+            let error_data: TickerEventsError = serde_json::from_str(response_body)?;
+
+            Err(io::Error::Server(url, error_data.message.to_string()))
+        }
+    }
+}
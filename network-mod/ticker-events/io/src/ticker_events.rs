@@ -0,0 +1,61 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use snp_mod_io::snp;
+
+/// The change of the symbol of a ticker, which is effective from the date.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TickerChange {
+    pub date: NaiveDate,
+    pub ticker: Arc<str>,
+}
+
+/// The data that is needed to interact with the Ticker-events modules.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Data {
+    pub name: Arc<str>,
+    pub composite_figi: Arc<str>,
+    pub cik: Option<Arc<str>>,
+    /// In ascending order of date, where the first one is the symbol of the listing.
+    pub ticker_changes: Vec<TickerChange>,
+}
+
+/// All possible errors of Ticker-events modules.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Failed to create an API client; can be invalid 'api_key'.")]
+    ApiKeyIsEmpty,
+
+    #[error("Failed to send a request to the service provider; can be invalid 'url', or 'api_key', or network IO problem: {0}")]
+    Request(#[from] reqwest::Error),
+
+    #[error("Can't process the body text from the response: {0}")]
+    JsonParse(#[from] serde_json::Error),
+
+    #[error("Provider server response error '{1}' for this url '{0}'.")]
+    Server(String, String),
+
+    /// Polygon.io doesn't know the ticker, like some of the delisted ones.
+    #[error("Ticker not found for this url '{0}'.")]
+    NotFound(String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The API of Ticker-events, which defines all the functionality of Ticker-events modules.
+#[async_trait]
+pub trait Api {
+    /// Returns the symbol changes of the ticker, where the `id` is a ticker, a CUSIP or a
+    /// composite FIGI.
+    async fn get_ticker_events(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        id: &str,
+    ) -> Result<Data>;
+}
+
+/// All the dependencies of the Ticker-events modules to other modules.
+pub trait Depends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync>;
+}
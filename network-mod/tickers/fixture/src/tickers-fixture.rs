@@ -0,0 +1,32 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{TimeZone, Utc};
+use network_mod_tickers_io::tickers as io;
+
+pub struct ServiceFake;
+
+#[async_trait]
+impl io::Api for ServiceFake {
+    async fn get_tickers(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        _request: &io::Request,
+    ) -> io::Result<Vec<io::Data>> {
+        Ok(vec![io::Data {
+            ticker: "AAPL".into(),
+            name: "Apple Inc.".into(),
+            market: "stocks".into(),
+            locale: "us".into(),
+            primary_exchange: Some("XNAS".into()),
+            ticker_type: Some("CS".into()),
+            active: true,
+            currency_name: Some("usd".into()),
+            cik: Some("0000320193".into()),
+            composite_figi: Some("BBG000B9XRY4".into()),
+            share_class_figi: Some("BBG001S5N8V8".into()),
+            last_updated_utc: Some(Utc.with_ymd_and_hms(2024, 6, 11, 0, 0, 0).unwrap()),
+            delisted_utc: None,
+        }])
+    }
+}
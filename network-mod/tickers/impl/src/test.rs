@@ -0,0 +1,148 @@
+use std::env;
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use network_mod_mock_server::{
+    mock_server::{MockServer, Route},
+    responses,
+};
+use rstest::rstest;
+use snp_mod_fixture::snp_fixture::ServiceFake;
+use snp_mod_io::snp;
+
+use crate::tickers_impl::Service;
+use io::Api;
+use network_mod_tickers_io::tickers as io;
+
+// It's not quite fit into the definiton of unit test, because it requests through network,
+// but we need it to stay here for future fast check.
+#[ignore]
+#[tokio::test]
+async fn call_nasdaq_tickers() -> io::Result<()> {
+    let api_key = env::var("API_KEY").unwrap();
+    let client = reqwest::Client::new();
+    let service = Service::new(Arc::new(client), &api_key).unwrap();
+
+    let response = service.get_tickers(given_deps(), &given_request()).await;
+    match response {
+        Ok(r) => {
+            dbg!(r);
+        }
+        Err(e) => {
+            dbg!(e);
+        }
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_paginated_tickers_from_mock_server() -> io::Result<()> {
+    let server = MockServer::start(vec![
+        Route::new(
+            "/v3/reference/tickers",
+            200,
+            responses::TICKERS_NASDAQ_CS_PAGE_2,
+        )
+        .with_query(
+            "cursor",
+            "YWN0aXZlPXRydWUmYXA9JTdCJTIydiUyMiUzQTElMkMlMjJvJTIyJTNBMCUyQyUyMmwlMjIlM0ElMjJBQVBMJTIyJTdEJmFzPSZleGNoYW5nZT1YTkFTJmxpbWl0PTImbWFya2V0PXN0b2NrcyZvcmRlcj1hc2Mmc29ydD10aWNrZXImdHlwZT1DUw",
+        ),
+        Route::new(
+            "/v3/reference/tickers",
+            200,
+            responses::TICKERS_NASDAQ_CS_PAGE_1,
+        )
+        .with_query("market", "stocks")
+        .with_query("type", "CS")
+        .with_query("exchange", "XNAS")
+        .with_query("active", "true")
+        .with_query("date", "2024-06-11"),
+    ])
+    .await
+    .unwrap();
+    let service = given_service(&server);
+
+    let tickers = service.get_tickers(given_deps(), &given_request()).await?;
+
+    assert_eq!(3, tickers.len());
+    let ticker = &tickers[1];
+    assert_eq!("AAPL", ticker.ticker.as_ref());
+    assert_eq!(Some("XNAS"), ticker.primary_exchange.as_deref());
+    assert_eq!(Some("CS"), ticker.ticker_type.as_deref());
+    assert_eq!(Some("0000320193"), ticker.cik.as_deref());
+    assert!(ticker.active);
+    // Polygon.io leaves the identifiers out of some tickers.
+    assert_eq!(None, tickers[2].cik);
+    assert_eq!(None, tickers[2].composite_figi);
+    assert_eq!(2, server.requests().len());
+    Ok(())
+}
+
+#[rstest]
+#[case(401, responses::ERROR_UNAUTHORIZED, "Unknown API Key")]
+#[case(
+    429,
+    responses::ERROR_TOO_MANY_REQUESTS,
+    "You've exceeded the maximum requests"
+)]
+#[tokio::test]
+async fn get_tickers_server_error(#[case] status: u16, #[case] body: &str, #[case] message: &str) {
+    let server = MockServer::start(vec![Route::new("/v3/reference/tickers", status, body)])
+        .await
+        .unwrap();
+    let service = given_service(&server);
+
+    let response = service.get_tickers(given_deps(), &given_request()).await;
+
+    match response {
+        Err(io::Error::Server(_, m)) => assert!(m.starts_with(message), "message {:?}", m),
+        r => panic!("unexpected response {:?}", r),
+    }
+}
+
+#[tokio::test]
+async fn get_tickers_malformed_json() {
+    let server = MockServer::start(vec![Route::new(
+        "/v3/reference/tickers",
+        200,
+        responses::MALFORMED,
+    )])
+    .await
+    .unwrap();
+    let service = given_service(&server);
+
+    let response = service.get_tickers(given_deps(), &given_request()).await;
+
+    assert!(
+        matches!(response, Err(io::Error::JsonParse(_))),
+        "{:?}",
+        response
+    );
+}
+
+fn given_service(server: &MockServer) -> Service {
+    let client = reqwest::Client::new();
+    Service::with_host(Arc::new(client), "key", &server.host()).unwrap()
+}
+
+fn given_request() -> io::Request {
+    io::Request {
+        market: Some("stocks".into()),
+        ticker_type: Some("CS".into()),
+        exchange: Some("XNAS".into()),
+        active: Some(true),
+        date: NaiveDate::from_ymd_opt(2024, 6, 11),
+    }
+}
+
+fn given_deps() -> Arc<dyn io::Depends + Send + Sync> {
+    Arc::new(DependsFake {})
+}
+
+struct DependsFake {}
+
+impl io::Depends for DependsFake {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
+        Arc::new(ServiceFake {})
+    }
+}
@@ -0,0 +1,169 @@
+use std::{collections::HashMap, sync::Arc};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use reqwest::{Client, StatusCode};
+use serde::Deserialize;
+
+use network_mod_tickers_io::tickers as io;
+
+/// The result structure of result in Polygon.io API.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct TickersResult {
+    // Whether or not the asset is actively traded.
+    active: bool,
+    // The CIK number for this ticker.
+    cik: Option<Arc<str>>,
+    // The composite OpenFIGI number for this ticker.
+    composite_figi: Option<Arc<str>>,
+    // The name of the currency that this asset is traded with.
+    currency_name: Option<Arc<str>>,
+    // The last date that the asset was traded.
+    delisted_utc: Option<DateTime<Utc>>,
+    // The information is accurate up to this time.
+    last_updated_utc: Option<DateTime<Utc>>,
+    // The locale of the asset.
+    locale: Arc<str>,
+    // The market type of the asset.
+    market: Arc<str>,
+    // The name of the asset.
+    name: Arc<str>,
+    // The ISO code of the primary listing exchange for this asset.
+    primary_exchange: Option<Arc<str>>,
+    // The share Class OpenFIGI number for this ticker.
+    share_class_figi: Option<Arc<str>>,
+    // The exchange symbol that this item is traded under.
+    ticker: Arc<str>,
+    // The type of the asset.
+    #[serde(rename = "type")]
+    ticker_type: Option<Arc<str>>,
+}
+
+/// The response structure of Polygon.io API.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct TickersResponse {
+    count: Option<i64>,
+    next_url: Option<Arc<str>>,
+    request_id: Arc<str>,
+    status: Arc<str>,
+    #[serde(default)]
+    results: Vec<TickersResult>,
+}
+
+/// The error structure of Polygon.io API.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct TickersError {
+    status: Arc<str>,
+    request_id: Arc<str>,
+    // Polygon.io names it `error` for authorization and rate limit failures.
+    #[serde(alias = "error")]
+    message: Arc<str>,
+}
+
+/// The service to implement the API of Tickers.
+#[derive(Debug)]
+pub struct Service {
+    url: Arc<str>,
+    api_key: Arc<str>,
+    client: Arc<Client>,
+}
+
+impl Service {
+    pub fn new(client: Arc<Client>, api_key: &str) -> io::Result<Self> {
+        Self::with_host(client, api_key, "https://api.polygon.io")
+    }
+
+    /// Creates the service against another host, e.g. a local mock server of Polygon.io.
+    pub fn with_host(client: Arc<Client>, api_key: &str, host: &str) -> io::Result<Self> {
+        if api_key.is_empty() {
+            return Err(io::Error::ApiKeyIsEmpty);
+        }
+
+        Ok(Self {
+            client,
+            api_key: api_key.into(),
+            url: format!("{}/v3/reference/tickers", host).into(),
+        })
+    }
+}
+
+#[async_trait]
+impl io::Api for Service {
+    async fn get_tickers(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        request: &io::Request,
+    ) -> io::Result<Vec<io::Data>> {
+        let date = request.date.map(|d| d.format("%Y-%m-%d").to_string());
+
+        let mut params = HashMap::new();
+        params.insert("apiKey", self.api_key.as_ref());
+        if let Some(market) = &request.market {
+            params.insert("market", market.as_ref());
+        }
+        if let Some(ticker_type) = &request.ticker_type {
+            params.insert("type", ticker_type.as_ref());
+        }
+        if let Some(exchange) = &request.exchange {
+            params.insert("exchange", exchange.as_ref());
+        }
+        if let Some(active) = request.active {
+            params.insert("active", if active { "true" } else { "false" });
+        }
+        if let Some(date) = &date {
+            params.insert("date", date.as_ref());
+        }
+        params.insert("sort", "ticker");
+        params.insert("order", "asc");
+        params.insert("limit", "1000");
+
+        let client = &self.client;
+        let mut url: String = self.url.to_string();
+        let mut tickers = vec![];
+
+        while !url.is_empty() {
+            let response = client.get(&url).query(&params).send().await?;
+            let status_code = response.status();
+
+            let response_body = &response.text().await?;
+
+            if status_code == StatusCode::OK {
+                let tickers_data: TickersResponse = serde_json::from_str(response_body)?;
+
+                tickers.extend(tickers_data.results.into_iter().map(|t| io::Data {
+                    ticker: t.ticker,
+                    name: t.name,
+                    market: t.market,
+                    locale: t.locale,
+                    primary_exchange: t.primary_exchange,
+                    ticker_type: t.ticker_type,
+                    active: t.active,
+                    currency_name: t.currency_name,
+                    cik: t.cik,
+                    composite_figi: t.composite_figi,
+                    share_class_figi: t.share_class_figi,
+                    last_updated_utc: t.last_updated_utc,
+                    delisted_utc: t.delisted_utc,
+                }));
+
+                // The `next_url` keeps the other params in its cursor.
+                params.retain(|k, _| *k == "apiKey");
+                url = match tickers_data.next_url {
+                    Some(u) => u.to_string(),
+                    None => "".to_string(),
+                };
+            } else {
+                let error_data: TickersError = serde_json::from_str(response_body)?;
+
+                return Err(io::Error::Server(
+                    url.to_string(),
+                    error_data.message.to_string(),
+                ));
+            }
+        }
+        Ok(tickers)
+    }
+}
@@ -0,0 +1,72 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use snp_mod_io::snp;
+
+/// The filters of the tickers to list, where `None` means no filter.
+#[derive(Debug, Clone, Default)]
+pub struct Request {
+    /// One of `stocks`, `crypto`, `fx`, `otc` or `indices`.
+    pub market: Option<Arc<str>>,
+    /// The type of the tickers, like `CS` for common stocks, or `ETF`.
+    pub ticker_type: Option<Arc<str>>,
+    /// The MIC of the primary exchange, like `XNAS`.
+    pub exchange: Option<Arc<str>>,
+    pub active: Option<bool>,
+    /// Lists the tickers that were available on the date, instead of today.
+    pub date: Option<NaiveDate>,
+}
+
+/// The data that is needed to interact with the Tickers modules.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Data {
+    pub ticker: Arc<str>,
+    pub name: Arc<str>,
+    pub market: Arc<str>,
+    pub locale: Arc<str>,
+    pub primary_exchange: Option<Arc<str>>,
+    pub ticker_type: Option<Arc<str>>,
+    pub active: bool,
+    pub currency_name: Option<Arc<str>>,
+    pub cik: Option<Arc<str>>,
+    pub composite_figi: Option<Arc<str>>,
+    pub share_class_figi: Option<Arc<str>>,
+    pub last_updated_utc: Option<DateTime<Utc>>,
+    pub delisted_utc: Option<DateTime<Utc>>,
+}
+
+/// All possible errors of Tickers modules.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Failed to create an API client; can be invalid 'api_key'.")]
+    ApiKeyIsEmpty,
+
+    #[error("Failed to send a request to the service provider; can be invalid 'url', or 'api_key', or network IO problem: {0}")]
+    Request(#[from] reqwest::Error),
+
+    #[error("Can't process the body text from the response: {0}")]
+    JsonParse(#[from] serde_json::Error),
+
+    #[error("Provider server response error '{1}' for this url '{0}'.")]
+    Server(String, String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The API of Tickers, which defines all the functionality of Tickers modules.
+#[async_trait]
+pub trait Api {
+    /// Follows all the pages, and returns the tickers that match the request in ascending
+    /// order of ticker.
+    async fn get_tickers(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        request: &Request,
+    ) -> Result<Vec<Data>>;
+}
+
+/// All the dependencies of the Tickers modules to other modules.
+pub trait Depends {
+    fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync>;
+}
@@ -1,7 +1,6 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use chrono::NaiveDate;
 use network_mod_trades_io::trades as io;
 use snp_mod_io::snp;
 
@@ -13,8 +12,20 @@ impl io::Api for ServiceFake {
         &self,
         _deps: Arc<dyn io::Depends + Send + Sync>,
         _ticker: &snp::SnP,
-        _date: &NaiveDate,
+        _request: &io::Request,
     ) -> io::Result<()> {
         Ok(())
     }
+
+    async fn get_trades_page(
+        &self,
+        _deps: Arc<dyn io::Depends + Send + Sync>,
+        _ticker: &snp::SnP,
+        _request: &io::Request,
+    ) -> io::Result<io::Page> {
+        Ok(io::Page {
+            trades: vec![],
+            next_url: None,
+        })
+    }
 }
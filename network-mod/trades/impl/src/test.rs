@@ -1,7 +1,13 @@
+use std::collections::HashMap;
 use std::env;
 use std::sync::Arc;
 
 use chrono::NaiveDate;
+use network_mod_mock_server::{
+    mock_server::{MockServer, Route},
+    responses,
+};
+use rstest::rstest;
 use snp_mod_fixture::snp_fixture::ServiceFake;
 use snp_mod_io::snp;
 
@@ -21,7 +27,7 @@ async fn call_aapl_trades() -> io::Result<()> {
     let channel = service.channel.clone();
     let deps = given_deps();
 
-    let _ = tokio::spawn(async move {
+    let _handle = tokio::spawn(async move {
         let mut counter = 0;
         while counter < 2 {
             let response = channel.recv();
@@ -42,13 +48,280 @@ async fn call_aapl_trades() -> io::Result<()> {
         .get_trades(
             deps,
             &snp::SnP::AAPL,
-            &NaiveDate::parse_from_str("March 14, 2024", format).unwrap(),
+            &io::Request {
+                date: Some(NaiveDate::parse_from_str("March 14, 2024", format).unwrap()),
+                ..Default::default()
+            },
         )
         .await;
 
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn get_paginated_trades_from_mock_server() -> io::Result<()> {
+    let server = MockServer::start(vec![
+        Route::new("/v3/trades/AAPL", 200, responses::TRADES_AAPL_PAGE_2).with_query(
+            "cursor",
+            "YXA9MiZhcz0mbGltaXQ9MiZvcmRlcj1hc2Mmc29ydD10aW1lc3RhbXA",
+        ),
+        Route::new("/v3/trades/AAPL", 200, responses::TRADES_AAPL_PAGE_1)
+            .with_query("timestamp", "2024-03-14"),
+    ])
+    .await
+    .unwrap();
+    let service = given_service(&server);
+    let channel = service.channel.clone();
+    let pages = std::thread::spawn(move || vec![channel.recv().unwrap(), channel.recv().unwrap()]);
+
+    service
+        .get_trades(given_deps(), &snp::SnP::AAPL, &given_request())
+        .await?;
+
+    let pages = pages.join().unwrap();
+    assert_eq!(2, pages[0].len());
+    assert_eq!(1, pages[1].len());
+    assert_eq!("52983525027949", pages[0][1].id.as_ref());
+    assert_eq!(172.51, pages[0][1].price);
+    assert_eq!(3, pages[0][1].size);
+    assert_eq!(Some(1710403201320166000), pages[1][0].participant_timestamp);
+    assert_eq!(1710403201320493000, pages[1][0].sip_timestamp);
+    assert_eq!(1081, pages[1][0].sequence_number);
+    assert_eq!(1064, pages[0][1].sequence_number);
+    assert_eq!(Some(0), pages[0][1].correction);
+    assert_eq!(None, pages[0][0].correction);
+    assert_eq!(Some(1710403200205290000), pages[0][1].trf_timestamp);
+    assert_eq!(None, pages[1][0].trf_timestamp);
+    assert_eq!(4, pages[0][1].exchange);
+    assert_eq!(
+        Some("FINRA Alternative Display Facility"),
+        pages[0][1].exchange_name.as_deref()
+    );
+    assert_eq!(vec![12, 37], pages[0][1].conditions);
+    assert_eq!(
+        vec![None, Some("Odd Lot Trade")],
+        pages[0][1]
+            .condition_names
+            .iter()
+            .map(|c| c.as_deref())
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(Some(3), pages[0][1].tape);
+    assert_eq!(Some(201), pages[0][1].trf_id);
+    assert_eq!(Some("FINRA NYSE TRF"), pages[0][1].trf_name.as_deref());
+    // The exchange of Nasdaq isn't stored, and the trade has no condition.
+    assert_eq!(None, pages[1][0].exchange_name);
+    assert!(pages[1][0].condition_names.is_empty());
+    assert_eq!(None, pages[1][0].trf_id);
+    assert_eq!(2, server.requests().len());
+    Ok(())
+}
+
+// The cassette is written by hand in the format of the recorder, not recorded from Polygon.io.
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn replay_synthetic_aapl_trades_cassette() -> io::Result<()> {
+    let server = MockServer::cassette(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/cassettes/synthetic-aapl-2024-03-14.json"
+    ))
+    .await
+    .unwrap();
+    let api_key = env::var("API_KEY").unwrap_or_else(|_| "key".to_string());
+    let client = reqwest::Client::new();
+    let service = Service::with_host(Arc::new(client), &api_key, &server.host()).unwrap();
+    let channel = service.channel.clone();
+    let pages = std::thread::spawn(move || channel.iter().collect::<Vec<_>>());
+
+    service
+        .get_trades(given_deps(), &snp::SnP::AAPL, &given_request())
+        .await?;
+    // Dropping the service closes the channel, so the collector ends.
+    drop(service);
+
+    let pages = pages.join().unwrap();
+    assert!(pages.len() >= 2, "pages {:?}", pages.len());
+    assert!(pages.iter().all(|page| !page.is_empty()));
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_first_trades_page() -> io::Result<()> {
+    let server = MockServer::start(vec![Route::new(
+        "/v3/trades/AAPL",
+        200,
+        responses::TRADES_AAPL_PAGE_1,
+    )
+    .with_query("timestamp", "2024-03-14")
+    .with_query("sort", "timestamp")
+    .with_query("order", "asc")])
+    .await
+    .unwrap();
+    let service = given_service(&server);
+
+    let page = service
+        .get_trades_page(given_deps(), &snp::SnP::AAPL, &given_request())
+        .await?;
+
+    assert_eq!(2, page.trades.len());
+    assert_eq!(
+        Some(format!(
+            "{}/v3/trades/AAPL?cursor=YXA9MiZhcz0mbGltaXQ9MiZvcmRlcj1hc2Mmc29ydD10aW1lc3RhbXA",
+            server.host()
+        )),
+        page.next_url.map(|u| u.to_string())
+    );
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn get_trades_of_time_range() -> io::Result<()> {
+    let server = MockServer::start(vec![Route::new(
+        "/v3/trades/AAPL",
+        200,
+        responses::TRADES_AAPL_PAGE_2,
+    )
+    .with_query("timestamp.gte", "1710403201000000000")
+    .with_query("timestamp.lt", "1710403202000000000")
+    .with_query("limit", "50000")
+    .with_query("order", "desc")])
+    .await
+    .unwrap();
+    let service = given_service(&server);
+    let channel = service.channel.clone();
+    let pages = std::thread::spawn(move || channel.recv().unwrap());
+    let request = io::Request {
+        timestamp_gte: Some(1710403201000000000),
+        timestamp_lt: Some(1710403202000000000),
+        limit: Some(50000),
+        order: io::Order::Desc,
+        ..Default::default()
+    };
+
+    service
+        .get_trades(given_deps(), &snp::SnP::AAPL, &request)
+        .await?;
+
+    let page = pages.join().unwrap();
+    assert_eq!(1, page.len());
+    let requests = server.requests();
+    assert_eq!(1, requests.len());
+    assert!(!requests[0].query_pairs().any(|(k, _)| k == "timestamp"));
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn resume_trades_from_failed_page() -> io::Result<()> {
+    let cursor = "YXA9MiZhcz0mbGltaXQ9MiZvcmRlcj1hc2Mmc29ydD10aW1lc3RhbXA";
+    let failing = MockServer::start(vec![
+        Route::new("/v3/trades/AAPL", 429, responses::ERROR_TOO_MANY_REQUESTS)
+            .with_query("cursor", cursor),
+        Route::new("/v3/trades/AAPL", 200, responses::TRADES_AAPL_PAGE_1),
+    ])
+    .await
+    .unwrap();
+    let service = given_service(&failing);
+    let channel = service.channel.clone();
+    let first = std::thread::spawn(move || channel.recv().unwrap());
+
+    let response = service
+        .get_trades(given_deps(), &snp::SnP::AAPL, &given_request())
+        .await;
+
+    assert_eq!(2, first.join().unwrap().len());
+    let resumed_cursor = match response {
+        Err(io::Error::Server(url, _)) => io::cursor_of(&url),
+        r => panic!("unexpected response {:?}", r),
+    };
+    assert_eq!(Some(cursor), resumed_cursor.as_deref());
+
+    let server = MockServer::start(vec![Route::new(
+        "/v3/trades/AAPL",
+        200,
+        responses::TRADES_AAPL_PAGE_2,
+    )
+    .with_query("cursor", cursor)])
+    .await
+    .unwrap();
+    let service = given_service(&server);
+    let channel = service.channel.clone();
+    let rest = std::thread::spawn(move || channel.recv().unwrap());
+    let request = io::Request {
+        cursor: resumed_cursor,
+        ..given_request()
+    };
+
+    service
+        .get_trades(given_deps(), &snp::SnP::AAPL, &request)
+        .await?;
+
+    let page = rest.join().unwrap();
+    assert_eq!(1, page.len());
+    assert_eq!(1081, page[0].sequence_number);
+    assert_eq!(1, server.requests().len());
+    Ok(())
+}
+
+#[rstest]
+#[case(401, responses::ERROR_UNAUTHORIZED, "Unknown API Key")]
+#[case(
+    429,
+    responses::ERROR_TOO_MANY_REQUESTS,
+    "You've exceeded the maximum requests"
+)]
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn get_trades_server_error(#[case] status: u16, #[case] body: &str, #[case] message: &str) {
+    let server = MockServer::start(vec![Route::new("/v3/trades/AAPL", status, body)])
+        .await
+        .unwrap();
+    let service = given_service(&server);
+
+    let response = service
+        .get_trades(given_deps(), &snp::SnP::AAPL, &given_request())
+        .await;
+
+    match response {
+        Err(io::Error::Server(url, m)) => {
+            assert!(!url.contains("apiKey"), "url {:?}", url);
+            assert!(m.starts_with(message), "message {:?}", m);
+        }
+        r => panic!("unexpected response {:?}", r),
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn get_trades_malformed_json() {
+    let server = MockServer::start(vec![Route::new(
+        "/v3/trades/AAPL",
+        200,
+        responses::MALFORMED,
+    )])
+    .await
+    .unwrap();
+    let service = given_service(&server);
+
+    let response = service
+        .get_trades(given_deps(), &snp::SnP::AAPL, &given_request())
+        .await;
+
+    assert!(
+        matches!(response, Err(io::Error::JsonParse(_))),
+        "{:?}",
+        response
+    );
+}
+
+fn given_service(server: &MockServer) -> Service {
+    let client = reqwest::Client::new();
+    Service::with_host(Arc::new(client), "key", &server.host()).unwrap()
+}
+
+fn given_request() -> io::Request {
+    io::Request {
+        date: NaiveDate::from_ymd_opt(2024, 3, 14),
+        ..Default::default()
+    }
+}
+
 fn given_deps() -> Arc<dyn io::Depends + Send + Sync> {
     Arc::new(DependsFake {})
 }
@@ -59,4 +332,15 @@ impl io::Depends for DependsFake {
     fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync> {
         Arc::new(ServiceFake {})
     }
+
+    fn provide_names(&self) -> Arc<io::Names> {
+        Arc::new(io::Names {
+            exchanges: HashMap::from([
+                (4, "FINRA Alternative Display Facility".into()),
+                (201, "FINRA NYSE TRF".into()),
+            ]),
+            // Form T isn't stored, so its name is unknown.
+            conditions: HashMap::from([(37, "Odd Lot Trade".into())]),
+        })
+    }
 }
@@ -1,9 +1,8 @@
 use std::{collections::HashMap, sync::Arc};
 
 use async_trait::async_trait;
-use chrono::NaiveDate;
 use crossbeam::channel::{bounded, Receiver, Sender};
-use reqwest::{Client, StatusCode};
+use reqwest::{Client, StatusCode, Url};
 use serde::Deserialize;
 use snp_mod_io::snp::SnP;
 
@@ -14,9 +13,10 @@ use network_mod_trades_io::trades as io;
 #[allow(dead_code)]
 pub struct TradesResult {
     // A list of condition codes.
+    #[serde(default)]
     conditions: Vec<i32>,
     // The trade correction indicator.
-    correction: i32,
+    correction: Option<i32>,
     // The exchange ID.
     exchange: i32,
     // The Trade ID which uniquely identifies a trade. These are unique per combination of ticker,
@@ -25,7 +25,7 @@ pub struct TradesResult {
     id: Arc<str>,
     // The nanosecond accuracy Participant/Exchange Unix Timestamp. This is the timestamp of when
     // the trade was actually generated at the exchange.
-    participant_timestamp: i64,
+    participant_timestamp: Option<i64>,
     // The price of the trade. This is the actual dollar value per whole share of this trade. A
     // trade of 100 shares with a price of $2.00 would be worth a total dollar value of
     // $200.00.
@@ -43,7 +43,7 @@ pub struct TradesResult {
     // Tape A is NYSE listed securities
     // Tape B is NYSE ARCA / NYSE American
     // Tape C is NASDAQ
-    tape: i32,
+    tape: Option<i32>,
     // The ID for the Trade Reporting Facility where the trade took place.
     trf_id: Option<i32>,
     // The nanosecond accuracy TRF (Trade Reporting Facility) Unix Timestamp. This is the timestamp
@@ -67,6 +67,8 @@ pub struct TradesResponse {
 pub struct TradesError {
     status: Arc<str>,
     request_id: Arc<str>,
+    // Polygon.io names it `error` for authorization and rate limit failures.
+    #[serde(alias = "error")]
     message: Arc<str>,
 }
 
@@ -82,6 +84,11 @@ pub struct Service {
 
 impl Service {
     pub fn new(client: Arc<Client>, api_key: &str) -> io::Result<Self> {
+        Self::with_host(client, api_key, "https://api.polygon.io")
+    }
+
+    /// Creates the service against another host, e.g. a local mock server of Polygon.io.
+    pub fn with_host(client: Arc<Client>, api_key: &str, host: &str) -> io::Result<Self> {
         if api_key.is_empty() {
             return Err(io::Error::ApiKeyIsEmpty);
         }
@@ -90,7 +97,7 @@ impl Service {
         Ok(Self {
             client,
             api_key: api_key.into(),
-            url: "https://api.polygon.io/v3/trades/{TICKER}?sort=timestamp&order=asc".into(),
+            url: format!("{}/v3/trades/{{TICKER}}", host).into(),
             sender,
             channel,
         })
@@ -103,66 +110,141 @@ impl io::Api for Service {
         &self,
         deps: Arc<dyn io::Depends + Send + Sync>,
         ticker: &SnP,
-        date: &NaiveDate,
+        request: &io::Request,
     ) -> io::Result<()> {
-        let formatted_date = date.format("%Y-%m-%d").to_string();
+        let sender = &self.sender;
+        let mut request = request.clone();
+
+        loop {
+            let page = self.get_trades_page(deps.clone(), ticker, &request).await?;
+
+            let _ = sender.send(page.trades)?;
+            match page.next_url.as_deref().and_then(io::cursor_of) {
+                Some(cursor) => request.cursor = Some(cursor),
+                None => break,
+            }
+        }
+        Ok(())
+    }
+
+    async fn get_trades_page(
+        &self,
+        deps: Arc<dyn io::Depends + Send + Sync>,
+        ticker: &SnP,
+        request: &io::Request,
+    ) -> io::Result<io::Page> {
+        let date = request.date.map(|d| d.format("%Y-%m-%d").to_string());
+        let timestamp_gte = request.timestamp_gte.map(|t| t.to_string());
+        let timestamp_lt = request.timestamp_lt.map(|t| t.to_string());
+        let limit = request.limit.map(|l| l.to_string());
 
         let mut params = HashMap::new();
 
         params.insert("apiKey", self.api_key.as_ref());
-        params.insert("timestamp", &formatted_date[..]);
+        if let Some(date) = &date {
+            params.insert("timestamp", date.as_ref());
+        }
+        if let Some(timestamp_gte) = &timestamp_gte {
+            params.insert("timestamp.gte", timestamp_gte.as_ref());
+        }
+        if let Some(timestamp_lt) = &timestamp_lt {
+            params.insert("timestamp.lt", timestamp_lt.as_ref());
+        }
+        match &request.cursor {
+            Some(cursor) => {
+                // The cursor keeps the order and the limit of the first page.
+                params.insert("cursor", cursor.as_ref());
+            }
+            None => {
+                if let Some(limit) = &limit {
+                    params.insert("limit", limit.as_ref());
+                }
+                params.insert("sort", "timestamp");
+                params.insert("order", request.order.as_str());
+            }
+        }
 
         let snp = deps.provide_snp();
         let snp_symbol_rc = snp.get_symbol(ticker).clone();
         let snp_symbol = &snp_symbol_rc[..];
-        dbg!(&snp_symbol);
 
-        let client = &self.client;
-        let sender = &self.sender;
-        let mut url: String = self.url.to_string().replace("{TICKER}", snp_symbol);
-        dbg!(&formatted_date);
-        dbg!(&self.api_key);
-
-        while !url.is_empty() {
-            dbg!(&url);
-
-            let response = client.get(&url).query(&params).send().await?;
-            let status_code = response.status();
-            dbg!(&status_code);
-
-            let response_body = &response.text().await?;
-            dbg!(&response_body);
-
-            if status_code == StatusCode::OK {
-                let trades_data: TradesResponse =
-                    serde_json::from_str(response_body).map_err(io::Error::JsonParse)?;
-
-                let result = trades_data
-                    .results
-                    .into_iter()
-                    .map(|t| io::Data {
-                        id: t.id,
-                        price: t.price,
-                        size: t.size,
-                        participant_timestamp: t.participant_timestamp,
-                    })
-                    .collect();
-
-                let _ = sender.send(result)?;
-                url = if let Some(u) = trades_data.next_url {
-                    u.to_string()
-                } else {
-                    "".to_string()
-                };
-            } else {
-                let error_data: TradesError = serde_json::from_str(response_body)?;
-                dbg!(&error_data);
-
-                return Err(
-                    io::Error::Server(url.to_string(), error_data.message.to_string()).into(),
-                );
-            }
+        let names = deps.provide_names();
+
+        let url = self
+            .client
+            .get(self.url.replace("{TICKER}", snp_symbol))
+            .query(&params)
+            .build()?
+            .url()
+            .clone();
+
+        let response = self.client.get(url.clone()).send().await?;
+        let status_code = response.status();
+        dbg!(&status_code);
+
+        let response_body = &response.text().await?;
+
+        if status_code == StatusCode::OK {
+            let trades_data: TradesResponse =
+                serde_json::from_str(response_body).map_err(io::Error::JsonParse)?;
+
+            let trades = trades_data
+                .results
+                .into_iter()
+                .map(|t| io::Data {
+                    id: t.id,
+                    price: t.price,
+                    size: t.size,
+                    participant_timestamp: t.participant_timestamp,
+                    sip_timestamp: t.sip_timestamp,
+                    sequence_number: t.sequence_number,
+                    exchange: t.exchange,
+                    exchange_name: names.exchanges.get(&t.exchange).cloned(),
+                    condition_names: t
+                        .conditions
+                        .iter()
+                        .map(|c| names.conditions.get(c).cloned())
+                        .collect(),
+                    conditions: t.conditions,
+                    correction: t.correction,
+                    tape: t.tape,
+                    trf_id: t.trf_id,
+                    trf_name: t.trf_id.and_then(|id| names.exchanges.get(&id).cloned()),
+                    trf_timestamp: t.trf_timestamp,
+                })
+                .collect();
+
+            Ok(io::Page {
+                trades,
+                next_url: trades_data
+                    .next_url
+                    .and_then(|u| Url::parse(&u).ok())
+                    .map(|u| without_api_key(&u).into()),
+            })
+        } else {
+            let error_data: TradesError = serde_json::from_str(response_body)?;
+            dbg!(&error_data);
+
+            Err(io::Error::Server(
+                without_api_key(&url),
+                error_data.message.to_string(),
+            ))
         }
-        Ok(())
     }
 }
+
+/// Returns the URL without the `apiKey`, so it can be logged or stored.
+fn without_api_key(url: &Url) -> String {
+    let mut url = url.clone();
+    let pairs: Vec<(String, String)> = url
+        .query_pairs()
+        .filter(|(k, _)| k != "apiKey")
+        .map(|(k, v)| (k.into_owned(), v.into_owned()))
+        .collect();
+    if pairs.is_empty() {
+        url.set_query(None);
+    } else {
+        url.query_pairs_mut().clear().extend_pairs(pairs);
+    }
+    url.to_string()
+}
@@ -1,18 +1,97 @@
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 use async_trait::async_trait;
 use chrono::NaiveDate;
 use serde::Deserialize;
 use snp_mod_io::snp;
 
+/// The trades to request, where `None` means no filter.
+#[derive(Debug, Clone, Default)]
+pub struct Request {
+    /// Requests the trades of the day, which is sent as `timestamp`.
+    pub date: Option<NaiveDate>,
+    /// The nanosecond Unix timestamp of the first trade, inclusive.
+    pub timestamp_gte: Option<i64>,
+    /// The nanosecond Unix timestamp of the last trade, exclusive.
+    pub timestamp_lt: Option<i64>,
+    /// The number of trades of a page, up to 50000.
+    pub limit: Option<u32>,
+    pub order: Order,
+    /// Resumes from the page of this cursor, instead of the first page.
+    pub cursor: Option<Arc<str>>,
+}
+
+/// The order of the trades by their SIP timestamp.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Order {
+    #[default]
+    Asc,
+    Desc,
+}
+
+impl Order {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Order::Asc => "asc",
+            Order::Desc => "desc",
+        }
+    }
+}
+
+/// Returns the cursor of a page URL, like the one of `Error::Server`, to resume from.
+pub fn cursor_of(url: &str) -> Option<Arc<str>> {
+    reqwest::Url::parse(url)
+        .ok()?
+        .query_pairs()
+        .find(|(k, _)| k == "cursor")
+        .map(|(_, v)| v.as_ref().into())
+}
+
+/// A page of trades.
+#[derive(Debug)]
+pub struct Page {
+    pub trades: Vec<Data>,
+    /// The URL of the next page without the `apiKey`, where `None` means the last page.
+    pub next_url: Option<Arc<str>>,
+}
+
 /// The data that is needed to interact with the Trades modules.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[allow(dead_code)]
 pub struct Data {
     pub id: Arc<str>,
     pub price: f64,
     pub size: i32,
-    pub participant_timestamp: i64,
+    /// The nanosecond Unix timestamp of when the exchange generated the trade.
+    pub participant_timestamp: Option<i64>,
+    /// The nanosecond Unix timestamp of when the SIP received the trade.
+    pub sip_timestamp: i64,
+    /// Increasing and unique per ticker in a session, but not always sequential.
+    pub sequence_number: i64,
+    /// The ID of the exchange, as in the exchanges reference.
+    pub exchange: i32,
+    pub exchange_name: Option<Arc<str>>,
+    /// The IDs of the conditions, as in the conditions reference.
+    pub conditions: Vec<i32>,
+    /// The names of the conditions, in the order of `conditions`, where `None` is an unknown one.
+    pub condition_names: Vec<Option<Arc<str>>>,
+    /// The correction indicator, where `None` and 0 mean the trade isn't corrected.
+    pub correction: Option<i32>,
+    /// The tape of the listing exchange, where 1 is A for NYSE, 2 is B for NYSE Arca and
+    /// NYSE American, and 3 is C for Nasdaq.
+    pub tape: Option<i32>,
+    /// The ID of the trade reporting facility, as in the exchanges reference.
+    pub trf_id: Option<i32>,
+    pub trf_name: Option<Arc<str>>,
+    /// The nanosecond Unix timestamp of when the trade reporting facility received the trade.
+    pub trf_timestamp: Option<i64>,
+}
+
+/// The names of the exchanges and the conditions by their IDs, to decode the trades.
+#[derive(Debug, Clone, Default)]
+pub struct Names {
+    pub exchanges: HashMap<i32, Arc<str>>,
+    pub conditions: HashMap<i32, Arc<str>>,
 }
 
 /// All possible errors of Trades modules.
@@ -39,16 +118,26 @@ pub type Result<T> = std::result::Result<T, Error>;
 /// The API of Trades, which defines all the functionality of Trades modules.
 #[async_trait]
 pub trait Api {
+    /// Follows all the pages, and sends the trades of each page over the channel of the service.
     async fn get_trades(
         &self,
         deps: Arc<dyn Depends + Send + Sync>,
         ticker: &snp::SnP,
-        date: &NaiveDate,
+        request: &Request,
     ) -> Result<()>;
+
+    /// Requests one page, where the `cursor` of the request selects a page after the first.
+    async fn get_trades_page(
+        &self,
+        deps: Arc<dyn Depends + Send + Sync>,
+        ticker: &snp::SnP,
+        request: &Request,
+    ) -> Result<Page>;
 }
 
 /// All the dependencies of the Trades modules to other modules.
 pub trait Depends {
     fn provide_snp(&self) -> Arc<dyn snp::Api + Send + Sync>;
+    /// The names of the stored exchanges and conditions.
+    fn provide_names(&self) -> Arc<Names>;
 }
-
@@ -36,6 +36,7 @@ pkgs.mkShell {
   DB_PORT = "${toString localConfig.dbPort}";
   POLICY_COOL_DOWN_TIME = "${toString localConfig.policyCoolDownTime}";
   POLICY_ALLOWED_REQUESTS = "${toString localConfig.policyAllowedRequests}";
+  CACHE_DIR = "${localConfig.cacheDir or ""}";
 
   shellHook = ''
     # Write out local.properties for Android Studio.
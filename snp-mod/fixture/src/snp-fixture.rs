@@ -21,4 +21,8 @@ impl io::Api for ServiceFake {
     fn get_snp_by_date(&self, _date: &NaiveDate) -> Vec<io::SnP> {
         vec![io::SnP::AAPL]
     }
+
+    fn get_all_snp(&self) -> Vec<io::SnP> {
+        vec![io::SnP::AAPL]
+    }
 }
@@ -9,8 +9,8 @@ use crate::{
 use snp_mod_io::snp::SnP;
 
 pub fn get_snp_by_date(date: &NaiveDate) -> Vec<SnP> {
-    let set = BTreeSet::from_iter(SnPList::VALUES2024.into_iter());
-    let snps = get_all_updates()
+    let set = BTreeSet::from_iter(SnPList::VALUES2024);
+    get_all_updates()
         .into_iter()
         .filter(|update| update.date > *date)
         .fold(set, |mut acc, update| {
@@ -18,13 +18,29 @@ pub fn get_snp_by_date(date: &NaiveDate) -> Vec<SnP> {
                 acc.insert(t);
             }
             if let SnpTicker::Available(t) = update.added_ticker {
-                if acc.contains(&t) == true {
+                if acc.contains(&t) {
                     acc.remove(&t);
                 }
             }
             acc
         })
         .into_iter()
-        .collect();
-    snps
+        .collect()
+}
+
+pub fn get_all_snp() -> Vec<SnP> {
+    let set = BTreeSet::from_iter(SnPList::VALUES2024);
+    get_all_updates()
+        .into_iter()
+        .fold(set, |mut acc, update| {
+            if let SnpTicker::Available(t) = update.removed_ticker {
+                acc.insert(t);
+            }
+            if let SnpTicker::Available(t) = update.added_ticker {
+                acc.insert(t);
+            }
+            acc
+        })
+        .into_iter()
+        .collect()
 }
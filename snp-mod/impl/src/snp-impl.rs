@@ -4,7 +4,7 @@ use std::{collections::HashMap, fmt, rc::Rc, sync::Arc};
 
 use snp_mod_io::snp as io;
 
-use crate::snp_by_date::get_snp_by_date;
+use crate::snp_by_date::{get_all_snp, get_snp_by_date};
 
 #[derive(Debug, Serialize, Deserialize)]
 struct SnpStockUnformatted<'a> {
@@ -1372,6 +1372,12 @@ impl Service {
     }
 }
 
+impl Default for Service {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl io::Api for Service {
     fn get_snp(&self, ticker: &str) -> io::Result<io::SnP> {
         get_snp(ticker)
@@ -1388,6 +1394,10 @@ impl io::Api for Service {
     fn get_snp_by_date(&self, date: &NaiveDate) -> Vec<io::SnP> {
         get_snp_by_date(date)
     }
+
+    fn get_all_snp(&self) -> Vec<io::SnP> {
+        get_all_snp()
+    }
 }
 
 pub fn get_snp(ticker: &str) -> io::Result<io::SnP> {
@@ -2228,9 +2238,9 @@ impl fmt::Display for WrapSnP {
     }
 }
 
-impl Into<WrapSnP> for io::SnP {
-    fn into(self) -> WrapSnP {
-        WrapSnP(self)
+impl From<io::SnP> for WrapSnP {
+    fn from(snp: io::SnP) -> Self {
+        WrapSnP(snp)
     }
 }
 
@@ -3088,8 +3098,7 @@ fn format_snp(
                 stock.date_added
             );
             FormatError::ParseError(e)
-        })?
-        .into();
+        })?;
     Ok((
         snp,
         io::Data {
@@ -63,10 +63,9 @@ fn format_update<'a>(update: &SnPUpdateUnformatted<'a>) -> Result<SnPUpdate, Err
         .map_err(|e| {
             println!("parsing error happened: '{}'", update.date);
             Error::ParseError(e)
-        })?
-        .into();
+        })?;
 
-    let added_ticker = if update.added_ticker == "" {
+    let added_ticker = if update.added_ticker.is_empty() {
         SnpTicker::Empty
     } else {
         let ticker = get_snp(update.added_ticker).map_err(|e| {
@@ -76,7 +75,7 @@ fn format_update<'a>(update: &SnPUpdateUnformatted<'a>) -> Result<SnPUpdate, Err
         SnpTicker::Available(ticker)
     };
 
-    let removed_ticker = if update.removed_ticker == "" {
+    let removed_ticker = if update.removed_ticker.is_empty() {
         SnpTicker::Empty
     } else {
         let ticker = get_snp(update.removed_ticker).map_err(|e| {
@@ -1,9 +1,9 @@
-use crate::snp_impl::{get_snp, get_snp_map};
+use crate::snp_impl::{get_snp, get_snp_map, Service};
 use crate::snp_update::{format_all_updates, get_all_updates, get_all_updates_unformatted};
 use chrono::NaiveDate;
-use snp_mod_io::snp::SnP;
+use snp_mod_io::snp::{Instrument, SnP};
 
-use crate::snp_by_date::get_snp_by_date;
+use crate::snp_by_date::{get_all_snp, get_snp_by_date};
 use rstest::rstest;
 
 #[test]
@@ -52,3 +52,38 @@ fn call_count_get_snp_by_date(#[case] date: &str, #[case] count: usize) {
     let list = get_snp_by_date(&NaiveDate::parse_from_str(date, format).unwrap());
     assert_eq!(count, list.len())
 }
+
+#[test]
+fn all_snp_covers_every_date() {
+    let format = "%B %e, %Y";
+    let all = get_all_snp();
+    for date in ["June 17, 1997", "December 7, 1999", "March 19, 2018"] {
+        let list = get_snp_by_date(&NaiveDate::parse_from_str(date, format).unwrap());
+        assert!(list.iter().all(|t| all.binary_search(t).is_ok()));
+    }
+    assert!(all.len() > 503);
+}
+
+#[rstest]
+#[case("AAPL", Instrument::Stock(SnP::AAPL))]
+#[case("O:AAPL240621C00190000", Instrument::Option("O:AAPL240621C00190000".into()))]
+#[case("X:BTCUSD", Instrument::Crypto { base: "BTC".into(), quote: "USD".into() })]
+#[case("X:ETHUSDT", Instrument::Crypto { base: "ETH".into(), quote: "USDT".into() })]
+#[case("C:EURUSD", Instrument::Forex { base: "EUR".into(), quote: "USD".into() })]
+#[case("I:SPX", Instrument::Index("I:SPX".into()))]
+fn parse_instrument(#[case] ticker: &str, #[case] instrument: Instrument) {
+    let service = Service::new();
+
+    assert_eq!(instrument, Instrument::parse(ticker, &service).unwrap());
+    assert_eq!(ticker, instrument.ticker(&service).as_ref());
+}
+
+#[rstest]
+#[case("ZVZZT")]
+#[case("C:USD")]
+#[case("X:BTC-USD")]
+#[case("I:")]
+#[case("Z:SPX")]
+fn parse_unknown_instrument(#[case] ticker: &str) {
+    assert!(Instrument::parse(ticker, &Service::new()).is_err());
+}
@@ -846,6 +846,114 @@ pub enum SnP {
     FSR,
 }
 
+/// The indices that are crawled and stored beside the equities.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord, Clone, Copy)]
+pub enum Index {
+    /// The S&P 500, whose constituents are the `SnP`.
+    SPX,
+    /// The Nasdaq-100.
+    NDX,
+    /// The Dow Jones Industrial Average.
+    DJI,
+}
+
+impl Index {
+    pub const ALL: [Index; 3] = [Index::SPX, Index::NDX, Index::DJI];
+
+    /// The ticker of the index in Polygon.io, like `I:SPX`.
+    pub fn ticker(&self) -> &'static str {
+        match self {
+            Index::SPX => "I:SPX",
+            Index::NDX => "I:NDX",
+            Index::DJI => "I:DJI",
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Index::SPX => "S&P 500",
+            Index::NDX => "Nasdaq-100",
+            Index::DJI => "Dow Jones Industrial Average",
+        }
+    }
+
+    /// Returns the index of a ticker like `I:SPX`.
+    pub fn from_ticker(ticker: &str) -> Result<Self> {
+        Index::ALL
+            .into_iter()
+            .find(|index| index.ticker() == ticker)
+            .ok_or_else(|| Error::NotFound(ticker.to_string()))
+    }
+}
+
+/// An instrument of Polygon.io, by which the network modules request the markets beyond the
+/// S&P 500 equities.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Instrument {
+    Stock(SnP),
+    /// An options contract by its ticker, e.g. `O:AAPL240621C00190000`.
+    Option(Arc<str>),
+    /// A crypto pair, e.g. `X:BTCUSD` is BTC priced in USD.
+    Crypto {
+        base: Arc<str>,
+        quote: Arc<str>,
+    },
+    /// A currency pair, e.g. `C:EURUSD` is EUR priced in USD.
+    Forex {
+        base: Arc<str>,
+        quote: Arc<str>,
+    },
+    /// An index by its ticker, e.g. `I:SPX`.
+    Index(Arc<str>),
+}
+
+/// The quote currencies of the crypto pairs that are longer than three letters.
+const LONG_CRYPTO_QUOTES: [&str; 2] = ["USDT", "USDC"];
+
+impl Instrument {
+    /// Parses a ticker of Polygon.io, like `AAPL`, `X:BTCUSD`, `C:EURUSD` or `I:SPX`, where a
+    /// stock must have been a member of the S&P 500.
+    pub fn parse(ticker: &str, snp: &dyn Api) -> Result<Self> {
+        let not_found = || Error::NotFound(ticker.to_string());
+        match ticker.split_once(':') {
+            None => Ok(Instrument::Stock(snp.get_snp(ticker)?)),
+            Some(("O", contract)) if !contract.is_empty() => Ok(Instrument::Option(ticker.into())),
+            Some(("I", index)) if !index.is_empty() => Ok(Instrument::Index(ticker.into())),
+            Some(("X", pair)) => {
+                let quote_len = LONG_CRYPTO_QUOTES
+                    .iter()
+                    .find(|quote| pair.ends_with(*quote))
+                    .map_or(3, |quote| quote.len());
+                let (base, quote) = split_pair(pair, quote_len).ok_or_else(not_found)?;
+                Ok(Instrument::Crypto { base, quote })
+            }
+            Some(("C", pair)) => {
+                let (base, quote) = split_pair(pair, 3).ok_or_else(not_found)?;
+                Ok(Instrument::Forex { base, quote })
+            }
+            _ => Err(not_found()),
+        }
+    }
+
+    /// Returns the ticker of Polygon.io, like `AAPL` or `X:BTCUSD`.
+    pub fn ticker(&self, snp: &dyn Api) -> Arc<str> {
+        match self {
+            Instrument::Stock(ticker) => snp.get_symbol(ticker),
+            Instrument::Option(ticker) | Instrument::Index(ticker) => ticker.clone(),
+            Instrument::Crypto { base, quote } => format!("X:{}{}", base, quote).into(),
+            Instrument::Forex { base, quote } => format!("C:{}{}", base, quote).into(),
+        }
+    }
+}
+
+fn split_pair(pair: &str, quote_len: usize) -> Option<(Arc<str>, Arc<str>)> {
+    if pair.len() <= quote_len || !pair.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return None;
+    }
+    let (base, quote) = pair.split_at(pair.len() - quote_len);
+    Some((base.into(), quote.into()))
+}
+
 pub type Result<T> = std::result::Result<T, Error>;
 
 /// The API of SnP, which defines all the functionality of SnP modules.
@@ -854,6 +962,8 @@ pub trait Api {
     fn get_symbol(&self, snp: &SnP) -> Arc<str>;
     fn get_snp_map(&self) -> HashMap<SnP, Data>;
     fn get_snp_by_date(&self, date: &NaiveDate) -> Vec<SnP>;
+    /// Returns every ticker that has ever been a member, sorted.
+    fn get_all_snp(&self) -> Vec<SnP>;
 }
 
 /// All possible errors of SnP modules.